@@ -0,0 +1,555 @@
+//! Ciphersuites as defined in the IETF draft. A ciphersuite fixes the hash function used by `hash_to_scalar`,
+//! the hash-to-curve suite used to create the generators and the octet encoding of points and scalars.
+//! The generators created by a ciphersuite are returned as `SignatureParamsG1` so that the keys and
+//! parameters are the same types as used by BBS+.
+
+use crate::error::BBSPlusError;
+use crate::setup::{PublicKeyG2, SecretKey, SignatureParamsG1};
+use ark_bls12_381::{Bls12_381, Fq, Fq2, G1Affine, G1Projective, G2Affine};
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{BigInteger, FromBytes, PrimeField, Zero};
+use ark_std::{any::Any, vec, vec::Vec};
+use dock_crypto_utils::hashing_utils::{bls12_381::hash_to_g1, expand_message_xmd};
+use sha2::Sha256;
+
+/// Ciphersuite of the BBS signature scheme. Only the methods without a default implementation are
+/// specific to the ciphersuite, the rest are the operations of the draft that depend on them.
+pub trait Ciphersuite<E: PairingEngine> {
+    /// The ciphersuite identifier, `ciphersuite_id` in the draft
+    const CIPHERSUITE_ID: &'static [u8];
+    /// Number of bytes produced by `expand_message` when hashing to a scalar or creating generators
+    const EXPAND_LEN: usize;
+
+    /// `expand_message` as defined in section 5.3 of RFC 9380
+    fn expand_message(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8>;
+
+    /// Hash to a point in the prime order subgroup of G1 as defined in section 3 of RFC 9380
+    fn hash_to_g1(msg: &[u8], dst: &[u8]) -> E::G1Projective;
+
+    /// Compressed octet encoding of a G1 element
+    fn g1_to_octets(point: &E::G1Affine) -> Vec<u8>;
+
+    /// Compressed octet encoding of a G2 element
+    fn g2_to_octets(point: &E::G2Affine) -> Vec<u8>;
+
+    /// Decode a G1 element from its compressed octet encoding. Checks that the point is in the
+    /// prime order subgroup.
+    fn octets_to_g1(bytes: &[u8]) -> Result<E::G1Affine, BBSPlusError>;
+
+    /// Decode a G2 element from its compressed octet encoding. Checks that the point is in the
+    /// prime order subgroup.
+    fn octets_to_g2(bytes: &[u8]) -> Result<E::G2Affine, BBSPlusError>;
+
+    /// Identifier of the interface, `api_id` in the draft. This crate only supports the interface where
+    /// generators are created with hash-to-curve and messages are mapped to scalars by hashing
+    fn api_id() -> Vec<u8> {
+        [Self::CIPHERSUITE_ID, b"H2G_HM2S_"].concat()
+    }
+
+    /// `hash_to_scalar` from the draft. Returns `OS2IP(expand_message(msg, dst, expand_len)) mod r`
+    fn hash_to_scalar(msg: &[u8], dst: &[u8]) -> E::Fr {
+        E::Fr::from_be_bytes_mod_order(&Self::expand_message(msg, dst, Self::EXPAND_LEN))
+    }
+
+    /// Big-endian octet encoding of a scalar, `I2OSP(scalar, octet_scalar_length)` in the draft
+    fn scalar_to_octets(scalar: &E::Fr) -> Vec<u8> {
+        scalar.into_repr().to_bytes_be()
+    }
+
+    /// Decode a scalar from its big-endian octet encoding. Fails if the integer is not less than the
+    /// order of the scalar field.
+    fn octets_to_scalar(bytes: &[u8]) -> Result<E::Fr, BBSPlusError> {
+        if bytes.len() != Self::scalar_to_octets(&E::Fr::zero()).len() {
+            return Err(BBSPlusError::InvalidOctetEncoding);
+        }
+        let mut le_bytes = bytes.to_vec();
+        le_bytes.reverse();
+        let repr = <E::Fr as PrimeField>::BigInt::read(le_bytes.as_slice())
+            .map_err(|_| BBSPlusError::InvalidOctetEncoding)?;
+        E::Fr::from_repr(repr).ok_or(BBSPlusError::InvalidOctetEncoding)
+    }
+
+    /// Generate the secret key from the secret `key_material` which must be at least 32 bytes. `key_info`
+    /// is optional public data and `key_dst` defaults to `api_id || "KEYGEN_DST_"`.
+    fn keygen(
+        key_material: &[u8],
+        key_info: &[u8],
+        key_dst: Option<&[u8]>,
+    ) -> Result<SecretKey<E::Fr>, BBSPlusError> {
+        if key_material.len() < 32 {
+            return Err(BBSPlusError::InsufficientKeyMaterial(key_material.len()));
+        }
+        if key_info.len() > 65535 {
+            return Err(BBSPlusError::KeyInfoTooLong(key_info.len()));
+        }
+        let default_dst = [Self::api_id().as_slice(), b"KEYGEN_DST_"].concat();
+        let key_dst = key_dst.unwrap_or(&default_dst);
+        // derive_input = key_material || I2OSP(length(key_info), 2) || key_info
+        let derive_input = [
+            key_material,
+            &(key_info.len() as u16).to_be_bytes(),
+            key_info,
+        ]
+        .concat();
+        let sk = Self::hash_to_scalar(&derive_input, key_dst);
+        if sk.is_zero() {
+            return Err(BBSPlusError::ZeroSecretKey);
+        }
+        Ok(SecretKey(sk))
+    }
+
+    /// Map the octet string messages to scalars by hashing them, `messages_to_scalars` in the draft
+    fn messages_to_scalars(messages: &[&[u8]]) -> Vec<E::Fr> {
+        let map_dst = [Self::api_id().as_slice(), b"MAP_MSG_TO_SCALAR_AS_HASH_"].concat();
+        messages
+            .iter()
+            .map(|m| Self::hash_to_scalar(m, &map_dst))
+            .collect()
+    }
+
+    /// Create `count` generators from the given seed, `hash_to_generators` in the draft
+    fn hash_to_generators(count: usize, generator_seed: &[u8], api_id: &[u8]) -> Vec<E::G1Affine> {
+        let seed_dst = [api_id, b"SIG_GENERATOR_SEED_"].concat();
+        let generator_dst = [api_id, b"SIG_GENERATOR_DST_"].concat();
+        let mut v = Self::expand_message(generator_seed, &seed_dst, Self::EXPAND_LEN);
+        let generators = (1..=count as u64)
+            .map(|i| {
+                v = Self::expand_message(
+                    &[v.as_slice(), &i.to_be_bytes()].concat(),
+                    &seed_dst,
+                    Self::EXPAND_LEN,
+                );
+                Self::hash_to_g1(&v, &generator_dst)
+            })
+            .collect::<Vec<_>>();
+        E::G1Projective::batch_normalization_into_affine(&generators)
+    }
+
+    /// The base point `P1` of G1 used in the signature instead of the standard generator.
+    fn p1() -> E::G1Affine {
+        Self::hash_to_generators(
+            1,
+            &[Self::api_id().as_slice(), b"BP_MESSAGE_GENERATOR_SEED"].concat(),
+            &Self::api_id(),
+        )
+        .remove(0)
+    }
+
+    /// Create the signature parameters for signing `message_count` messages. `g1` is `P1`, `g2` is the
+    /// standard generator of G2 and `h_0` and `h` are the generators `Q_1` and `H_1, ..., H_L` respectively.
+    /// Parameters for fewer messages are a prefix of the parameters for more messages.
+    fn signature_params(message_count: usize) -> SignatureParamsG1<E> {
+        assert_ne!(message_count, 0);
+        let api_id = Self::api_id();
+        let mut h = Self::hash_to_generators(
+            message_count + 1,
+            &[api_id.as_slice(), b"MESSAGE_GENERATOR_SEED"].concat(),
+            &api_id,
+        );
+        let h_0 = h.remove(0);
+        SignatureParamsG1 {
+            g1: Self::p1(),
+            g2: E::G2Affine::prime_subgroup_generator(),
+            h_0,
+            h,
+        }
+    }
+
+    /// Compute the `domain` which binds the signature or proof to the public key, the parameters and
+    /// the `header`, `calculate_domain` in the draft
+    fn calculate_domain(
+        pk: &PublicKeyG2<E>,
+        params: &SignatureParamsG1<E>,
+        header: &[u8],
+    ) -> E::Fr {
+        let api_id = Self::api_id();
+        // dom_input = PK || I2OSP(L, 8) || Q_1 || H_1 || ... || H_L || api_id || I2OSP(length(header), 8) || header
+        let mut dom_input = Self::g2_to_octets(&pk.0);
+        dom_input.extend_from_slice(&(params.h.len() as u64).to_be_bytes());
+        dom_input.append(&mut Self::g1_to_octets(&params.h_0));
+        for h in &params.h {
+            dom_input.append(&mut Self::g1_to_octets(h));
+        }
+        dom_input.extend_from_slice(&api_id);
+        dom_input.extend_from_slice(&(header.len() as u64).to_be_bytes());
+        dom_input.extend_from_slice(header);
+        Self::hash_to_scalar(&dom_input, &[api_id.as_slice(), b"H2S_"].concat())
+    }
+}
+
+/// The ciphersuite `BLS12-381-SHA-256`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bls12381Sha256;
+
+impl Ciphersuite<Bls12_381> for Bls12381Sha256 {
+    const CIPHERSUITE_ID: &'static [u8] = b"BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_";
+    const EXPAND_LEN: usize = 48;
+
+    fn expand_message(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+        expand_message_xmd::<Sha256>(msg, dst, len_in_bytes)
+    }
+
+    fn hash_to_g1(msg: &[u8], dst: &[u8]) -> G1Projective {
+        hash_to_g1::<Sha256>(msg, dst)
+    }
+
+    fn g1_to_octets(point: &G1Affine) -> Vec<u8> {
+        if point.is_zero() {
+            return compressed_infinity(48);
+        }
+        let mut bytes = point.x.into_repr().to_bytes_be();
+        set_flags(&mut bytes, point.y > -point.y);
+        bytes
+    }
+
+    fn g2_to_octets(point: &G2Affine) -> Vec<u8> {
+        if point.is_zero() {
+            return compressed_infinity(96);
+        }
+        // x = x_0 + x_1 * u is encoded as x_1 || x_0
+        let mut bytes = point.x.c1.into_repr().to_bytes_be();
+        bytes.append(&mut point.x.c0.into_repr().to_bytes_be());
+        set_flags(&mut bytes, point.y > -point.y);
+        bytes
+    }
+
+    fn octets_to_g1(bytes: &[u8]) -> Result<G1Affine, BBSPlusError> {
+        if bytes.len() != 48 {
+            return Err(BBSPlusError::InvalidOctetEncoding);
+        }
+        let (mut x_bytes, greatest) = match read_flags(bytes)? {
+            Some(f) => f,
+            None => return Ok(G1Affine::zero()),
+        };
+        let x = fq_from_be_bytes(&mut x_bytes)?;
+        let point =
+            G1Affine::get_point_from_x(x, greatest).ok_or(BBSPlusError::InvalidOctetEncoding)?;
+        if !point.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(BBSPlusError::InvalidOctetEncoding);
+        }
+        Ok(point)
+    }
+
+    fn octets_to_g2(bytes: &[u8]) -> Result<G2Affine, BBSPlusError> {
+        if bytes.len() != 96 {
+            return Err(BBSPlusError::InvalidOctetEncoding);
+        }
+        let (x_bytes, greatest) = match read_flags(bytes)? {
+            Some(f) => f,
+            None => return Ok(G2Affine::zero()),
+        };
+        let (c1, c0) = x_bytes.split_at(48);
+        let x = Fq2::new(
+            fq_from_be_bytes(&mut c0.to_vec())?,
+            fq_from_be_bytes(&mut c1.to_vec())?,
+        );
+        let point =
+            G2Affine::get_point_from_x(x, greatest).ok_or(BBSPlusError::InvalidOctetEncoding)?;
+        if !point.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(BBSPlusError::InvalidOctetEncoding);
+        }
+        Ok(point)
+    }
+}
+
+/// Compute the `domain` with the ciphersuite of this crate whose identifier is `ciphersuite_id`. Used when the
+/// ciphersuite is only known at runtime, like from a deserialized statement. Fails if there is no ciphersuite with
+/// that identifier for the pairing `E`.
+pub fn calculate_domain_for_ciphersuite<E: PairingEngine>(
+    ciphersuite_id: &[u8],
+    pk: &PublicKeyG2<E>,
+    params: &SignatureParamsG1<E>,
+    header: &[u8],
+) -> Result<E::Fr, BBSPlusError> {
+    if ciphersuite_id == <Bls12381Sha256 as Ciphersuite<Bls12_381>>::CIPHERSUITE_ID {
+        // The ciphersuite is only implemented for BLS12-381 so `E` must be `Bls12_381`
+        if let (Some(pk), Some(params)) = (
+            (pk as &dyn Any).downcast_ref::<PublicKeyG2<Bls12_381>>(),
+            (params as &dyn Any).downcast_ref::<SignatureParamsG1<Bls12_381>>(),
+        ) {
+            let domain = Bls12381Sha256::calculate_domain(pk, params, header);
+            if let Some(domain) = (&domain as &dyn Any).downcast_ref::<E::Fr>() {
+                return Ok(*domain);
+            }
+        }
+    }
+    Err(BBSPlusError::UnsupportedCiphersuite(
+        ciphersuite_id.to_vec(),
+    ))
+}
+
+// Flags in the most significant bits of the compressed encoding of BLS12-381 points as described in
+// <https://github.com/zkcrypto/pairing/tree/master/src/bls12_381#serialization>
+const COMPRESSION_FLAG: u8 = 0x80;
+const INFINITY_FLAG: u8 = 0x40;
+const SIGN_FLAG: u8 = 0x20;
+
+fn compressed_infinity(len: usize) -> Vec<u8> {
+    let mut bytes = vec![0; len];
+    bytes[0] = COMPRESSION_FLAG | INFINITY_FLAG;
+    bytes
+}
+
+fn set_flags(bytes: &mut [u8], y_is_greatest: bool) {
+    bytes[0] |= COMPRESSION_FLAG;
+    if y_is_greatest {
+        bytes[0] |= SIGN_FLAG;
+    }
+}
+
+/// Returns the encoded x coordinate with the flags cleared and whether the y coordinate is the
+/// lexicographically largest or `None` if the point is the point at infinity
+fn read_flags(bytes: &[u8]) -> Result<Option<(Vec<u8>, bool)>, BBSPlusError> {
+    let flags = bytes[0] & (COMPRESSION_FLAG | INFINITY_FLAG | SIGN_FLAG);
+    if flags & COMPRESSION_FLAG == 0 {
+        return Err(BBSPlusError::InvalidOctetEncoding);
+    }
+    let mut x_bytes = bytes.to_vec();
+    x_bytes[0] &= !(COMPRESSION_FLAG | INFINITY_FLAG | SIGN_FLAG);
+    if flags & INFINITY_FLAG != 0 {
+        if flags & SIGN_FLAG != 0 || x_bytes.iter().any(|b| *b != 0) {
+            return Err(BBSPlusError::InvalidOctetEncoding);
+        }
+        return Ok(None);
+    }
+    Ok(Some((x_bytes, flags & SIGN_FLAG != 0)))
+}
+
+fn fq_from_be_bytes(bytes: &mut [u8]) -> Result<Fq, BBSPlusError> {
+    bytes.reverse();
+    let repr = <Fq as PrimeField>::BigInt::read(&*bytes)
+        .map_err(|_| BBSPlusError::InvalidOctetEncoding)?;
+    Fq::from_repr(repr).ok_or(BBSPlusError::InvalidOctetEncoding)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::bbs::SignatureG1;
+    use ark_bls12_381::{Fr, G2Projective};
+    use ark_std::{
+        rand::{rngs::StdRng, SeedableRng},
+        UniformRand,
+    };
+
+    // Fixtures of the ciphersuite BLS12-381-SHA-256 from the draft
+    const KEY_MATERIAL: &str = "746869732d49532d6a7573742d616e2d546573742d494b4d2d746f2d67656e65726174652d246528724074232d6b6579";
+    const KEY_INFO: &str = "746869732d49532d736f6d652d6b65792d6d657461646174612d746f2d62652d757365642d696e2d746573742d6b65792d67656e";
+    pub(crate) const SK: &str = "60e55110f76883a13d030b2f6bd11883422d5abde717569fc0731f51237169fc";
+    pub(crate) const PK: &str = "a820f230f6ae38503b86c70dc50b61c58a77e45c39ab25c0652bbaa8fa136f2851bd4781c9dcde39fc9d1d52c9e60268061e7d7632171d91aa8d460acee0e96f1e7c4cfb12d3ff9ab5d5dc91c277db75c845d649ef3c4f63aebc364cd55ded0c";
+    pub(crate) const HEADER: &str = "11223344556677889900aabbccddeeff";
+    pub(crate) const MESSAGES: [&str; 10] = [
+        "9872ad089e452c7b6e283dfac2a80d58e8d0ff71cc4d5e310a1debdda4a45f02",
+        "c344136d9ab02da4dd5908bbba913ae6f58c2cc844b802a6f811f5fb075f9b80",
+        "7372e9daa5ed31e6cd5c825eac1b855e84476a1d94932aa348e07b73",
+        "77fe97eb97a1ebe2e81e4e3597a3ee740a66e9ef2412472c",
+        "496694774c5604ab1b2544eababcf0f53278ff50",
+        "515ae153e22aae04ad16f759e07237b4",
+        "d183ddc6e2665aa4e2f088af",
+        "ac55fb33a75909ed",
+        "96012096",
+        "",
+    ];
+    const MSG_1: &str = MESSAGES[0];
+    pub(crate) const SIG_1: &str = "84773160b824e194073a57493dac1a20b667af70cd2352d8af241c77658da5253aa8458317cca0eae615690d55b1f27164657dcafee1d5c1973947aa70e2cfbb4c892340be5969920d0916067b4565a0";
+    pub(crate) const SIG_10: &str = "8339b285a4acd89dec7777c09543a43e3cc60684b0a6f8ab335da4825c96e1463e28f8c5f4fd0641d19cec5920d3a8ff4bedb6c9691454597bbd298288abed3632078557b2ace7d44caed846e1a0a1e8";
+
+    /// The draft's messages decoded from hex
+    pub(crate) fn messages() -> Vec<Vec<u8>> {
+        MESSAGES.iter().map(|m| hex::decode(m).unwrap()).collect()
+    }
+
+    #[test]
+    fn key_generation_test_vector() {
+        let sk = Bls12381Sha256::keygen(
+            &hex::decode(KEY_MATERIAL).unwrap(),
+            &hex::decode(KEY_INFO).unwrap(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(hex::encode(Bls12381Sha256::scalar_to_octets(&sk.0)), SK);
+
+        let params = Bls12381Sha256::signature_params(1);
+        let pk = PublicKeyG2::generate_using_secret_key(&sk, &params);
+        assert_eq!(hex::encode(Bls12381Sha256::g2_to_octets(&pk.0)), PK);
+        assert_eq!(
+            Bls12381Sha256::octets_to_g2(&hex::decode(PK).unwrap()).unwrap(),
+            pk.0
+        );
+
+        assert!(Bls12381Sha256::keygen(&[1; 31], &[], None).is_err());
+    }
+
+    #[test]
+    fn generators_test_vector() {
+        let params = Bls12381Sha256::signature_params(1);
+        assert_eq!(
+            hex::encode(Bls12381Sha256::g1_to_octets(&params.g1)),
+            "a8ce256102840821a3e94ea9025e4662b205762f9776b3a766c872b948f1fd225e7c59698588e70d11406d161b4e28c9"
+        );
+        assert_eq!(
+            hex::encode(Bls12381Sha256::g1_to_octets(&params.h_0)),
+            "a9ec65b70a7fbe40c874c9eb041c2cb0a7af36ccec1bea48fa2ba4c2eb67ef7f9ecb17ed27d38d27cdeddff44c8137be"
+        );
+        assert_eq!(
+            hex::encode(Bls12381Sha256::g1_to_octets(&params.h[0])),
+            "98cd5313283aaf5db1b3ba8611fe6070d19e605de4078c38df36019fbaad0bd28dd090fd24ed27f7f4d22d5ff5dea7d4"
+        );
+        assert_eq!(params.g2, G2Affine::prime_subgroup_generator());
+
+        // Parameters for fewer messages are a prefix of those for more messages
+        let params_5 = Bls12381Sha256::signature_params(5);
+        assert_eq!(params_5.h_0, params.h_0);
+        assert_eq!(params_5.h[0], params.h[0]);
+        assert!(params_5.is_valid());
+    }
+
+    #[test]
+    fn single_message_signature_test_vector() {
+        let sk = SecretKey(Bls12381Sha256::octets_to_scalar(&hex::decode(SK).unwrap()).unwrap());
+        let pk = PublicKeyG2(Bls12381Sha256::octets_to_g2(&hex::decode(PK).unwrap()).unwrap());
+        let params = Bls12381Sha256::signature_params(1);
+        let header = hex::decode(HEADER).unwrap();
+
+        let msg = hex::decode(MSG_1).unwrap();
+        let messages = Bls12381Sha256::messages_to_scalars(&[&msg]);
+        assert_eq!(
+            hex::encode(Bls12381Sha256::scalar_to_octets(&messages[0])),
+            "1cb5bb86114b34dc438a911617655a1db595abafac92f47c5001799cf624b430"
+        );
+
+        let expected_sig = SIG_1;
+        let sig =
+            SignatureG1::<Bls12_381>::new::<Bls12381Sha256>(&messages, &sk, &pk, &params, &header)
+                .unwrap();
+        assert_eq!(hex::encode(sig.to_octets::<Bls12381Sha256>()), expected_sig);
+
+        let sig = SignatureG1::<Bls12_381>::from_octets::<Bls12381Sha256>(
+            &hex::decode(expected_sig).unwrap(),
+        )
+        .unwrap();
+        sig.verify::<Bls12381Sha256>(&messages, &pk, &params, &header)
+            .unwrap();
+        // Different header fails verification
+        assert!(sig
+            .verify::<Bls12381Sha256>(&messages, &pk, &params, &[])
+            .is_err());
+    }
+
+    #[test]
+    fn multi_message_signature_test_vector() {
+        let sk = SecretKey(Bls12381Sha256::octets_to_scalar(&hex::decode(SK).unwrap()).unwrap());
+        let pk = PublicKeyG2(Bls12381Sha256::octets_to_g2(&hex::decode(PK).unwrap()).unwrap());
+        let params = Bls12381Sha256::signature_params(10);
+        let header = hex::decode(HEADER).unwrap();
+
+        let msgs = messages();
+        let messages = Bls12381Sha256::messages_to_scalars(
+            &msgs.iter().map(|m| m.as_slice()).collect::<Vec<_>>(),
+        );
+        let expected_scalars = [
+            "1cb5bb86114b34dc438a911617655a1db595abafac92f47c5001799cf624b430",
+            "154249d503c093ac2df516d4bb88b510d54fd97e8d7121aede420a25d9521952",
+            "0c7c4c85cdab32e6fdb0de267b16fa3212733d4e3a3f0d0f751657578b26fe22",
+            "4a196deafee5c23f630156ae13be3e46e53b7e39094d22877b8cba7f14640888",
+            "34c5ea4f2ba49117015a02c711bb173c11b06b3f1571b88a2952b93d0ed4cf7e",
+            "4045b39b83055cd57a4d0203e1660800fabe434004dbdc8730c21ce3f0048b08",
+            "064621da4377b6b1d05ecc37cf3b9dfc94b9498d7013dc5c4a82bf3bb1750743",
+            "34ac9196ace0a37e147e32319ea9b3d8cc7d21870d3c3ba071246859cca49b02",
+            "57eb93f417c43200e9784fa5ea5a59168d3dbc38df707a13bb597c871b2a5f74",
+            "08e3afeb2b4f2b5f907924ef42856616e6f2d5f1fb373736db1cca32707a7d16",
+        ];
+        for (m, expected) in messages.iter().zip(expected_scalars) {
+            assert_eq!(hex::encode(Bls12381Sha256::scalar_to_octets(m)), expected);
+        }
+
+        let sig =
+            SignatureG1::<Bls12_381>::new::<Bls12381Sha256>(&messages, &sk, &pk, &params, &header)
+                .unwrap();
+        assert_eq!(hex::encode(sig.to_octets::<Bls12381Sha256>()), SIG_10);
+        sig.verify::<Bls12381Sha256>(&messages, &pk, &params, &header)
+            .unwrap();
+
+        // Modified message fails verification
+        let mut wrong_messages = messages.clone();
+        wrong_messages[9] = Bls12381Sha256::messages_to_scalars(&[&[1]])[0];
+        assert!(sig
+            .verify::<Bls12381Sha256>(&wrong_messages, &pk, &params, &header)
+            .is_err());
+        // Signature over the 1st message only doesn't verify with the params for all messages
+        let sig_1 =
+            SignatureG1::<Bls12_381>::from_octets::<Bls12381Sha256>(&hex::decode(SIG_1).unwrap())
+                .unwrap();
+        assert!(sig_1
+            .verify::<Bls12381Sha256>(&messages, &pk, &params, &header)
+            .is_err());
+    }
+
+    #[test]
+    fn keygen_errors() {
+        assert!(matches!(
+            Bls12381Sha256::keygen(&[1; 31], &[], None),
+            Err(BBSPlusError::InsufficientKeyMaterial(31))
+        ));
+        assert!(matches!(
+            Bls12381Sha256::keygen(&[1; 32], &[0; 65536], None),
+            Err(BBSPlusError::KeyInfoTooLong(65536))
+        ));
+    }
+
+    #[test]
+    fn domain_for_ciphersuite_id() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let params = <Bls12381Sha256 as Ciphersuite<Bls12_381>>::signature_params(5);
+        let pk = PublicKeyG2::<Bls12_381>(G2Projective::rand(&mut rng).into_affine());
+        let header = b"test-header";
+        assert_eq!(
+            calculate_domain_for_ciphersuite(Bls12381Sha256::CIPHERSUITE_ID, &pk, &params, header)
+                .unwrap(),
+            Bls12381Sha256::calculate_domain(&pk, &params, header)
+        );
+        assert!(matches!(
+            calculate_domain_for_ciphersuite(b"unknown", &pk, &params, header),
+            Err(BBSPlusError::UnsupportedCiphersuite(_))
+        ));
+    }
+
+    #[test]
+    fn octet_encoding() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        for _ in 0..10 {
+            let g1 = G1Projective::rand(&mut rng).into_affine();
+            let bytes = Bls12381Sha256::g1_to_octets(&g1);
+            assert_eq!(bytes.len(), 48);
+            assert_eq!(Bls12381Sha256::octets_to_g1(&bytes).unwrap(), g1);
+
+            let g2 = G2Projective::rand(&mut rng).into_affine();
+            let bytes = Bls12381Sha256::g2_to_octets(&g2);
+            assert_eq!(bytes.len(), 96);
+            assert_eq!(Bls12381Sha256::octets_to_g2(&bytes).unwrap(), g2);
+
+            let f = Fr::rand(&mut rng);
+            let bytes = Bls12381Sha256::scalar_to_octets(&f);
+            assert_eq!(bytes.len(), 32);
+            assert_eq!(Bls12381Sha256::octets_to_scalar(&bytes).unwrap(), f);
+        }
+
+        let bytes = Bls12381Sha256::g1_to_octets(&G1Affine::zero());
+        assert_eq!(
+            Bls12381Sha256::octets_to_g1(&bytes).unwrap(),
+            G1Affine::zero()
+        );
+        let bytes = Bls12381Sha256::g2_to_octets(&G2Affine::zero());
+        assert_eq!(
+            Bls12381Sha256::octets_to_g2(&bytes).unwrap(),
+            G2Affine::zero()
+        );
+
+        // Uncompressed flag not set
+        let mut bytes = Bls12381Sha256::g1_to_octets(&G1Projective::rand(&mut rng).into_affine());
+        bytes[0] &= 0x7f;
+        assert!(Bls12381Sha256::octets_to_g1(&bytes).is_err());
+        // Scalar not less than the group order
+        assert!(Bls12381Sha256::octets_to_scalar(&[0xff; 32]).is_err());
+    }
+}
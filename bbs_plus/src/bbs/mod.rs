@@ -0,0 +1,26 @@
+//! BBS signature as per the IETF draft [The BBS Signature Scheme](https://datatracker.ietf.org/doc/draft-irtf-cfrg-bbs-signatures/).
+//! Unlike BBS+, the signature is `(A, e)` without `s` and `e` is derived deterministically. The messages are
+//! mapped to scalars and the generators are created as specified by the [`ciphersuite`]. The keys and
+//! parameters are the same as BBS+, i.e. [`SecretKey`], [`PublicKeyG2`] and [`SignatureParamsG1`]
+//! where `g1` is `P1`, `g2` is `BP2`, `h_0` is `Q_1` and `h` are the message generators `H_i`.
+//!
+//! Provides
+//! - ciphersuite `BLS12-381-SHA-256` with key generation, hashing messages to scalars and octet encodings
+//! - signature creation and verification in group G1 in module [`signature`]
+//! - proof of knowledge of signature and corresponding messages in module [`proof`]. The proof
+//!   uses this crate's serialization and not the octet encoding of the draft.
+//!
+//! [`ciphersuite`]: crate::bbs::ciphersuite
+//! [`signature`]: crate::bbs::signature
+//! [`proof`]: crate::bbs::proof
+//! [`SecretKey`]: crate::setup::SecretKey
+//! [`PublicKeyG2`]: crate::setup::PublicKeyG2
+//! [`SignatureParamsG1`]: crate::setup::SignatureParamsG1
+
+pub mod ciphersuite;
+pub mod proof;
+pub mod signature;
+
+pub use ciphersuite::{calculate_domain_for_ciphersuite, Bls12381Sha256, Ciphersuite};
+pub use proof::{PoKOfSignatureG1Proof, PoKOfSignatureG1Protocol};
+pub use signature::SignatureG1;
@@ -0,0 +1,1099 @@
+//! Proof of knowledge of the BBS signature and corresponding messages as per section 3.5 of the IETF draft
+//! # Examples
+//!
+//! Creating proof of knowledge of signature and verifying it:
+//!
+//! ```
+//! use ark_bls12_381::Bls12_381;
+//! use bbs_plus::bbs::{Bls12381Sha256, Ciphersuite, PoKOfSignatureG1Protocol, PoKOfSignatureG1Proof};
+//! use ark_std::collections::{BTreeSet, BTreeMap};
+//!
+//! let mut blindings = BTreeMap::new();
+//! let mut revealed_indices = BTreeSet::new();
+//!
+//! // Populate `blindings` with message index and corresponding blinding
+//! // Populate `revealed_indices` with 0-based indices of revealed messages
+//!
+//! let pok = PoKOfSignatureG1Protocol::init::<_, Bls12381Sha256>(
+//!             &mut rng,
+//!             &sig,
+//!             &params,
+//!             &pk,
+//!             header,
+//!             &messages,
+//!             blindings,
+//!             revealed_indices,
+//!         )
+//!         .unwrap();
+//!
+//! // The challenge can be created as in the draft using the presentation header `ph`
+//! let mut chal_bytes = vec![];
+//! pok.challenge_contribution::<Bls12381Sha256, _>(&revealed_msgs, &mut chal_bytes).unwrap();
+//! let challenge = PoKOfSignatureG1Proof::compute_challenge::<Bls12381Sha256>(&chal_bytes, ph);
+//!
+//! let proof = pok.gen_proof(&challenge).unwrap();
+//!
+//! proof
+//!             .verify::<Bls12381Sha256>(
+//!                 &revealed_msgs,
+//!                 &challenge,
+//!                 &pk,
+//!                 &params,
+//!                 header,
+//!             )
+//!             .unwrap();
+//!
+//! // See tests for more examples
+//! ```
+
+use crate::bbs::{ciphersuite::Ciphersuite, signature::signature_dst, signature::SignatureG1};
+use crate::error::BBSPlusError;
+use crate::setup::{PublicKeyG2, SignatureParamsG1};
+use ark_ec::msm::VariableBaseMSM;
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{Field, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Debug,
+    io::{Read, Write},
+    rand::RngCore,
+    vec,
+    vec::Vec,
+    One, UniformRand,
+};
+use dock_crypto_utils::randomized_pairing_check::RandomizedPairingChecker;
+use dock_crypto_utils::secret::{Secret, SecretAs};
+use dock_crypto_utils::serde_utils::*;
+use schnorr_pok::{error::SchnorrError, SchnorrCommitment, SchnorrResponse};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use zeroize::Zeroize;
+
+/// Proof of knowledge of BBS signature in group G1. The signature is randomized as `A_bar`, `B_bar` and `D`
+/// and then 2 Schnorr protocols are executed, the first proves knowledge of `(e, r1)` and the second of
+/// `(r3, {m_j}_{j \notin D})` where `D` is the set of disclosed messages. Like the BBS+ protocol, this executes
+/// in 2 phases, pre-challenge (`init`) and post-challenge (`gen_proof`) so that it can be composed with
+/// other protocols.
+#[serde_as]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct PoKOfSignatureG1Protocol<E: PairingEngine> {
+    #[serde_as(as = "AffineGroupBytes")]
+    pub A_bar: E::G1Affine,
+    #[serde_as(as = "AffineGroupBytes")]
+    pub B_bar: E::G1Affine,
+    #[serde_as(as = "AffineGroupBytes")]
+    pub D: E::G1Affine,
+    #[serde_as(as = "FieldBytes")]
+    pub domain: E::Fr,
+    /// For proving relation `-B_bar = A_bar * e + D * {-r1}`
+    pub sc_comm_1: SchnorrCommitment<E::G1Affine>,
+    #[serde_as(as = "SecretAs<[FieldBytes; 2]>")]
+    sc_wits_1: Secret<[E::Fr; 2]>,
+    /// For proving relation `-(P1 + Q_1*domain + \sum_{i in D}(H_i*m_i))` = `D*{-r3} + \sum_{j notin D}(H_j*m_j)`
+    pub sc_comm_2: SchnorrCommitment<E::G1Affine>,
+    #[serde_as(as = "SecretAs<Vec<FieldBytes>>")]
+    sc_wits_2: Secret<Vec<E::Fr>>,
+}
+
+/// Proof of knowledge of the BBS signature in G1. It contains the randomized signature, commitment (Schnorr step 1)
+/// and response (Schnorr step 3) to both Schnorr protocols in `T_` and `sc_resp_`. The responses of the first
+/// protocol are `(e^, r1^)` and of the second are `(r3^, m^_j1, ..., m^_jU)` as in the draft.
+#[serde_as]
+#[derive(
+    Clone, PartialEq, Eq, Debug, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+pub struct PoKOfSignatureG1Proof<E: PairingEngine> {
+    #[serde_as(as = "AffineGroupBytes")]
+    pub A_bar: E::G1Affine,
+    #[serde_as(as = "AffineGroupBytes")]
+    pub B_bar: E::G1Affine,
+    #[serde_as(as = "AffineGroupBytes")]
+    pub D: E::G1Affine,
+    #[serde_as(as = "AffineGroupBytes")]
+    pub T1: E::G1Affine,
+    pub sc_resp_1: SchnorrResponse<E::G1Affine>,
+    #[serde_as(as = "AffineGroupBytes")]
+    pub T2: E::G1Affine,
+    pub sc_resp_2: SchnorrResponse<E::G1Affine>,
+}
+
+impl<E: PairingEngine> PoKOfSignatureG1Protocol<E> {
+    /// Initiate the protocol, i.e. pre-challenge phase. This will generate the randomized signature and execute
+    /// the commit-to-randomness step (Step 1) of both Schnorr protocols. Accepts the indices of the
+    /// multi-message which are revealed to the verifier and thus their knowledge is not proven.
+    /// Accepts blindings (randomness) to be used for any messages in the multi-message. This is useful
+    /// when some messages need to be proven to be the same as they will generate the same response (step 3 in
+    /// Schnorr protocol). If extra blindings are passed, or passed for revealed messages, they are ignored.
+    /// The public key and `header` are needed to compute the `domain` which was used in signing.
+    pub fn init<R: RngCore, C: Ciphersuite<E>>(
+        rng: &mut R,
+        signature: &SignatureG1<E>,
+        params: &SignatureParamsG1<E>,
+        pk: &PublicKeyG2<E>,
+        header: &[u8],
+        messages: &[E::Fr],
+        blindings: BTreeMap<usize, E::Fr>,
+        revealed_msg_indices: BTreeSet<usize>,
+    ) -> Result<Self, BBSPlusError> {
+        Self::init_with_domain(
+            rng,
+            signature,
+            params,
+            C::calculate_domain(pk, params, header),
+            messages,
+            blindings,
+            revealed_msg_indices,
+        )
+    }
+
+    /// Same as `Self::init` but accepts the `domain` computed with `Ciphersuite::calculate_domain` rather
+    /// than the public key and header. Useful when the ciphersuite is not known at the call site.
+    pub fn init_with_domain<R: RngCore>(
+        rng: &mut R,
+        signature: &SignatureG1<E>,
+        params: &SignatureParamsG1<E>,
+        domain: E::Fr,
+        messages: &[E::Fr],
+        mut blindings: BTreeMap<usize, E::Fr>,
+        revealed_msg_indices: BTreeSet<usize>,
+    ) -> Result<Self, BBSPlusError> {
+        if messages.len() != params.supported_message_count() {
+            return Err(BBSPlusError::MessageCountIncompatibleWithSigParams(
+                messages.len(),
+                params.supported_message_count(),
+            ));
+        }
+
+        // No message index should be >= max messages
+        for idx in &revealed_msg_indices {
+            if *idx >= messages.len() {
+                return Err(BBSPlusError::InvalidMessageIdx(*idx));
+            }
+        }
+
+        // Generate any blindings that are not explicitly passed. At the end of the loop, we should have
+        // a blinding for every message whose knowledge is to be proven
+        for i in 0..messages.len() {
+            if !revealed_msg_indices.contains(&i) && !blindings.contains_key(&i) {
+                blindings.insert(i, E::Fr::rand(rng));
+            }
+        }
+
+        let random_scalars = [
+            E::Fr::rand(rng),
+            E::Fr::rand(rng),
+            E::Fr::rand(rng),
+            E::Fr::rand(rng),
+            E::Fr::rand(rng),
+        ];
+        Self::init_with_random_scalars(
+            signature,
+            params,
+            domain,
+            messages,
+            blindings,
+            revealed_msg_indices,
+            random_scalars,
+        )
+    }
+
+    /// Initiate the protocol with the given random scalars `(r1, r2, e~, r1~, r3~)` in the order of the draft
+    /// and a blinding `m~_j` for each undisclosed message. Used to reproduce the draft's fixtures.
+    fn init_with_random_scalars(
+        signature: &SignatureG1<E>,
+        params: &SignatureParamsG1<E>,
+        domain: E::Fr,
+        messages: &[E::Fr],
+        mut blindings: BTreeMap<usize, E::Fr>,
+        revealed_msg_indices: BTreeSet<usize>,
+        random_scalars: [E::Fr; 5],
+    ) -> Result<Self, BBSPlusError> {
+        let [r1, r2, e_tilde, r1_tilde, r3_tilde] = random_scalars;
+        let r3 = r2.inverse().ok_or(BBSPlusError::CannotInvert0)?;
+
+        // B = P1 + Q_1 * domain + H_1 * msg_1 + ... + H_L * msg_L
+        let b = SignatureG1::b(messages, &domain, params)?;
+
+        // D = B * r2
+        let D = b.mul(r2.into_repr());
+        // A_bar = A * (r1 * r2)
+        let A_bar = signature.A.mul((r1 * r2).into_repr());
+        let A_bar_affine = A_bar.into_affine();
+        // B_bar = D * r1 - A_bar * e
+        let B_bar = D.mul(r1.into_repr()) - A_bar_affine.mul(signature.e.into_repr());
+        let D_affine = D.into_affine();
+
+        // The relation `B_bar = D * r1 - A_bar * e` is proved as `-B_bar = A_bar * e + D * {-r1}` so that
+        // the responses are `e~ + c * e` and `r1~ - c * r1` as in the draft.
+        let bases_1 = [A_bar_affine, D_affine];
+        let randomness_1 = vec![e_tilde, r1_tilde];
+        let wits_1 = [signature.e, -r1];
+        let sc_comm_1 = SchnorrCommitment::new(&bases_1, randomness_1);
+
+        // Since `D = B * r2`, the relation `P1 + Q_1*domain + \sum_{i in D}(H_i*m_i) = D*r3 - \sum_{j notin D}(H_j*m_j)`
+        // holds. Negating both sides, `D*{-r3} + \sum_{j notin D}(H_j*m_j) = -(P1 + Q_1*domain + \sum_{i in D}(H_i*m_i))`
+        // and knowledge of `-r3` and all undisclosed messages `m_j` is proven.
+        let mut bases_2 = Vec::with_capacity(1 + blindings.len());
+        let mut randomness_2 = Vec::with_capacity(1 + blindings.len());
+        let mut wits_2 = Vec::with_capacity(1 + blindings.len());
+        bases_2.push(D_affine);
+        randomness_2.push(r3_tilde);
+        wits_2.push(-r3);
+
+        // Capture all unrevealed messages `m_j` and corresponding `H_j`
+        for i in 0..messages.len() {
+            if !revealed_msg_indices.contains(&i) {
+                bases_2.push(params.h[i]);
+                randomness_2.push(blindings.remove(&i).unwrap());
+                wits_2.push(messages[i]);
+            }
+        }
+
+        let sc_comm_2 = SchnorrCommitment::new(&bases_2, randomness_2);
+        Ok(Self {
+            A_bar: A_bar_affine,
+            B_bar: B_bar.into_affine(),
+            D: D_affine,
+            domain,
+            sc_comm_1,
+            sc_wits_1: Secret::new(wits_1),
+            sc_comm_2,
+            sc_wits_2: Secret::new(wits_2),
+        })
+    }
+
+    /// Get the contribution of this protocol towards the challenge, i.e. bytecode of items that will be hashed
+    pub fn challenge_contribution<C: Ciphersuite<E>, W: Write>(
+        &self,
+        revealed_msgs: &BTreeMap<usize, E::Fr>,
+        writer: W,
+    ) -> Result<(), BBSPlusError> {
+        PoKOfSignatureG1Proof::compute_challenge_contribution::<C, W>(
+            &self.A_bar,
+            &self.B_bar,
+            &self.D,
+            &self.sc_comm_1.t,
+            &self.sc_comm_2.t,
+            &self.domain,
+            revealed_msgs,
+            writer,
+        )
+    }
+
+    /// Generate proof. Post-challenge phase of the protocol.
+    pub fn gen_proof(self, challenge: &E::Fr) -> Result<PoKOfSignatureG1Proof<E>, BBSPlusError> {
+        // Schnorr response for relation `-B_bar = A_bar * e + D * {-r1}`
+        let resp_1 = self
+            .sc_comm_1
+            .response(self.sc_wits_1.expose(), challenge)?;
+        // Schnorr response for relation `-(P1 + Q_1*domain + \sum_{i in D}(H_i*m_i))` = `D*{-r3} + \sum_{j notin D}(H_j*m_j)`
+        let resp_2 = self
+            .sc_comm_2
+            .response(self.sc_wits_2.expose(), challenge)?;
+
+        Ok(PoKOfSignatureG1Proof {
+            A_bar: self.A_bar,
+            B_bar: self.B_bar,
+            D: self.D,
+            T1: self.sc_comm_1.t,
+            sc_resp_1: resp_1,
+            T2: self.sc_comm_2.t,
+            sc_resp_2: resp_2,
+        })
+    }
+}
+
+impl<E: PairingEngine> Zeroize for PoKOfSignatureG1Protocol<E> {
+    fn zeroize(&mut self) {
+        // Other members of `self` are public anyway
+        self.sc_comm_1.zeroize();
+        self.sc_wits_1.zeroize();
+        self.sc_comm_2.zeroize();
+        self.sc_wits_2.zeroize();
+    }
+}
+
+impl<E: PairingEngine> Drop for PoKOfSignatureG1Protocol<E> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<E: PairingEngine> PoKOfSignatureG1Proof<E> {
+    /// Verify if the proof is valid. Assumes that the public key and parameters have been
+    /// validated already.
+    pub fn verify<C: Ciphersuite<E>>(
+        &self,
+        revealed_msgs: &BTreeMap<usize, E::Fr>,
+        challenge: &E::Fr,
+        pk: &PublicKeyG2<E>,
+        params: &SignatureParamsG1<E>,
+        header: &[u8],
+    ) -> Result<(), BBSPlusError> {
+        self.verify_with_domain(
+            revealed_msgs,
+            challenge,
+            pk,
+            params,
+            &C::calculate_domain(pk, params, header),
+        )
+    }
+
+    /// Same as `Self::verify` but accepts the `domain` computed with `Ciphersuite::calculate_domain` rather
+    /// than the header.
+    pub fn verify_with_domain(
+        &self,
+        revealed_msgs: &BTreeMap<usize, E::Fr>,
+        challenge: &E::Fr,
+        pk: &PublicKeyG2<E>,
+        params: &SignatureParamsG1<E>,
+        domain: &E::Fr,
+    ) -> Result<(), BBSPlusError> {
+        self.verify_except_pairings(revealed_msgs, challenge, params, domain)?;
+
+        // Verify the randomized signature, e(A_bar, W) * e(B_bar, -BP2) == 1
+        if !E::product_of_pairings(&[
+            (E::G1Prepared::from(self.A_bar), E::G2Prepared::from(pk.0)),
+            (
+                E::G1Prepared::from(-self.B_bar),
+                E::G2Prepared::from(params.g2),
+            ),
+        ])
+        .is_one()
+        {
+            return Err(BBSPlusError::PairingCheckFailed);
+        }
+        Ok(())
+    }
+
+    pub fn verify_with_randomized_pairing_checker<C: Ciphersuite<E>>(
+        &self,
+        revealed_msgs: &BTreeMap<usize, E::Fr>,
+        challenge: &E::Fr,
+        pk: &PublicKeyG2<E>,
+        params: &SignatureParamsG1<E>,
+        header: &[u8],
+        pairing_checker: &mut RandomizedPairingChecker<E>,
+    ) -> Result<(), BBSPlusError> {
+        self.verify_except_pairings(
+            revealed_msgs,
+            challenge,
+            params,
+            &C::calculate_domain(pk, params, header),
+        )?;
+        pairing_checker.add_sources(self.A_bar, pk.0, self.B_bar, params.g2);
+        Ok(())
+    }
+
+    /// For the verifier to independently calculate the challenge
+    pub fn challenge_contribution<C: Ciphersuite<E>, W: Write>(
+        &self,
+        revealed_msgs: &BTreeMap<usize, E::Fr>,
+        pk: &PublicKeyG2<E>,
+        params: &SignatureParamsG1<E>,
+        header: &[u8],
+        writer: W,
+    ) -> Result<(), BBSPlusError> {
+        Self::compute_challenge_contribution::<C, W>(
+            &self.A_bar,
+            &self.B_bar,
+            &self.D,
+            &self.T1,
+            &self.T2,
+            &C::calculate_domain(pk, params, header),
+            revealed_msgs,
+            writer,
+        )
+    }
+
+    /// Serializes the randomized signature, the Schnorr commitments and the `domain` as the draft does for
+    /// challenge calculation, i.e. `serialize((R, i1, msg_i1, ..., iR, msg_iR, Abar, Bbar, D, T1, T2, domain))`
+    /// where `R` is the number of revealed messages.
+    pub fn compute_challenge_contribution<C: Ciphersuite<E>, W: Write>(
+        A_bar: &E::G1Affine,
+        B_bar: &E::G1Affine,
+        D: &E::G1Affine,
+        T1: &E::G1Affine,
+        T2: &E::G1Affine,
+        domain: &E::Fr,
+        revealed_msgs: &BTreeMap<usize, E::Fr>,
+        mut writer: W,
+    ) -> Result<(), BBSPlusError> {
+        let mut bytes = (revealed_msgs.len() as u64).to_be_bytes().to_vec();
+        for (i, msg) in revealed_msgs {
+            bytes.extend_from_slice(&(*i as u64).to_be_bytes());
+            bytes.append(&mut C::scalar_to_octets(msg));
+        }
+        for p in [A_bar, B_bar, D, T1, T2] {
+            bytes.append(&mut C::g1_to_octets(p));
+        }
+        bytes.append(&mut C::scalar_to_octets(domain));
+        writer
+            .write_all(&bytes)
+            .map_err(|e| SerializationError::from(e).into())
+    }
+
+    /// Compute the challenge as in the draft from the output of `challenge_contribution` and the
+    /// presentation header `ph`. When used with other protocols, the challenge is created from the
+    /// contributions of all protocols instead.
+    pub fn compute_challenge<C: Ciphersuite<E>>(challenge_contribution: &[u8], ph: &[u8]) -> E::Fr {
+        let c_octs = [challenge_contribution, &(ph.len() as u64).to_be_bytes(), ph].concat();
+        C::hash_to_scalar(&c_octs, &signature_dst::<E, C>())
+    }
+
+    /// Get the response from post-challenge phase of the Schnorr protocol for the given message index
+    /// `msg_idx`. Used when comparing message equality
+    pub fn get_resp_for_message(
+        &self,
+        msg_idx: usize,
+        revealed_msg_ids: &BTreeSet<usize>,
+    ) -> Result<&E::Fr, BBSPlusError> {
+        // Revealed messages are not part of Schnorr protocol
+        if revealed_msg_ids.contains(&msg_idx) {
+            return Err(BBSPlusError::InvalidMsgIdxForResponse(msg_idx));
+        }
+        // Adjust message index as the revealed messages are not part of the Schnorr protocol
+        let mut adjusted_idx = msg_idx;
+        for i in revealed_msg_ids {
+            if *i < msg_idx {
+                adjusted_idx -= 1;
+            }
+        }
+        // 1 added to the index, since 0th index is reserved for `-r3`
+        let r = self.sc_resp_2.get_response(1 + adjusted_idx)?;
+        Ok(r)
+    }
+
+    pub fn verify_schnorr_proofs(
+        &self,
+        revealed_msgs: &BTreeMap<usize, E::Fr>,
+        challenge: &E::Fr,
+        domain: &E::Fr,
+        params: &SignatureParamsG1<E>,
+    ) -> Result<(), BBSPlusError> {
+        // Verify the 1st Schnorr proof
+        let bases_1 = [self.A_bar, self.D];
+        match self
+            .sc_resp_1
+            .is_valid(&bases_1, &-self.B_bar, &self.T1, challenge)
+        {
+            Ok(()) => (),
+            Err(SchnorrError::InvalidResponse) => {
+                return Err(BBSPlusError::FirstSchnorrVerificationFailed)
+            }
+            Err(other) => return Err(BBSPlusError::SchnorrError(other)),
+        }
+
+        // Verify the 2nd Schnorr proof
+        let mut bases_2 =
+            Vec::with_capacity(1 + params.supported_message_count() - revealed_msgs.len());
+        bases_2.push(self.D);
+
+        let mut bases_revealed = Vec::with_capacity(2 + revealed_msgs.len());
+        let mut exponents = Vec::with_capacity(2 + revealed_msgs.len());
+        bases_revealed.push(params.g1);
+        exponents.push(E::Fr::one().into_repr());
+        bases_revealed.push(params.h_0);
+        exponents.push(domain.into_repr());
+        for i in 0..params.supported_message_count() {
+            if let Some(message) = revealed_msgs.get(&i) {
+                bases_revealed.push(params.h[i]);
+                exponents.push(message.into_repr());
+            } else {
+                bases_2.push(params.h[i]);
+            }
+        }
+        // pr = -(P1 + Q_1*domain + \sum_{i in D}(H_i*m_i))
+        let pr = -VariableBaseMSM::multi_scalar_mul(&bases_revealed, &exponents);
+        let pr = pr.into_affine();
+        match self.sc_resp_2.is_valid(&bases_2, &pr, &self.T2, challenge) {
+            Ok(()) => (),
+            Err(SchnorrError::InvalidResponse) => {
+                return Err(BBSPlusError::SecondSchnorrVerificationFailed)
+            }
+            Err(other) => return Err(BBSPlusError::SchnorrError(other)),
+        }
+
+        Ok(())
+    }
+
+    /// Verify the proof except the pairing equations. This is useful when doing several verifications (of this
+    /// protocol or others) and the pairing equations are combined in a randomized pairing check.
+    fn verify_except_pairings(
+        &self,
+        revealed_msgs: &BTreeMap<usize, E::Fr>,
+        challenge: &E::Fr,
+        params: &SignatureParamsG1<E>,
+        domain: &E::Fr,
+    ) -> Result<(), BBSPlusError> {
+        if self.A_bar.is_zero() {
+            return Err(BBSPlusError::ZeroSignature);
+        }
+        self.verify_schnorr_proofs(revealed_msgs, challenge, domain, params)
+    }
+}
+
+mod serialization {
+    use super::*;
+
+    impl<E: PairingEngine> CanonicalSerialize for PoKOfSignatureG1Protocol<E> {
+        fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+            self.A_bar.serialize(&mut writer)?;
+            self.B_bar.serialize(&mut writer)?;
+            self.D.serialize(&mut writer)?;
+            self.domain.serialize(&mut writer)?;
+            ark_serialize::CanonicalSerialize::serialize(&self.sc_comm_1, &mut writer)?;
+            let sc_wits_1 = self.sc_wits_1.expose_for_serialization()?;
+            sc_wits_1[0].serialize(&mut writer)?;
+            sc_wits_1[1].serialize(&mut writer)?;
+            ark_serialize::CanonicalSerialize::serialize(&self.sc_comm_2, &mut writer)?;
+            self.sc_wits_2.serialize(&mut writer)
+        }
+
+        fn serialized_size(&self) -> usize {
+            self.A_bar.serialized_size()
+                + self.B_bar.serialized_size()
+                + self.D.serialized_size()
+                + self.domain.serialized_size()
+                + self.sc_comm_1.serialized_size()
+                + self.sc_wits_1.expose()[0].serialized_size()
+                + self.sc_wits_1.expose()[1].serialized_size()
+                + self.sc_comm_2.serialized_size()
+                + self.sc_wits_2.serialized_size()
+        }
+
+        fn serialize_uncompressed<W: Write>(
+            &self,
+            mut writer: W,
+        ) -> Result<(), SerializationError> {
+            self.A_bar.serialize_uncompressed(&mut writer)?;
+            self.B_bar.serialize_uncompressed(&mut writer)?;
+            self.D.serialize_uncompressed(&mut writer)?;
+            self.domain.serialize(&mut writer)?;
+            self.sc_comm_1.serialize_uncompressed(&mut writer)?;
+            let sc_wits_1 = self.sc_wits_1.expose_for_serialization()?;
+            sc_wits_1[0].serialize(&mut writer)?;
+            sc_wits_1[1].serialize(&mut writer)?;
+            self.sc_comm_2.serialize_uncompressed(&mut writer)?;
+            self.sc_wits_2.serialize(&mut writer)
+        }
+
+        fn serialize_unchecked<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+            self.A_bar.serialize_unchecked(&mut writer)?;
+            self.B_bar.serialize_unchecked(&mut writer)?;
+            self.D.serialize_unchecked(&mut writer)?;
+            self.domain.serialize(&mut writer)?;
+            self.sc_comm_1.serialize_unchecked(&mut writer)?;
+            let sc_wits_1 = self.sc_wits_1.expose_for_serialization()?;
+            sc_wits_1[0].serialize(&mut writer)?;
+            sc_wits_1[1].serialize(&mut writer)?;
+            self.sc_comm_2.serialize_unchecked(&mut writer)?;
+            self.sc_wits_2.serialize_unchecked(&mut writer)
+        }
+
+        fn uncompressed_size(&self) -> usize {
+            self.A_bar.uncompressed_size()
+                + self.B_bar.uncompressed_size()
+                + self.D.uncompressed_size()
+                + self.domain.serialized_size()
+                + self.sc_comm_1.uncompressed_size()
+                + self.sc_wits_1.expose()[0].serialized_size()
+                + self.sc_wits_1.expose()[1].serialized_size()
+                + self.sc_comm_2.uncompressed_size()
+                + self.sc_wits_2.serialized_size()
+        }
+    }
+
+    impl<E: PairingEngine> CanonicalDeserialize for PoKOfSignatureG1Protocol<E> {
+        fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+            let A_bar = E::G1Affine::deserialize(&mut reader)?;
+            let B_bar = E::G1Affine::deserialize(&mut reader)?;
+            let D = E::G1Affine::deserialize(&mut reader)?;
+            let domain = E::Fr::deserialize(&mut reader)?;
+            let sc_comm_1 = ark_serialize::CanonicalDeserialize::deserialize(&mut reader)?;
+            let sc_wits_1 = Secret::new([
+                E::Fr::deserialize(&mut reader)?,
+                E::Fr::deserialize(&mut reader)?,
+            ]);
+            let sc_comm_2 = ark_serialize::CanonicalDeserialize::deserialize(&mut reader)?;
+            let sc_wits_2 = <Secret<Vec<E::Fr>>>::deserialize(&mut reader)?;
+            Ok(Self {
+                A_bar,
+                B_bar,
+                D,
+                domain,
+                sc_comm_1,
+                sc_wits_1,
+                sc_comm_2,
+                sc_wits_2,
+            })
+        }
+
+        fn deserialize_uncompressed<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+            let A_bar = E::G1Affine::deserialize_uncompressed(&mut reader)?;
+            let B_bar = E::G1Affine::deserialize_uncompressed(&mut reader)?;
+            let D = E::G1Affine::deserialize_uncompressed(&mut reader)?;
+            let domain = E::Fr::deserialize(&mut reader)?;
+            let sc_comm_1 =
+                <SchnorrCommitment<E::G1Affine>>::deserialize_uncompressed(&mut reader)?;
+            let sc_wits_1 = Secret::new([
+                E::Fr::deserialize(&mut reader)?,
+                E::Fr::deserialize(&mut reader)?,
+            ]);
+            let sc_comm_2 =
+                <SchnorrCommitment<E::G1Affine>>::deserialize_uncompressed(&mut reader)?;
+            let sc_wits_2 = <Secret<Vec<E::Fr>>>::deserialize(&mut reader)?;
+            Ok(Self {
+                A_bar,
+                B_bar,
+                D,
+                domain,
+                sc_comm_1,
+                sc_wits_1,
+                sc_comm_2,
+                sc_wits_2,
+            })
+        }
+
+        fn deserialize_unchecked<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+            let A_bar = E::G1Affine::deserialize_unchecked(&mut reader)?;
+            let B_bar = E::G1Affine::deserialize_unchecked(&mut reader)?;
+            let D = E::G1Affine::deserialize_unchecked(&mut reader)?;
+            let domain = E::Fr::deserialize(&mut reader)?;
+            let sc_comm_1 = <SchnorrCommitment<E::G1Affine>>::deserialize_unchecked(&mut reader)?;
+            let sc_wits_1 = Secret::new([
+                E::Fr::deserialize(&mut reader)?,
+                E::Fr::deserialize(&mut reader)?,
+            ]);
+            let sc_comm_2 = <SchnorrCommitment<E::G1Affine>>::deserialize_unchecked(&mut reader)?;
+            let sc_wits_2 = <Secret<Vec<E::Fr>>>::deserialize_unchecked(&mut reader)?;
+            Ok(Self {
+                A_bar,
+                B_bar,
+                D,
+                domain,
+                sc_comm_1,
+                sc_wits_1,
+                sc_comm_2,
+                sc_wits_2,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bbs::ciphersuite::{
+        tests::{messages, HEADER as FIXTURE_HEADER, PK, SIG_1, SIG_10},
+        Bls12381Sha256,
+    };
+    use crate::setup::KeypairG2;
+    use crate::{test_secret_serialization, test_serialization};
+    use ark_bls12_381::Bls12_381;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+    use std::time::Instant;
+
+    type Fr = <Bls12_381 as PairingEngine>::Fr;
+
+    const HEADER: &[u8] = b"test-header";
+    /// Presentation header of the draft's proof fixtures
+    const PRESENTATION_HEADER: &str =
+        "bed231d880675ed101ead304512e043ade9958dd0241ea70b4b3957fba941501";
+
+    fn sig_setup<R: RngCore>(
+        rng: &mut R,
+        message_count: usize,
+    ) -> (
+        Vec<Fr>,
+        SignatureParamsG1<Bls12_381>,
+        KeypairG2<Bls12_381>,
+        SignatureG1<Bls12_381>,
+    ) {
+        let messages: Vec<Fr> = (0..message_count)
+            .into_iter()
+            .map(|_| Fr::rand(rng))
+            .collect();
+        let params = Bls12381Sha256::signature_params(message_count);
+        let keypair = KeypairG2::<Bls12_381>::generate_using_rng(rng, &params);
+        let sig = SignatureG1::<Bls12_381>::new::<Bls12381Sha256>(
+            &messages,
+            &keypair.secret_key,
+            &keypair.public_key,
+            &params,
+            HEADER,
+        )
+        .unwrap();
+        (messages, params, keypair, sig)
+    }
+
+    #[test]
+    fn pok_signature_revealed_message() {
+        // Create and verify proof of knowledge of a signature when some messages are revealed
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let message_count = 20;
+        let (messages, params, keypair, sig) = sig_setup(&mut rng, message_count);
+        sig.verify::<Bls12381Sha256>(&messages, &keypair.public_key, &params, HEADER)
+            .unwrap();
+
+        let mut revealed_indices = BTreeSet::new();
+        revealed_indices.insert(0);
+        revealed_indices.insert(2);
+
+        let mut revealed_msgs = BTreeMap::new();
+        for i in revealed_indices.iter() {
+            revealed_msgs.insert(*i, messages[*i]);
+        }
+
+        let start = Instant::now();
+        let pok = PoKOfSignatureG1Protocol::init::<_, Bls12381Sha256>(
+            &mut rng,
+            &sig,
+            &params,
+            &keypair.public_key,
+            HEADER,
+            &messages,
+            BTreeMap::new(),
+            revealed_indices.clone(),
+        )
+        .unwrap();
+        let mut chal_bytes_prover = vec![];
+        pok.challenge_contribution::<Bls12381Sha256, _>(&revealed_msgs, &mut chal_bytes_prover)
+            .unwrap();
+        let ph = b"presentation-header";
+        let challenge_prover =
+            PoKOfSignatureG1Proof::compute_challenge::<Bls12381Sha256>(&chal_bytes_prover, ph);
+
+        test_secret_serialization!(PoKOfSignatureG1Protocol<Bls12_381>, pok);
+
+        let proof = pok.gen_proof(&challenge_prover).unwrap();
+        println!(
+            "Time to create proof with message size {} and revealing {} messages is {:?}",
+            message_count,
+            revealed_indices.len(),
+            start.elapsed()
+        );
+
+        let start = Instant::now();
+        let mut chal_bytes_verifier = vec![];
+        proof
+            .challenge_contribution::<Bls12381Sha256, _>(
+                &revealed_msgs,
+                &keypair.public_key,
+                &params,
+                HEADER,
+                &mut chal_bytes_verifier,
+            )
+            .unwrap();
+        let challenge_verifier =
+            PoKOfSignatureG1Proof::compute_challenge::<Bls12381Sha256>(&chal_bytes_verifier, ph);
+        assert_eq!(chal_bytes_prover, chal_bytes_verifier);
+        assert_eq!(challenge_prover, challenge_verifier);
+
+        proof
+            .verify::<Bls12381Sha256>(
+                &revealed_msgs,
+                &challenge_verifier,
+                &keypair.public_key,
+                &params,
+                HEADER,
+            )
+            .unwrap();
+        println!(
+            "Time to verify proof with message size {} and revealing {} messages is {:?}",
+            message_count,
+            revealed_indices.len(),
+            start.elapsed()
+        );
+
+        // Proof fails to verify with a different header or a wrong revealed message
+        assert!(proof
+            .verify::<Bls12381Sha256>(
+                &revealed_msgs,
+                &challenge_verifier,
+                &keypair.public_key,
+                &params,
+                b"another-header",
+            )
+            .is_err());
+        let mut wrong_revealed_msgs = revealed_msgs.clone();
+        wrong_revealed_msgs.insert(0, Fr::rand(&mut rng));
+        assert!(proof
+            .verify::<Bls12381Sha256>(
+                &wrong_revealed_msgs,
+                &challenge_verifier,
+                &keypair.public_key,
+                &params,
+                HEADER,
+            )
+            .is_err());
+
+        // Verifying with the precomputed `domain` is same as verifying with the header
+        let domain = Bls12381Sha256::calculate_domain(&keypair.public_key, &params, HEADER);
+        proof
+            .verify_with_domain(
+                &revealed_msgs,
+                &challenge_verifier,
+                &keypair.public_key,
+                &params,
+                &domain,
+            )
+            .unwrap();
+
+        test_serialization!(PoKOfSignatureG1Proof<Bls12_381>, proof);
+    }
+
+    #[test]
+    fn pok_multiple_sigs_with_same_msg_and_randomized_pairing_check() {
+        // Prove knowledge of 2 signatures whose messages at certain indices are equal by using the
+        // same blindings for those messages
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let message_count = 10;
+        let (messages_1, params_1, keypair_1, sig_1) = sig_setup(&mut rng, message_count);
+        let params_2 = Bls12381Sha256::signature_params(message_count + 2);
+        let keypair_2 = KeypairG2::<Bls12_381>::generate_using_rng(&mut rng, &params_2);
+        let mut messages_2: Vec<Fr> = (0..message_count + 2)
+            .into_iter()
+            .map(|_| Fr::rand(&mut rng))
+            .collect();
+        // Message at index 1 in 1st sig is same as message at index 4 in 2nd sig
+        messages_2[4] = messages_1[1];
+        let sig_2 = SignatureG1::<Bls12_381>::new::<Bls12381Sha256>(
+            &messages_2,
+            &keypair_2.secret_key,
+            &keypair_2.public_key,
+            &params_2,
+            HEADER,
+        )
+        .unwrap();
+
+        let same_blinding = Fr::rand(&mut rng);
+        let mut blindings_1 = BTreeMap::new();
+        blindings_1.insert(1, same_blinding);
+        let mut blindings_2 = BTreeMap::new();
+        blindings_2.insert(4, same_blinding);
+
+        let pok_1 = PoKOfSignatureG1Protocol::init::<_, Bls12381Sha256>(
+            &mut rng,
+            &sig_1,
+            &params_1,
+            &keypair_1.public_key,
+            HEADER,
+            &messages_1,
+            blindings_1,
+            BTreeSet::new(),
+        )
+        .unwrap();
+        let pok_2 = PoKOfSignatureG1Protocol::init::<_, Bls12381Sha256>(
+            &mut rng,
+            &sig_2,
+            &params_2,
+            &keypair_2.public_key,
+            HEADER,
+            &messages_2,
+            blindings_2,
+            BTreeSet::new(),
+        )
+        .unwrap();
+
+        let mut chal_bytes = vec![];
+        pok_1
+            .challenge_contribution::<Bls12381Sha256, _>(&BTreeMap::new(), &mut chal_bytes)
+            .unwrap();
+        pok_2
+            .challenge_contribution::<Bls12381Sha256, _>(&BTreeMap::new(), &mut chal_bytes)
+            .unwrap();
+        let challenge =
+            PoKOfSignatureG1Proof::compute_challenge::<Bls12381Sha256>(&chal_bytes, &[]);
+
+        let proof_1 = pok_1.gen_proof(&challenge).unwrap();
+        let proof_2 = pok_2.gen_proof(&challenge).unwrap();
+
+        // Responses for the equal messages are equal
+        assert_eq!(
+            proof_1.get_resp_for_message(1, &BTreeSet::new()).unwrap(),
+            proof_2.get_resp_for_message(4, &BTreeSet::new()).unwrap()
+        );
+        assert_ne!(
+            proof_1.get_resp_for_message(2, &BTreeSet::new()).unwrap(),
+            proof_2.get_resp_for_message(4, &BTreeSet::new()).unwrap()
+        );
+
+        for lazy in [true, false] {
+            let mut checker = RandomizedPairingChecker::new_using_rng(&mut rng, lazy);
+            proof_1
+                .verify_with_randomized_pairing_checker::<Bls12381Sha256>(
+                    &BTreeMap::new(),
+                    &challenge,
+                    &keypair_1.public_key,
+                    &params_1,
+                    HEADER,
+                    &mut checker,
+                )
+                .unwrap();
+            proof_2
+                .verify_with_randomized_pairing_checker::<Bls12381Sha256>(
+                    &BTreeMap::new(),
+                    &challenge,
+                    &keypair_2.public_key,
+                    &params_2,
+                    HEADER,
+                    &mut checker,
+                )
+                .unwrap();
+            assert!(checker.verify());
+        }
+    }
+
+    /// `mocked_calculate_random_scalars` of the draft which creates the random scalars for its fixtures
+    fn mocked_random_scalars(count: usize) -> Vec<Fr> {
+        let seed = hex::decode("332e313431353932363533353839373933323338343632363433333833323739")
+            .unwrap();
+        let dst = [
+            Bls12381Sha256::api_id().as_slice(),
+            b"MOCK_RANDOM_SCALARS_DST_",
+        ]
+        .concat();
+        let expand_len = <Bls12381Sha256 as Ciphersuite<Bls12_381>>::EXPAND_LEN;
+        Bls12381Sha256::expand_message(&seed, &dst, count * expand_len)
+            .chunks(expand_len)
+            .map(Fr::from_be_bytes_mod_order)
+            .collect()
+    }
+
+    /// Octet encoding of the proof as in the draft, `Abar || Bbar || D || e^ || r1^ || r3^ || m^_j1 || ... || m^_jU || c`
+    fn proof_to_octets(proof: &PoKOfSignatureG1Proof<Bls12_381>, challenge: &Fr) -> Vec<u8> {
+        let mut bytes = vec![];
+        for p in [&proof.A_bar, &proof.B_bar, &proof.D] {
+            bytes.append(&mut Bls12381Sha256::g1_to_octets(p));
+        }
+        for s in proof.sc_resp_1.0.iter().chain(proof.sc_resp_2.0.iter()) {
+            bytes.append(&mut Bls12381Sha256::scalar_to_octets(s));
+        }
+        bytes.append(&mut Bls12381Sha256::scalar_to_octets(challenge));
+        bytes
+    }
+
+    /// Create a proof with the draft's mocked random scalars for the fixture signature over the first
+    /// `message_count` messages and check it against the expected octets. The keys, messages, signatures,
+    /// headers and random scalars are the draft's fixtures and the expected proofs were created from them.
+    fn check_proof_test_vector(
+        message_count: usize,
+        signature: &str,
+        revealed_indices: BTreeSet<usize>,
+        expected_proof: &str,
+    ) {
+        let pk = PublicKeyG2(Bls12381Sha256::octets_to_g2(&hex::decode(PK).unwrap()).unwrap());
+        let header = hex::decode(FIXTURE_HEADER).unwrap();
+        let ph = hex::decode(PRESENTATION_HEADER).unwrap();
+        let params = Bls12381Sha256::signature_params(message_count);
+        let msgs = messages();
+        let messages = Bls12381Sha256::messages_to_scalars(
+            &msgs[..message_count]
+                .iter()
+                .map(|m| m.as_slice())
+                .collect::<Vec<_>>(),
+        );
+        let sig = SignatureG1::<Bls12_381>::from_octets::<Bls12381Sha256>(
+            &hex::decode(signature).unwrap(),
+        )
+        .unwrap();
+
+        // Random scalars are `(r1, r2, e~, r1~, r3~, m~_j1, ..., m~_jU)`
+        let unrevealed_count = message_count - revealed_indices.len();
+        let mut random_scalars = mocked_random_scalars(5 + unrevealed_count);
+        let blindings = (0..message_count)
+            .filter(|i| !revealed_indices.contains(i))
+            .zip(random_scalars.drain(5..))
+            .collect::<BTreeMap<_, _>>();
+        let revealed_msgs = revealed_indices
+            .iter()
+            .map(|i| (*i, messages[*i]))
+            .collect::<BTreeMap<_, _>>();
+
+        let pok = PoKOfSignatureG1Protocol::init_with_random_scalars(
+            &sig,
+            &params,
+            Bls12381Sha256::calculate_domain(&pk, &params, &header),
+            &messages,
+            blindings,
+            revealed_indices,
+            [
+                random_scalars[0],
+                random_scalars[1],
+                random_scalars[2],
+                random_scalars[3],
+                random_scalars[4],
+            ],
+        )
+        .unwrap();
+        let mut chal_bytes = vec![];
+        pok.challenge_contribution::<Bls12381Sha256, _>(&revealed_msgs, &mut chal_bytes)
+            .unwrap();
+        let challenge =
+            PoKOfSignatureG1Proof::compute_challenge::<Bls12381Sha256>(&chal_bytes, &ph);
+        let proof = pok.gen_proof(&challenge).unwrap();
+        assert_eq!(
+            hex::encode(proof_to_octets(&proof, &challenge)),
+            expected_proof
+        );
+
+        // The verifier computes the same challenge and the proof verifies
+        let mut chal_bytes_verifier = vec![];
+        proof
+            .challenge_contribution::<Bls12381Sha256, _>(
+                &revealed_msgs,
+                &pk,
+                &params,
+                &header,
+                &mut chal_bytes_verifier,
+            )
+            .unwrap();
+        assert_eq!(chal_bytes_verifier, chal_bytes);
+        proof
+            .verify::<Bls12381Sha256>(&revealed_msgs, &challenge, &pk, &params, &header)
+            .unwrap();
+    }
+
+    #[test]
+    fn mocked_random_scalars_test_vector() {
+        let scalars = mocked_random_scalars(10);
+        assert_eq!(
+            hex::encode(Bls12381Sha256::scalar_to_octets(&scalars[0])),
+            "04f8e2518993c4383957ad14eb13a023c4ad0c67d01ec86eeb902e732ed6df3f"
+        );
+        assert_eq!(
+            hex::encode(Bls12381Sha256::scalar_to_octets(&scalars[9])),
+            "485e2adab17b76f5334c95bf36c03ccf91cef77dcfcdc6b8a69e2090b3156663"
+        );
+    }
+
+    #[test]
+    fn single_message_proof_test_vector() {
+        check_proof_test_vector(
+            1,
+            SIG_1,
+            [0].into_iter().collect(),
+            "94916292a7a6bade28456c601d3af33fcf39278d6594b467e128a3f83686a104ef2b2fcf72df0215eeaf69262ffe8194a19fab31a82ddbe06908985abc4c9825788b8a1610942d12b7f5debbea8985296361206dbace7af0cc834c80f33e0aadaeea5597befbb651827b5eed5a66f1a959bb46cfd5ca1a817a14475960f69b32c54db7587b5ee3ab665fbd37b506830a49f21d592f5e634f47cee05a025a2f8f94e73a6c15f02301d1178a92873b6e8634bafe4983c3e15a663d64080678dbf29417519b78af042be2b3e1c4d08b8d520ffab008cbaaca5671a15b22c239b38e940cfeaa5e72104576a9ec4a6fad78c532381aeaa6fb56409cef56ee5c140d455feeb04426193c57086c9b6d397d9418",
+        );
+    }
+
+    #[test]
+    fn multi_message_proof_test_vectors() {
+        // All messages disclosed
+        check_proof_test_vector(
+            10,
+            SIG_10,
+            (0..10).collect(),
+            "b1f468aec2001c4f54cb56f707c6222a43e5803a25b2253e67b2210ab2ef9eab52db2d4b379935c4823281eaf767fd37b08ce80dc65de8f9769d27099ae649ad4c9b4bd2cc23edcba52073a298087d2495e6d57aaae051ef741adf1cbce65c64a73c8c97264177a76c4a03341956d2ae45ed3438ce598d5cda4f1bf9507fecef47855480b7b30b5e4052c92a4360110c67327365763f5aa9fb85ddcbc2975449b8c03db1216ca66b310f07d0ccf12ab460cdc6003b677fed36d0a23d0818a9d4d098d44f749e91008cf50e8567ef936704c8277b7710f41ab7e6e16408ab520edc290f9801349aee7b7b4e318e6a76e028e1dea911e2e7baec6a6a174da1a22362717fbae1cd961d7bf4adce1d31c2ab",
+        );
+        // Messages at indices 0, 2, 4 and 6 disclosed
+        check_proof_test_vector(
+            10,
+            SIG_10,
+            [0, 2, 4, 6].into_iter().collect(),
+            "a2ed608e8e12ed21abc2bf154e462d744a367c7f1f969bdbf784a2a134c7db2d340394223a5397a3011b1c340ebc415199462ba6f31106d8a6da8b513b37a47afe93c9b3474d0d7a354b2edc1b88818b063332df774c141f7a07c48fe50d452f897739228c88afc797916dca01e8f03bd9c5375c7a7c59996e514bb952a436afd24457658acbaba5ddac2e693ac481356918cd38025d86b28650e909defe9604a7259f44386b861608be742af7775a2e71a6070e5836f5f54dc43c60096834a5b6da295bf8f081f72b7cdf7f3b4347fb3ff19edaa9e74055c8ba46dbcb7594fb2b06633bb5324192eb9be91be0d33e453b4d3127459de59a5e2193c900816f049a02cb9127dac894418105fa1641d5a206ec9c42177af9316f433417441478276ca0303da8f941bf2e0222a43251cf5c2bf6eac1961890aa740534e519c1767e1223392a3a286b0f4d91f7f25217a7862b8fcc1810cdcfddde2a01c80fcc90b632585fec12dc4ae8fea1918e9ddeb9414623a457e88f53f545841f9d5dcb1f8e160d1560770aa79d65e2eca8edeaecb73fb7e995608b820c4a64de6313a370ba05dc25ed7c1d185192084963652f2870341bdaa4b1a37f8c06348f38a4f80c5a2650a21d59f09e8305dcd3fc3ac30e2a",
+        );
+    }
+}
@@ -0,0 +1,299 @@
+//! BBS signature and verification as per section 3.4 of the IETF draft
+//! # Examples
+//!
+//! Creating signature and verifying it:
+//!
+//! ```
+//! use ark_bls12_381::Bls12_381;
+//! use bbs_plus::bbs::{Bls12381Sha256, Ciphersuite, SignatureG1};
+//! use bbs_plus::setup::PublicKeyG2;
+//!
+//! // `key_material` is secret and should be at least 32 random bytes, `key_info` is optional public data
+//! let key_material = [7u8; 32];
+//! let key_info = b"key-info";
+//! let header = b"header";
+//!
+//! let params = <Bls12381Sha256 as Ciphersuite<Bls12_381>>::signature_params(3);
+//! let sk = <Bls12381Sha256 as Ciphersuite<Bls12_381>>::keygen(&key_material, key_info, None).unwrap();
+//! let pk = PublicKeyG2::generate_using_secret_key(&sk, &params);
+//!
+//! // Octet string messages are mapped to elements of the scalar field
+//! let msgs: [&[u8]; 3] = [b"message-1", b"message-2", b"message-3"];
+//! let messages = <Bls12381Sha256 as Ciphersuite<Bls12_381>>::messages_to_scalars(&msgs);
+//!
+//! let sig = SignatureG1::<Bls12_381>::new::<Bls12381Sha256>(&messages, &sk, &pk, &params, header).unwrap();
+//! sig.verify::<Bls12381Sha256>(&messages, &pk, &params, header).unwrap();
+//!
+//! // Signature can be converted to the octet encoding of the draft
+//! let bytes = sig.to_octets::<Bls12381Sha256>();
+//! ```
+
+use crate::bbs::ciphersuite::Ciphersuite;
+use crate::error::BBSPlusError;
+use crate::setup::{PublicKeyG2, SecretKey, SignatureParamsG1};
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{fields::Field, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    io::{Read, Write},
+    vec::Vec,
+    One, Zero,
+};
+use dock_crypto_utils::serde_utils::*;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use zeroize::Zeroize;
+
+/// BBS signature created by the signer after signing a multi-message. Unlike BBS+, there is no `s`
+#[serde_as]
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+pub struct SignatureG1<E: PairingEngine> {
+    #[serde_as(as = "AffineGroupBytes")]
+    pub A: E::G1Affine,
+    #[serde_as(as = "FieldBytes")]
+    pub e: E::Fr,
+}
+
+impl<E: PairingEngine> Zeroize for SignatureG1<E> {
+    fn zeroize(&mut self) {
+        self.A.zeroize();
+        self.e.zeroize();
+    }
+}
+
+impl<E: PairingEngine> Drop for SignatureG1<E> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<E: PairingEngine> SignatureG1<E> {
+    /// Create a new signature over the messages. The public key is needed as the signature is bound
+    /// to it and to the `header` through the `domain`. `e` is derived deterministically from the secret
+    /// key, the messages and the `domain`.
+    pub fn new<C: Ciphersuite<E>>(
+        messages: &[E::Fr],
+        sk: &SecretKey<E::Fr>,
+        pk: &PublicKeyG2<E>,
+        params: &SignatureParamsG1<E>,
+        header: &[u8],
+    ) -> Result<Self, BBSPlusError> {
+        check_message_count(messages, params)?;
+        let domain = C::calculate_domain(pk, params, header);
+
+        // e = hash_to_scalar(serialize((SK, msg_1, ..., msg_L, domain)), signature_dst)
+        let mut e_input = C::scalar_to_octets(&sk.0);
+        for m in messages {
+            e_input.append(&mut C::scalar_to_octets(m));
+        }
+        e_input.append(&mut C::scalar_to_octets(&domain));
+        let e = C::hash_to_scalar(&e_input, &signature_dst::<E, C>());
+
+        // B = P1 + Q_1 * domain + H_1 * msg_1 + ... + H_L * msg_L
+        let b = Self::b(messages, &domain, params)?;
+        // 1/(e+x)
+        let e_plus_x_inv = (e + sk.0).inverse().ok_or(BBSPlusError::CannotInvert0)?;
+        let A = b.mul(e_plus_x_inv.into_repr());
+        Ok(Self {
+            A: A.into_affine(),
+            e,
+        })
+    }
+
+    /// Checks that the elliptic curve point in the signature is non-zero
+    pub fn is_non_zero(&self) -> bool {
+        !self.A.is_zero()
+    }
+
+    /// Verify the validity of the signature. Assumes that the public key and parameters
+    /// have been validated already.
+    pub fn verify<C: Ciphersuite<E>>(
+        &self,
+        messages: &[E::Fr],
+        pk: &PublicKeyG2<E>,
+        params: &SignatureParamsG1<E>,
+        header: &[u8],
+    ) -> Result<(), BBSPlusError> {
+        check_message_count(messages, params)?;
+        if !self.is_non_zero() {
+            return Err(BBSPlusError::ZeroSignature);
+        }
+        let domain = C::calculate_domain(pk, params, header);
+        let b = Self::b(messages, &domain, params)?;
+        let g2_e = params.g2.mul(self.e.into_repr());
+        // Check e(A, W + BP2 * e) * e(B, -BP2) == 1
+        if !E::product_of_pairings(&[
+            (
+                E::G1Prepared::from(self.A),
+                E::G2Prepared::from(g2_e.add_mixed(&pk.0).into_affine()),
+            ),
+            (
+                E::G1Prepared::from(b.into_affine()),
+                E::G2Prepared::from(-params.g2),
+            ),
+        ])
+        .is_one()
+        {
+            return Err(BBSPlusError::InvalidSignature);
+        }
+        Ok(())
+    }
+
+    /// Octet encoding of the signature as per the draft, `A || e`
+    pub fn to_octets<C: Ciphersuite<E>>(&self) -> Vec<u8> {
+        let mut bytes = C::g1_to_octets(&self.A);
+        bytes.append(&mut C::scalar_to_octets(&self.e));
+        bytes
+    }
+
+    /// Decode the signature from its octet encoding. Fails if `A` is the identity or `e` is 0
+    pub fn from_octets<C: Ciphersuite<E>>(bytes: &[u8]) -> Result<Self, BBSPlusError> {
+        let g1_len = C::g1_to_octets(&E::G1Affine::zero()).len();
+        if bytes.len() <= g1_len {
+            return Err(BBSPlusError::InvalidOctetEncoding);
+        }
+        let A = C::octets_to_g1(&bytes[..g1_len])?;
+        let e = C::octets_to_scalar(&bytes[g1_len..])?;
+        if A.is_zero() || e.is_zero() {
+            return Err(BBSPlusError::InvalidOctetEncoding);
+        }
+        Ok(Self { A, e })
+    }
+
+    /// Compute `B = P1 + Q_1 * domain + H_1 * msg_1 + ... + H_L * msg_L`. This is `b` of BBS+ with
+    /// `domain` in place of `s`
+    pub fn b(
+        messages: &[E::Fr],
+        domain: &E::Fr,
+        params: &SignatureParamsG1<E>,
+    ) -> Result<E::G1Projective, BBSPlusError> {
+        params.b(
+            messages
+                .iter()
+                .enumerate()
+                .collect::<BTreeMap<usize, &E::Fr>>(),
+            domain,
+        )
+    }
+}
+
+/// Domain separation tag for `hash_to_scalar` when creating `e`, `domain` and the challenge
+pub(crate) fn signature_dst<E: PairingEngine, C: Ciphersuite<E>>() -> Vec<u8> {
+    [C::api_id().as_slice(), b"H2S_"].concat()
+}
+
+fn check_message_count<E: PairingEngine>(
+    messages: &[E::Fr],
+    params: &SignatureParamsG1<E>,
+) -> Result<(), BBSPlusError> {
+    if messages.is_empty() {
+        return Err(BBSPlusError::NoMessageToSign);
+    }
+    if messages.len() != params.supported_message_count() {
+        return Err(BBSPlusError::MessageCountIncompatibleWithSigParams(
+            messages.len(),
+            params.supported_message_count(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bbs::ciphersuite::Bls12381Sha256;
+    use crate::setup::KeypairG2;
+    use crate::test_serialization;
+    use ark_bls12_381::{Bls12_381, G1Affine};
+    use ark_std::{
+        rand::{rngs::StdRng, SeedableRng},
+        UniformRand,
+    };
+    use std::time::Instant;
+
+    type Fr = <Bls12_381 as PairingEngine>::Fr;
+
+    #[test]
+    fn signature_verification() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let message_count = 20;
+        let messages: Vec<Fr> = (0..message_count)
+            .into_iter()
+            .map(|_| Fr::rand(&mut rng))
+            .collect();
+        let header = b"test-header";
+
+        let params = Bls12381Sha256::signature_params(message_count);
+        let keypair = KeypairG2::<Bls12_381>::generate_using_rng(&mut rng, &params);
+        let pk = &keypair.public_key;
+
+        let start = Instant::now();
+        let sig = SignatureG1::<Bls12_381>::new::<Bls12381Sha256>(
+            &messages,
+            &keypair.secret_key,
+            pk,
+            &params,
+            header,
+        )
+        .unwrap();
+        println!(
+            "Time to sign multi-message of size {} is {:?}",
+            message_count,
+            start.elapsed()
+        );
+
+        let start = Instant::now();
+        sig.verify::<Bls12381Sha256>(&messages, pk, &params, header)
+            .unwrap();
+        println!(
+            "Time to verify signature over multi-message of size {} is {:?}",
+            message_count,
+            start.elapsed()
+        );
+
+        // Signing is deterministic
+        let sig_1 = SignatureG1::<Bls12_381>::new::<Bls12381Sha256>(
+            &messages,
+            &keypair.secret_key,
+            pk,
+            &params,
+            header,
+        )
+        .unwrap();
+        assert_eq!(sig, sig_1);
+
+        let mut zero_sig = sig.clone();
+        zero_sig.A = G1Affine::zero();
+        assert!(zero_sig
+            .verify::<Bls12381Sha256>(&messages, pk, &params, header)
+            .is_err());
+
+        let mut wrong_messages = messages.clone();
+        wrong_messages[3] = Fr::rand(&mut rng);
+        assert!(sig
+            .verify::<Bls12381Sha256>(&wrong_messages, pk, &params, header)
+            .is_err());
+        assert!(sig
+            .verify::<Bls12381Sha256>(&messages, pk, &params, b"another-header")
+            .is_err());
+
+        // Signature doesn't verify with a different public key
+        let keypair_1 = KeypairG2::<Bls12_381>::generate_using_rng(&mut rng, &params);
+        assert!(sig
+            .verify::<Bls12381Sha256>(&messages, &keypair_1.public_key, &params, header)
+            .is_err());
+
+        let bytes = sig.to_octets::<Bls12381Sha256>();
+        assert_eq!(bytes.len(), 80);
+        assert_eq!(
+            SignatureG1::<Bls12_381>::from_octets::<Bls12381Sha256>(&bytes).unwrap(),
+            sig
+        );
+
+        test_serialization!(SignatureG1<Bls12_381>, sig);
+    }
+}
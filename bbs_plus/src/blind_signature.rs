@@ -0,0 +1,393 @@
+//! Issuance of a BBS+ signature in group G1 where some of the messages are hidden from the signer.
+//! 1. The signer sends a fresh nonce to the requester.
+//! 2. The requester commits to the messages it wants to hide as `h_0*blinding + sum(h_i*m_i)` for
+//!    the hidden messages `m_i` and proves knowledge of the committed messages and the blinding with a
+//!    non-interactive Schnorr proof bound to the nonce. The commitment and proof are sent as a [`BlindSignatureRequest`].
+//! 3. The signer verifies the proof and signs the commitment along with the messages it knows, sending
+//!    the blinded signature as a [`BlindSignatureResponse`].
+//! 4. The requester unblinds the signature to get a regular [`SignatureG1`] over all the messages.
+//!
+//! # Examples
+//!
+//! ```
+//! use ark_bls12_381::Bls12_381;
+//! use blake2::Blake2b;
+//! use bbs_plus::blind_signature::{BlindSignatureRequest, BlindSignatureResponse};
+//!
+//! // Signer sends `nonce` to the requester. Requester wants to hide messages at indices 0 and 2
+//! // and `committed_messages` is the map of index to message.
+//! let (request, blinding) = BlindSignatureRequest::<Bls12_381>::new::<_, Blake2b>(&mut rng, committed_messages, &params, &nonce).unwrap();
+//!
+//! // Signer verifies the request and signs the remaining messages given as the map `uncommitted_messages`
+//! let response = BlindSignatureResponse::new::<_, Blake2b>(&mut rng, &request, &nonce, uncommitted_messages, &keypair.secret_key, &params).unwrap();
+//!
+//! // Requester unblinds the signature and verifies it
+//! let sig = response.unblind(&blinding);
+//! sig.verify(&messages, &keypair.public_key, &params).unwrap();
+//! ```
+
+use crate::error::BBSPlusError;
+use crate::setup::{SecretKey, SignatureParamsG1};
+use crate::signature::SignatureG1;
+use ark_ec::PairingEngine;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    collections::{BTreeMap, BTreeSet},
+    io::{Read, Write},
+    rand::RngCore,
+    vec,
+    vec::Vec,
+    UniformRand,
+};
+use digest::Digest;
+use dock_crypto_utils::serde_utils::*;
+use schnorr_pok::{compute_random_oracle_challenge, SchnorrCommitment, SchnorrResponse};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use zeroize::Zeroize;
+
+/// Sent by the requester to the signer. Contains the commitment to the hidden messages and a proof of
+/// knowledge of the committed messages and the blinding.
+#[serde_as]
+#[derive(
+    Clone, PartialEq, Eq, Debug, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+pub struct BlindSignatureRequest<E: PairingEngine> {
+    /// Indices of the messages committed in `commitment`
+    pub committed_message_indices: BTreeSet<usize>,
+    /// `h_0*blinding + sum(h_i*m_i)` for all `i` in `committed_message_indices`
+    #[serde_as(as = "AffineGroupBytes")]
+    pub commitment: E::G1Affine,
+    /// Commitment to the randomness of the Schnorr proof
+    #[serde_as(as = "AffineGroupBytes")]
+    pub t: E::G1Affine,
+    /// Responses of the Schnorr proof, one for each committed message in order of the indices and last for the blinding
+    pub response: SchnorrResponse<E::G1Affine>,
+}
+
+/// Sent by the signer to the requester. Contains the signature on the commitment and the messages known
+/// to the signer.
+#[derive(
+    Clone, PartialEq, Eq, Debug, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct BlindSignatureResponse<E: PairingEngine> {
+    pub blinded_signature: SignatureG1<E>,
+}
+
+impl<E: PairingEngine> BlindSignatureRequest<E> {
+    /// Commit to the messages to be hidden from the signer and prove knowledge of them. `committed_messages`
+    /// is the map of message index to message and `nonce` is given by the signer. Returns the request
+    /// and the blinding used in the commitment which must be kept secret and is needed to unblind the signature.
+    pub fn new<R: RngCore, D: Digest>(
+        rng: &mut R,
+        committed_messages: BTreeMap<usize, &E::Fr>,
+        params: &SignatureParamsG1<E>,
+        nonce: &[u8],
+    ) -> Result<(Self, E::Fr), BBSPlusError> {
+        if committed_messages.is_empty() {
+            return Err(BBSPlusError::NoMessageToSign);
+        }
+        let blinding = E::Fr::rand(rng);
+        let committed_message_indices = committed_messages.keys().cloned().collect::<BTreeSet<_>>();
+        // Checks that indices are valid
+        let commitment = params.commit_to_messages(committed_messages.clone(), &blinding)?;
+
+        let bases = Self::bases(&committed_message_indices, params)?;
+        let mut witnesses = committed_messages
+            .into_values()
+            .copied()
+            .collect::<Vec<_>>();
+        witnesses.push(blinding);
+        let blindings = (0..bases.len())
+            .map(|_| E::Fr::rand(rng))
+            .collect::<Vec<_>>();
+        let sc_comm = SchnorrCommitment::new(&bases, blindings);
+
+        let mut challenge_bytes = vec![];
+        Self::compute_challenge_contribution(
+            &bases,
+            &commitment,
+            &sc_comm.t,
+            nonce,
+            &mut challenge_bytes,
+        )?;
+        let challenge = compute_random_oracle_challenge::<E::Fr, D>(&challenge_bytes);
+        let response = sc_comm.response(&witnesses, &challenge)?;
+        witnesses.zeroize();
+        Ok((
+            Self {
+                committed_message_indices,
+                commitment,
+                t: sc_comm.t,
+                response,
+            },
+            blinding,
+        ))
+    }
+
+    /// Verify the proof of knowledge of committed messages using the `nonce` sent to the requester
+    pub fn verify<D: Digest>(
+        &self,
+        params: &SignatureParamsG1<E>,
+        nonce: &[u8],
+    ) -> Result<(), BBSPlusError> {
+        if self.committed_message_indices.is_empty() {
+            return Err(BBSPlusError::NoMessageToSign);
+        }
+        let bases = Self::bases(&self.committed_message_indices, params)?;
+        let mut challenge_bytes = vec![];
+        Self::compute_challenge_contribution(
+            &bases,
+            &self.commitment,
+            &self.t,
+            nonce,
+            &mut challenge_bytes,
+        )?;
+        let challenge = compute_random_oracle_challenge::<E::Fr, D>(&challenge_bytes);
+        self.response
+            .is_valid(&bases, &self.commitment, &self.t, &challenge)?;
+        Ok(())
+    }
+
+    /// Bases of the commitment, `h_i` for the committed messages in order of their indices and then `h_0`
+    fn bases(
+        committed_message_indices: &BTreeSet<usize>,
+        params: &SignatureParamsG1<E>,
+    ) -> Result<Vec<E::G1Affine>, BBSPlusError> {
+        let mut bases = Vec::with_capacity(committed_message_indices.len() + 1);
+        for i in committed_message_indices {
+            if *i >= params.supported_message_count() {
+                return Err(BBSPlusError::InvalidMessageIdx(*i));
+            }
+            bases.push(params.h[*i]);
+        }
+        bases.push(params.h_0);
+        Ok(bases)
+    }
+
+    fn compute_challenge_contribution<W: Write>(
+        bases: &[E::G1Affine],
+        commitment: &E::G1Affine,
+        t: &E::G1Affine,
+        nonce: &[u8],
+        mut writer: W,
+    ) -> Result<(), BBSPlusError> {
+        for b in bases {
+            b.serialize_unchecked(&mut writer)?;
+        }
+        commitment.serialize_unchecked(&mut writer)?;
+        t.serialize_unchecked(&mut writer)?;
+        nonce.serialize_unchecked(&mut writer)?;
+        Ok(())
+    }
+}
+
+impl<E: PairingEngine> BlindSignatureResponse<E> {
+    /// Verify the request using the `nonce` sent to the requester and sign the commitment in it along with
+    /// `uncommitted_messages`, the map of message index to message for messages known to the signer. The
+    /// committed and uncommitted messages together should be all the messages supported by `params`.
+    pub fn new<R: RngCore, D: Digest>(
+        rng: &mut R,
+        request: &BlindSignatureRequest<E>,
+        nonce: &[u8],
+        uncommitted_messages: BTreeMap<usize, &E::Fr>,
+        sk: &SecretKey<E::Fr>,
+        params: &SignatureParamsG1<E>,
+    ) -> Result<Self, BBSPlusError> {
+        for i in uncommitted_messages.keys() {
+            if request.committed_message_indices.contains(i) {
+                return Err(BBSPlusError::MessageIndexBothCommittedAndUncommitted(*i));
+            }
+        }
+        let message_count = request.committed_message_indices.len() + uncommitted_messages.len();
+        if message_count != params.supported_message_count() {
+            return Err(BBSPlusError::MessageCountIncompatibleWithSigParams(
+                message_count,
+                params.supported_message_count(),
+            ));
+        }
+        request.verify::<D>(params, nonce)?;
+        let blinded_signature = SignatureG1::new_with_committed_messages(
+            rng,
+            &request.commitment,
+            uncommitted_messages,
+            sk,
+            params,
+        )?;
+        Ok(Self { blinded_signature })
+    }
+
+    /// Unblind the signature using the blinding returned when creating the request. The signature should
+    /// be verified before use.
+    pub fn unblind(self, blinding: &E::Fr) -> SignatureG1<E> {
+        self.blinded_signature.unblind(blinding)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::setup::KeypairG2;
+    use crate::test_serialization;
+    use ark_bls12_381::Bls12_381;
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+    use blake2::Blake2b;
+
+    type Fr = <Bls12_381 as PairingEngine>::Fr;
+
+    #[test]
+    fn blind_signature_issuance() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let message_count = 6;
+        let params = SignatureParamsG1::<Bls12_381>::generate_using_rng(&mut rng, message_count);
+        let keypair = KeypairG2::<Bls12_381>::generate_using_rng(&mut rng, &params);
+        let messages = (0..message_count)
+            .map(|_| Fr::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let nonce = b"test-nonce";
+
+        for committed_indices in [vec![0], vec![1, 3, 4], vec![0, 1, 2, 3, 4]] {
+            let committed_messages = committed_indices
+                .iter()
+                .map(|i| (*i, &messages[*i]))
+                .collect::<BTreeMap<_, _>>();
+            let uncommitted_messages = (0..message_count)
+                .filter(|i| !committed_indices.contains(i))
+                .map(|i| (i, &messages[i]))
+                .collect::<BTreeMap<_, _>>();
+
+            let (request, blinding) = BlindSignatureRequest::new::<_, Blake2b>(
+                &mut rng,
+                committed_messages.clone(),
+                &params,
+                nonce,
+            )
+            .unwrap();
+            test_serialization!(BlindSignatureRequest<Bls12_381>, request);
+            request.verify::<Blake2b>(&params, nonce).unwrap();
+
+            // Request is bound to the nonce
+            assert!(request
+                .verify::<Blake2b>(&params, b"another-nonce")
+                .is_err());
+            assert!(BlindSignatureResponse::new::<_, Blake2b>(
+                &mut rng,
+                &request,
+                b"another-nonce",
+                uncommitted_messages.clone(),
+                &keypair.secret_key,
+                &params,
+            )
+            .is_err());
+
+            // Request with a different commitment doesn't verify
+            let mut tampered_request = request.clone();
+            tampered_request.commitment = tampered_request.commitment + params.h_0;
+            assert!(tampered_request.verify::<Blake2b>(&params, nonce).is_err());
+
+            // Request with different committed indices doesn't verify
+            let mut tampered_request = request.clone();
+            tampered_request
+                .committed_message_indices
+                .insert(message_count - 1);
+            assert!(tampered_request.verify::<Blake2b>(&params, nonce).is_err());
+
+            let response = BlindSignatureResponse::new::<_, Blake2b>(
+                &mut rng,
+                &request,
+                nonce,
+                uncommitted_messages.clone(),
+                &keypair.secret_key,
+                &params,
+            )
+            .unwrap();
+            test_serialization!(BlindSignatureResponse<Bls12_381>, response);
+
+            // Blinded signature doesn't verify
+            assert!(response
+                .blinded_signature
+                .verify(&messages, &keypair.public_key, &params)
+                .is_err());
+
+            let sig = response.unblind(&blinding);
+            sig.verify(&messages, &keypair.public_key, &params).unwrap();
+        }
+    }
+
+    #[test]
+    fn invalid_blind_signature_request() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let message_count = 4;
+        let params = SignatureParamsG1::<Bls12_381>::generate_using_rng(&mut rng, message_count);
+        let keypair = KeypairG2::<Bls12_381>::generate_using_rng(&mut rng, &params);
+        let messages = (0..message_count)
+            .map(|_| Fr::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let nonce = b"test-nonce";
+
+        // No message to commit
+        assert!(BlindSignatureRequest::<Bls12_381>::new::<_, Blake2b>(
+            &mut rng,
+            BTreeMap::new(),
+            &params,
+            nonce
+        )
+        .is_err());
+
+        // Invalid message index
+        assert!(BlindSignatureRequest::<Bls12_381>::new::<_, Blake2b>(
+            &mut rng,
+            [(message_count, &messages[0])].into_iter().collect(),
+            &params,
+            nonce
+        )
+        .is_err());
+
+        let (request, _) = BlindSignatureRequest::new::<_, Blake2b>(
+            &mut rng,
+            [(0, &messages[0]), (1, &messages[1])].into_iter().collect(),
+            &params,
+            nonce,
+        )
+        .unwrap();
+
+        // Signer's messages overlap with committed messages
+        assert!(BlindSignatureResponse::new::<_, Blake2b>(
+            &mut rng,
+            &request,
+            nonce,
+            [(1, &messages[1]), (2, &messages[2]), (3, &messages[3])]
+                .into_iter()
+                .collect(),
+            &keypair.secret_key,
+            &params,
+        )
+        .is_err());
+
+        // Not all messages are signed
+        assert!(BlindSignatureResponse::new::<_, Blake2b>(
+            &mut rng,
+            &request,
+            nonce,
+            [(2, &messages[2])].into_iter().collect(),
+            &keypair.secret_key,
+            &params,
+        )
+        .is_err());
+
+        // Commitment not created with the correct bases is rejected
+        let mut wrong_request = request.clone();
+        wrong_request.commitment = params.h[0].mul(messages[0]).into_affine();
+        assert!(BlindSignatureResponse::new::<_, Blake2b>(
+            &mut rng,
+            &wrong_request,
+            nonce,
+            [(2, &messages[2]), (3, &messages[3])].into_iter().collect(),
+            &keypair.secret_key,
+            &params,
+        )
+        .is_err());
+    }
+}
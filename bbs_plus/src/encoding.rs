@@ -0,0 +1,477 @@
+//! Encoding of claim values like integers, decimals, dates, booleans and strings to messages (field elements)
+//! before signing them.
+//!
+//! Encodings of integers, decimals and dates preserve order, i.e. if `a <= b` then the encoding of `a`
+//! is less than or equal to the encoding of `b` when both are seen as integers, and the encoding fits
+//! in a `u64`. Thus these can be used with the bound check statements of the `proof_system` crate like
+//! `BoundCheckLegoGroth16` which prove `min <= message <= max` for `u64` bounds. The bounds should be
+//! converted using the same encoding with functions like [`signed_integer_to_bound`] and [`date_to_bound`].
+//! Negative integers, decimals and dates before 1970-01-01 are encoded by adding an offset of 2^63 so that
+//! they are mapped to non-negative integers.
+//!
+//! All encodings except [`Encoding::Hashed`] are reversible so a verifier can decode the revealed messages.
+//! Arbitrary bytes are encoded by hashing and thus cannot be decoded, and neither is their order preserved.
+//!
+//! # Examples
+//!
+//! ```
+//! use ark_bls12_381::Fr;
+//! use bbs_plus::encoding::{encode_signed_integer, decode_signed_integer, encode_date, signed_integer_to_bound, date_to_bound, Encoding};
+//!
+//! let age: Fr = encode_signed_integer(25);
+//! assert_eq!(decode_signed_integer(&age).unwrap(), 25);
+//!
+//! // Bounds for proving 18 <= age <= 150 with a bound check statement
+//! let (min, max) = (signed_integer_to_bound(18), signed_integer_to_bound(150));
+//!
+//! let birth_date: Fr = encode_date(1990, 5, 17).unwrap();
+//! // Bounds for proving birth date is before 2000-01-01
+//! let max = date_to_bound(1999, 12, 31).unwrap();
+//!
+//! // The verifier decodes revealed messages using the encoding from the credential's schema
+//! assert_eq!(Encoding::Date.decode_to_string(&birth_date).unwrap(), "1990-05-17");
+//! ```
+
+use crate::error::BBSPlusError;
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::{format, string::String, vec, vec::Vec};
+use digest::{BlockInput, Digest};
+use dock_crypto_utils::hashing_utils::hash_to_field_elem;
+use serde::{Deserialize, Serialize};
+
+/// Offset added to the signed integers so that their encoding is non-negative and preserves order
+pub const SIGNED_INTEGER_OFFSET: u64 = 1 << 63;
+
+/// Maximum number of decimal places supported by the fixed point decimal encoding
+pub const MAX_DECIMAL_PLACES: u32 = 18;
+
+/// Encodings of values to messages. A credential's schema can specify the encoding for each message
+/// so that the verifier knows how to decode the revealed messages and convert the bounds for bound checks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Encoding {
+    Boolean,
+    UnsignedInteger,
+    SignedInteger,
+    /// Decimal with the given number of decimal places, encoded as a signed integer after scaling
+    FixedPointDecimal(u32),
+    /// Date as the number of days since 1970-01-01, encoded as a signed integer
+    Date,
+    ShortString,
+    Hashed,
+}
+
+impl Encoding {
+    /// Whether the original value can be recovered from the message
+    pub fn is_reversible(&self) -> bool {
+        !matches!(self, Self::Hashed)
+    }
+
+    /// Whether the encoding preserves order and thus can be used with bound checks
+    pub fn is_order_preserving(&self) -> bool {
+        matches!(
+            self,
+            Self::UnsignedInteger | Self::SignedInteger | Self::FixedPointDecimal(_) | Self::Date
+        )
+    }
+
+    /// Decode the message to a string representation of the value. Dates are formatted as `YYYY-MM-DD`.
+    /// Returns an error if the encoding is not reversible or the message is not a valid encoding.
+    pub fn decode_to_string<F: PrimeField>(&self, message: &F) -> Result<String, BBSPlusError> {
+        match self {
+            Self::Boolean => Ok(format!("{}", decode_bool(message)?)),
+            Self::UnsignedInteger => Ok(format!("{}", decode_unsigned_integer(message)?)),
+            Self::SignedInteger => Ok(format!("{}", decode_signed_integer(message)?)),
+            Self::FixedPointDecimal(decimal_places) => {
+                decode_fixed_point_decimal(message, *decimal_places)
+            }
+            Self::Date => {
+                let (year, month, day) = decode_date(message)?;
+                Ok(format!("{:04}-{:02}-{:02}", year, month, day))
+            }
+            Self::ShortString => String::from_utf8(decode_short_string(message)?)
+                .map_err(|_| BBSPlusError::CannotDecode),
+            Self::Hashed => Err(BBSPlusError::IrreversibleEncoding),
+        }
+    }
+}
+
+pub fn encode_bool<F: PrimeField>(value: bool) -> F {
+    F::from(value as u64)
+}
+
+pub fn decode_bool<F: PrimeField>(message: &F) -> Result<bool, BBSPlusError> {
+    match decode_unsigned_integer(message)? {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(BBSPlusError::CannotDecode),
+    }
+}
+
+pub fn encode_unsigned_integer<F: PrimeField>(value: u64) -> F {
+    F::from(value)
+}
+
+pub fn decode_unsigned_integer<F: PrimeField>(message: &F) -> Result<u64, BBSPlusError> {
+    let repr = message.into_repr();
+    let limbs = repr.as_ref();
+    if limbs[1..].iter().any(|l| *l != 0) {
+        return Err(BBSPlusError::CannotDecode);
+    }
+    Ok(limbs[0])
+}
+
+/// The integer that the signed integer is encoded to. Use this to convert bounds for bound checks.
+pub fn signed_integer_to_bound(value: i64) -> u64 {
+    (value as u64) ^ SIGNED_INTEGER_OFFSET
+}
+
+pub fn encode_signed_integer<F: PrimeField>(value: i64) -> F {
+    F::from(signed_integer_to_bound(value))
+}
+
+pub fn decode_signed_integer<F: PrimeField>(message: &F) -> Result<i64, BBSPlusError> {
+    Ok((decode_unsigned_integer(message)? ^ SIGNED_INTEGER_OFFSET) as i64)
+}
+
+/// The integer that the decimal, given as a string like `-12.05`, is encoded to with `decimal_places`
+/// decimal places. The decimal is scaled by `10^decimal_places` so it should not have more decimal places
+/// than `decimal_places`. Use this to convert bounds for bound checks.
+pub fn fixed_point_decimal_to_bound(value: &str, decimal_places: u32) -> Result<u64, BBSPlusError> {
+    Ok(signed_integer_to_bound(parse_decimal(
+        value,
+        decimal_places,
+    )?))
+}
+
+pub fn encode_fixed_point_decimal<F: PrimeField>(
+    value: &str,
+    decimal_places: u32,
+) -> Result<F, BBSPlusError> {
+    Ok(F::from(fixed_point_decimal_to_bound(
+        value,
+        decimal_places,
+    )?))
+}
+
+/// Decode to a string with exactly `decimal_places` decimal places
+pub fn decode_fixed_point_decimal<F: PrimeField>(
+    message: &F,
+    decimal_places: u32,
+) -> Result<String, BBSPlusError> {
+    if decimal_places > MAX_DECIMAL_PLACES {
+        return Err(BBSPlusError::UnsupportedDecimalPlaces(decimal_places));
+    }
+    let value = decode_signed_integer(message)?;
+    let sign = if value < 0 { "-" } else { "" };
+    let abs = value.unsigned_abs();
+    if decimal_places == 0 {
+        return Ok(format!("{}{}", sign, abs));
+    }
+    let scale = 10u64.pow(decimal_places);
+    Ok(format!(
+        "{}{}.{:0width$}",
+        sign,
+        abs / scale,
+        abs % scale,
+        width = decimal_places as usize
+    ))
+}
+
+/// The integer that the date is encoded to. Use this to convert bounds for bound checks.
+pub fn date_to_bound(year: i32, month: u32, day: u32) -> Result<u64, BBSPlusError> {
+    Ok(signed_integer_to_bound(days_from_civil(year, month, day)?))
+}
+
+/// Encode the date given as year, month (1-12) and day (1-31) in the proleptic Gregorian calendar
+pub fn encode_date<F: PrimeField>(year: i32, month: u32, day: u32) -> Result<F, BBSPlusError> {
+    Ok(F::from(date_to_bound(year, month, day)?))
+}
+
+/// Returns the date as year, month and day
+pub fn decode_date<F: PrimeField>(message: &F) -> Result<(i32, u32, u32), BBSPlusError> {
+    civil_from_days(decode_signed_integer(message)?)
+}
+
+/// Maximum length in bytes of strings that can be encoded reversibly for the field `F`
+pub fn max_short_string_len<F: PrimeField>() -> usize {
+    // 1 byte is for the length and the rest should be less than the modulus
+    (F::size_in_bits() - 1) / 8 - 1
+}
+
+/// Encode a short string (or any bytes) reversibly. The encoding is the length followed by the bytes
+/// interpreted as a little endian integer. Use [`encode_bytes_by_hash`] for longer strings.
+pub fn encode_short_string<F: PrimeField>(value: &[u8]) -> Result<F, BBSPlusError> {
+    if value.len() > max_short_string_len::<F>() {
+        return Err(BBSPlusError::StringTooLongForEncoding(
+            value.len(),
+            max_short_string_len::<F>(),
+        ));
+    }
+    let mut bytes = Vec::with_capacity(1 + value.len());
+    bytes.push(value.len() as u8);
+    bytes.extend_from_slice(value);
+    Ok(F::from_le_bytes_mod_order(&bytes))
+}
+
+pub fn decode_short_string<F: PrimeField>(message: &F) -> Result<Vec<u8>, BBSPlusError> {
+    let bytes = message.into_repr().to_bytes_le();
+    let len = bytes[0] as usize;
+    if len > max_short_string_len::<F>() || bytes[1 + len..].iter().any(|b| *b != 0) {
+        return Err(BBSPlusError::CannotDecode);
+    }
+    Ok(bytes[1..1 + len].to_vec())
+}
+
+/// Encode arbitrary bytes by hashing them to a field element using the hash to field of RFC 9380
+/// with domain separation tag `dst`. The encoding is not reversible.
+pub fn encode_bytes_by_hash<F: PrimeField, D: Digest + BlockInput>(value: &[u8], dst: &[u8]) -> F {
+    hash_to_field_elem::<F, D>(value, dst)
+}
+
+/// Parse a decimal like `-12.05` to an integer after scaling by `10^decimal_places`
+fn parse_decimal(value: &str, decimal_places: u32) -> Result<i64, BBSPlusError> {
+    if decimal_places > MAX_DECIMAL_PLACES {
+        return Err(BBSPlusError::UnsupportedDecimalPlaces(decimal_places));
+    }
+    let (negative, unsigned) = match value.strip_prefix('-') {
+        Some(v) => (true, v),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (int_part, frac_part) = match unsigned.split_once('.') {
+        Some((i, f)) => (i, f),
+        None => (unsigned, ""),
+    };
+    if int_part.is_empty()
+        || frac_part.len() > decimal_places as usize
+        || !int_part
+            .bytes()
+            .chain(frac_part.bytes())
+            .all(|b| b.is_ascii_digit())
+        || (unsigned.contains('.') && frac_part.is_empty())
+    {
+        return Err(BBSPlusError::InvalidDecimal(value.into()));
+    }
+    // Scaled magnitude as `int_part || frac_part || 0*` with total `decimal_places` digits after the decimal point
+    let mut magnitude = 0i128;
+    let padding = vec![b'0'; decimal_places as usize - frac_part.len()];
+    for b in int_part.bytes().chain(frac_part.bytes()).chain(padding) {
+        magnitude = magnitude * 10 + (b - b'0') as i128;
+        if magnitude > i64::MAX as i128 + 1 {
+            return Err(BBSPlusError::InvalidDecimal(value.into()));
+        }
+    }
+    let scaled = if negative { -magnitude } else { magnitude };
+    i64::try_from(scaled).map_err(|_| BBSPlusError::InvalidDecimal(value.into()))
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Number of days since 1970-01-01. Uses the algorithm from <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(year: i32, month: u32, day: u32) -> Result<i64, BBSPlusError> {
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year as i64, month) {
+        return Err(BBSPlusError::InvalidDate(year, month, day));
+    }
+    let y = year as i64 - (month <= 2) as i64;
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let m = month as i64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    Ok(era * 146097 + doe - 719468)
+}
+
+/// Inverse of [`days_from_civil`]. Uses the algorithm from <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+fn civil_from_days(days: i64) -> Result<(i32, u32, u32), BBSPlusError> {
+    let z = days.checked_add(719468).ok_or(BBSPlusError::CannotDecode)?;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    let year = i32::try_from(year).map_err(|_| BBSPlusError::CannotDecode)?;
+    Ok((year, month, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_381::Fr;
+    use ark_ff::Zero;
+    use sha2::Sha256;
+
+    #[test]
+    fn integers_and_booleans() {
+        for v in [0, 1, 100, u64::MAX] {
+            assert_eq!(
+                decode_unsigned_integer(&encode_unsigned_integer::<Fr>(v)).unwrap(),
+                v
+            );
+        }
+        for v in [0, 1, -1, 25, -1000, i64::MIN, i64::MAX] {
+            let m = encode_signed_integer::<Fr>(v);
+            assert_eq!(decode_signed_integer(&m).unwrap(), v);
+            assert_eq!(m, Fr::from(signed_integer_to_bound(v)));
+        }
+        // Order is preserved
+        let values = [i64::MIN, -1000, -1, 0, 1, 25, i64::MAX];
+        for w in values.windows(2) {
+            assert!(signed_integer_to_bound(w[0]) < signed_integer_to_bound(w[1]));
+        }
+        assert_eq!(signed_integer_to_bound(0), SIGNED_INTEGER_OFFSET);
+
+        assert!(decode_bool(&encode_bool::<Fr>(true)).unwrap());
+        assert!(!decode_bool(&encode_bool::<Fr>(false)).unwrap());
+        assert!(decode_bool(&Fr::from(2u64)).is_err());
+
+        // Messages larger than a u64 can't be decoded
+        let large = Fr::from(u64::MAX) + Fr::from(1u64);
+        assert!(decode_unsigned_integer(&large).is_err());
+        assert!(decode_signed_integer(&-Fr::from(1u64)).is_err());
+    }
+
+    #[test]
+    fn fixed_point_decimals() {
+        for (value, places, expected_scaled, decoded) in [
+            ("12.5", 2, 1250, "12.50"),
+            ("-12.05", 2, -1205, "-12.05"),
+            ("+0.001", 3, 1, "0.001"),
+            ("-0.5", 1, -5, "-0.5"),
+            ("7", 0, 7, "7"),
+            ("42", 3, 42000, "42.000"),
+        ] {
+            assert_eq!(
+                fixed_point_decimal_to_bound(value, places).unwrap(),
+                signed_integer_to_bound(expected_scaled)
+            );
+            let m = encode_fixed_point_decimal::<Fr>(value, places).unwrap();
+            assert_eq!(decode_fixed_point_decimal(&m, places).unwrap(), decoded);
+            assert_eq!(
+                Encoding::FixedPointDecimal(places)
+                    .decode_to_string(&m)
+                    .unwrap(),
+                decoded
+            );
+        }
+        for (value, places) in [
+            ("12.345", 2),
+            ("", 2),
+            ("1.", 2),
+            (".5", 2),
+            ("1.2.3", 2),
+            ("abc", 2),
+            ("1e5", 2),
+            ("99999999999999999999", 0),
+            ("1", MAX_DECIMAL_PLACES + 1),
+        ] {
+            assert!(fixed_point_decimal_to_bound(value, places).is_err());
+        }
+        // Order is preserved
+        let values = ["-1.5", "-1.25", "0", "0.01", "3"];
+        for w in values.windows(2) {
+            assert!(
+                fixed_point_decimal_to_bound(w[0], 2).unwrap()
+                    < fixed_point_decimal_to_bound(w[1], 2).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn dates() {
+        for (y, m, d, days) in [
+            (1970, 1, 1, 0),
+            (1970, 1, 2, 1),
+            (1969, 12, 31, -1),
+            (2000, 2, 29, 11016),
+            (2023, 6, 15, 19523),
+            (1900, 3, 1, -25508),
+            (-1, 12, 31, -719529),
+        ] {
+            assert_eq!(
+                date_to_bound(y, m, d).unwrap(),
+                signed_integer_to_bound(days)
+            );
+            let msg = encode_date::<Fr>(y, m, d).unwrap();
+            assert_eq!(decode_date(&msg).unwrap(), (y, m, d));
+        }
+        assert_eq!(
+            Encoding::Date
+                .decode_to_string(&encode_date::<Fr>(1990, 5, 17).unwrap())
+                .unwrap(),
+            "1990-05-17"
+        );
+        // Order is preserved
+        assert!(date_to_bound(1999, 12, 31).unwrap() < date_to_bound(2000, 1, 1).unwrap());
+        assert!(date_to_bound(1960, 6, 1).unwrap() < date_to_bound(1970, 1, 1).unwrap());
+
+        for (y, m, d) in [
+            (2023, 2, 29),
+            (1900, 2, 29),
+            (2023, 13, 1),
+            (2023, 0, 1),
+            (2023, 4, 31),
+            (2023, 1, 0),
+        ] {
+            assert!(date_to_bound(y, m, d).is_err());
+        }
+        // Dates in the range of day counts are decoded
+        for days in [-1_000_000, -719468, 0, 59, 60, 1_000_000] {
+            let (y, m, d) = civil_from_days(days).unwrap();
+            assert_eq!(days_from_civil(y, m, d).unwrap(), days);
+        }
+    }
+
+    #[test]
+    fn strings_and_bytes() {
+        assert_eq!(max_short_string_len::<Fr>(), 30);
+        for s in [
+            "",
+            "a",
+            "hello world",
+            "abc\0",
+            "012345678901234567890123456789",
+        ] {
+            let m = encode_short_string::<Fr>(s.as_bytes()).unwrap();
+            assert_eq!(decode_short_string(&m).unwrap(), s.as_bytes());
+            assert_eq!(Encoding::ShortString.decode_to_string(&m).unwrap(), s);
+        }
+        // Trailing zero bytes are not lost
+        assert_ne!(
+            encode_short_string::<Fr>(b"abc").unwrap(),
+            encode_short_string::<Fr>(b"abc\0").unwrap()
+        );
+        assert!(encode_short_string::<Fr>(&[1; 31]).is_err());
+        assert!(decode_short_string(&-Fr::from(1u64)).is_err());
+        assert!(Encoding::ShortString
+            .decode_to_string(&encode_short_string::<Fr>(&[0xff, 0xfe]).unwrap())
+            .is_err());
+
+        let dst = b"BBS-PLUS-TEST-ENCODING";
+        let m = encode_bytes_by_hash::<Fr, Sha256>(b"a long string that doesn't fit", dst);
+        assert_eq!(
+            m,
+            encode_bytes_by_hash::<Fr, Sha256>(b"a long string that doesn't fit", dst)
+        );
+        assert_ne!(
+            m,
+            encode_bytes_by_hash::<Fr, Sha256>(b"another string", dst)
+        );
+        assert!(!m.is_zero());
+        assert!(Encoding::Hashed.decode_to_string(&m).is_err());
+
+        assert!(!Encoding::Hashed.is_reversible());
+        assert!(Encoding::ShortString.is_reversible());
+        assert!(Encoding::Date.is_order_preserving());
+        assert!(!Encoding::ShortString.is_order_preserving());
+        assert!(!Encoding::Boolean.is_order_preserving());
+    }
+}
@@ -1,7 +1,7 @@
 // TODO: At some point this should be replaced with crates anyhow and thiserror but thiserror is no_std compatible at the moment.
 
 use ark_serialize::SerializationError;
-use ark_std::fmt::Debug;
+use ark_std::{fmt::Debug, string::String, vec::Vec};
 use dock_crypto_utils::serde_utils::ArkSerializationError;
 use schnorr_pok::error::SchnorrError;
 use serde::Serialize;
@@ -22,6 +22,57 @@ pub enum BBSPlusError {
     /// 2nd schnorr proof failed during verification of proof of knowledge of signature
     SecondSchnorrVerificationFailed,
     InvalidMsgIdxForResponse(usize),
+    /// Message count is not revealed at `MESSAGE_COUNT_INDEX` or differs from the expected count which
+    /// is contained
+    MessageCountNotRevealed(usize),
+    /// Key material for key generation should be at least 32 bytes long
+    InsufficientKeyMaterial(usize),
+    /// Key info for key generation should be at most 65535 bytes long
+    KeyInfoTooLong(usize),
+    /// Secret key derived during key generation is 0
+    ZeroSecretKey,
+    /// Bytes do not correspond to a valid octet encoding of a point or scalar as per the ciphersuite
+    InvalidOctetEncoding,
+    /// No ciphersuite with the contained identifier is supported for the pairing
+    UnsupportedCiphersuite(Vec<u8>),
+    /// Threshold should be non-zero and at most the total number of participants. Contains the threshold and the total
+    InvalidThreshold(u16, u16),
+    /// Participant ids should be non-zero and at most the total number of participants
+    InvalidParticipantId(u16),
+    AlreadyReceivedFromParticipant(u16),
+    /// Contains the participant id, the number of commitments sent and the expected number
+    IncorrectNumberOfDkgCommitments(u16, usize, usize),
+    /// Share sent by the participant during DKG doesn't match its commitments
+    InvalidDkgShare(u16),
+    MissingShareFromParticipant(u16),
+    /// Number of signers is less than the threshold. Contains the number of signers and the threshold
+    BelowThreshold(usize, u16),
+    ParticipantNotInSigners(u16),
+    MissingRound1MessageFromParticipant(u16),
+    NoPartialSignatures,
+    /// Partial signature has a different `e` or `s` than others
+    InconsistentPartialSignature(u16),
+    /// Signer was asked to sign a message at an index that is also committed by the requester
+    MessageIndexBothCommittedAndUncommitted(usize),
+    /// Number of signatures and number of message vectors given for batch verification differ
+    SignatureAndMessageCountMismatch(usize, usize),
+    /// Batch verification failed. Contains the indices of the invalid signatures
+    BatchVerificationFailed(Vec<usize>),
+    /// Message is not a valid encoding of a value of the expected type
+    CannotDecode,
+    /// Encoding does not allow getting the original value back, like hashing
+    IrreversibleEncoding,
+    UnsupportedDecimalPlaces(u32),
+    InvalidDecimal(String),
+    InvalidDate(i32, u32, u32),
+    StringTooLongForEncoding(usize, usize),
+    /// Old and new public keys in a key rotation are the same
+    SameKeyInRotation,
+    InvalidKeyRotationRecord,
+    /// Record at the index doesn't rotate from the previous key in the chain or rotates to a key already in the chain
+    BrokenKeyRotationChain(usize),
+    /// Record at the index in the chain is invalid
+    InvalidKeyRotationRecordInChain(usize),
     #[serde(with = "ArkSerializationError")]
     Serialization(SerializationError),
     SchnorrError(SchnorrError),
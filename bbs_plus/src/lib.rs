@@ -11,17 +11,29 @@
 //! 1. Signature parameters and key generation module - [`setup`]
 //! 2. Signature module - [`signature`]
 //! 3. Proof of knowledge of signature module - [`proof`]
+//! 4. BBS signature as per the IETF draft and its proof of knowledge - [`bbs`]
+//! 5. Threshold issuance of BBS+ signatures - [`threshold`]
+//! 6. Issuance of BBS+ signatures over messages hidden from the signer - [`blind_signature`]
+//! 7. Encoding of values like integers, dates and strings to messages - [`encoding`]
 //!
 //! The implementation tries to use the same variable names as the paper and thus violate Rust's naming conventions at places.
 //!
 //! [`setup`]: crate::setup
 //! [`signature`]: crate::signature
 //! [`proof`]: crate::proof
+//! [`bbs`]: crate::bbs
+//! [`threshold`]: crate::threshold
+//! [`blind_signature`]: crate::blind_signature
+//! [`encoding`]: crate::encoding
 
+pub mod bbs;
+pub mod blind_signature;
+pub mod encoding;
 pub mod error;
 pub mod proof;
 pub mod setup;
 pub mod signature;
+pub mod threshold;
 
 pub mod prelude {
     pub use crate::error::BBSPlusError;
@@ -64,4 +76,21 @@ pub mod tests {
             assert_eq!($obj, deser);
         };
     }
+
+    /// Serialization of objects containing secrets should fail unless the feature `serialize-secrets` is enabled
+    #[macro_export]
+    macro_rules! test_secret_serialization {
+        ($obj_type:ty, $obj: ident) => {
+            if dock_crypto_utils::secret::SECRETS_SERIALIZABLE {
+                test_serialization!($obj_type, $obj);
+            } else {
+                let mut serz = vec![];
+                assert!(CanonicalSerialize::serialize(&$obj, &mut serz).is_err());
+                assert!($obj.serialize_unchecked(&mut serz).is_err());
+                assert!($obj.serialize_uncompressed(&mut serz).is_err());
+                assert!(serde_json::to_string(&$obj).is_err());
+                assert!(rmp_serde::to_vec_named(&$obj).is_err());
+            }
+        };
+    }
 }
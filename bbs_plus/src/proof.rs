@@ -9,6 +9,7 @@
 //! use bbs_plus::signature::SignatureG1;
 //! use bbs_plus::proof::PoKOfSignatureG1Protocol;
 //! use ark_std::collections::{BTreeSet, BTreeMap};
+//! use dock_crypto_utils::transcript::Transcript;
 //!
 //! let params_g1 = SignatureParamsG1::<Bls12_381>::generate_using_rng(&mut rng, 5);
 //! let keypair_g2 = KeypairG2::<Bls12_381>::generate(&mut rng, &params_g1);
@@ -40,10 +41,19 @@
 //!         )
 //!         .unwrap();
 //!
-//! // challenge is generated (see tests)
+//! let mut revealed_msgs = BTreeMap::new();
+//! // Populate `revealed_msgs` with 0-based indices of revealed messages and the messages
+//!
+//! // challenge is generated from a transcript of the protocol
+//! let mut transcript = Transcript::new(b"my-app");
+//! pok.append_to_transcript(&revealed_msgs, &params_g1, &mut transcript).unwrap();
+//! let challenge = transcript.challenge_scalar::<Fr, Blake2b>(b"challenge");
 //! let proof = pok.gen_proof(&challenge).unwrap();
 //!
-//! let mut revealed_msgs = BTreeMap::new();
+//! // Verifier generates the same challenge from the proof
+//! let mut transcript = Transcript::new(b"my-app");
+//! proof.append_to_transcript(&revealed_msgs, &params_g1, &mut transcript).unwrap();
+//! let challenge = transcript.challenge_scalar::<Fr, Blake2b>(b"challenge");
 //! proof
 //!             .verify(
 //!                 &revealed_msgs,
@@ -58,7 +68,7 @@
 
 use crate::error::BBSPlusError;
 use crate::setup::{PublicKeyG2, SignatureParamsG1};
-use crate::signature::SignatureG1;
+use crate::signature::{length_tagged_messages, SignatureG1, MESSAGE_COUNT_INDEX};
 use ark_ec::msm::VariableBaseMSM;
 use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
 use ark_ff::{Field, PrimeField, Zero};
@@ -73,13 +83,25 @@ use ark_std::{
     One, UniformRand,
 };
 use dock_crypto_utils::randomized_pairing_check::RandomizedPairingChecker;
+use dock_crypto_utils::secret::{Secret, SecretAs};
 use dock_crypto_utils::serde_utils::*;
+use dock_crypto_utils::transcript::Transcript;
 use schnorr_pok::{error::SchnorrError, SchnorrCommitment, SchnorrResponse};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 pub use serialization::*;
 use zeroize::Zeroize;
 
+/// A proof with the public values needed to verify it, i.e. `(proof, revealed messages, challenge, public key, params)`.
+/// Used in batch verification of proofs.
+pub type PoKOfSignatureG1ProofBatchItem<'a, E> = (
+    &'a PoKOfSignatureG1Proof<E>,
+    &'a BTreeMap<usize, <E as PairingEngine>::Fr>,
+    &'a <E as PairingEngine>::Fr,
+    &'a PublicKeyG2<E>,
+    &'a SignatureParamsG1<E>,
+);
+
 /// Proof of knowledge of BBS+ signature in group G1
 /// The BBS+ signature proves validity of a set of messages {m_i}, i in I. This stateful protocol proves knowledge of such
 /// a signature whilst selectively disclosing only a subset of the messages, {m_i} for i in a disclosed set D. The
@@ -100,12 +122,12 @@ pub struct PoKOfSignatureG1Protocol<E: PairingEngine> {
     pub d: E::G1Affine,
     /// For proving relation `A_bar - d = A_prime * -e + h_0 * r2`
     pub sc_comm_1: SchnorrCommitment<E::G1Affine>,
-    #[serde_as(as = "[FieldBytes; 2]")]
-    sc_wits_1: [E::Fr; 2],
+    #[serde_as(as = "SecretAs<[FieldBytes; 2]>")]
+    sc_wits_1: Secret<[E::Fr; 2]>,
     /// For proving relation `g1 + \sum_{i in D}(h_i*m_i)` = `d*r3 + {h_0}*{-s'} + sum_{j notin D}(h_j*m_j)`
     pub sc_comm_2: SchnorrCommitment<E::G1Affine>,
-    #[serde_as(as = "Vec<FieldBytes>")]
-    sc_wits_2: Vec<E::Fr>,
+    #[serde_as(as = "SecretAs<Vec<FieldBytes>>")]
+    sc_wits_2: Secret<Vec<E::Fr>>,
 }
 
 /// Proof of knowledge of the signature in G1. It contains the randomized signature, commitment (Schnorr step 1)
@@ -253,12 +275,86 @@ where
             A_bar: A_bar.into_affine(),
             d: bases_2.remove(0),
             sc_comm_1,
-            sc_wits_1: wits_1,
+            sc_wits_1: Secret::new(wits_1),
             sc_comm_2,
-            sc_wits_2: wits_2,
+            sc_wits_2: Secret::new(wits_2),
         })
     }
 
+    /// Initiate the protocol for a signature created using [`SignatureG1::new_with_params_prefix`].
+    /// `params` can support more messages than the signature, like params extended using
+    /// [`SignatureParamsG1::extend`] after signing, and only their prefix for the length tagged messages
+    /// is used so `params` can be used for challenge contribution as well. Indices in `blindings` and
+    /// `revealed_msg_indices` are of the length tagged messages, i.e. `i+1` for `messages[i]`, and the
+    /// message count at [`MESSAGE_COUNT_INDEX`] must be revealed as the verifier needs it to pick the prefix.
+    pub fn init_with_params_prefix<R: RngCore>(
+        rng: &mut R,
+        signature: &SignatureG1<E>,
+        params: &SignatureParamsG1<E>,
+        messages: &[E::Fr],
+        blindings: BTreeMap<usize, E::Fr>,
+        revealed_msg_indices: BTreeSet<usize>,
+    ) -> Result<Self, BBSPlusError> {
+        if !revealed_msg_indices.contains(&MESSAGE_COUNT_INDEX) {
+            return Err(BBSPlusError::MessageCountNotRevealed(messages.len()));
+        }
+        Self::init(
+            rng,
+            signature,
+            &params.prefix(messages.len() + 1)?,
+            &length_tagged_messages(messages, messages.len() + 1)?,
+            blindings,
+            revealed_msg_indices,
+        )
+    }
+
+    /// Initiate the protocol for a signature created using [`SignatureG1::new`] over `messages` with params
+    /// that have since been extended using [`SignatureParamsG1::extend`]. Only the prefix of `params` for
+    /// `messages` is used so `params` can be used for challenge contribution as well. The verifier uses
+    /// [`PoKOfSignatureG1Proof::verify_with_plain_params_prefix`] with the message count of the signature.
+    pub fn init_with_plain_params_prefix<R: RngCore>(
+        rng: &mut R,
+        signature: &SignatureG1<E>,
+        params: &SignatureParamsG1<E>,
+        messages: &[E::Fr],
+        blindings: BTreeMap<usize, E::Fr>,
+        revealed_msg_indices: BTreeSet<usize>,
+    ) -> Result<Self, BBSPlusError> {
+        Self::init(
+            rng,
+            signature,
+            &params.prefix(messages.len())?,
+            messages,
+            blindings,
+            revealed_msg_indices,
+        )
+    }
+
+    /// Initiate the protocol for a signature created using [`SignatureG1::new_with_variable_length_messages`].
+    /// `params` are created from the signer's label for any message count greater than `messages.len()`
+    /// and the proof is over the length tagged messages padded to the size of `params`, see
+    /// [`length_tagged_messages`]. Thus the verifier only learns that there are fewer messages than the
+    /// params support. Indices in `blindings` and `revealed_msg_indices` are of the length tagged messages,
+    /// i.e. `i+1` for `messages[i]`. The message count is at [`MESSAGE_COUNT_INDEX`](crate::signature::MESSAGE_COUNT_INDEX) and can be revealed
+    /// like other messages or its bounds proven using its response from [`PoKOfSignatureG1Proof::get_resp_for_message`].
+    pub fn init_with_variable_length_messages<R: RngCore>(
+        rng: &mut R,
+        signature: &SignatureG1<E>,
+        params: &SignatureParamsG1<E>,
+        messages: &[E::Fr],
+        blindings: BTreeMap<usize, E::Fr>,
+        revealed_msg_indices: BTreeSet<usize>,
+    ) -> Result<Self, BBSPlusError> {
+        Self::init(
+            rng,
+            signature,
+            params,
+            &length_tagged_messages(messages, params.supported_message_count())?,
+            blindings,
+            revealed_msg_indices,
+        )
+    }
+
     /// Get the contribution of this protocol towards the challenge, i.e. bytecode of items that will be hashed
     pub fn challenge_contribution<W: Write>(
         &self,
@@ -278,12 +374,35 @@ where
         )
     }
 
+    /// Append the contribution of this protocol towards the challenge to the transcript
+    pub fn append_to_transcript(
+        &self,
+        revealed_msgs: &BTreeMap<usize, E::Fr>,
+        params: &SignatureParamsG1<E>,
+        transcript: &mut Transcript,
+    ) -> Result<(), BBSPlusError> {
+        Self::compute_transcript_contribution(
+            &self.A_prime,
+            &self.A_bar,
+            &self.d,
+            &self.sc_comm_1.t,
+            &self.sc_comm_2.t,
+            revealed_msgs,
+            params,
+            transcript,
+        )
+    }
+
     /// Generate proof. Post-challenge phase of the protocol.
     pub fn gen_proof(self, challenge: &E::Fr) -> Result<PoKOfSignatureG1Proof<E>, BBSPlusError> {
         // Schnorr response for relation `A_bar - d == A'*{-e} + h_0*r2`
-        let resp_1 = self.sc_comm_1.response(&self.sc_wits_1, challenge)?;
+        let resp_1 = self
+            .sc_comm_1
+            .response(self.sc_wits_1.expose(), challenge)?;
         // Schnorr response for relation `g1 + \sum_{i in D}(h_i*m_i)` = `d*r3 + {h_0}*{-s'} + \sum_{j not in D}(h_j*{-m_j})`
-        let resp_2 = self.sc_comm_2.response(&self.sc_wits_2, challenge)?;
+        let resp_2 = self
+            .sc_comm_2
+            .response(self.sc_wits_2.expose(), challenge)?;
 
         Ok(PoKOfSignatureG1Proof {
             A_prime: self.A_prime,
@@ -344,6 +463,50 @@ where
             .serialize_unchecked(&mut writer)?;
         T2.serialize_unchecked(&mut writer).map_err(|e| e.into())
     }
+
+    /// Append the values of [`PoKOfSignatureG1Protocol::compute_challenge_contribution`] to the transcript with a
+    /// label each
+    pub fn compute_transcript_contribution(
+        A_prime: &E::G1Affine,
+        A_bar: &E::G1Affine,
+        d: &E::G1Affine,
+        T1: &E::G1Affine,
+        T2: &E::G1Affine,
+        revealed_msgs: &BTreeMap<usize, E::Fr>,
+        params: &SignatureParamsG1<E>,
+        transcript: &mut Transcript,
+    ) -> Result<(), BBSPlusError> {
+        transcript.append_point(b"A_bar", A_bar)?;
+
+        // For 1st Schnorr
+        transcript.append_point(b"A_prime", A_prime)?;
+        transcript.append_point(b"h_0", &params.h_0)?;
+        let mut A_bar_minus_d = A_bar.into_projective();
+        A_bar_minus_d -= d.into_projective();
+        transcript.append_point(b"A_bar_minus_d", &A_bar_minus_d.into_affine())?;
+        transcript.append_point(b"T1", T1)?;
+
+        // For 2nd Schnorr
+        let mut bases_revealed = Vec::with_capacity(1 + revealed_msgs.len());
+        let mut exponents = Vec::with_capacity(1 + revealed_msgs.len());
+        transcript.append_point(b"g1", &params.g1)?;
+        transcript.append_scalar(b"revealed_msg", &E::Fr::one())?;
+        bases_revealed.push(params.g1);
+        exponents.push(E::Fr::one().into_repr());
+        for (i, msg) in revealed_msgs {
+            assert!(*i < params.h.len());
+            transcript.append_point(b"h", &params.h[*i])?;
+            transcript.append_scalar(b"revealed_msg", msg)?;
+            bases_revealed.push(params.h[*i]);
+            exponents.push(msg.into_repr());
+        }
+        transcript.append_point(
+            b"revealed_msgs_commitment",
+            &VariableBaseMSM::multi_scalar_mul(&bases_revealed, &exponents).into_affine(),
+        )?;
+        transcript.append_point(b"T2", T2)?;
+        Ok(())
+    }
 }
 
 impl<E: PairingEngine> Zeroize for PoKOfSignatureG1Protocol<E> {
@@ -392,6 +555,43 @@ where
         Ok(())
     }
 
+    /// Verify the proof created using [`PoKOfSignatureG1Protocol::init_with_params_prefix`] for a
+    /// signature over `message_count` messages. `revealed_msgs` must contain the message count at
+    /// [`MESSAGE_COUNT_INDEX`] and it must be `message_count` as the signature is bound to its count.
+    pub fn verify_with_params_prefix(
+        &self,
+        revealed_msgs: &BTreeMap<usize, E::Fr>,
+        challenge: &E::Fr,
+        pk: &PublicKeyG2<E>,
+        params: &SignatureParamsG1<E>,
+        message_count: usize,
+    ) -> Result<(), BBSPlusError> {
+        if revealed_msgs.get(&MESSAGE_COUNT_INDEX) != Some(&E::Fr::from(message_count as u64)) {
+            return Err(BBSPlusError::MessageCountNotRevealed(message_count));
+        }
+        self.verify(
+            revealed_msgs,
+            challenge,
+            pk,
+            &params.prefix(message_count + 1)?,
+        )
+    }
+
+    /// Verify the proof created using [`PoKOfSignatureG1Protocol::init_with_plain_params_prefix`] for a
+    /// signature over `message_count` messages. As the message count is not signed, a signature over fewer
+    /// messages is also valid over `message_count` messages when the rest are 0 so the verifier must decide
+    /// `message_count`, like from the credential schema at the time of signing.
+    pub fn verify_with_plain_params_prefix(
+        &self,
+        revealed_msgs: &BTreeMap<usize, E::Fr>,
+        challenge: &E::Fr,
+        pk: &PublicKeyG2<E>,
+        params: &SignatureParamsG1<E>,
+        message_count: usize,
+    ) -> Result<(), BBSPlusError> {
+        self.verify(revealed_msgs, challenge, pk, &params.prefix(message_count)?)
+    }
+
     pub fn verify_with_randomized_pairing_checker(
         &self,
         revealed_msgs: &BTreeMap<usize, E::Fr>,
@@ -405,6 +605,77 @@ where
         Ok(())
     }
 
+    /// Verify several proofs together where the proofs can be of signatures by different signers and
+    /// with different params. The Schnorr proofs are verified individually but the pairing checks
+    /// `e(A'_i, w_i) == e(A_bar_i, g2_i)` are combined with a random linear combination where terms with
+    /// the same public key `w_i` or the same `g2_i` are merged. Thus the number of pairings is the number
+    /// of distinct public keys plus the number of distinct `g2`s. If verification fails, each proof is
+    /// verified to find the invalid ones and their indices are returned in the error.
+    pub fn verify_batch<R: RngCore>(
+        rng: &mut R,
+        proofs: &[PoKOfSignatureG1ProofBatchItem<E>],
+    ) -> Result<(), BBSPlusError> {
+        if Self::verify_batch_without_pinpointing(rng, proofs) {
+            return Ok(());
+        }
+        let invalid = proofs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, (proof, revealed_msgs, challenge, pk, params))| {
+                proof
+                    .verify(revealed_msgs, challenge, pk, params)
+                    .err()
+                    .map(|_| i)
+            })
+            .collect::<Vec<_>>();
+        Err(BBSPlusError::BatchVerificationFailed(invalid))
+    }
+
+    fn verify_batch_without_pinpointing<R: RngCore>(
+        rng: &mut R,
+        proofs: &[PoKOfSignatureG1ProofBatchItem<E>],
+    ) -> bool {
+        // Distinct public keys and the sum of `A'_i*r_i` paired with each
+        let mut pks = Vec::<E::G2Affine>::new();
+        let mut pk_terms = Vec::<E::G1Projective>::new();
+        // Distinct `g2`s and the sum of `A_bar_i*r_i` paired with each
+        let mut g2s = Vec::<E::G2Affine>::new();
+        let mut g2_terms = Vec::<E::G1Projective>::new();
+        for (proof, revealed_msgs, challenge, pk, params) in proofs {
+            if proof
+                .verify_except_pairings(revealed_msgs, challenge, params)
+                .is_err()
+            {
+                return false;
+            }
+            let r = E::Fr::rand(rng).into_repr();
+            let A_prime_r = proof.A_prime.mul(r);
+            match pks.iter().position(|p| *p == pk.0) {
+                Some(j) => pk_terms[j] += &A_prime_r,
+                None => {
+                    pks.push(pk.0);
+                    pk_terms.push(A_prime_r);
+                }
+            }
+            let A_bar_r = proof.A_bar.mul(r);
+            match g2s.iter().position(|g| *g == params.g2) {
+                Some(j) => g2_terms[j] += &A_bar_r,
+                None => {
+                    g2s.push(params.g2);
+                    g2_terms.push(A_bar_r);
+                }
+            }
+        }
+        let mut pairing_checker = RandomizedPairingChecker::<E>::new_using_rng(rng, true);
+        pairing_checker.add_multiple_sources(
+            &E::G1Projective::batch_normalization_into_affine(&pk_terms),
+            &pks,
+            &E::G1Projective::batch_normalization_into_affine(&g2_terms),
+            &g2s,
+        );
+        pairing_checker.verify()
+    }
+
     /// For the verifier to independently calculate the challenge
     pub fn challenge_contribution<W: Write>(
         &self,
@@ -424,6 +695,25 @@ where
         )
     }
 
+    /// For the verifier to independently calculate the challenge using a transcript
+    pub fn append_to_transcript(
+        &self,
+        revealed_msgs: &BTreeMap<usize, E::Fr>,
+        params: &SignatureParamsG1<E>,
+        transcript: &mut Transcript,
+    ) -> Result<(), BBSPlusError> {
+        PoKOfSignatureG1Protocol::compute_transcript_contribution(
+            &self.A_prime,
+            &self.A_bar,
+            &self.d,
+            &self.T1,
+            &self.T2,
+            revealed_msgs,
+            params,
+            transcript,
+        )
+    }
+
     /// Get the response from post-challenge phase of the Schnorr protocol for the given message index
     /// `msg_idx`. Used when comparing message equality
     pub fn get_resp_for_message(
@@ -471,27 +761,7 @@ where
         }
 
         // Verify the 2nd Schnorr proof
-        let mut bases_2 =
-            Vec::with_capacity(2 + params.supported_message_count() - revealed_msgs.len());
-        bases_2.push(self.d);
-        bases_2.push(params.h_0);
-
-        let mut bases_revealed = Vec::with_capacity(1 + revealed_msgs.len());
-        let mut exponents = Vec::with_capacity(1 + revealed_msgs.len());
-        bases_revealed.push(params.g1);
-        exponents.push(E::Fr::one().into_repr());
-        for i in 0..params.supported_message_count() {
-            if revealed_msgs.contains_key(&i) {
-                let message = revealed_msgs.get(&i).unwrap();
-                bases_revealed.push(params.h[i]);
-                exponents.push(message.into_repr());
-            } else {
-                bases_2.push(params.h[i]);
-            }
-        }
-        // pr = -g1 + \sum_{i in D}(h_i*{-m_i}) = -(g1 + \sum_{i in D}(h_i*{m_i}))
-        let pr = -VariableBaseMSM::multi_scalar_mul(&bases_revealed, &exponents);
-        let pr = pr.into_affine();
+        let (bases_2, pr) = Self::second_schnorr_bases_and_instance(&self.d, revealed_msgs, params);
         match self.sc_resp_2.is_valid(&bases_2, &pr, &self.T2, challenge) {
             Ok(()) => (),
             Err(SchnorrError::InvalidResponse) => {
@@ -516,6 +786,75 @@ where
         }
         self.verify_schnorr_proofs(revealed_msgs, challenge, params)
     }
+
+    /// Simulate the proof for the given challenge without knowing the messages or the signature. Only the Schnorr
+    /// proofs are simulated, i.e. `d` is chosen randomly and the commitments `T1` and `T2` are computed from random
+    /// responses. The randomized signature `(A_prime, A_bar)` has to satisfy `e(A_prime, pk) == e(A_bar, g2)` and is
+    /// taken as input since it cannot be created without the signer's secret key, as `A_bar = A_prime * sk`.
+    pub fn simulate<R: RngCore>(
+        rng: &mut R,
+        A_prime: E::G1Affine,
+        A_bar: E::G1Affine,
+        revealed_msgs: &BTreeMap<usize, E::Fr>,
+        challenge: &E::Fr,
+        params: &SignatureParamsG1<E>,
+    ) -> Self {
+        let d = E::G1Projective::rand(rng).into_affine();
+
+        // For relation `A_bar - d = A_prime * -e + h_0 * r2`
+        let mut A_bar_minus_d = A_bar.into_projective();
+        A_bar_minus_d -= d.into_projective();
+        let (T1, sc_resp_1) = SchnorrResponse::simulate(
+            rng,
+            &[A_prime, params.h_0],
+            &A_bar_minus_d.into_affine(),
+            challenge,
+        );
+
+        // For relation `d*{-r3} + h_0*s_prime + \sum_{j \notin D}(h_j*m_j)` = `-g1 + \sum_{i \in D}(h_i*{-m_i})`
+        let (bases_2, pr) = Self::second_schnorr_bases_and_instance(&d, revealed_msgs, params);
+        let (T2, sc_resp_2) = SchnorrResponse::simulate(rng, &bases_2, &pr, challenge);
+
+        Self {
+            A_prime,
+            A_bar,
+            d,
+            T1,
+            sc_resp_1,
+            T2,
+            sc_resp_2,
+        }
+    }
+
+    /// Bases and instance of the 2nd Schnorr proof, i.e. `[d, h_0, h_j for all j notin D]` and
+    /// `-g1 + \sum_{i in D}(h_i*{-m_i})`
+    fn second_schnorr_bases_and_instance(
+        d: &E::G1Affine,
+        revealed_msgs: &BTreeMap<usize, E::Fr>,
+        params: &SignatureParamsG1<E>,
+    ) -> (Vec<E::G1Affine>, E::G1Affine) {
+        let mut bases_2 =
+            Vec::with_capacity(2 + params.supported_message_count() - revealed_msgs.len());
+        bases_2.push(*d);
+        bases_2.push(params.h_0);
+
+        let mut bases_revealed = Vec::with_capacity(1 + revealed_msgs.len());
+        let mut exponents = Vec::with_capacity(1 + revealed_msgs.len());
+        bases_revealed.push(params.g1);
+        exponents.push(E::Fr::one().into_repr());
+        for i in 0..params.supported_message_count() {
+            if revealed_msgs.contains_key(&i) {
+                let message = revealed_msgs.get(&i).unwrap();
+                bases_revealed.push(params.h[i]);
+                exponents.push(message.into_repr());
+            } else {
+                bases_2.push(params.h[i]);
+            }
+        }
+        // pr = -g1 + \sum_{i in D}(h_i*{-m_i}) = -(g1 + \sum_{i in D}(h_i*{m_i}))
+        let pr = -VariableBaseMSM::multi_scalar_mul(&bases_revealed, &exponents);
+        (bases_2, pr.into_affine())
+    }
 }
 
 mod serialization {
@@ -527,8 +866,9 @@ mod serialization {
             self.A_bar.serialize(&mut writer)?;
             self.d.serialize(&mut writer)?;
             ark_serialize::CanonicalSerialize::serialize(&self.sc_comm_1, &mut writer)?;
-            self.sc_wits_1[0].serialize(&mut writer)?;
-            self.sc_wits_1[1].serialize(&mut writer)?;
+            let sc_wits_1 = self.sc_wits_1.expose_for_serialization()?;
+            sc_wits_1[0].serialize(&mut writer)?;
+            sc_wits_1[1].serialize(&mut writer)?;
             ark_serialize::CanonicalSerialize::serialize(&self.sc_comm_2, &mut writer)?;
             self.sc_wits_2.serialize(&mut writer)
         }
@@ -538,8 +878,8 @@ mod serialization {
                 + self.A_bar.serialized_size()
                 + self.d.serialized_size()
                 + self.sc_comm_1.serialized_size()
-                + self.sc_wits_1[0].serialized_size()
-                + self.sc_wits_1[1].serialized_size()
+                + self.sc_wits_1.expose()[0].serialized_size()
+                + self.sc_wits_1.expose()[1].serialized_size()
                 + self.sc_comm_2.serialized_size()
                 + self.sc_wits_2.serialized_size()
         }
@@ -552,8 +892,9 @@ mod serialization {
             self.A_bar.serialize_uncompressed(&mut writer)?;
             self.d.serialize_uncompressed(&mut writer)?;
             self.sc_comm_1.serialize_uncompressed(&mut writer)?;
-            self.sc_wits_1[0].serialize(&mut writer)?;
-            self.sc_wits_1[1].serialize(&mut writer)?;
+            let sc_wits_1 = self.sc_wits_1.expose_for_serialization()?;
+            sc_wits_1[0].serialize(&mut writer)?;
+            sc_wits_1[1].serialize(&mut writer)?;
             self.sc_comm_2.serialize_uncompressed(&mut writer)?;
             self.sc_wits_2.serialize(&mut writer)
         }
@@ -563,8 +904,9 @@ mod serialization {
             self.A_bar.serialize_unchecked(&mut writer)?;
             self.d.serialize_unchecked(&mut writer)?;
             self.sc_comm_1.serialize_unchecked(&mut writer)?;
-            self.sc_wits_1[0].serialize(&mut writer)?;
-            self.sc_wits_1[1].serialize(&mut writer)?;
+            let sc_wits_1 = self.sc_wits_1.expose_for_serialization()?;
+            sc_wits_1[0].serialize(&mut writer)?;
+            sc_wits_1[1].serialize(&mut writer)?;
             self.sc_comm_2.serialize_unchecked(&mut writer)?;
             self.sc_wits_2.serialize_unchecked(&mut writer)
         }
@@ -574,8 +916,8 @@ mod serialization {
                 + self.A_bar.uncompressed_size()
                 + self.d.uncompressed_size()
                 + self.sc_comm_1.uncompressed_size()
-                + self.sc_wits_1[0].serialized_size()
-                + self.sc_wits_1[1].serialized_size()
+                + self.sc_wits_1.expose()[0].serialized_size()
+                + self.sc_wits_1.expose()[1].serialized_size()
                 + self.sc_comm_2.uncompressed_size()
                 + self.sc_wits_2.serialized_size()
         }
@@ -587,12 +929,12 @@ mod serialization {
             let A_bar = E::G1Affine::deserialize(&mut reader)?;
             let d = E::G1Affine::deserialize(&mut reader)?;
             let sc_comm_1 = ark_serialize::CanonicalDeserialize::deserialize(&mut reader)?;
-            let sc_wits_1 = [
+            let sc_wits_1 = Secret::new([
                 E::Fr::deserialize(&mut reader)?,
                 E::Fr::deserialize(&mut reader)?,
-            ];
+            ]);
             let sc_comm_2 = ark_serialize::CanonicalDeserialize::deserialize(&mut reader)?;
-            let sc_wits_2 = <Vec<E::Fr>>::deserialize(&mut reader)?;
+            let sc_wits_2 = <Secret<Vec<E::Fr>>>::deserialize(&mut reader)?;
             Ok(Self {
                 A_prime,
                 A_bar,
@@ -610,13 +952,13 @@ mod serialization {
             let d = E::G1Affine::deserialize_uncompressed(&mut reader)?;
             let sc_comm_1 =
                 <SchnorrCommitment<E::G1Affine>>::deserialize_uncompressed(&mut reader)?;
-            let sc_wits_1 = [
+            let sc_wits_1 = Secret::new([
                 E::Fr::deserialize(&mut reader)?,
                 E::Fr::deserialize(&mut reader)?,
-            ];
+            ]);
             let sc_comm_2 =
                 <SchnorrCommitment<E::G1Affine>>::deserialize_uncompressed(&mut reader)?;
-            let sc_wits_2 = <Vec<E::Fr>>::deserialize(&mut reader)?;
+            let sc_wits_2 = <Secret<Vec<E::Fr>>>::deserialize(&mut reader)?;
             Ok(Self {
                 A_prime,
                 A_bar,
@@ -633,12 +975,12 @@ mod serialization {
             let A_bar = E::G1Affine::deserialize_unchecked(&mut reader)?;
             let d = E::G1Affine::deserialize_unchecked(&mut reader)?;
             let sc_comm_1 = <SchnorrCommitment<E::G1Affine>>::deserialize_unchecked(&mut reader)?;
-            let sc_wits_1 = [
+            let sc_wits_1 = Secret::new([
                 E::Fr::deserialize(&mut reader)?,
                 E::Fr::deserialize(&mut reader)?,
-            ];
+            ]);
             let sc_comm_2 = <SchnorrCommitment<E::G1Affine>>::deserialize_unchecked(&mut reader)?;
-            let sc_wits_2 = <Vec<E::Fr>>::deserialize(&mut reader)?;
+            let sc_wits_2 = <Secret<Vec<E::Fr>>>::deserialize(&mut reader)?;
             Ok(Self {
                 A_prime,
                 A_bar,
@@ -656,7 +998,8 @@ mod serialization {
 mod tests {
     use super::*;
     use crate::setup::KeypairG2;
-    use crate::test_serialization;
+    use crate::signature::MESSAGE_COUNT_INDEX;
+    use crate::{test_secret_serialization, test_serialization};
     use ark_bls12_381::Bls12_381;
     use ark_serialize::CanonicalDeserialize;
     use ark_std::{
@@ -719,14 +1062,10 @@ mod tests {
         .unwrap();
         proof_create_duration += start.elapsed();
 
-        // Protocol can be serialized
-        test_serialization!(PoKOfSignatureG1Protocol<Bls12_381>, pok);
-        let pok_json = serde_json::to_string(&pok).unwrap();
-        println!("serde pok ser={:?}", pok_json);
-        let pok_json_deser =
-            serde_json::from_str::<PoKOfSignatureG1Protocol<Bls12_381>>(&pok_json).unwrap();
-        println!("serde pok deser={:?}", pok_json_deser);
-        assert_eq!(pok_json_deser, pok);
+        // Protocol contains secrets so it can only be serialized when explicitly enabled
+        test_secret_serialization!(PoKOfSignatureG1Protocol<Bls12_381>, pok);
+        // Unrevealed messages are witnesses and are not printed
+        assert!(!format!("{:?}", pok).contains(&format!("{:?}", messages[1])));
 
         let mut chal_bytes_prover = vec![];
         pok.challenge_contribution(&revealed_msgs, &params, &mut chal_bytes_prover)
@@ -774,6 +1113,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn pok_signature_with_transcript() {
+        // Challenge is derived from a transcript, with and without compatibility mode
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let message_count = 10;
+        let (messages, params, keypair, sig) = sig_setup(&mut rng, message_count);
+        let revealed_msgs = BTreeMap::from([(1, messages[1]), (3, messages[3])]);
+
+        let pok = PoKOfSignatureG1Protocol::init(
+            &mut rng,
+            &sig,
+            &params,
+            messages.as_slice(),
+            BTreeMap::new(),
+            revealed_msgs.keys().cloned().collect(),
+        )
+        .unwrap();
+
+        // In compatibility mode, the challenge is same as the one from the contribution bytes
+        let mut chal_bytes = vec![];
+        pok.challenge_contribution(&revealed_msgs, &params, &mut chal_bytes)
+            .unwrap();
+        let mut transcript = Transcript::new_compatible();
+        pok.append_to_transcript(&revealed_msgs, &params, &mut transcript)
+            .unwrap();
+        assert_eq!(
+            transcript.challenge_scalar::<Fr, Blake2b>(b"challenge"),
+            compute_random_oracle_challenge::<Fr, Blake2b>(&chal_bytes)
+        );
+
+        let mut transcript = Transcript::new(b"test");
+        pok.append_to_transcript(&revealed_msgs, &params, &mut transcript)
+            .unwrap();
+        let challenge_prover = transcript.challenge_scalar::<Fr, Blake2b>(b"challenge");
+        let proof = pok.gen_proof(&challenge_prover).unwrap();
+
+        let mut transcript = Transcript::new(b"test");
+        proof
+            .append_to_transcript(&revealed_msgs, &params, &mut transcript)
+            .unwrap();
+        let challenge_verifier = transcript.challenge_scalar::<Fr, Blake2b>(b"challenge");
+        assert_eq!(challenge_prover, challenge_verifier);
+        proof
+            .verify(
+                &revealed_msgs,
+                &challenge_verifier,
+                &keypair.public_key,
+                &params,
+            )
+            .unwrap();
+
+        // Challenge for a different domain is different and the proof does not verify with it
+        let mut transcript = Transcript::new(b"other");
+        proof
+            .append_to_transcript(&revealed_msgs, &params, &mut transcript)
+            .unwrap();
+        let challenge_other = transcript.challenge_scalar::<Fr, Blake2b>(b"challenge");
+        assert_ne!(challenge_prover, challenge_other);
+        assert!(proof
+            .verify(
+                &revealed_msgs,
+                &challenge_other,
+                &keypair.public_key,
+                &params
+            )
+            .is_err());
+    }
+
     #[test]
     fn test_PoK_multiple_sigs_with_same_msg() {
         // Prove knowledge of multiple signatures and the equality of a specific message under both signatures.
@@ -1158,4 +1565,509 @@ mod tests {
             start.elapsed()
         );
     }
+
+    fn batch_items<'a>(
+        proofs: &'a [PoKOfSignatureG1Proof<Bls12_381>],
+        revealed_msgs: &'a [BTreeMap<usize, Fr>],
+        challenges: &'a [Fr],
+        pks: &[&'a PublicKeyG2<Bls12_381>],
+        params: &[&'a SignatureParamsG1<Bls12_381>],
+    ) -> Vec<PoKOfSignatureG1ProofBatchItem<'a, Bls12_381>> {
+        (0..proofs.len())
+            .map(|i| {
+                (
+                    &proofs[i],
+                    &revealed_msgs[i],
+                    &challenges[i],
+                    pks[i],
+                    params[i],
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn batch_verify_proofs_with_different_public_keys() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let message_count = 5;
+        let params_1 = SignatureParamsG1::<Bls12_381>::generate_using_rng(&mut rng, message_count);
+        let params_2 = SignatureParamsG1::<Bls12_381>::generate_using_rng(&mut rng, message_count);
+        let keypair_1 = KeypairG2::<Bls12_381>::generate_using_rng(&mut rng, &params_1);
+        let keypair_2 = KeypairG2::<Bls12_381>::generate_using_rng(&mut rng, &params_1);
+        let keypair_3 = KeypairG2::<Bls12_381>::generate_using_rng(&mut rng, &params_2);
+
+        // 2 signers sharing params and 1 using different params, each signing several times
+        let mut signers = vec![];
+        for _ in 0..3 {
+            signers.push((&keypair_1, &params_1));
+            signers.push((&keypair_2, &params_1));
+            signers.push((&keypair_3, &params_2));
+        }
+
+        let mut revealed_msgs = vec![];
+        let mut proofs = vec![];
+        let mut challenges = vec![];
+        for (i, (keypair, params)) in signers.iter().enumerate() {
+            let messages: Vec<Fr> = (0..message_count).map(|_| Fr::rand(&mut rng)).collect();
+            let sig =
+                SignatureG1::<Bls12_381>::new(&mut rng, &messages, &keypair.secret_key, params)
+                    .unwrap();
+            let revealed_indices = [i % message_count].into_iter().collect::<BTreeSet<_>>();
+            let revealed = revealed_indices
+                .iter()
+                .map(|j| (*j, messages[*j]))
+                .collect::<BTreeMap<_, _>>();
+            let pok = PoKOfSignatureG1Protocol::init(
+                &mut rng,
+                &sig,
+                params,
+                &messages,
+                BTreeMap::new(),
+                revealed_indices,
+            )
+            .unwrap();
+            let mut chal_bytes = vec![];
+            pok.challenge_contribution(&revealed, params, &mut chal_bytes)
+                .unwrap();
+            let challenge = compute_random_oracle_challenge::<Fr, Blake2b>(&chal_bytes);
+            proofs.push(pok.gen_proof(&challenge).unwrap());
+            revealed_msgs.push(revealed);
+            challenges.push(challenge);
+        }
+
+        let mut pks = signers
+            .iter()
+            .map(|(k, _)| &k.public_key)
+            .collect::<Vec<_>>();
+        let params = signers.iter().map(|(_, p)| *p).collect::<Vec<_>>();
+
+        let start = Instant::now();
+        PoKOfSignatureG1Proof::verify_batch(
+            &mut rng,
+            &batch_items(&proofs, &revealed_msgs, &challenges, &pks, &params),
+        )
+        .unwrap();
+        println!(
+            "Time to verify {} proofs as a batch: {:?}",
+            proofs.len(),
+            start.elapsed()
+        );
+        PoKOfSignatureG1Proof::<Bls12_381>::verify_batch(&mut rng, &[]).unwrap();
+
+        // Proofs verified with the wrong public key are pinpointed
+        pks[1] = &keypair_1.public_key;
+        pks[5] = &keypair_2.public_key;
+        match PoKOfSignatureG1Proof::verify_batch(
+            &mut rng,
+            &batch_items(&proofs, &revealed_msgs, &challenges, &pks, &params),
+        ) {
+            Err(BBSPlusError::BatchVerificationFailed(invalid)) => assert_eq!(invalid, vec![1, 5]),
+            _ => panic!("batch verification should have failed"),
+        }
+        pks[1] = &keypair_2.public_key;
+        pks[5] = &keypair_3.public_key;
+
+        // Proof with an incorrect challenge is pinpointed
+        challenges[7] += Fr::from(1u64);
+        match PoKOfSignatureG1Proof::verify_batch(
+            &mut rng,
+            &batch_items(&proofs, &revealed_msgs, &challenges, &pks, &params),
+        ) {
+            Err(BBSPlusError::BatchVerificationFailed(invalid)) => assert_eq!(invalid, vec![7]),
+            _ => panic!("batch verification should have failed"),
+        }
+    }
+
+    #[test]
+    fn pok_signature_with_extended_params() {
+        // Prove knowledge of signatures created before and after extending the params, both using the
+        // extended params
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let label = b"test-params";
+        let old_message_count = 4;
+        let new_message_count = 7;
+        // One more generator for the message count
+        let old_params =
+            SignatureParamsG1::<Bls12_381>::new::<Blake2b>(label, old_message_count + 1);
+        let keypair = KeypairG2::<Bls12_381>::generate_using_rng(&mut rng, &old_params);
+        let old_messages: Vec<Fr> = (0..old_message_count).map(|_| Fr::rand(&mut rng)).collect();
+        let old_sig = SignatureG1::<Bls12_381>::new_with_params_prefix(
+            &mut rng,
+            &old_messages,
+            &keypair.secret_key,
+            &old_params,
+        )
+        .unwrap();
+
+        let params = old_params
+            .extend::<Blake2b>(label, new_message_count + 1)
+            .unwrap();
+        let new_messages: Vec<Fr> = (0..new_message_count).map(|_| Fr::rand(&mut rng)).collect();
+        let new_sig = SignatureG1::<Bls12_381>::new_with_params_prefix(
+            &mut rng,
+            &new_messages,
+            &keypair.secret_key,
+            &params,
+        )
+        .unwrap();
+        old_sig
+            .verify_with_params_prefix(&old_messages, &keypair.public_key, &params)
+            .unwrap();
+        new_sig
+            .verify_with_params_prefix(&new_messages, &keypair.public_key, &params)
+            .unwrap();
+
+        // The old signature can't be passed off as one over more messages, padding the old messages with
+        // 0s doesn't verify as the count is signed.
+        let mut padded_messages = old_messages.clone();
+        padded_messages.resize(new_message_count, Fr::zero());
+        assert!(old_sig
+            .verify_with_params_prefix(&padded_messages, &keypair.public_key, &params)
+            .is_err());
+
+        // Whereas a signature without the count is valid over more messages which are 0
+        let plain_params = SignatureParamsG1::<Bls12_381>::new::<Blake2b>(label, old_message_count);
+        let plain_sig = SignatureG1::<Bls12_381>::new(
+            &mut rng,
+            &old_messages,
+            &keypair.secret_key,
+            &plain_params,
+        )
+        .unwrap();
+        let plain_params_extended = plain_params
+            .extend::<Blake2b>(label, new_message_count)
+            .unwrap();
+        plain_sig
+            .verify(
+                &padded_messages,
+                &keypair.public_key,
+                &plain_params_extended,
+            )
+            .unwrap();
+
+        // The signature without the count remains valid and provable over the prefix of the extended params
+        plain_sig
+            .verify_with_plain_params_prefix(
+                &old_messages,
+                &keypair.public_key,
+                &plain_params_extended,
+            )
+            .unwrap();
+        let plain_revealed = [(1, old_messages[1])]
+            .into_iter()
+            .collect::<BTreeMap<_, _>>();
+        let plain_pok = PoKOfSignatureG1Protocol::init_with_plain_params_prefix(
+            &mut rng,
+            &plain_sig,
+            &plain_params_extended,
+            &old_messages,
+            BTreeMap::new(),
+            [1].into_iter().collect(),
+        )
+        .unwrap();
+        let mut chal_bytes = vec![];
+        plain_pok
+            .challenge_contribution(&plain_revealed, &plain_params_extended, &mut chal_bytes)
+            .unwrap();
+        let challenge = compute_random_oracle_challenge::<Fr, Blake2b>(&chal_bytes);
+        let plain_proof = plain_pok.gen_proof(&challenge).unwrap();
+        plain_proof
+            .verify_with_plain_params_prefix(
+                &plain_revealed,
+                &challenge,
+                &keypair.public_key,
+                &plain_params_extended,
+                old_message_count,
+            )
+            .unwrap();
+        // The proof is over the prefix for the signature's message count only
+        assert!(plain_proof
+            .verify_with_plain_params_prefix(
+                &plain_revealed,
+                &challenge,
+                &keypair.public_key,
+                &plain_params_extended,
+                old_message_count + 1,
+            )
+            .is_err());
+        assert!(plain_proof
+            .verify(
+                &plain_revealed,
+                &challenge,
+                &keypair.public_key,
+                &plain_params_extended
+            )
+            .is_err());
+
+        // Full params can't be used with the old signature without taking the prefix
+        assert!(PoKOfSignatureG1Protocol::init(
+            &mut rng,
+            &old_sig,
+            &params,
+            &length_tagged_messages(&old_messages, old_message_count + 1).unwrap(),
+            BTreeMap::new(),
+            BTreeSet::new(),
+        )
+        .is_err());
+
+        // The message count must be revealed
+        assert!(PoKOfSignatureG1Protocol::init_with_params_prefix(
+            &mut rng,
+            &old_sig,
+            &params,
+            &old_messages,
+            BTreeMap::new(),
+            [2].into_iter().collect(),
+        )
+        .is_err());
+
+        // Indices are of the length tagged messages so index 2 is of the 2nd message
+        let revealed_indices = [MESSAGE_COUNT_INDEX, 2]
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+        let old_revealed = [
+            (MESSAGE_COUNT_INDEX, Fr::from(old_message_count as u64)),
+            (2, old_messages[1]),
+        ]
+        .into_iter()
+        .collect::<BTreeMap<_, _>>();
+        let new_revealed = [
+            (MESSAGE_COUNT_INDEX, Fr::from(new_message_count as u64)),
+            (2, new_messages[1]),
+        ]
+        .into_iter()
+        .collect::<BTreeMap<_, _>>();
+        let old_pok = PoKOfSignatureG1Protocol::init_with_params_prefix(
+            &mut rng,
+            &old_sig,
+            &params,
+            &old_messages,
+            BTreeMap::new(),
+            revealed_indices.clone(),
+        )
+        .unwrap();
+        let new_pok = PoKOfSignatureG1Protocol::init_with_params_prefix(
+            &mut rng,
+            &new_sig,
+            &params,
+            &new_messages,
+            BTreeMap::new(),
+            revealed_indices,
+        )
+        .unwrap();
+
+        let mut chal_bytes = vec![];
+        old_pok
+            .challenge_contribution(&old_revealed, &params, &mut chal_bytes)
+            .unwrap();
+        new_pok
+            .challenge_contribution(&new_revealed, &params, &mut chal_bytes)
+            .unwrap();
+        let challenge = compute_random_oracle_challenge::<Fr, Blake2b>(&chal_bytes);
+        let old_proof = old_pok.gen_proof(&challenge).unwrap();
+        let new_proof = new_pok.gen_proof(&challenge).unwrap();
+
+        old_proof
+            .verify_with_params_prefix(
+                &old_revealed,
+                &challenge,
+                &keypair.public_key,
+                &params,
+                old_message_count,
+            )
+            .unwrap();
+        new_proof
+            .verify_with_params_prefix(
+                &new_revealed,
+                &challenge,
+                &keypair.public_key,
+                &params,
+                new_message_count,
+            )
+            .unwrap();
+        new_proof
+            .verify(&new_revealed, &challenge, &keypair.public_key, &params)
+            .unwrap();
+
+        // Verifying with an incorrect message count fails, whether or not the revealed count is changed
+        // to match it
+        for count in [new_message_count, new_message_count + 1] {
+            assert!(old_proof
+                .verify_with_params_prefix(
+                    &old_revealed,
+                    &challenge,
+                    &keypair.public_key,
+                    &params,
+                    count,
+                )
+                .is_err());
+            let mut wrong_revealed = old_revealed.clone();
+            wrong_revealed.insert(MESSAGE_COUNT_INDEX, Fr::from(count as u64));
+            assert!(old_proof
+                .verify_with_params_prefix(
+                    &wrong_revealed,
+                    &challenge,
+                    &keypair.public_key,
+                    &params,
+                    count,
+                )
+                .is_err());
+        }
+        // Verifying without the revealed count fails
+        let mut no_count_revealed = old_revealed.clone();
+        no_count_revealed.remove(&MESSAGE_COUNT_INDEX);
+        assert!(old_proof
+            .verify_with_params_prefix(
+                &no_count_revealed,
+                &challenge,
+                &keypair.public_key,
+                &params,
+                old_message_count,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn pok_signature_with_variable_length_messages() {
+        // Prove knowledge of signatures over different number of messages using the same params, once
+        // revealing the message count and once hiding it
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let label = b"test-variable-length";
+        let max_message_count = 8;
+        let params = SignatureParamsG1::<Bls12_381>::new::<Blake2b>(label, max_message_count + 1);
+        let keypair = KeypairG2::<Bls12_381>::generate_using_rng(&mut rng, &params);
+
+        for count in [0, 3, max_message_count] {
+            let messages: Vec<Fr> = (0..count).map(|_| Fr::rand(&mut rng)).collect();
+            let sig = SignatureG1::<Bls12_381>::new_with_variable_length_messages::<_, Blake2b>(
+                &mut rng,
+                &messages,
+                &keypair.secret_key,
+                label,
+            )
+            .unwrap();
+
+            for reveal_count in [true, false] {
+                let mut revealed_indices = BTreeSet::new();
+                let mut revealed_msgs = BTreeMap::new();
+                if reveal_count {
+                    revealed_indices.insert(MESSAGE_COUNT_INDEX);
+                    revealed_msgs.insert(MESSAGE_COUNT_INDEX, Fr::from(count as u64));
+                }
+                if count > 0 {
+                    // Reveal the 1st message which is at index 1
+                    revealed_indices.insert(1);
+                    revealed_msgs.insert(1, messages[0]);
+                }
+
+                let pok = PoKOfSignatureG1Protocol::init_with_variable_length_messages(
+                    &mut rng,
+                    &sig,
+                    &params,
+                    &messages,
+                    BTreeMap::new(),
+                    revealed_indices,
+                )
+                .unwrap();
+                let mut chal_bytes = vec![];
+                pok.challenge_contribution(&revealed_msgs, &params, &mut chal_bytes)
+                    .unwrap();
+                let challenge = compute_random_oracle_challenge::<Fr, Blake2b>(&chal_bytes);
+                let proof = pok.gen_proof(&challenge).unwrap();
+                proof
+                    .verify(&revealed_msgs, &challenge, &keypair.public_key, &params)
+                    .unwrap();
+
+                if reveal_count {
+                    // Incorrect message count fails verification
+                    let mut wrong_revealed_msgs = revealed_msgs.clone();
+                    wrong_revealed_msgs.insert(MESSAGE_COUNT_INDEX, Fr::from(count as u64 + 1));
+                    assert!(proof
+                        .verify(
+                            &wrong_revealed_msgs,
+                            &challenge,
+                            &keypair.public_key,
+                            &params
+                        )
+                        .is_err());
+                } else {
+                    // Response for the hidden message count is available to prove relations on it
+                    proof
+                        .get_resp_for_message(MESSAGE_COUNT_INDEX, &BTreeSet::new())
+                        .unwrap();
+                }
+            }
+        }
+
+        // Params must support more messages than the signature
+        let messages: Vec<Fr> = (0..max_message_count + 1)
+            .map(|_| Fr::rand(&mut rng))
+            .collect();
+        let sig = SignatureG1::<Bls12_381>::new_with_variable_length_messages::<_, Blake2b>(
+            &mut rng,
+            &messages,
+            &keypair.secret_key,
+            label,
+        )
+        .unwrap();
+        assert!(
+            PoKOfSignatureG1Protocol::init_with_variable_length_messages(
+                &mut rng,
+                &sig,
+                &params,
+                &messages,
+                BTreeMap::new(),
+                BTreeSet::new(),
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn simulated_pok_signature() {
+        // Simulated proofs of knowledge of a signature are accepted for the challenge they are simulated for
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let message_count = 10;
+        let (messages, params, keypair, _) = sig_setup(&mut rng, message_count);
+
+        let mut revealed_msgs = BTreeMap::new();
+        revealed_msgs.insert(1, messages[1]);
+        revealed_msgs.insert(4, messages[4]);
+
+        // The randomized signature is created by the signer
+        let A_prime = <Bls12_381 as PairingEngine>::G1Projective::rand(&mut rng).into_affine();
+        let A_bar = A_prime.mul(keypair.secret_key.0.into_repr()).into_affine();
+
+        let challenge = Fr::rand(&mut rng);
+        let proof = PoKOfSignatureG1Proof::simulate(
+            &mut rng,
+            A_prime,
+            A_bar,
+            &revealed_msgs,
+            &challenge,
+            &params,
+        );
+        proof
+            .verify(&revealed_msgs, &challenge, &keypair.public_key, &params)
+            .unwrap();
+        assert!(proof
+            .verify(
+                &revealed_msgs,
+                &Fr::rand(&mut rng),
+                &keypair.public_key,
+                &params
+            )
+            .is_err());
+
+        let mut other_revealed_msgs = revealed_msgs.clone();
+        other_revealed_msgs.insert(4, messages[5]);
+        assert!(proof
+            .verify(
+                &other_revealed_msgs,
+                &challenge,
+                &keypair.public_key,
+                &params
+            )
+            .is_err());
+    }
 }
@@ -8,6 +8,7 @@
 //! ```
 //! use ark_bls12_381::Bls12_381;
 //! use blake2::Blake2b;
+//! use sha2::Sha256;
 //! use bbs_plus::setup::{SignatureParamsG1, SignatureParamsG2, KeypairG1, KeypairG2};
 //!
 //! let params_g1 = SignatureParamsG1::<Bls12_381>::generate_using_rng(&mut rng, 5);
@@ -15,6 +16,18 @@
 //! let params_g1_1 = SignatureParamsG1::<Bls12_381>::new::<Blake2b>(&[1, 2, 3, 4], 5);
 //! let params_g2_1 = SignatureParamsG2::<Bls12_381>::new::<Blake2b>(&[1, 2, 3, 4], 5);
 //!
+//! // Params can also be created using the hash to curve of RFC 9380 with a domain separation tag so that
+//! // other implementations can reproduce them
+//! let params_g1_2 = SignatureParamsG1::<Bls12_381>::new_using_hash_to_curve::<Sha256>(&[1, 2, 3, 4], b"MY-APP-BBS-PARAMS_XMD:SHA-256_SSWU_RO_", 5);
+//!
+//! // Params created from a label can be extended to support more messages. The extended params are the
+//! // same as the ones created for 8 messages and the first 5 generators are unchanged so signatures created
+//! // with `params_g1_1` are valid with `params_g1_3.prefix(5).unwrap()`. Since a signature over fewer messages
+//! // is also valid over more messages which are 0, the verifier picks the prefix for such signatures. Signatures
+//! // can instead be created with `SignatureG1::new_with_params_prefix` which signs the message count as well
+//! let params_g1_3 = params_g1_1.extend::<Blake2b>(&[1, 2, 3, 4], 8).unwrap();
+//! assert_eq!(params_g1_3, SignatureParamsG1::<Bls12_381>::new::<Blake2b>(&[1, 2, 3, 4], 8));
+//!
 //! let keypair_g2 = KeypairG2::<Bls12_381>::generate_using_rng(&mut rng, &params_g1);
 //! let keypair_g1 = KeypairG1::<Bls12_381>::generate_using_rng(&mut rng, &params_g2);
 //!
@@ -43,11 +56,14 @@ use ark_std::{
     UniformRand, Zero,
 };
 use digest::{BlockInput, Digest, FixedOutput, Reset, Update};
-use schnorr_pok::{error::SchnorrError, impl_proof_of_knowledge_of_discrete_log};
+use schnorr_pok::{
+    compute_random_oracle_challenge, error::SchnorrError, impl_proof_of_knowledge_of_discrete_log,
+};
 use zeroize::Zeroize;
 
 use dock_crypto_utils::hashing_utils::{
-    field_elem_from_seed, projective_group_elem_from_try_and_incr,
+    field_elem_from_seed, projective_group_elem_from_hash_to_curve,
+    projective_group_elem_from_try_and_incr, HashToCurve,
 };
 use dock_crypto_utils::msm::variable_base_msm;
 use dock_crypto_utils::serde_utils::*;
@@ -164,6 +180,145 @@ macro_rules! impl_sig_params {
                 }
             }
 
+            /// Generate params by hashing a known string like [`Self::new`] but using the hash to curve
+            /// of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html) with domain separation tag `dst`
+            /// rather than try-and-increment. The hashed messages are the same as in `new` so any other
+            /// implementation of RFC 9380 can reproduce these params from `label` and `dst`.
+            pub fn new_using_hash_to_curve<D: Digest + BlockInput>(
+                label: &[u8],
+                dst: &[u8],
+                message_count: usize,
+            ) -> Self
+            where
+                E::$group_affine: HashToCurve,
+                E::$other_group_affine: HashToCurve,
+            {
+                assert_ne!(message_count, 0);
+                let mut sig_group_elems = Vec::with_capacity(message_count + 2);
+                let g1 = projective_group_elem_from_hash_to_curve::<E::$group_affine, D>(
+                    &to_bytes![label, " : g1".as_bytes()].unwrap(),
+                    dst,
+                );
+                let mut h = cfg_into_iter!((0..=message_count))
+                    .map(|i| {
+                        projective_group_elem_from_hash_to_curve::<E::$group_affine, D>(
+                            &to_bytes![label, " : h_".as_bytes(), i as u64].unwrap(),
+                            dst,
+                        )
+                    })
+                    .collect::<Vec<E::$group_projective>>();
+                sig_group_elems.push(g1);
+                sig_group_elems.append(&mut h);
+                let mut sig_group_elems =
+                    E::$group_projective::batch_normalization_into_affine(&sig_group_elems);
+                let g1 = sig_group_elems.remove(0);
+                let h_0 = sig_group_elems.remove(0);
+
+                let g2 = projective_group_elem_from_hash_to_curve::<E::$other_group_affine, D>(
+                    &to_bytes![label, " : g2".as_bytes()].unwrap(),
+                    dst,
+                )
+                .into_affine();
+                Self {
+                    g1,
+                    g2,
+                    h_0,
+                    h: sig_group_elems,
+                }
+            }
+
+            /// Extend params created by [`Self::new`] with `label` to support `message_count` messages by
+            /// hashing to get only the additional generators. The result is the same as calling `new` with
+            /// `label` and `message_count` so signatures created with the current params remain valid over
+            /// the prefix of the extended params, see [`Self::prefix`]. Returns an error if the params already
+            /// support more than `message_count` messages.
+            ///
+            /// **Note**: A signature over `n` messages is also valid over `n + k` messages under the extended
+            /// params when the last `k` messages are 0 so the extended params alone don't tell whether a
+            /// signature was created before or after the extension. Signatures created before the extension
+            /// with `new` are verified and proven over the prefix of `n` messages, where the verifier decides
+            /// `n`, using the `*_with_plain_params_prefix` functions. Signatures created with
+            /// `new_with_params_prefix` sign the message count as well so the count is bound to the signature.
+            pub fn extend<D: Digest>(
+                &self,
+                label: &[u8],
+                message_count: usize,
+            ) -> Result<Self, BBSPlusError> {
+                self.check_extension_size(message_count)?;
+                let h = cfg_into_iter!((self.supported_message_count() + 1..=message_count))
+                    .map(|i| {
+                        projective_group_elem_from_try_and_incr::<E::$group_affine, D>(
+                            &to_bytes![label, " : h_".as_bytes(), i as u64].unwrap(),
+                        )
+                    })
+                    .collect::<Vec<E::$group_projective>>();
+                self.extend_with(h)
+            }
+
+            /// Extend params created by [`Self::new_using_hash_to_curve`] with `label` and `dst` to support
+            /// `message_count` messages. Similar to [`Self::extend`].
+            pub fn extend_using_hash_to_curve<D: Digest + BlockInput>(
+                &self,
+                label: &[u8],
+                dst: &[u8],
+                message_count: usize,
+            ) -> Result<Self, BBSPlusError>
+            where
+                E::$group_affine: HashToCurve,
+            {
+                self.check_extension_size(message_count)?;
+                let h = cfg_into_iter!((self.supported_message_count() + 1..=message_count))
+                    .map(|i| {
+                        projective_group_elem_from_hash_to_curve::<E::$group_affine, D>(
+                            &to_bytes![label, " : h_".as_bytes(), i as u64].unwrap(),
+                            dst,
+                        )
+                    })
+                    .collect::<Vec<E::$group_projective>>();
+                self.extend_with(h)
+            }
+
+            /// Params for only the first `message_count` messages. This is how params extended using
+            /// [`Self::extend`] are used with signatures created before the extension. See the note on
+            /// [`Self::extend`] on binding the message count.
+            pub fn prefix(&self, message_count: usize) -> Result<Self, BBSPlusError> {
+                if message_count == 0 || message_count > self.supported_message_count() {
+                    return Err(BBSPlusError::MessageCountIncompatibleWithSigParams(
+                        message_count,
+                        self.supported_message_count(),
+                    ));
+                }
+                Ok(Self {
+                    g1: self.g1,
+                    g2: self.g2,
+                    h_0: self.h_0,
+                    h: self.h[0..message_count].to_vec(),
+                })
+            }
+
+            fn check_extension_size(&self, message_count: usize) -> Result<(), BBSPlusError> {
+                if message_count < self.supported_message_count() {
+                    return Err(BBSPlusError::MessageCountIncompatibleWithSigParams(
+                        message_count,
+                        self.supported_message_count(),
+                    ));
+                }
+                Ok(())
+            }
+
+            fn extend_with(&self, new_h: Vec<E::$group_projective>) -> Result<Self, BBSPlusError> {
+                let mut h = self.h.clone();
+                h.append(&mut E::$group_projective::batch_normalization_into_affine(
+                    &new_h,
+                ));
+                Ok(Self {
+                    g1: self.g1,
+                    g2: self.g2,
+                    h_0: self.h_0,
+                    h,
+                })
+            }
+
             /// Generate params using a random number generator
             pub fn generate_using_rng<R>(rng: &mut R, message_count: usize) -> Self
             where
@@ -375,14 +530,142 @@ impl_keypair!(KeypairG1, G1Projective, PublicKeyG1, SignatureParamsG2);
 impl_proof_of_knowledge_of_discrete_log!(PoKSecretKeyInPublicKeyG2, PoKSecretKeyInPublicKeyG2Proof);
 impl_proof_of_knowledge_of_discrete_log!(PoKSecretKeyInPublicKeyG1, PoKSecretKeyInPublicKeyG1Proof);
 
+/// Label included in the challenge of the proofs in a key rotation record
+const KEY_ROTATION_LABEL: &[u8] = b"BBS+-key-rotation";
+
+/// Record of the signer rotating its key from `old_public_key` to `new_public_key`. Contains proofs of
+/// knowledge of both the old and the new secret key with a common challenge that binds both public keys.
+/// Thus the proof of the old key acts as a signature by the old key on the new public key and the proof
+/// of the new key shows that the new key is not derived from someone else's key. Verifiers who trust the
+/// old public key can use a chain of such records to trust the current public key.
+#[serde_as]
+#[derive(
+    Clone, PartialEq, Eq, Debug, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct KeyRotationRecordG2<E: PairingEngine> {
+    pub old_public_key: PublicKeyG2<E>,
+    pub new_public_key: PublicKeyG2<E>,
+    /// Proof of knowledge of the secret key of `old_public_key`
+    pub old_key_proof: PoKSecretKeyInPublicKeyG2Proof<E::G2Affine>,
+    /// Proof of knowledge of the secret key of `new_public_key`
+    pub new_key_proof: PoKSecretKeyInPublicKeyG2Proof<E::G2Affine>,
+}
+
+impl<E: PairingEngine> KeyRotationRecordG2<E> {
+    /// Create a record of rotating the key from `old_keypair` to `new_keypair`.
+    pub fn new<R: RngCore, D: Digest>(
+        rng: &mut R,
+        old_keypair: &KeypairG2<E>,
+        new_keypair: &KeypairG2<E>,
+        params: &SignatureParamsG1<E>,
+    ) -> Result<Self, BBSPlusError> {
+        if old_keypair.public_key == new_keypair.public_key {
+            return Err(BBSPlusError::SameKeyInRotation);
+        }
+        let old_protocol =
+            PoKSecretKeyInPublicKeyG2::init(old_keypair.secret_key.0, E::Fr::rand(rng), &params.g2);
+        let new_protocol =
+            PoKSecretKeyInPublicKeyG2::init(new_keypair.secret_key.0, E::Fr::rand(rng), &params.g2);
+        let challenge = Self::compute_challenge::<D>(
+            &old_keypair.public_key,
+            &new_keypair.public_key,
+            &old_protocol.t,
+            &new_protocol.t,
+            params,
+        )?;
+        Ok(Self {
+            old_public_key: old_keypair.public_key.clone(),
+            new_public_key: new_keypair.public_key.clone(),
+            old_key_proof: old_protocol.gen_proof(&challenge),
+            new_key_proof: new_protocol.gen_proof(&challenge),
+        })
+    }
+
+    /// Verify the record. Assumes that the old public key and the params have been validated already.
+    pub fn verify<D: Digest>(&self, params: &SignatureParamsG1<E>) -> Result<(), BBSPlusError> {
+        if !self.new_public_key.is_valid() || self.old_public_key == self.new_public_key {
+            return Err(BBSPlusError::InvalidKeyRotationRecord);
+        }
+        let challenge = Self::compute_challenge::<D>(
+            &self.old_public_key,
+            &self.new_public_key,
+            &self.old_key_proof.t,
+            &self.new_key_proof.t,
+            params,
+        )?;
+        if !self
+            .old_key_proof
+            .verify(&self.old_public_key.0, &params.g2, &challenge)
+            || !self
+                .new_key_proof
+                .verify(&self.new_public_key.0, &params.g2, &challenge)
+        {
+            return Err(BBSPlusError::InvalidKeyRotationRecord);
+        }
+        Ok(())
+    }
+
+    /// Verify a chain of records where the 1st record rotates from `initial_public_key` and each
+    /// subsequent record rotates from the new key of the previous record. Returns the public keys in
+    /// the lineage, starting from `initial_public_key` and ending with the current public key.
+    pub fn verify_chain<'a, D: Digest>(
+        initial_public_key: &'a PublicKeyG2<E>,
+        records: &'a [Self],
+        params: &SignatureParamsG1<E>,
+    ) -> Result<Vec<&'a PublicKeyG2<E>>, BBSPlusError> {
+        let mut lineage = Vec::with_capacity(records.len() + 1);
+        lineage.push(initial_public_key);
+        for (i, record) in records.iter().enumerate() {
+            if &record.old_public_key != lineage[i] {
+                return Err(BBSPlusError::BrokenKeyRotationChain(i));
+            }
+            record
+                .verify::<D>(params)
+                .map_err(|_| BBSPlusError::InvalidKeyRotationRecordInChain(i))?;
+            if lineage.contains(&&record.new_public_key) {
+                return Err(BBSPlusError::BrokenKeyRotationChain(i));
+            }
+            lineage.push(&record.new_public_key);
+        }
+        Ok(lineage)
+    }
+
+    fn compute_challenge<D: Digest>(
+        old_public_key: &PublicKeyG2<E>,
+        new_public_key: &PublicKeyG2<E>,
+        old_t: &E::G2Affine,
+        new_t: &E::G2Affine,
+        params: &SignatureParamsG1<E>,
+    ) -> Result<E::Fr, BBSPlusError> {
+        let mut challenge_bytes = KEY_ROTATION_LABEL.to_vec();
+        PoKSecretKeyInPublicKeyG2::compute_challenge_contribution(
+            &params.g2,
+            &old_public_key.0,
+            old_t,
+            &mut challenge_bytes,
+        )?;
+        PoKSecretKeyInPublicKeyG2::compute_challenge_contribution(
+            &params.g2,
+            &new_public_key.0,
+            new_t,
+            &mut challenge_bytes,
+        )?;
+        Ok(compute_random_oracle_challenge::<E::Fr, D>(
+            &challenge_bytes,
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_serialization;
+    use crate::{test_secret_serialization, test_serialization};
     use ark_bls12_381::Bls12_381;
     use ark_std::rand::{rngs::StdRng, SeedableRng};
     use blake2::Blake2b;
     use schnorr_pok::compute_random_oracle_challenge;
+    use sha2::Sha256;
 
     type Fr = <Bls12_381 as PairingEngine>::Fr;
 
@@ -416,6 +699,83 @@ mod tests {
             let label_2 = "test2".as_bytes();
             let params_2 = $params::<Bls12_381>::new::<Blake2b>(&label_2, $message_count);
             assert_ne!(params_1, params_2);
+
+            // Extending params is same as generating params for more messages and the prefix of the
+            // extended params is the original params
+            let params_1_extended = params_1
+                .extend::<Blake2b>(&label_1, $message_count + 3)
+                .unwrap();
+            assert_eq!(
+                params_1_extended,
+                $params::<Bls12_381>::new::<Blake2b>(&label_1, $message_count + 3)
+            );
+            assert_eq!(params_1_extended.prefix($message_count).unwrap(), params_1);
+            assert_eq!(
+                params_1
+                    .extend::<Blake2b>(&label_1, $message_count)
+                    .unwrap(),
+                params_1
+            );
+            // Params can't be shrunk by extending
+            assert!(params_1_extended
+                .extend::<Blake2b>(&label_1, $message_count)
+                .is_err());
+            assert!(params_1_extended.prefix($message_count + 4).is_err());
+            assert!(params_1_extended.prefix(0).is_err());
+        };
+    }
+
+    macro_rules! test_params_using_hash_to_curve {
+        ($params:ident, $message_count: ident) => {
+            let label_1 = "test1".as_bytes();
+            let dst = b"BBS-PLUS-TEST-PARAMS_XMD:SHA-256_SSWU_RO_";
+            let params_1 = $params::<Bls12_381>::new_using_hash_to_curve::<Sha256>(
+                &label_1,
+                dst,
+                $message_count,
+            );
+            assert!(params_1.is_valid());
+            assert_eq!(params_1.h.len(), $message_count);
+
+            // Same label and dst should generate same params
+            let params_1_again = $params::<Bls12_381>::new_using_hash_to_curve::<Sha256>(
+                &label_1,
+                dst,
+                $message_count,
+            );
+            assert_eq!(params_1_again, params_1);
+
+            // Different label or dst should generate different params
+            let label_2 = "test2".as_bytes();
+            let params_2 = $params::<Bls12_381>::new_using_hash_to_curve::<Sha256>(
+                &label_2,
+                dst,
+                $message_count,
+            );
+            assert_ne!(params_1, params_2);
+            let params_3 = $params::<Bls12_381>::new_using_hash_to_curve::<Sha256>(
+                &label_1,
+                b"BBS-PLUS-TEST-PARAMS-OTHER_XMD:SHA-256_SSWU_RO_",
+                $message_count,
+            );
+            assert_ne!(params_1, params_3);
+
+            assert_eq!(
+                params_1
+                    .extend_using_hash_to_curve::<Sha256>(&label_1, dst, $message_count + 2)
+                    .unwrap(),
+                $params::<Bls12_381>::new_using_hash_to_curve::<Sha256>(
+                    &label_1,
+                    dst,
+                    $message_count + 2
+                )
+            );
+
+            // Differs from the params generated using try-and-increment
+            assert_ne!(
+                params_1,
+                $params::<Bls12_381>::new::<Sha256>(&label_1, $message_count)
+            );
         };
     }
 
@@ -475,6 +835,8 @@ mod tests {
         let message_count = 10;
         test_params!(SignatureParamsG1, message_count);
         test_params!(SignatureParamsG2, message_count);
+        test_params_using_hash_to_curve!(SignatureParamsG1, message_count);
+        test_params_using_hash_to_curve!(SignatureParamsG2, message_count);
     }
 
     #[test]
@@ -500,7 +862,7 @@ mod tests {
                     .challenge_contribution(base, &pk.0, &mut chal_contrib_prover)
                     .unwrap();
 
-                test_serialization!(
+                test_secret_serialization!(
                     $protocol_name<<Bls12_381 as PairingEngine>::$group_affine>,
                     protocol
                 );
@@ -542,4 +904,103 @@ mod tests {
             SignatureParamsG2
         );
     }
+
+    #[test]
+    fn key_rotation() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let params = SignatureParamsG1::<Bls12_381>::generate_using_rng(&mut rng, 5);
+        let keypairs = (0..4)
+            .map(|_| KeypairG2::<Bls12_381>::generate_using_rng(&mut rng, &params))
+            .collect::<Vec<_>>();
+
+        assert!(KeyRotationRecordG2::new::<_, Blake2b>(
+            &mut rng,
+            &keypairs[0],
+            &keypairs[0],
+            &params
+        )
+        .is_err());
+
+        let records = (1..keypairs.len())
+            .map(|i| {
+                KeyRotationRecordG2::new::<_, Blake2b>(
+                    &mut rng,
+                    &keypairs[i - 1],
+                    &keypairs[i],
+                    &params,
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        for r in &records {
+            r.verify::<Blake2b>(&params).unwrap();
+        }
+        let record = records[0].clone();
+        test_serialization!(KeyRotationRecordG2<Bls12_381>, record);
+
+        let lineage = KeyRotationRecordG2::verify_chain::<Blake2b>(
+            &keypairs[0].public_key,
+            &records,
+            &params,
+        )
+        .unwrap();
+        assert_eq!(
+            lineage,
+            keypairs.iter().map(|k| &k.public_key).collect::<Vec<_>>()
+        );
+        // Empty chain
+        assert_eq!(
+            KeyRotationRecordG2::verify_chain::<Blake2b>(&keypairs[2].public_key, &[], &params)
+                .unwrap(),
+            vec![&keypairs[2].public_key]
+        );
+
+        // Chain not starting from the initial key
+        assert!(KeyRotationRecordG2::verify_chain::<Blake2b>(
+            &keypairs[1].public_key,
+            &records,
+            &params
+        )
+        .is_err());
+        // Chain with a missing record
+        assert!(KeyRotationRecordG2::verify_chain::<Blake2b>(
+            &keypairs[0].public_key,
+            &[records[0].clone(), records[2].clone()],
+            &params
+        )
+        .is_err());
+
+        // Record with the new key replaced doesn't verify
+        let other_keypair = KeypairG2::<Bls12_381>::generate_using_rng(&mut rng, &params);
+        let mut tampered = records[1].clone();
+        tampered.new_public_key = other_keypair.public_key.clone();
+        assert!(tampered.verify::<Blake2b>(&params).is_err());
+        assert!(KeyRotationRecordG2::verify_chain::<Blake2b>(
+            &keypairs[0].public_key,
+            &[records[0].clone(), tampered],
+            &params
+        )
+        .is_err());
+
+        // Record by someone not knowing the old secret key doesn't verify
+        let mut forged =
+            KeyRotationRecordG2::new::<_, Blake2b>(&mut rng, &other_keypair, &keypairs[1], &params)
+                .unwrap();
+        forged.old_public_key = keypairs[0].public_key.clone();
+        assert!(forged.verify::<Blake2b>(&params).is_err());
+
+        // Rotating back to an earlier key is rejected
+        let back =
+            KeyRotationRecordG2::new::<_, Blake2b>(&mut rng, &keypairs[3], &keypairs[1], &params)
+                .unwrap();
+        back.verify::<Blake2b>(&params).unwrap();
+        let mut cyclic = records.clone();
+        cyclic.push(back);
+        assert!(KeyRotationRecordG2::verify_chain::<Blake2b>(
+            &keypairs[0].public_key,
+            &cyclic,
+            &params
+        )
+        .is_err());
+    }
 }
@@ -35,6 +35,15 @@
 //! let sig_g2 = SignatureG2::<Bls12_381>::new(&mut rng, &messages, &keypair_g1.secret_key, &params_g2).unwrap();
 //! sig_g2.verify(&messages, pk_g1, &params_g2).unwrap();
 //!
+//! // Signatures over a variable number of messages sign the message count as well and create the params
+//! // from a label for the needed number of messages. The public key should be created with params from the same label.
+//! let sig_g1_var = SignatureG1::<Bls12_381>::new_with_variable_length_messages::<_, Blake2b>(&mut rng, &messages, &keypair_g2.secret_key, label).unwrap();
+//! sig_g1_var.verify_with_variable_length_messages::<Blake2b>(&messages, pk_g2, label).unwrap();
+//!
+//! // Several signatures by the same signer can be verified together faster. `multi_messages[i]` are
+//! // the messages of `sigs_g1[i]`
+//! SignatureG1::<Bls12_381>::verify_batch(&mut rng, &sigs_g1, &multi_messages, pk_g2, &params_g1).unwrap();
+//!
 //! // Requesting a partially blind signature from the signer, i.e. where signer does not know all the messages
 //! // Requester creates a Pedersen commitment over the messages he wants to hide from the signer.
 //! // Requester creates a map of message index to message as `committed_messages` and random field element
@@ -94,12 +103,20 @@ use ark_std::{
 };
 
 use crate::setup::{PublicKeyG1, PublicKeyG2, SecretKey, SignatureParamsG1, SignatureParamsG2};
-use ark_std::collections::BTreeMap;
+use ark_std::{cfg_iter, collections::BTreeMap, vec::Vec};
+use digest::Digest;
+use dock_crypto_utils::ec::blinded_mul;
+use dock_crypto_utils::ff::blinded_inverse;
+use dock_crypto_utils::msm::variable_base_msm;
+use dock_crypto_utils::randomized_pairing_check::RandomizedPairingChecker;
 use dock_crypto_utils::serde_utils::*;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use zeroize::Zeroize;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 macro_rules! impl_signature_struct {
     ( $name:ident, $group:ident ) => {
         /// Signature created by the signer after signing a multi-message
@@ -164,8 +181,47 @@ macro_rules! pairing_check_for_g2_sig {
     };
 }
 
+// Macro to add the pairing check `e(A, w) == e(k, g2)` to the pairing checker when signature is in group G1
+macro_rules! batch_pairing_check_for_g1_sig {
+    ($checker:expr, $A:expr, $w:expr, $g2:expr, $k:expr) => {
+        $checker.add_sources($A, $w, $k, $g2)
+    };
+}
+
+// Macro to add the pairing check `e(w, A) == e(g2, k)` to the pairing checker when signature is in group G2
+macro_rules! batch_pairing_check_for_g2_sig {
+    ($checker:expr, $A:expr, $w:expr, $g2:expr, $k:expr) => {
+        $checker.add_sources($w, $A, $g2, $k)
+    };
+}
+
+/// Index of the message count in the messages signed by a signature over variable length messages. See
+/// [`length_tagged_messages`]
+pub const MESSAGE_COUNT_INDEX: usize = 0;
+
+/// Messages signed by a signature over a variable length message list `messages`. These are the message count
+/// followed by `messages` and then zeroes such that there are `total_count` messages. Signatures are
+/// created with `total_count` as `messages.len() + 1` but the padding is used when proving knowledge of
+/// the signature with params supporting more messages to hide the message count.
+pub fn length_tagged_messages<F: PrimeField>(
+    messages: &[F],
+    total_count: usize,
+) -> Result<Vec<F>, BBSPlusError> {
+    if messages.len() + 1 > total_count {
+        return Err(BBSPlusError::MessageCountIncompatibleWithSigParams(
+            messages.len() + 1,
+            total_count,
+        ));
+    }
+    let mut tagged = Vec::with_capacity(total_count);
+    tagged.push(F::from(messages.len() as u64));
+    tagged.extend_from_slice(messages);
+    tagged.resize(total_count, F::zero());
+    Ok(tagged)
+}
+
 macro_rules! impl_signature_alg {
-    ( $name:ident, $params:ident, $pk:ident, $sig_group_proj:ident, $sig_group_affine:ident, $pairing:tt ) => {
+    ( $name:ident, $params:ident, $pk:ident, $sig_group_proj:ident, $sig_group_affine:ident, $pairing:tt, $batch_pairing:tt ) => {
         /// Signature creation and verification
         impl<E: PairingEngine> $name<E> {
             /// Create a new signature with all messages known to the signer.
@@ -197,19 +253,125 @@ macro_rules! impl_signature_alg {
                 )
             }
 
+            /// Create a new signature over a list of messages of any size, even empty. The signature is
+            /// over the message count followed by the messages, see [`length_tagged_messages`], and the
+            /// params are created from `label` for that many messages. The signer's public key must be
+            /// created using params created from `label` (with any message count).
+            pub fn new_with_variable_length_messages<R: RngCore, D: Digest>(
+                rng: &mut R,
+                messages: &[E::Fr],
+                sk: &SecretKey<E::Fr>,
+                label: &[u8],
+            ) -> Result<Self, BBSPlusError> {
+                Self::new_with_params_prefix(
+                    rng,
+                    messages,
+                    sk,
+                    &$params::<E>::new::<D>(label, messages.len() + 1),
+                )
+            }
+
+            /// Create a new signature over the message count followed by the messages using the prefix of
+            /// `params` for that many messages, see [`length_tagged_messages`]. Use this rather than
+            /// [`Self::new`] when the params might later be extended to support more messages, like with
+            /// `extend`, as a signature over `n` messages created with [`Self::new`] is also valid over
+            /// `n + k` messages under the extended params with the last `k` messages as 0. Signing the count
+            /// prevents such a signature from being passed off as one over more messages.
+            pub fn new_with_params_prefix<R: RngCore>(
+                rng: &mut R,
+                messages: &[E::Fr],
+                sk: &SecretKey<E::Fr>,
+                params: &$params<E>,
+            ) -> Result<Self, BBSPlusError> {
+                Self::new(
+                    rng,
+                    &length_tagged_messages(messages, messages.len() + 1)?,
+                    sk,
+                    &params.prefix(messages.len() + 1)?,
+                )
+            }
+
             /// Create a new (partially)blind signature where some of the messages are hidden from the
             /// signer under the given commitment. `uncommitted_messages` is the map from message index
             /// to message. Eg if signer while signing a multi-message `[m_0, m_1, m_2, m_3, m_4]` only
             /// knows of messages `m_1`, `m_3` and `m_4` while messages `m_0` and `m_2` are
             /// committed in `commitment` by the requester, `uncommitted_messages` will be the mapping
             /// `(1 -> m_1), (3 -> m_3), (4 -> m_4)`. It is assumed that the signer has verified the requester's
-            /// knowledge of `m_0` and `m_2` in the `commitment`
+            /// knowledge of `m_0` and `m_2` in the `commitment`. For signatures in group G1, the module
+            /// [`blind_signature`](crate::blind_signature) does this verification.
             pub fn new_with_committed_messages<R: RngCore>(
                 rng: &mut R,
                 commitment: &E::$sig_group_affine,
                 uncommitted_messages: BTreeMap<usize, &E::Fr>,
                 sk: &SecretKey<E::Fr>,
                 params: &$params<E>,
+            ) -> Result<Self, BBSPlusError> {
+                Self::new_with_committed_messages_using(
+                    rng,
+                    commitment,
+                    uncommitted_messages,
+                    sk,
+                    params,
+                    false,
+                )
+            }
+
+            /// Same as [`Self::new`] but hardened against timing side channels on the secret key, see
+            /// [`Self::new_with_committed_messages_hardened`].
+            pub fn new_hardened<R: RngCore>(
+                rng: &mut R,
+                messages: &[E::Fr],
+                sk: &SecretKey<E::Fr>,
+                params: &$params<E>,
+            ) -> Result<Self, BBSPlusError> {
+                if messages.is_empty() {
+                    return Err(BBSPlusError::NoMessageToSign);
+                }
+                if messages.len() != params.supported_message_count() {
+                    return Err(BBSPlusError::MessageCountIncompatibleWithSigParams(
+                        messages.len(),
+                        params.supported_message_count(),
+                    ));
+                }
+                let msg_map: BTreeMap<usize, &E::Fr> =
+                    messages.iter().enumerate().map(|(i, e)| (i, e)).collect();
+                Self::new_with_committed_messages_hardened(
+                    rng,
+                    &E::$sig_group_affine::zero(),
+                    msg_map,
+                    sk,
+                    params,
+                )
+            }
+
+            /// Same as [`Self::new_with_committed_messages`] but hardened against timing side channels on the
+            /// secret key, meant for signers running on shared hosts. `1/(e+x)` is computed with a blinded inversion
+            /// and the multiplication by it uses the Montgomery ladder over random shares of the scalar. Slower than
+            /// [`Self::new_with_committed_messages`].
+            pub fn new_with_committed_messages_hardened<R: RngCore>(
+                rng: &mut R,
+                commitment: &E::$sig_group_affine,
+                uncommitted_messages: BTreeMap<usize, &E::Fr>,
+                sk: &SecretKey<E::Fr>,
+                params: &$params<E>,
+            ) -> Result<Self, BBSPlusError> {
+                Self::new_with_committed_messages_using(
+                    rng,
+                    commitment,
+                    uncommitted_messages,
+                    sk,
+                    params,
+                    true,
+                )
+            }
+
+            fn new_with_committed_messages_using<R: RngCore>(
+                rng: &mut R,
+                commitment: &E::$sig_group_affine,
+                uncommitted_messages: BTreeMap<usize, &E::Fr>,
+                sk: &SecretKey<E::Fr>,
+                params: &$params<E>,
+                hardened: bool,
             ) -> Result<Self, BBSPlusError> {
                 if uncommitted_messages.is_empty() {
                     return Err(BBSPlusError::NoMessageToSign);
@@ -229,12 +391,9 @@ macro_rules! impl_signature_alg {
                 let b = params.b(uncommitted_messages, &s)?;
 
                 let e = E::Fr::rand(rng);
-                // 1/(e+x)
-                let e_plus_x_inv = (e + sk.0).inverse().ok_or(BBSPlusError::CannotInvert0)?;
-
-                // {commitment + b} * {1/(e+x)}
+                // {commitment + b}
                 let commitment_plus_b = b.add_mixed(commitment);
-                let A = <E::$sig_group_proj as Group>::mul(&commitment_plus_b, &e_plus_x_inv);
+                let A = Self::compute_A(rng, &commitment_plus_b, &e, sk, hardened)?;
                 Ok(Self {
                     A: A.into_affine(),
                     e,
@@ -242,6 +401,28 @@ macro_rules! impl_signature_alg {
                 })
             }
 
+            /// Compute `A = {commitment + b} * {1/(e+x)}`
+            fn compute_A<R: RngCore>(
+                rng: &mut R,
+                commitment_plus_b: &E::$sig_group_proj,
+                e: &E::Fr,
+                sk: &SecretKey<E::Fr>,
+                hardened: bool,
+            ) -> Result<E::$sig_group_proj, BBSPlusError> {
+                Ok(if hardened {
+                    // 1/(e+x)
+                    let e_plus_x_inv =
+                        blinded_inverse(rng, &(*e + sk.0)).ok_or(BBSPlusError::CannotInvert0)?;
+                    // {commitment + b} * {1/(e+x)}
+                    blinded_mul(rng, commitment_plus_b, &e_plus_x_inv)
+                } else {
+                    // 1/(e+x)
+                    let e_plus_x_inv = (*e + sk.0).inverse().ok_or(BBSPlusError::CannotInvert0)?;
+                    // {commitment + b} * {1/(e+x)}
+                    <E::$sig_group_proj as Group>::mul(commitment_plus_b, &e_plus_x_inv)
+                })
+            }
+
             /// Checks that the elliptic curve point in the signature is non-zero
             pub fn is_non_zero(&self) -> bool {
                 !self.A.is_zero()
@@ -295,6 +476,152 @@ macro_rules! impl_signature_alg {
                 }
                 Ok(())
             }
+
+            /// Verify a signature created using [`Self::new_with_variable_length_messages`]
+            pub fn verify_with_variable_length_messages<D: Digest>(
+                &self,
+                messages: &[E::Fr],
+                pk: &$pk<E>,
+                label: &[u8],
+            ) -> Result<(), BBSPlusError> {
+                self.verify_with_params_prefix(
+                    messages,
+                    pk,
+                    &$params::<E>::new::<D>(label, messages.len() + 1),
+                )
+            }
+
+            /// Verify a signature created using [`Self::new_with_params_prefix`]. `params` can support more
+            /// messages than the signature, like params extended after signing.
+            pub fn verify_with_params_prefix(
+                &self,
+                messages: &[E::Fr],
+                pk: &$pk<E>,
+                params: &$params<E>,
+            ) -> Result<(), BBSPlusError> {
+                self.verify(
+                    &length_tagged_messages(messages, messages.len() + 1)?,
+                    pk,
+                    &params.prefix(messages.len() + 1)?,
+                )
+            }
+
+            /// Verify a signature created using [`Self::new`] with params that have since been extended, like
+            /// with `extend`, to support more messages. Only the prefix of `params` for `messages` is used. As
+            /// the message count is not signed, the signature is also valid over more messages which are 0 so
+            /// the verifier should pick the message count of `messages` rather than accept any count.
+            pub fn verify_with_plain_params_prefix(
+                &self,
+                messages: &[E::Fr],
+                pk: &$pk<E>,
+                params: &$params<E>,
+            ) -> Result<(), BBSPlusError> {
+                self.verify(messages, pk, &params.prefix(messages.len())?)
+            }
+
+            /// Verify several signatures by the same signer where `messages[i]` are the messages of
+            /// `signatures[i]`. Rather than doing a pairing check for each signature, the pairing checks
+            /// `e(A_i, g2*e_i + w) == e(b_i, g2)` are rearranged as `e(A_i, w) == e(b_i - A_i*e_i, g2)` and
+            /// combined using random linear combinations so that only 2 pairings are needed. If the
+            /// combined check fails, each signature is verified to find the invalid ones and their
+            /// indices are returned in the error. Assumes that the public key and parameters have been
+            /// validated already.
+            pub fn verify_batch<R: RngCore>(
+                rng: &mut R,
+                signatures: &[Self],
+                messages: &[Vec<E::Fr>],
+                pk: &$pk<E>,
+                params: &$params<E>,
+            ) -> Result<(), BBSPlusError> {
+                if signatures.len() != messages.len() {
+                    return Err(BBSPlusError::SignatureAndMessageCountMismatch(
+                        signatures.len(),
+                        messages.len(),
+                    ));
+                }
+                if signatures.is_empty() {
+                    return Ok(());
+                }
+                let mut pairing_checker = RandomizedPairingChecker::new_using_rng(rng, true);
+                let res = Self::add_batch_to_randomized_pairing_checker(
+                    rng,
+                    signatures,
+                    messages,
+                    pk,
+                    params,
+                    &mut pairing_checker,
+                );
+                if res.is_ok() && pairing_checker.verify() {
+                    return Ok(());
+                }
+                // Find the invalid signatures
+                let invalid = cfg_iter!(signatures)
+                    .zip(cfg_iter!(messages))
+                    .enumerate()
+                    .filter_map(|(i, (sig, msgs))| sig.verify(msgs, pk, params).err().map(|_| i))
+                    .collect::<Vec<_>>();
+                Err(BBSPlusError::BatchVerificationFailed(invalid))
+            }
+
+            /// Same as `verify_batch` but adds the combined pairing check to the given `pairing_checker` which
+            /// the caller must verify and the invalid signatures are not identified. Useful when the pairing
+            /// checks of several batches or of other objects are to be combined.
+            pub fn add_batch_to_randomized_pairing_checker<R: RngCore>(
+                rng: &mut R,
+                signatures: &[Self],
+                messages: &[Vec<E::Fr>],
+                pk: &$pk<E>,
+                params: &$params<E>,
+                pairing_checker: &mut RandomizedPairingChecker<E>,
+            ) -> Result<(), BBSPlusError> {
+                if signatures.len() != messages.len() {
+                    return Err(BBSPlusError::SignatureAndMessageCountMismatch(
+                        signatures.len(),
+                        messages.len(),
+                    ));
+                }
+                for msgs in messages {
+                    if msgs.is_empty() {
+                        return Err(BBSPlusError::NoMessageToSign);
+                    }
+                    if msgs.len() != params.supported_message_count() {
+                        return Err(BBSPlusError::MessageCountIncompatibleWithSigParams(
+                            msgs.len(),
+                            params.supported_message_count(),
+                        ));
+                    }
+                }
+                if signatures.iter().any(|s| !s.is_non_zero()) {
+                    return Err(BBSPlusError::ZeroSignature);
+                }
+                let randoms = (0..signatures.len())
+                    .map(|_| E::Fr::rand(rng))
+                    .collect::<Vec<_>>();
+                let b = cfg_iter!(signatures)
+                    .zip(cfg_iter!(messages))
+                    .map(|(sig, msgs)| {
+                        params.b(
+                            msgs.iter().enumerate().collect::<BTreeMap<usize, &E::Fr>>(),
+                            &sig.s,
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let b = E::$sig_group_proj::batch_normalization_into_affine(&b);
+                let A = signatures.iter().map(|s| s.A).collect::<Vec<_>>();
+                // sum(A_i*r_i)
+                let A_combined = variable_base_msm(&A, &randoms).into_affine();
+                // sum(b_i*r_i - A_i*e_i*r_i)
+                let mut scalars = cfg_iter!(signatures)
+                    .zip(cfg_iter!(randoms))
+                    .map(|(s, r)| -(s.e * r))
+                    .collect::<Vec<_>>();
+                scalars.extend_from_slice(&randoms);
+                let mut bases = A;
+                bases.extend(b);
+                let k = variable_base_msm(&bases, &scalars).into_affine();
+                $batch_pairing!(pairing_checker, A_combined, pk.0, params.g2, k);
+                Ok(())
+            }
         }
     };
 }
@@ -305,7 +632,8 @@ impl_signature_alg!(
     PublicKeyG2,
     G1Projective,
     G1Affine,
-    pairing_check_for_g1_sig
+    pairing_check_for_g1_sig,
+    batch_pairing_check_for_g1_sig
 );
 impl_signature_alg!(
     SignatureG2,
@@ -313,7 +641,8 @@ impl_signature_alg!(
     PublicKeyG1,
     G2Projective,
     G2Affine,
-    pairing_check_for_g2_sig
+    pairing_check_for_g2_sig,
+    batch_pairing_check_for_g2_sig
 );
 
 #[cfg(test)]
@@ -321,11 +650,12 @@ mod tests {
     use super::*;
     use crate::setup::{KeypairG1, KeypairG2};
     use crate::test_serialization;
-    use ark_bls12_381::{Bls12_381, G1Affine, G2Affine};
+    use ark_bls12_381::{Bls12_381, G1Affine, G1Projective, G2Affine};
     use ark_std::{
         rand::{rngs::StdRng, SeedableRng},
         UniformRand,
     };
+    use blake2::Blake2b;
     use std::collections::HashSet;
     use std::time::Instant;
 
@@ -410,6 +740,87 @@ mod tests {
         };
     }
 
+    macro_rules! test_batch_verif {
+        ($keypair:ident, $params:ident, $sig:ident, $rng:ident, $message_count: ident, $group: ident) => {
+            let params = $params::<Bls12_381>::generate_using_rng(&mut $rng, $message_count);
+            let keypair = $keypair::<Bls12_381>::generate_using_rng(&mut $rng, &params);
+            let public_key = &keypair.public_key;
+            let sig_count = 20;
+            let messages = (0..sig_count)
+                .map(|_| {
+                    (0..$message_count)
+                        .map(|_| Fr::rand(&mut $rng))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            let mut sigs = messages
+                .iter()
+                .map(|m| {
+                    $sig::<Bls12_381>::new(&mut $rng, m, &keypair.secret_key, &params).unwrap()
+                })
+                .collect::<Vec<_>>();
+
+            let start = Instant::now();
+            for (sig, m) in sigs.iter().zip(messages.iter()) {
+                sig.verify(m, public_key, &params).unwrap();
+            }
+            println!(
+                "Time to verify {} signatures one by one is {:?}",
+                sig_count,
+                start.elapsed()
+            );
+
+            let start = Instant::now();
+            $sig::verify_batch(&mut $rng, &sigs, &messages, public_key, &params).unwrap();
+            println!(
+                "Time to verify {} signatures as a batch is {:?}",
+                sig_count,
+                start.elapsed()
+            );
+
+            $sig::verify_batch(&mut $rng, &[], &[], public_key, &params).unwrap();
+            $sig::verify_batch(&mut $rng, &sigs[..1], &messages[..1], public_key, &params).unwrap();
+            assert!(
+                $sig::verify_batch(&mut $rng, &sigs, &messages[1..], public_key, &params).is_err()
+            );
+
+            // Signatures by a different signer don't verify
+            let other_keypair = $keypair::<Bls12_381>::generate_using_rng(&mut $rng, &params);
+            match $sig::verify_batch(
+                &mut $rng,
+                &sigs,
+                &messages,
+                &other_keypair.public_key,
+                &params,
+            ) {
+                Err(BBSPlusError::BatchVerificationFailed(invalid)) => {
+                    assert_eq!(invalid, (0..sig_count).collect::<Vec<_>>())
+                }
+                _ => panic!("batch verification should have failed"),
+            }
+
+            // Invalid signatures are pinpointed
+            sigs[3].e += Fr::one();
+            sigs[11].s += Fr::one();
+            let mut messages_1 = messages.clone();
+            messages_1[17][0] += Fr::one();
+            match $sig::verify_batch(&mut $rng, &sigs, &messages_1, public_key, &params) {
+                Err(BBSPlusError::BatchVerificationFailed(invalid)) => {
+                    assert_eq!(invalid, vec![3, 11, 17])
+                }
+                _ => panic!("batch verification should have failed"),
+            }
+
+            sigs[5].A = $group::zero();
+            match $sig::verify_batch(&mut $rng, &sigs, &messages, public_key, &params) {
+                Err(BBSPlusError::BatchVerificationFailed(invalid)) => {
+                    assert_eq!(invalid, vec![3, 5, 11])
+                }
+                _ => panic!("batch verification should have failed"),
+            }
+        };
+    }
+
     #[test]
     fn signature_verification() {
         // Test signing and verification
@@ -444,4 +855,232 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn batch_verification() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let message_count = 10;
+
+        {
+            test_batch_verif!(
+                KeypairG2,
+                SignatureParamsG1,
+                SignatureG1,
+                rng,
+                message_count,
+                G1Affine
+            );
+        }
+
+        {
+            test_batch_verif!(
+                KeypairG1,
+                SignatureParamsG2,
+                SignatureG2,
+                rng,
+                message_count,
+                G2Affine
+            );
+        }
+    }
+
+    #[test]
+    fn variable_length_messages() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let label = b"test-variable-length";
+        macro_rules! check {
+            ($keypair:ident, $params:ident, $sig:ident) => {
+                let keypair = $keypair::<Bls12_381>::generate_using_rng(
+                    &mut rng,
+                    &$params::<Bls12_381>::new::<Blake2b>(label, 1),
+                );
+                for count in [0, 1, 4, 9] {
+                    let messages = (0..count).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+                    let sig = $sig::<Bls12_381>::new_with_variable_length_messages::<_, Blake2b>(
+                        &mut rng,
+                        &messages,
+                        &keypair.secret_key,
+                        label,
+                    )
+                    .unwrap();
+                    sig.verify_with_variable_length_messages::<Blake2b>(
+                        &messages,
+                        &keypair.public_key,
+                        label,
+                    )
+                    .unwrap();
+                    // Same as a signature over the length tagged messages
+                    sig.verify(
+                        &length_tagged_messages(&messages, count + 1).unwrap(),
+                        &keypair.public_key,
+                        &$params::<Bls12_381>::new::<Blake2b>(label, count + 1),
+                    )
+                    .unwrap();
+
+                    // Doesn't verify with fewer or more messages or a different label
+                    if count > 0 {
+                        assert!(sig
+                            .verify_with_variable_length_messages::<Blake2b>(
+                                &messages[0..count - 1],
+                                &keypair.public_key,
+                                label,
+                            )
+                            .is_err());
+                    }
+                    let mut more_messages = messages.clone();
+                    more_messages.push(Fr::zero());
+                    assert!(sig
+                        .verify_with_variable_length_messages::<Blake2b>(
+                            &more_messages,
+                            &keypair.public_key,
+                            label,
+                        )
+                        .is_err());
+                    assert!(sig
+                        .verify_with_variable_length_messages::<Blake2b>(
+                            &messages,
+                            &keypair.public_key,
+                            b"another-label",
+                        )
+                        .is_err());
+                }
+            };
+        }
+        check!(KeypairG2, SignatureParamsG1, SignatureG1);
+        check!(KeypairG1, SignatureParamsG2, SignatureG2);
+
+        let messages = vec![Fr::rand(&mut rng), Fr::rand(&mut rng)];
+        assert_eq!(
+            length_tagged_messages(&messages, 5).unwrap(),
+            vec![
+                Fr::from(2u64),
+                messages[0],
+                messages[1],
+                Fr::zero(),
+                Fr::zero()
+            ]
+        );
+        assert!(length_tagged_messages(&messages, 2).is_err());
+    }
+
+    #[test]
+    fn hardened_signing() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let message_count = 5;
+        let messages: Vec<Fr> = (0..message_count).map(|_| Fr::rand(&mut rng)).collect();
+
+        macro_rules! check {
+            ($keypair:ident, $params:ident, $sig:ident) => {
+                let params = $params::<Bls12_381>::generate_using_rng(&mut rng, message_count);
+                let keypair = $keypair::<Bls12_381>::generate_using_rng(&mut rng, &params);
+                let sig = $sig::<Bls12_381>::new_hardened(
+                    &mut rng,
+                    &messages,
+                    &keypair.secret_key,
+                    &params,
+                )
+                .unwrap();
+                sig.verify(&messages, &keypair.public_key, &params).unwrap();
+
+                assert!($sig::<Bls12_381>::new_hardened(
+                    &mut rng,
+                    &messages[1..],
+                    &keypair.secret_key,
+                    &params
+                )
+                .is_err());
+
+                // Blind signature
+                let blinding = Fr::rand(&mut rng);
+                let committed_messages = [(0, &messages[0]), (3, &messages[3])]
+                    .into_iter()
+                    .collect::<BTreeMap<_, _>>();
+                let commitment = params
+                    .commit_to_messages(committed_messages, &blinding)
+                    .unwrap();
+                let uncommitted_messages =
+                    [(1, &messages[1]), (2, &messages[2]), (4, &messages[4])]
+                        .into_iter()
+                        .collect::<BTreeMap<_, _>>();
+                let blinded_sig = $sig::<Bls12_381>::new_with_committed_messages_hardened(
+                    &mut rng,
+                    &commitment,
+                    uncommitted_messages,
+                    &keypair.secret_key,
+                    &params,
+                )
+                .unwrap();
+                assert!(blinded_sig
+                    .verify(&messages, &keypair.public_key, &params)
+                    .is_err());
+                blinded_sig
+                    .unblind(&blinding)
+                    .verify(&messages, &keypair.public_key, &params)
+                    .unwrap();
+            };
+        }
+        check!(KeypairG2, SignatureParamsG1, SignatureG1);
+        check!(KeypairG1, SignatureParamsG2, SignatureG2);
+
+        // `e` is pinned such that `1/(e+x)` is 1 and -1, the scalars with the fewest and the most bits. Hardened
+        // signing gives the same `A` as the unhardened one for both.
+        let base = G1Projective::rand(&mut rng);
+        let sk = SecretKey(Fr::rand(&mut rng));
+        for e in [Fr::one() - sk.0, -Fr::one() - sk.0] {
+            assert_eq!(
+                SignatureG1::<Bls12_381>::compute_A(&mut rng, &base, &e, &sk, true).unwrap(),
+                SignatureG1::<Bls12_381>::compute_A(&mut rng, &base, &e, &sk, false).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn hardened_signing_timing_independent_of_secret_key() {
+        // Compares wall-clock time so run it explicitly on an idle machine with a release build
+        // `e` is random when signing so it is pinned here such that the secret dependent scalar `1/(e+x)`
+        // is 1 once and -1 once. Without hardening, multiplying by 1 is much faster than by -1 so the
+        // comparison can tell the difference. With hardening, the time taken should be about the same,
+        // the margin is only for noise. The fastest of several runs is taken to reduce noise.
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let base = G1Projective::rand(&mut rng);
+        let sk = SecretKey(Fr::rand(&mut rng));
+        // `1/(e+x)` is 1 for `e_small` and -1 for `e_large`
+        let e_small = Fr::one() - sk.0;
+        let e_large = -Fr::one() - sk.0;
+        let iterations = 10;
+        let runs = 5;
+
+        let mut time = |e: &Fr, hardened: bool| {
+            (0..runs)
+                .map(|_| {
+                    let start = Instant::now();
+                    for _ in 0..iterations {
+                        let _ =
+                            SignatureG1::<Bls12_381>::compute_A(&mut rng, &base, e, &sk, hardened)
+                                .unwrap();
+                    }
+                    start.elapsed().as_secs_f64()
+                })
+                .fold(f64::INFINITY, f64::min)
+        };
+
+        // Warm up
+        time(&e_large, true);
+
+        let (t_small, t_large) = (time(&e_small, false), time(&e_large, false));
+        println!(
+            "Signing time when 1/(e+x) is small {:?}, when large {:?}",
+            t_small, t_large
+        );
+        assert!(t_small / t_large < 0.5, "ratio {}", t_small / t_large);
+
+        let (t_small, t_large) = (time(&e_small, true), time(&e_large, true));
+        println!(
+            "Hardened signing time when 1/(e+x) is small {:?}, when large {:?}",
+            t_small, t_large
+        );
+        let ratio = t_small / t_large;
+        assert!(ratio > 0.5 && ratio < 2.0, "ratio {}", ratio);
+    }
 }
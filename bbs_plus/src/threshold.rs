@@ -0,0 +1,849 @@
+#![allow(non_snake_case)]
+
+//! Threshold issuance of BBS+ signatures in group G1 where any `threshold` of the `total` signers
+//! can jointly create a signature and no single signer ever knows the secret key. The result is a
+//! regular [`SignatureG1`] which verifies with the [`PublicKeyG2`] output by the key generation.
+//!
+//! The protocol has 3 phases
+//! 1. Distributed key generation (DKG) without a dealer. Each signer acts as the dealer of a Feldman
+//!    verifiable secret sharing of a random value and the secret key `x` is the sum of these values.
+//!    A signer's share of `x` is the sum of the shares it received. See [`DkgParticipant`].
+//! 2. Preprocessing. The signers of a session need additive shares of the product `r*(e+x)` where `r`
+//!    is random. This uses a multiplication triple `(a, b, c = a*b)`, additively shared among the signers,
+//!    which is created before the messages to sign are known. Each signing session consumes a triple
+//!    and a triple must never be reused. See [`simulate_multiplication_triple_generation`].
+//! 3. Signing in 2 rounds. In the 1st round, each signer samples its shares of `e`, `s` and `r` and
+//!    broadcasts its shares of `e` and `s` and its shares of `r` and `e+x` masked with the triple. In the
+//!    2nd round, each signer computes its share `u_i` of `u = r*(e+x)` and `R_i = B*r_i` where
+//!    `B = g_1 + h_0*s + sum(h_i*m_i)` and sends these to the aggregator as a [`PartialSignature`]. The
+//!    aggregator computes `A = sum(R_i) * 1/sum(u_i) = B * 1/(e+x)`.
+//!
+//! Shares received during the DKG are checked against the dealer's commitments but signers are otherwise
+//! assumed to follow the protocol. The aggregator should verify the aggregated signature before using it.
+//!
+//! # Examples
+//!
+//! ```
+//! use ark_bls12_381::Bls12_381;
+//! use bbs_plus::setup::SignatureParamsG1;
+//! use bbs_plus::threshold::{DkgParticipant, PartialSignature, SigningRound1State, simulate_multiplication_triple_generation};
+//!
+//! let params = SignatureParamsG1::<Bls12_381>::generate_using_rng(&mut rng, 5);
+//!
+//! // DKG with 3 out of 5 signers. Each participant with id `i` creates its state and commitments and
+//! // broadcasts the commitments.
+//! let (mut participant_1, comms_1) = DkgParticipant::<Bls12_381>::new(&mut rng, 1, 3, 5, &params).unwrap();
+//! let (mut participant_2, comms_2) = DkgParticipant::<Bls12_381>::new(&mut rng, 2, 3, 5, &params).unwrap();
+//! // .. and so on for participants 3, 4 and 5
+//!
+//! // Each participant sends the share meant for each other participant privately
+//! let share_1_for_2 = participant_1.share_for(2).unwrap();
+//! participant_2.receive_share(share_1_for_2, &comms_1, &params).unwrap();
+//! // .. and so on for all pairs of participants
+//!
+//! // Once a participant has received shares from everyone, it gets its secret key share and the public key
+//! let (sk_share_1, public_key) = participant_1.finish().unwrap();
+//!
+//! // For signing, signers 1, 2 and 4 get their shares of a multiplication triple in the preprocessing phase
+//! let signers = [1, 2, 4].into_iter().collect::<BTreeSet<_>>();
+//! let mut triples = simulate_multiplication_triple_generation(&mut rng, &signers);
+//!
+//! // Each signer starts the session and broadcasts the 1st round message
+//! let (round1_1, msg_1) = SigningRound1State::init(&mut rng, &sk_share_1, signers.clone(), triples.remove(&1).unwrap()).unwrap();
+//! // .. and so on for signers 2 and 4
+//!
+//! // After receiving the 1st round messages of all signers, each creates its partial signature
+//! let partial_1 = round1_1.create_partial_signature(&messages, &[msg_1, msg_2, msg_4], &params).unwrap();
+//! // .. and so on for signers 2 and 4
+//!
+//! // The aggregator combines them into a signature
+//! let sig = PartialSignature::aggregate(&[partial_1, partial_2, partial_4]).unwrap();
+//! sig.verify(&messages, &public_key, &params).unwrap();
+//! ```
+
+use crate::error::BBSPlusError;
+use crate::setup::{PublicKeyG2, SignatureParamsG1};
+use crate::signature::SignatureG1;
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{Field, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    collections::{BTreeMap, BTreeSet},
+    io::{Read, Write},
+    rand::RngCore,
+    vec::Vec,
+    UniformRand, Zero,
+};
+use dock_crypto_utils::serde_utils::*;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use zeroize::Zeroize;
+
+/// Identifier of a signer. Ids start from 1 as the secret key is the evaluation of the sharing
+/// polynomial at 0.
+pub type ParticipantId = u16;
+
+/// Share of the secret key held by a signer after the DKG.
+#[serde_as]
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+    CanonicalSerialize,
+    CanonicalDeserialize,
+    Serialize,
+    Deserialize,
+    Zeroize,
+)]
+pub struct SecretKeyShare<F: PrimeField> {
+    pub id: ParticipantId,
+    pub threshold: u16,
+    #[serde_as(as = "FieldBytes")]
+    pub share: F,
+}
+
+impl<F: PrimeField> Drop for SecretKeyShare<F> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// Commitments to the coefficients of a participant's secret polynomial, broadcast during the DKG.
+/// The commitment to the constant term is the participant's contribution to the public key.
+#[serde_as]
+#[derive(
+    Clone, PartialEq, Eq, Debug, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+pub struct DkgCommitments<E: PairingEngine> {
+    pub id: ParticipantId,
+    #[serde_as(as = "Vec<AffineGroupBytes>")]
+    pub commitments: Vec<E::G2Affine>,
+}
+
+/// State of a participant during the DKG.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DkgParticipant<E: PairingEngine> {
+    pub id: ParticipantId,
+    pub threshold: u16,
+    pub total: u16,
+    /// Coefficients of the secret polynomial, `coefficients[0]` is the participant's contribution to the secret key
+    coefficients: Vec<E::Fr>,
+    /// Shares received from participants, including from itself
+    received_shares: BTreeMap<ParticipantId, E::Fr>,
+    /// Commitments to the constant term of the polynomials of participants, including itself
+    received_pk_contributions: BTreeMap<ParticipantId, E::G2Affine>,
+}
+
+impl<E: PairingEngine> Zeroize for DkgParticipant<E> {
+    fn zeroize(&mut self) {
+        self.coefficients.zeroize();
+        for (_, s) in self.received_shares.iter_mut() {
+            s.zeroize();
+        }
+    }
+}
+
+impl<E: PairingEngine> Drop for DkgParticipant<E> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<E: PairingEngine> DkgParticipant<E> {
+    /// Start the DKG for participant `id` where any `threshold` of the `total` participants can sign.
+    /// Returns the participant's state and the commitments to be broadcast to all other participants.
+    pub fn new<R: RngCore>(
+        rng: &mut R,
+        id: ParticipantId,
+        threshold: u16,
+        total: u16,
+        params: &SignatureParamsG1<E>,
+    ) -> Result<(Self, DkgCommitments<E>), BBSPlusError> {
+        if threshold == 0 || threshold > total {
+            return Err(BBSPlusError::InvalidThreshold(threshold, total));
+        }
+        if id == 0 || id > total {
+            return Err(BBSPlusError::InvalidParticipantId(id));
+        }
+        let coefficients = (0..threshold).map(|_| E::Fr::rand(rng)).collect::<Vec<_>>();
+        let commitments = E::G2Projective::batch_normalization_into_affine(
+            &coefficients
+                .iter()
+                .map(|c| params.g2.mul(c.into_repr()))
+                .collect::<Vec<_>>(),
+        );
+        let mut participant = Self {
+            id,
+            threshold,
+            total,
+            coefficients,
+            received_shares: BTreeMap::new(),
+            received_pk_contributions: BTreeMap::new(),
+        };
+        let own_share = participant.share_for(id)?;
+        participant.received_shares.insert(id, own_share);
+        participant
+            .received_pk_contributions
+            .insert(id, commitments[0]);
+        Ok((participant, DkgCommitments { id, commitments }))
+    }
+
+    /// Share for the participant `recipient`, i.e. the secret polynomial evaluated at `recipient`.
+    /// This must be sent to the recipient over a private channel.
+    pub fn share_for(&self, recipient: ParticipantId) -> Result<E::Fr, BBSPlusError> {
+        if recipient == 0 || recipient > self.total {
+            return Err(BBSPlusError::InvalidParticipantId(recipient));
+        }
+        // Horner's method
+        let x = E::Fr::from(recipient as u64);
+        Ok(self
+            .coefficients
+            .iter()
+            .rev()
+            .fold(E::Fr::zero(), |acc, c| acc * x + c))
+    }
+
+    /// Process the share received from another participant after checking it against that participant's
+    /// broadcast commitments.
+    pub fn receive_share(
+        &mut self,
+        share: E::Fr,
+        commitments: &DkgCommitments<E>,
+        params: &SignatureParamsG1<E>,
+    ) -> Result<(), BBSPlusError> {
+        let sender = commitments.id;
+        if sender == 0 || sender > self.total {
+            return Err(BBSPlusError::InvalidParticipantId(sender));
+        }
+        if self.received_shares.contains_key(&sender) {
+            return Err(BBSPlusError::AlreadyReceivedFromParticipant(sender));
+        }
+        if commitments.commitments.len() != self.threshold as usize {
+            return Err(BBSPlusError::IncorrectNumberOfDkgCommitments(
+                sender,
+                commitments.commitments.len(),
+                self.threshold as usize,
+            ));
+        }
+        // Check g2*share == sum(commitments[k] * id^k) using Horner's method
+        let x = E::Fr::from(self.id as u64).into_repr();
+        let expected = commitments
+            .commitments
+            .iter()
+            .rev()
+            .fold(E::G2Projective::zero(), |acc, c| acc.mul(x).add_mixed(c));
+        if params.g2.mul(share.into_repr()) != expected {
+            return Err(BBSPlusError::InvalidDkgShare(sender));
+        }
+        self.received_shares.insert(sender, share);
+        self.received_pk_contributions
+            .insert(sender, commitments.commitments[0]);
+        Ok(())
+    }
+
+    /// Finish the DKG once shares from all participants have been received. Returns the participant's
+    /// share of the secret key and the public key.
+    pub fn finish(self) -> Result<(SecretKeyShare<E::Fr>, PublicKeyG2<E>), BBSPlusError> {
+        for i in 1..=self.total {
+            if !self.received_shares.contains_key(&i) {
+                return Err(BBSPlusError::MissingShareFromParticipant(i));
+            }
+        }
+        let share = self
+            .received_shares
+            .values()
+            .fold(E::Fr::zero(), |acc, s| acc + s);
+        let pk = self
+            .received_pk_contributions
+            .values()
+            .fold(E::G2Projective::zero(), |acc, c| acc.add_mixed(c));
+        Ok((
+            SecretKeyShare {
+                id: self.id,
+                threshold: self.threshold,
+                share,
+            },
+            PublicKeyG2(pk.into_affine()),
+        ))
+    }
+}
+
+/// A signer's additive share of a multiplication triple `(a, b, c)` where `c = a*b`. Created in the
+/// preprocessing phase for a particular set of signers and must be used in only one signing session.
+#[serde_as]
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+    CanonicalSerialize,
+    CanonicalDeserialize,
+    Serialize,
+    Deserialize,
+    Zeroize,
+)]
+pub struct MultiplicationTripleShare<F: PrimeField> {
+    pub id: ParticipantId,
+    #[serde_as(as = "FieldBytes")]
+    pub a: F,
+    #[serde_as(as = "FieldBytes")]
+    pub b: F,
+    #[serde_as(as = "FieldBytes")]
+    pub c: F,
+}
+
+impl<F: PrimeField> Drop for MultiplicationTripleShare<F> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// Simulates, in-process, the preprocessing phase which creates a multiplication triple for the given
+/// signers. Each signer samples its shares `a_i` and `b_i` and for each pair of signers `i` and `j`, the
+/// cross term `a_i*b_j` is split into additive shares of `i` and `j` using a two-party multiplication.
+/// In a deployment the two-party multiplication is a protocol like one based on oblivious transfer such
+/// that neither party learns the other's input, here its output is computed directly. No party,
+/// including the one running this simulation in a deployment, should learn all shares of a triple
+/// as that together with the 1st round messages of a session reveals the secret key.
+pub fn simulate_multiplication_triple_generation<R: RngCore, F: PrimeField>(
+    rng: &mut R,
+    signers: &BTreeSet<ParticipantId>,
+) -> BTreeMap<ParticipantId, MultiplicationTripleShare<F>> {
+    let mut triples = signers
+        .iter()
+        .map(|i| {
+            let a = F::rand(rng);
+            let b = F::rand(rng);
+            let c = a * b;
+            (*i, MultiplicationTripleShare { id: *i, a, b, c })
+        })
+        .collect::<BTreeMap<_, _>>();
+    for i in signers {
+        for j in signers {
+            if i == j {
+                continue;
+            }
+            // Two-party multiplication of `a_i` and `b_j` giving `i` and `j` additive shares of the product
+            let product = triples[i].a * triples[j].b;
+            let share_i = F::rand(rng);
+            triples.get_mut(i).unwrap().c += share_i;
+            triples.get_mut(j).unwrap().c += product - share_i;
+        }
+    }
+    triples
+}
+
+/// Message broadcast by each signer in the 1st round of signing.
+#[serde_as]
+#[derive(
+    Clone, PartialEq, Eq, Debug, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+pub struct SigningRound1Message<F: PrimeField> {
+    pub sender: ParticipantId,
+    /// Signer's share of `e`
+    #[serde_as(as = "FieldBytes")]
+    pub e_share: F,
+    /// Signer's share of `s`
+    #[serde_as(as = "FieldBytes")]
+    pub s_share: F,
+    /// Signer's share of `r` masked with its share of triple's `a`
+    #[serde_as(as = "FieldBytes")]
+    pub masked_r_share: F,
+    /// Signer's share of `e + x` masked with its share of triple's `b`
+    #[serde_as(as = "FieldBytes")]
+    pub masked_e_plus_x_share: F,
+}
+
+/// State of a signer after the 1st round of signing.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SigningRound1State<F: PrimeField> {
+    pub id: ParticipantId,
+    pub signers: BTreeSet<ParticipantId>,
+    r_share: F,
+    triple: MultiplicationTripleShare<F>,
+}
+
+impl<F: PrimeField> Zeroize for SigningRound1State<F> {
+    fn zeroize(&mut self) {
+        self.r_share.zeroize();
+        self.triple.zeroize();
+    }
+}
+
+impl<F: PrimeField> Drop for SigningRound1State<F> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<F: PrimeField> SigningRound1State<F> {
+    /// Start a signing session among `signers` using the signer's share of the secret key and its share of
+    /// a multiplication triple created for the `signers`. Returns the state and the message to be broadcast
+    /// to all other signers.
+    pub fn init<R: RngCore>(
+        rng: &mut R,
+        secret_key_share: &SecretKeyShare<F>,
+        signers: BTreeSet<ParticipantId>,
+        triple: MultiplicationTripleShare<F>,
+    ) -> Result<(Self, SigningRound1Message<F>), BBSPlusError> {
+        let id = secret_key_share.id;
+        if signers.len() < secret_key_share.threshold as usize {
+            return Err(BBSPlusError::BelowThreshold(
+                signers.len(),
+                secret_key_share.threshold,
+            ));
+        }
+        if signers.contains(&0) {
+            return Err(BBSPlusError::InvalidParticipantId(0));
+        }
+        if !signers.contains(&id) {
+            return Err(BBSPlusError::ParticipantNotInSigners(id));
+        }
+        if triple.id != id {
+            return Err(BBSPlusError::InvalidParticipantId(triple.id));
+        }
+        let e_share = F::rand(rng);
+        let s_share = F::rand(rng);
+        let r_share = F::rand(rng);
+        // Additive share of the secret key
+        let x_share = lagrange_basis_at_0::<F>(&signers, id) * secret_key_share.share;
+        let message = SigningRound1Message {
+            sender: id,
+            e_share,
+            s_share,
+            masked_r_share: r_share - triple.a,
+            masked_e_plus_x_share: e_share + x_share - triple.b,
+        };
+        Ok((
+            Self {
+                id,
+                signers,
+                r_share,
+                triple,
+            },
+            message,
+        ))
+    }
+
+    /// Create the partial signature over `messages` after receiving the 1st round messages of all
+    /// signers, including this signer's own.
+    pub fn create_partial_signature<E: PairingEngine<Fr = F>>(
+        self,
+        messages: &[F],
+        round1_messages: &[SigningRound1Message<F>],
+        params: &SignatureParamsG1<E>,
+    ) -> Result<PartialSignature<E>, BBSPlusError> {
+        if messages.is_empty() {
+            return Err(BBSPlusError::NoMessageToSign);
+        }
+        if messages.len() != params.supported_message_count() {
+            return Err(BBSPlusError::MessageCountIncompatibleWithSigParams(
+                messages.len(),
+                params.supported_message_count(),
+            ));
+        }
+        let mut senders = BTreeSet::new();
+        for m in round1_messages {
+            if !self.signers.contains(&m.sender) {
+                return Err(BBSPlusError::ParticipantNotInSigners(m.sender));
+            }
+            if !senders.insert(m.sender) {
+                return Err(BBSPlusError::AlreadyReceivedFromParticipant(m.sender));
+            }
+        }
+        if let Some(i) = self.signers.difference(&senders).next() {
+            return Err(BBSPlusError::MissingRound1MessageFromParticipant(*i));
+        }
+
+        let mut e = F::zero();
+        let mut s = F::zero();
+        // epsilon = r - a and delta = e + x - b
+        let mut epsilon = F::zero();
+        let mut delta = F::zero();
+        for m in round1_messages {
+            e += m.e_share;
+            s += m.s_share;
+            epsilon += m.masked_r_share;
+            delta += m.masked_e_plus_x_share;
+        }
+        // Share of u = r*(e+x) = (epsilon + a)*(delta + b) = c + epsilon*b + delta*a + epsilon*delta.
+        // Only one signer adds the term epsilon*delta
+        let mut u = self.triple.c + epsilon * self.triple.b + delta * self.triple.a;
+        if self.signers.iter().next() == Some(&self.id) {
+            u += epsilon * delta;
+        }
+        let b = params.b(messages.iter().enumerate().collect(), &s)?;
+        let R = b.mul(self.r_share.into_repr()).into_affine();
+        Ok(PartialSignature {
+            id: self.id,
+            R,
+            u,
+            e,
+            s,
+        })
+    }
+}
+
+/// Partial signature sent by a signer to the aggregator.
+#[serde_as]
+#[derive(
+    Clone, PartialEq, Eq, Debug, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+pub struct PartialSignature<E: PairingEngine> {
+    pub id: ParticipantId,
+    /// `B*r_i` where `r_i` is the signer's share of `r`
+    #[serde_as(as = "AffineGroupBytes")]
+    pub R: E::G1Affine,
+    /// Signer's share of `r*(e+x)`
+    #[serde_as(as = "FieldBytes")]
+    pub u: E::Fr,
+    #[serde_as(as = "FieldBytes")]
+    pub e: E::Fr,
+    #[serde_as(as = "FieldBytes")]
+    pub s: E::Fr,
+}
+
+impl<E: PairingEngine> PartialSignature<E> {
+    /// Aggregate the partial signatures of all signers of a session into a signature. The aggregated
+    /// signature should be verified before use.
+    pub fn aggregate(partial_signatures: &[Self]) -> Result<SignatureG1<E>, BBSPlusError> {
+        if partial_signatures.is_empty() {
+            return Err(BBSPlusError::NoPartialSignatures);
+        }
+        let e = partial_signatures[0].e;
+        let s = partial_signatures[0].s;
+        let mut ids = BTreeSet::new();
+        let mut R = E::G1Projective::zero();
+        let mut u = E::Fr::zero();
+        for p in partial_signatures {
+            if !ids.insert(p.id) {
+                return Err(BBSPlusError::AlreadyReceivedFromParticipant(p.id));
+            }
+            if p.e != e || p.s != s {
+                return Err(BBSPlusError::InconsistentPartialSignature(p.id));
+            }
+            R.add_assign_mixed(&p.R);
+            u += p.u;
+        }
+        let u_inv = u.inverse().ok_or(BBSPlusError::CannotInvert0)?;
+        // A = B*r * 1/(r*(e+x)) = B * 1/(e+x)
+        Ok(SignatureG1 {
+            A: R.mul(u_inv.into_repr()).into_affine(),
+            e,
+            s,
+        })
+    }
+}
+
+/// Lagrange basis polynomial of participant `i` evaluated at 0 for interpolating over the given
+/// participants, i.e. `prod(j/(j-i))` for all `j != i` in `participants`.
+pub fn lagrange_basis_at_0<F: PrimeField>(
+    participants: &BTreeSet<ParticipantId>,
+    i: ParticipantId,
+) -> F {
+    let i_f = F::from(i as u64);
+    let mut numerator = F::one();
+    let mut denominator = F::one();
+    for j in participants {
+        if *j == i {
+            continue;
+        }
+        let j_f = F::from(*j as u64);
+        numerator *= j_f;
+        denominator *= j_f - i_f;
+    }
+    // Denominator is non-zero as participant ids are distinct
+    numerator * denominator.inverse().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::setup::SecretKey;
+    use crate::test_serialization;
+    use ark_bls12_381::Bls12_381;
+    use ark_std::{
+        rand::{rngs::StdRng, SeedableRng},
+        One,
+    };
+
+    type Fr = <Bls12_381 as PairingEngine>::Fr;
+
+    /// Run the DKG among all participants and return their secret key shares and the public key
+    fn do_dkg<R: RngCore>(
+        rng: &mut R,
+        threshold: u16,
+        total: u16,
+        params: &SignatureParamsG1<Bls12_381>,
+    ) -> (Vec<SecretKeyShare<Fr>>, PublicKeyG2<Bls12_381>) {
+        let mut participants = vec![];
+        let mut all_comms = vec![];
+        for i in 1..=total {
+            let (p, comms) = DkgParticipant::new(rng, i, threshold, total, params).unwrap();
+            assert_eq!(comms.commitments.len(), threshold as usize);
+            test_serialization!(DkgCommitments<Bls12_381>, comms);
+            participants.push(p);
+            all_comms.push(comms);
+        }
+        for i in 0..total as usize {
+            for j in 0..total as usize {
+                if i == j {
+                    continue;
+                }
+                let share = participants[i].share_for(j as u16 + 1).unwrap();
+                participants[j]
+                    .receive_share(share, &all_comms[i], params)
+                    .unwrap();
+            }
+        }
+        let mut shares = vec![];
+        let mut pk = None;
+        for p in participants {
+            let (share, p_pk) = p.finish().unwrap();
+            if let Some(pk) = &pk {
+                // All participants get the same public key
+                assert_eq!(pk, &p_pk);
+            } else {
+                pk = Some(p_pk);
+            }
+            shares.push(share);
+        }
+        (shares, pk.unwrap())
+    }
+
+    /// Run a signing session among the given signers and return the aggregated signature
+    fn sign<R: RngCore>(
+        rng: &mut R,
+        messages: &[Fr],
+        shares: &[SecretKeyShare<Fr>],
+        signers: &BTreeSet<ParticipantId>,
+        params: &SignatureParamsG1<Bls12_381>,
+    ) -> SignatureG1<Bls12_381> {
+        let mut triples = simulate_multiplication_triple_generation(rng, signers);
+        let mut states = vec![];
+        let mut round1_msgs = vec![];
+        for i in signers {
+            let (state, msg) = SigningRound1State::init(
+                rng,
+                &shares[*i as usize - 1],
+                signers.clone(),
+                triples.remove(i).unwrap(),
+            )
+            .unwrap();
+            test_serialization!(SigningRound1Message<Fr>, msg);
+            states.push(state);
+            round1_msgs.push(msg);
+        }
+        let partial_sigs = states
+            .into_iter()
+            .map(|s| {
+                s.create_partial_signature(messages, &round1_msgs, params)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let partial_sig = partial_sigs[0].clone();
+        test_serialization!(PartialSignature<Bls12_381>, partial_sig);
+        PartialSignature::aggregate(&partial_sigs).unwrap()
+    }
+
+    #[test]
+    fn multiplication_triple() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let signers = [1, 3, 4, 7].into_iter().collect::<BTreeSet<_>>();
+        let triples = simulate_multiplication_triple_generation::<_, Fr>(&mut rng, &signers);
+        assert_eq!(triples.len(), signers.len());
+        let (a, b, c) = triples
+            .values()
+            .fold((Fr::zero(), Fr::zero(), Fr::zero()), |(a, b, c), t| {
+                (a + t.a, b + t.b, c + t.c)
+            });
+        assert_eq!(a * b, c);
+        let triple = triples[&1].clone();
+        test_serialization!(MultiplicationTripleShare<Fr>, triple);
+    }
+
+    #[test]
+    fn threshold_signing() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let message_count = 6;
+        let params = SignatureParamsG1::<Bls12_381>::generate_using_rng(&mut rng, message_count);
+        let messages = (0..message_count)
+            .map(|_| Fr::rand(&mut rng))
+            .collect::<Vec<_>>();
+
+        for (threshold, total) in [(1, 1), (1, 3), (2, 3), (3, 5), (5, 5)] {
+            let (shares, pk) = do_dkg(&mut rng, threshold, total, &params);
+            assert!(pk.is_valid());
+            let share = shares[0].clone();
+            test_serialization!(SecretKeyShare<Fr>, share);
+
+            // Interpolating any `threshold` shares gives the secret key of the public key
+            let signers = (1..=threshold).collect::<BTreeSet<_>>();
+            let sk = signers.iter().fold(Fr::zero(), |acc, i| {
+                acc + lagrange_basis_at_0::<Fr>(&signers, *i) * shares[*i as usize - 1].share
+            });
+            assert_eq!(
+                PublicKeyG2::generate_using_secret_key(&SecretKey(sk), &params),
+                pk
+            );
+
+            // Any set of at least `threshold` signers can sign
+            let mut signer_sets = vec![
+                (1..=threshold).collect::<BTreeSet<_>>(),
+                (total - threshold + 1..=total).collect::<BTreeSet<_>>(),
+                (1..=total).collect::<BTreeSet<_>>(),
+            ];
+            if threshold < total {
+                signer_sets.push(
+                    (1..=total)
+                        .filter(|i| i % 2 == 1)
+                        .chain([2])
+                        .take(threshold as usize)
+                        .collect(),
+                );
+            }
+            for signers in signer_sets {
+                let sig = sign(&mut rng, &messages, &shares, &signers, &params);
+                sig.verify(&messages, &pk, &params).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_dkg() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let params = SignatureParamsG1::<Bls12_381>::generate_using_rng(&mut rng, 2);
+
+        assert!(DkgParticipant::new(&mut rng, 1, 0, 3, &params).is_err());
+        assert!(DkgParticipant::new(&mut rng, 1, 4, 3, &params).is_err());
+        assert!(DkgParticipant::new(&mut rng, 0, 2, 3, &params).is_err());
+        assert!(DkgParticipant::new(&mut rng, 4, 2, 3, &params).is_err());
+
+        let (p1, comms_1) = DkgParticipant::new(&mut rng, 1, 2, 3, &params).unwrap();
+        let (mut p2, comms_2) = DkgParticipant::new(&mut rng, 2, 2, 3, &params).unwrap();
+        assert!(p1.share_for(0).is_err());
+        assert!(p1.share_for(4).is_err());
+
+        // Share not meant for participant 2 is rejected
+        let share = p1.share_for(3).unwrap();
+        assert!(p2.receive_share(share, &comms_1, &params).is_err());
+
+        // Share not matching commitments is rejected
+        let share = p1.share_for(2).unwrap();
+        assert!(p2.receive_share(share, &comms_2, &params).is_err());
+        let mut wrong_comms = comms_1.clone();
+        wrong_comms.commitments.pop();
+        assert!(p2.receive_share(share, &wrong_comms, &params).is_err());
+
+        p2.receive_share(share, &comms_1, &params).unwrap();
+        // Can't receive from the same participant twice
+        assert!(p2.receive_share(share, &comms_1, &params).is_err());
+
+        // Share from participant 3 missing
+        assert!(p2.finish().is_err());
+    }
+
+    #[test]
+    fn invalid_signing() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let message_count = 3;
+        let params = SignatureParamsG1::<Bls12_381>::generate_using_rng(&mut rng, message_count);
+        let messages = (0..message_count)
+            .map(|_| Fr::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let (shares, pk) = do_dkg(&mut rng, 3, 5, &params);
+
+        // Less than threshold signers can't sign
+        let signers = [1, 2].into_iter().collect::<BTreeSet<_>>();
+        let mut triples = simulate_multiplication_triple_generation(&mut rng, &signers);
+        assert!(SigningRound1State::init(
+            &mut rng,
+            &shares[0],
+            signers.clone(),
+            triples.remove(&1).unwrap()
+        )
+        .is_err());
+
+        let signers = [1, 2, 3].into_iter().collect::<BTreeSet<_>>();
+        let mut triples = simulate_multiplication_triple_generation(&mut rng, &signers);
+        // Signer not part of the signers
+        assert!(SigningRound1State::init(
+            &mut rng,
+            &shares[3],
+            signers.clone(),
+            triples[&1].clone()
+        )
+        .is_err());
+        // Triple share of another signer
+        assert!(SigningRound1State::init(
+            &mut rng,
+            &shares[0],
+            signers.clone(),
+            triples[&2].clone()
+        )
+        .is_err());
+
+        let mut states = vec![];
+        let mut round1_msgs = vec![];
+        for i in &signers {
+            let (state, msg) = SigningRound1State::init(
+                &mut rng,
+                &shares[*i as usize - 1],
+                signers.clone(),
+                triples.remove(i).unwrap(),
+            )
+            .unwrap();
+            states.push(state);
+            round1_msgs.push(msg);
+        }
+
+        // Missing or duplicate 1st round messages
+        assert!(states[0]
+            .clone()
+            .create_partial_signature(&messages, &round1_msgs[..2], &params)
+            .is_err());
+        let mut dup_msgs = round1_msgs.clone();
+        dup_msgs.push(round1_msgs[1].clone());
+        assert!(states[0]
+            .clone()
+            .create_partial_signature(&messages, &dup_msgs, &params)
+            .is_err());
+        // Incorrect number of messages
+        assert!(states[0]
+            .clone()
+            .create_partial_signature(&messages[..2], &round1_msgs, &params)
+            .is_err());
+
+        let mut partial_sigs = states
+            .into_iter()
+            .map(|s| {
+                s.create_partial_signature(&messages, &round1_msgs, &params)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        PartialSignature::aggregate(&partial_sigs)
+            .unwrap()
+            .verify(&messages, &pk, &params)
+            .unwrap();
+
+        assert!(PartialSignature::<Bls12_381>::aggregate(&[]).is_err());
+
+        // Missing partial signature gives an invalid signature
+        PartialSignature::aggregate(&partial_sigs[..2])
+            .unwrap()
+            .verify(&messages, &pk, &params)
+            .unwrap_err();
+
+        // Tampered partial signature gives an invalid signature
+        partial_sigs[1].u += Fr::one();
+        PartialSignature::aggregate(&partial_sigs)
+            .unwrap()
+            .verify(&messages, &pk, &params)
+            .unwrap_err();
+
+        // Inconsistent `e`
+        partial_sigs[1].e += Fr::one();
+        assert!(PartialSignature::aggregate(&partial_sigs).is_err());
+    }
+}
@@ -0,0 +1,26 @@
+use ark_serialize::SerializationError;
+use ark_std::fmt::Debug;
+
+#[derive(Debug)]
+pub enum BulletproofsError {
+    /// Number of bits must be a power of 2 and at most 64
+    UnsupportedBitSize(usize),
+    /// Value does not fit in the given number of bits
+    ValueOutOfRange(usize),
+    /// Params support the given number of bases but the given number is needed
+    InsufficientBasesInParams(usize, usize),
+    /// Vectors expected to be of same size have different sizes
+    VectorLenMismatch(usize, usize),
+    /// Vector size must be a power of 2
+    NotPowerOf2(usize),
+    NoValues,
+    InvalidInnerProductProof,
+    InvalidRangeProof,
+    Serialization(SerializationError),
+}
+
+impl From<SerializationError> for BulletproofsError {
+    fn from(e: SerializationError) -> Self {
+        Self::Serialization(e)
+    }
+}
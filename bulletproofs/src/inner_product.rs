@@ -0,0 +1,270 @@
+//! Inner product argument as described in section 3 of the paper. Proves knowledge of vectors `a` and `b` such that
+//! `P = <a, g> + <b, h> + u * <a, b>` where `g` and `h` are vectors of bases and `u` is a base. The proof has
+//! `2 * log(n)` group elements and 2 field elements for vectors of size `n`.
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{batch_inversion, Field, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    cfg_into_iter,
+    io::{Read, Write},
+    vec::Vec,
+};
+use digest::Digest;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::error::BulletproofsError;
+use dock_crypto_utils::{
+    ec::batch_normalize_projective_into_affine, ff::inner_product,
+    hashing_utils::field_elem_from_try_and_incr, msm::variable_base_msm, serde_utils::*,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Squares of the challenges, squares of their inverses and the scalars for the bases
+pub type VerificationScalars<F> = (Vec<F>, Vec<F>, Vec<F>);
+
+#[serde_as]
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct InnerProductProof<G: AffineCurve> {
+    #[serde_as(as = "Vec<AffineGroupBytes>")]
+    pub L: Vec<G>,
+    #[serde_as(as = "Vec<AffineGroupBytes>")]
+    pub R: Vec<G>,
+    #[serde_as(as = "FieldBytes")]
+    pub a: G::ScalarField,
+    #[serde_as(as = "FieldBytes")]
+    pub b: G::ScalarField,
+}
+
+impl<G: AffineCurve> InnerProductProof<G> {
+    /// Create the proof for `P = <a, g> + <b, h> + u * <a, b>`. All vectors must be of the same size which must be a
+    /// power of 2. `transcript` contains the bytes hashed so far (which must include `P` and the bases), and the
+    /// prover's messages are appended to it.
+    pub fn new<D: Digest>(
+        transcript: &mut Vec<u8>,
+        mut g: Vec<G>,
+        mut h: Vec<G>,
+        u: &G,
+        mut a: Vec<G::ScalarField>,
+        mut b: Vec<G::ScalarField>,
+    ) -> Result<Self, BulletproofsError> {
+        let mut n = a.len();
+        check_sizes(n, &[b.len(), g.len(), h.len()])?;
+        let lg_n = n.trailing_zeros() as usize;
+        let mut L = Vec::with_capacity(lg_n);
+        let mut R = Vec::with_capacity(lg_n);
+        while n > 1 {
+            n /= 2;
+            let (a_lo, a_hi) = a.split_at(n);
+            let (b_lo, b_hi) = b.split_at(n);
+            let (g_lo, g_hi) = g.split_at(n);
+            let (h_lo, h_hi) = h.split_at(n);
+            let c_l = inner_product(a_lo, b_hi);
+            let c_r = inner_product(a_hi, b_lo);
+            let l = variable_base_msm(
+                &[g_hi, h_lo, &[*u]].concat(),
+                &[a_lo, b_hi, &[c_l]].concat(),
+            )
+            .into_affine();
+            let r = variable_base_msm(
+                &[g_lo, h_hi, &[*u]].concat(),
+                &[a_hi, b_lo, &[c_r]].concat(),
+            )
+            .into_affine();
+            l.serialize_unchecked(&mut *transcript)?;
+            r.serialize_unchecked(&mut *transcript)?;
+            let x = challenge::<G::ScalarField, D>(transcript)?;
+            let x_inv = x.inverse().unwrap();
+
+            let a_new = cfg_into_iter!(0..n)
+                .map(|i| a_lo[i] * x + a_hi[i] * x_inv)
+                .collect::<Vec<_>>();
+            let b_new = cfg_into_iter!(0..n)
+                .map(|i| b_lo[i] * x_inv + b_hi[i] * x)
+                .collect::<Vec<_>>();
+            let x_repr = x.into_repr();
+            let x_inv_repr = x_inv.into_repr();
+            let g_new = cfg_into_iter!(0..n)
+                .map(|i| g_lo[i].mul(x_inv_repr) + g_hi[i].mul(x_repr))
+                .collect::<Vec<_>>();
+            let h_new = cfg_into_iter!(0..n)
+                .map(|i| h_lo[i].mul(x_repr) + h_hi[i].mul(x_inv_repr))
+                .collect::<Vec<_>>();
+            a = a_new;
+            b = b_new;
+            g = batch_normalize_projective_into_affine(g_new);
+            h = batch_normalize_projective_into_affine(h_new);
+            L.push(l);
+            R.push(r);
+        }
+        Ok(Self {
+            L,
+            R,
+            a: a[0],
+            b: b[0],
+        })
+    }
+
+    /// Verify the proof for `P = <a, g> + <b, h> + u * <a, b>`. `transcript` must be the same as the one used by the
+    /// prover when creating the proof.
+    pub fn verify<D: Digest>(
+        &self,
+        transcript: &mut Vec<u8>,
+        g: &[G],
+        h: &[G],
+        u: &G,
+        P: &G,
+    ) -> Result<(), BulletproofsError> {
+        let n = g.len();
+        check_sizes(n, &[h.len()])?;
+        let (challenges_sq, challenges_inv_sq, s) =
+            self.verification_scalars::<D>(n, transcript)?;
+        // s_inv[i] = 1/s[i] = s[n - 1 - i]
+        let mut scalars = Vec::with_capacity(2 * n + 1 + 2 * self.L.len());
+        scalars.extend(s.iter().map(|s_i| self.a * s_i));
+        scalars.extend(s.iter().rev().map(|s_i| self.b * s_i));
+        scalars.push(self.a * self.b);
+        scalars.extend(challenges_sq.into_iter().map(|x| -x));
+        scalars.extend(challenges_inv_sq.into_iter().map(|x| -x));
+        let bases = [g, h, &[*u], &self.L, &self.R].concat();
+        if variable_base_msm(&bases, &scalars).into_affine() == *P {
+            Ok(())
+        } else {
+            Err(BulletproofsError::InvalidInnerProductProof)
+        }
+    }
+
+    /// Returns the squares of the challenges, the squares of the inverses of the challenges and the scalars
+    /// `s_i` such that the final bases are `g = <s, g>` and `h = <1/s, h>`. Used for verifying the proof with a
+    /// single multi-scalar multiplication.
+    pub fn verification_scalars<D: Digest>(
+        &self,
+        n: usize,
+        transcript: &mut Vec<u8>,
+    ) -> Result<VerificationScalars<G::ScalarField>, BulletproofsError> {
+        let lg_n = self.L.len();
+        if lg_n >= usize::BITS as usize || n != (1 << lg_n) {
+            return Err(BulletproofsError::InvalidInnerProductProof);
+        }
+        if self.R.len() != lg_n {
+            return Err(BulletproofsError::VectorLenMismatch(lg_n, self.R.len()));
+        }
+        let mut challenges = Vec::with_capacity(lg_n);
+        for (l, r) in self.L.iter().zip(self.R.iter()) {
+            l.serialize_unchecked(&mut *transcript)?;
+            r.serialize_unchecked(&mut *transcript)?;
+            challenges.push(challenge::<G::ScalarField, D>(transcript)?);
+        }
+        let mut challenges_inv = challenges.clone();
+        batch_inversion(&mut challenges_inv);
+
+        // s_0 = 1/(x_1 * x_2 * ... x_{lg_n}) and s_i is obtained from s_j, where j is i without its highest set bit
+        // k, by multiplying with the square of the challenge of the round which split on bit k
+        let mut s = Vec::with_capacity(n);
+        s.push(challenges_inv.iter().product::<G::ScalarField>());
+        let challenges_sq = challenges.iter().map(|x| x.square()).collect::<Vec<_>>();
+        for i in 1..n {
+            let k = (usize::BITS - 1 - i.leading_zeros()) as usize;
+            s.push(s[i - (1 << k)] * challenges_sq[lg_n - 1 - k]);
+        }
+        let challenges_inv_sq = challenges_inv.iter().map(|x| x.square()).collect();
+        Ok((challenges_sq, challenges_inv_sq, s))
+    }
+}
+
+/// Create a challenge by hashing the transcript and append the challenge to the transcript
+pub(crate) fn challenge<F: PrimeField, D: Digest>(
+    transcript: &mut Vec<u8>,
+) -> Result<F, BulletproofsError> {
+    let c = field_elem_from_try_and_incr::<F, D>(transcript);
+    c.serialize_unchecked(&mut *transcript)?;
+    Ok(c)
+}
+
+fn check_sizes(n: usize, others: &[usize]) -> Result<(), BulletproofsError> {
+    if !n.is_power_of_two() {
+        return Err(BulletproofsError::NotPowerOf2(n));
+    }
+    for o in others {
+        if *o != n {
+            return Err(BulletproofsError::VectorLenMismatch(n, *o));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::setup::SetupParams;
+    use ark_bls12_381::{Bls12_381, Fr};
+    use ark_ec::PairingEngine;
+    use ark_std::{
+        rand::{rngs::StdRng, SeedableRng},
+        UniformRand,
+    };
+    use blake2::Blake2b;
+
+    type G1 = <Bls12_381 as PairingEngine>::G1Affine;
+
+    #[test]
+    fn inner_product_argument() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let params = SetupParams::<G1>::new::<Blake2b>(b"test", 64);
+        for n in [1, 2, 4, 8, 32, 64] {
+            let (g, h) = params.bases(n).unwrap();
+            let a = (0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let b = (0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let P = variable_base_msm(
+                &[g, h, &[params.u]].concat(),
+                &[a.as_slice(), b.as_slice(), &[inner_product(&a, &b)]].concat(),
+            )
+            .into_affine();
+
+            let mut transcript = vec![];
+            P.serialize_unchecked(&mut transcript).unwrap();
+            let proof = InnerProductProof::new::<Blake2b>(
+                &mut transcript.clone(),
+                g.to_vec(),
+                h.to_vec(),
+                &params.u,
+                a.clone(),
+                b.clone(),
+            )
+            .unwrap();
+            assert_eq!(proof.L.len(), n.trailing_zeros() as usize);
+            proof
+                .verify::<Blake2b>(&mut transcript.clone(), g, h, &params.u, &P)
+                .unwrap();
+
+            let mut bytes = vec![];
+            CanonicalSerialize::serialize(&proof, &mut bytes).unwrap();
+            let deser: InnerProductProof<G1> =
+                CanonicalDeserialize::deserialize(&bytes[..]).unwrap();
+            assert_eq!(deser, proof);
+            let json = serde_json::to_string(&proof).unwrap();
+            assert_eq!(
+                serde_json::from_str::<InnerProductProof<G1>>(&json).unwrap(),
+                proof
+            );
+
+            // Fails for a different `P`
+            let mut b_wrong = b.clone();
+            b_wrong[0] += Fr::from(1u64);
+            let P_wrong = variable_base_msm(
+                &[g, h, &[params.u]].concat(),
+                &[a.as_slice(), b_wrong.as_slice(), &[inner_product(&a, &b)]].concat(),
+            )
+            .into_affine();
+            assert!(proof
+                .verify::<Blake2b>(&mut transcript.clone(), g, h, &params.u, &P_wrong)
+                .is_err());
+        }
+    }
+}
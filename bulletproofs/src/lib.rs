@@ -0,0 +1,24 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(non_snake_case)]
+
+//! Bulletproofs as described in the paper [Bulletproofs: Short Proofs for Confidential Transactions and More](https://eprint.iacr.org/2017/1066)
+//!
+//! Implements the inner product argument of section 3 and the aggregated range proof of section 4.3 which proves
+//! that each of several values committed in Pedersen commitments lies in `[0, 2^n)`. The proof does not need a
+//! trusted setup and its size is logarithmic in the total number of bits proven. The verifier checks the
+//! inner product argument with a single multi-scalar multiplication as described in section 6.2.
+//!
+//! The Fiat-Shamir challenges are created by hashing the public values and the prover's messages with a hash
+//! function given as a type parameter.
+
+pub mod error;
+pub mod inner_product;
+pub mod range_proof;
+pub mod setup;
+
+pub mod prelude {
+    pub use crate::error::BulletproofsError;
+    pub use crate::inner_product::InnerProductProof;
+    pub use crate::range_proof::RangeProof;
+    pub use crate::setup::SetupParams;
+}
@@ -0,0 +1,436 @@
+//! Aggregated range proof as described in sections 4.2 and 4.3 of the paper. Proves that each of the `m` values
+//! committed in Pedersen commitments `V_j = g * v_j + h * gamma_j` lies in `[0, 2^n)`. If `m` is not a power of 2,
+//! the values are padded with 0s, committed with blinding 0, so the padded commitments are the identity and are
+//! known to the verifier. The proof has `2 * log(n * m) + 4` group elements and 5 field elements.
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{batch_inversion, Field, One, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    cfg_into_iter, cfg_iter,
+    io::{Read, Write},
+    rand::RngCore,
+    vec::Vec,
+    UniformRand,
+};
+use digest::Digest;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::error::BulletproofsError;
+use crate::inner_product::{challenge, InnerProductProof};
+use crate::setup::SetupParams;
+use dock_crypto_utils::{
+    ec::batch_normalize_projective_into_affine, ff::inner_product, ff::powers,
+    msm::variable_base_msm, serde_utils::*,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+const TRANSCRIPT_LABEL: &[u8] = b"Bulletproofs range proof";
+
+#[serde_as]
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct RangeProof<G: AffineCurve> {
+    /// Commitment to the bits of the values
+    #[serde_as(as = "AffineGroupBytes")]
+    pub A: G,
+    /// Commitment to the blinding vectors
+    #[serde_as(as = "AffineGroupBytes")]
+    pub S: G,
+    /// Commitment to the coefficient of `x` in `t(x)`
+    #[serde_as(as = "AffineGroupBytes")]
+    pub T1: G,
+    /// Commitment to the coefficient of `x^2` in `t(x)`
+    #[serde_as(as = "AffineGroupBytes")]
+    pub T2: G,
+    #[serde_as(as = "FieldBytes")]
+    pub tau_x: G::ScalarField,
+    #[serde_as(as = "FieldBytes")]
+    pub mu: G::ScalarField,
+    #[serde_as(as = "FieldBytes")]
+    pub t_hat: G::ScalarField,
+    pub ipp: InnerProductProof<G>,
+}
+
+impl<G: AffineCurve> RangeProof<G> {
+    /// Create a proof that each of `values` lies in `[0, 2^n)` where the `j`-th value is committed as
+    /// `params.g * values[j] + params.h * blindings[j]`. `n` must be a power of 2 and at most 64 and `params` must
+    /// support at least `n * m` bits where `m` is the number of values rounded up to a power of 2.
+    pub fn new<R: RngCore, D: Digest>(
+        rng: &mut R,
+        params: &SetupParams<G>,
+        values: &[u64],
+        blindings: &[G::ScalarField],
+        n: usize,
+    ) -> Result<Self, BulletproofsError> {
+        Self::check_bit_size(n)?;
+        if values.is_empty() {
+            return Err(BulletproofsError::NoValues);
+        }
+        if values.len() != blindings.len() {
+            return Err(BulletproofsError::VectorLenMismatch(
+                values.len(),
+                blindings.len(),
+            ));
+        }
+        for (j, v) in values.iter().enumerate() {
+            if n < 64 && (*v >> n) != 0 {
+                return Err(BulletproofsError::ValueOutOfRange(j));
+            }
+        }
+        let m = values.len().next_power_of_two();
+        let nm = n * m;
+        let (gs, hs) = params.bases(nm)?;
+
+        let commitments = cfg_iter!(values)
+            .zip(cfg_iter!(blindings))
+            .map(|(v, b)| params.commit(&G::ScalarField::from(*v), b))
+            .collect::<Vec<_>>();
+        let mut transcript = Self::init_transcript(params, &commitments, n, m)?;
+
+        // Bits of the padded values
+        let a_L = (0..nm)
+            .map(|i| {
+                let v = values.get(i / n).copied().unwrap_or(0);
+                G::ScalarField::from((v >> (i % n)) & 1)
+            })
+            .collect::<Vec<_>>();
+        let a_R = cfg_iter!(a_L)
+            .map(|a| *a - G::ScalarField::one())
+            .collect::<Vec<_>>();
+
+        let alpha = G::ScalarField::rand(rng);
+        let rho = G::ScalarField::rand(rng);
+        let s_L = (0..nm)
+            .map(|_| G::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+        let s_R = (0..nm)
+            .map(|_| G::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+        let A = variable_base_msm(
+            &[gs, hs, &[params.h]].concat(),
+            &[a_L.as_slice(), a_R.as_slice(), &[alpha]].concat(),
+        )
+        .into_affine();
+        let S = variable_base_msm(
+            &[gs, hs, &[params.h]].concat(),
+            &[s_L.as_slice(), s_R.as_slice(), &[rho]].concat(),
+        )
+        .into_affine();
+        A.serialize_unchecked(&mut transcript)?;
+        S.serialize_unchecked(&mut transcript)?;
+        let y = challenge::<G::ScalarField, D>(&mut transcript)?;
+        let z = challenge::<G::ScalarField, D>(&mut transcript)?;
+
+        let y_powers = powers(&y, nm);
+        let d = Self::d(&z, n, m);
+        let l_0 = cfg_iter!(a_L).map(|a| *a - z).collect::<Vec<_>>();
+        let r_0 = cfg_into_iter!(0..nm)
+            .map(|i| y_powers[i] * (a_R[i] + z) + d[i])
+            .collect::<Vec<_>>();
+        let r_1 = cfg_into_iter!(0..nm)
+            .map(|i| y_powers[i] * s_R[i])
+            .collect::<Vec<_>>();
+        let t_1 = inner_product(&l_0, &r_1) + inner_product(&s_L, &r_0);
+        let t_2 = inner_product(&s_L, &r_1);
+
+        let tau_1 = G::ScalarField::rand(rng);
+        let tau_2 = G::ScalarField::rand(rng);
+        let T1 = params.commit(&t_1, &tau_1);
+        let T2 = params.commit(&t_2, &tau_2);
+        T1.serialize_unchecked(&mut transcript)?;
+        T2.serialize_unchecked(&mut transcript)?;
+        let x = challenge::<G::ScalarField, D>(&mut transcript)?;
+
+        let l = cfg_into_iter!(0..nm)
+            .map(|i| l_0[i] + s_L[i] * x)
+            .collect::<Vec<_>>();
+        let r = cfg_into_iter!(0..nm)
+            .map(|i| r_0[i] + r_1[i] * x)
+            .collect::<Vec<_>>();
+        let t_hat = inner_product(&l, &r);
+        // z^2 * gamma_0 + z^3 * gamma_1 + ...
+        let blindings_sum = blindings
+            .iter()
+            .zip(powers(&z, values.len() + 2).into_iter().skip(2))
+            .fold(G::ScalarField::zero(), |acc, (b, z_j)| acc + *b * z_j);
+        let tau_x = tau_2 * x.square() + tau_1 * x + blindings_sum;
+        let mu = alpha + rho * x;
+        tau_x.serialize_unchecked(&mut transcript)?;
+        mu.serialize_unchecked(&mut transcript)?;
+        t_hat.serialize_unchecked(&mut transcript)?;
+        let w = challenge::<G::ScalarField, D>(&mut transcript)?;
+
+        // h'_i = h_i * y^{-i}
+        let mut y_inv_powers = y_powers;
+        batch_inversion(&mut y_inv_powers);
+        let hs_prime = batch_normalize_projective_into_affine(
+            cfg_into_iter!(0..nm)
+                .map(|i| hs[i].mul(y_inv_powers[i].into_repr()))
+                .collect::<Vec<_>>(),
+        );
+        let u = params.u.mul(w.into_repr()).into_affine();
+        let ipp = InnerProductProof::new::<D>(&mut transcript, gs.to_vec(), hs_prime, &u, l, r)?;
+        Ok(Self {
+            A,
+            S,
+            T1,
+            T2,
+            tau_x,
+            mu,
+            t_hat,
+            ipp,
+        })
+    }
+
+    /// Verify the proof that each value committed in `commitments` lies in `[0, 2^n)`
+    pub fn verify<D: Digest>(
+        &self,
+        params: &SetupParams<G>,
+        commitments: &[G],
+        n: usize,
+    ) -> Result<(), BulletproofsError> {
+        Self::check_bit_size(n)?;
+        if commitments.is_empty() {
+            return Err(BulletproofsError::NoValues);
+        }
+        let m = commitments.len().next_power_of_two();
+        let nm = n * m;
+        let (gs, hs) = params.bases(nm)?;
+
+        let mut transcript = Self::init_transcript(params, commitments, n, m)?;
+        self.A.serialize_unchecked(&mut transcript)?;
+        self.S.serialize_unchecked(&mut transcript)?;
+        let y = challenge::<G::ScalarField, D>(&mut transcript)?;
+        let z = challenge::<G::ScalarField, D>(&mut transcript)?;
+        self.T1.serialize_unchecked(&mut transcript)?;
+        self.T2.serialize_unchecked(&mut transcript)?;
+        let x = challenge::<G::ScalarField, D>(&mut transcript)?;
+        self.tau_x.serialize_unchecked(&mut transcript)?;
+        self.mu.serialize_unchecked(&mut transcript)?;
+        self.t_hat.serialize_unchecked(&mut transcript)?;
+        let w = challenge::<G::ScalarField, D>(&mut transcript)?;
+
+        let y_powers = powers(&y, nm);
+        let z_powers = powers(&z, m + 3);
+        let sum_y_powers = y_powers
+            .iter()
+            .fold(G::ScalarField::zero(), |acc, y_i| acc + y_i);
+        // 2^0 + 2^1 + ... + 2^{n-1}
+        let sum_2_powers = G::ScalarField::from(2u64).pow([n as u64]) - G::ScalarField::one();
+        // delta(y, z) = (z - z^2) * <1, y^nm> - (z^3 + z^4 + ... + z^{m+2}) * <1, 2^n>
+        let delta = (z - z_powers[2]) * sum_y_powers
+            - z_powers[3..m + 3]
+                .iter()
+                .fold(G::ScalarField::zero(), |acc, z_j| acc + z_j)
+                * sum_2_powers;
+
+        // Check that g * t_hat + h * tau_x = V * z^2 + g * delta + T1 * x + T2 * x^2. The padded commitments
+        // are identity so are skipped
+        let mut bases = Vec::with_capacity(commitments.len() + 4);
+        let mut scalars = Vec::with_capacity(commitments.len() + 4);
+        bases.push(params.g);
+        scalars.push(self.t_hat - delta);
+        bases.push(params.h);
+        scalars.push(self.tau_x);
+        bases.extend_from_slice(commitments);
+        scalars.extend(z_powers[2..commitments.len() + 2].iter().map(|z_j| -*z_j));
+        bases.push(self.T1);
+        scalars.push(-x);
+        bases.push(self.T2);
+        scalars.push(-x.square());
+        if !variable_base_msm(&bases, &scalars).is_zero() {
+            return Err(BulletproofsError::InvalidRangeProof);
+        }
+
+        // Check the inner product argument in a single multi-scalar multiplication as
+        // A + S * x - <z, g> + <z + y^{-i} * d_i, h> - h * mu + u * w * t_hat + <x_j^2, L> + <x_j^{-2}, R>
+        //   - <a * s, g> - <b * s^{-1} * y^{-i}, h> - u * w * a * b = 0
+        let (challenges_sq, challenges_inv_sq, s) =
+            self.ipp.verification_scalars::<D>(nm, &mut transcript)?;
+        let mut y_inv_powers = y_powers;
+        batch_inversion(&mut y_inv_powers);
+        let d = Self::d(&z, n, m);
+        let a = self.ipp.a;
+        let b = self.ipp.b;
+
+        let g_scalars = cfg_iter!(s).map(|s_i| -z - a * s_i).collect::<Vec<_>>();
+        let h_scalars = cfg_into_iter!(0..nm)
+            .map(|i| z + y_inv_powers[i] * (d[i] - b * s[nm - 1 - i]))
+            .collect::<Vec<_>>();
+        let bases = [
+            gs,
+            hs,
+            &[self.A, self.S, params.h, params.u],
+            &self.ipp.L,
+            &self.ipp.R,
+        ]
+        .concat();
+        let scalars = [
+            g_scalars.as_slice(),
+            h_scalars.as_slice(),
+            &[G::ScalarField::one(), x, -self.mu, w * (self.t_hat - a * b)],
+            &challenges_sq,
+            &challenges_inv_sq,
+        ]
+        .concat();
+        if variable_base_msm(&bases, &scalars).is_zero() {
+            Ok(())
+        } else {
+            Err(BulletproofsError::InvalidRangeProof)
+        }
+    }
+
+    fn check_bit_size(n: usize) -> Result<(), BulletproofsError> {
+        if n == 0 || n > 64 || !n.is_power_of_two() {
+            return Err(BulletproofsError::UnsupportedBitSize(n));
+        }
+        Ok(())
+    }
+
+    fn init_transcript(
+        params: &SetupParams<G>,
+        commitments: &[G],
+        n: usize,
+        m: usize,
+    ) -> Result<Vec<u8>, BulletproofsError> {
+        let mut transcript = TRANSCRIPT_LABEL.to_vec();
+        (n as u64).serialize_unchecked(&mut transcript)?;
+        (m as u64).serialize_unchecked(&mut transcript)?;
+        params.g.serialize_unchecked(&mut transcript)?;
+        params.h.serialize_unchecked(&mut transcript)?;
+        params.u.serialize_unchecked(&mut transcript)?;
+        commitments.serialize_unchecked(&mut transcript)?;
+        Ok(transcript)
+    }
+
+    /// Vector `d` of size `n * m` where `d_{j*n + i} = z^{2+j} * 2^i`
+    fn d(z: &G::ScalarField, n: usize, m: usize) -> Vec<G::ScalarField> {
+        let two_powers = powers(&G::ScalarField::from(2u64), n);
+        let z_powers = powers(z, m + 2);
+        (0..n * m)
+            .map(|i| z_powers[2 + i / n] * two_powers[i % n])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_381::{Bls12_381, Fr};
+    use ark_ec::PairingEngine;
+    use ark_std::{
+        rand::{rngs::StdRng, SeedableRng},
+        UniformRand,
+    };
+    use blake2::Blake2b;
+    use std::time::Instant;
+
+    type G1 = <Bls12_381 as PairingEngine>::G1Affine;
+
+    #[test]
+    fn range_proof() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let params = SetupParams::<G1>::new::<Blake2b>(b"test", 512);
+
+        for (n, values) in [
+            (8, vec![0u64]),
+            (8, vec![255]),
+            (16, vec![7, 65535]),
+            (32, vec![1 << 31, 5, 100]),
+            (64, vec![u64::MAX]),
+            (64, vec![1, u64::MAX, 1 << 40, 0, 12345, 999, 1 << 63]),
+        ] {
+            let blindings = (0..values.len())
+                .map(|_| Fr::rand(&mut rng))
+                .collect::<Vec<_>>();
+            let commitments = values
+                .iter()
+                .zip(blindings.iter())
+                .map(|(v, b)| params.commit(&Fr::from(*v), b))
+                .collect::<Vec<_>>();
+
+            let start = Instant::now();
+            let proof =
+                RangeProof::new::<_, Blake2b>(&mut rng, &params, &values, &blindings, n).unwrap();
+            let proving_time = start.elapsed();
+
+            let start = Instant::now();
+            proof.verify::<Blake2b>(&params, &commitments, n).unwrap();
+            let verifying_time = start.elapsed();
+
+            let mut bytes = vec![];
+            CanonicalSerialize::serialize(&proof, &mut bytes).unwrap();
+            println!(
+                "For {} values of {} bits, proof size is {} bytes, proving time is {:?} and verifying time is {:?}",
+                values.len(),
+                n,
+                bytes.len(),
+                proving_time,
+                verifying_time
+            );
+            let deser: RangeProof<G1> = CanonicalDeserialize::deserialize(&bytes[..]).unwrap();
+            assert_eq!(deser, proof);
+            let json = serde_json::to_string(&proof).unwrap();
+            assert_eq!(
+                serde_json::from_str::<RangeProof<G1>>(&json).unwrap(),
+                proof
+            );
+
+            // Fails for a different commitment or bit size
+            let mut wrong_commitments = commitments.clone();
+            wrong_commitments[0] = params.commit(&Fr::from(values[0] + 1), &blindings[0]);
+            assert!(proof
+                .verify::<Blake2b>(&params, &wrong_commitments, n)
+                .is_err());
+            if n > 8 {
+                assert!(proof
+                    .verify::<Blake2b>(&params, &commitments, n / 2)
+                    .is_err());
+            }
+            if values.len() > 1 {
+                assert!(proof
+                    .verify::<Blake2b>(&params, &commitments[1..], n)
+                    .is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_range_proof() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let params = SetupParams::<G1>::new::<Blake2b>(b"test", 64);
+        let blindings = vec![Fr::rand(&mut rng), Fr::rand(&mut rng)];
+
+        assert!(matches!(
+            RangeProof::new::<_, Blake2b>(&mut rng, &params, &[256, 1], &blindings, 8),
+            Err(BulletproofsError::ValueOutOfRange(0))
+        ));
+        assert!(matches!(
+            RangeProof::new::<_, Blake2b>(&mut rng, &params, &[1, 2], &blindings, 12),
+            Err(BulletproofsError::UnsupportedBitSize(12))
+        ));
+        assert!(matches!(
+            RangeProof::new::<_, Blake2b>(&mut rng, &params, &[1, 2], &blindings, 64),
+            Err(BulletproofsError::InsufficientBasesInParams(64, 128))
+        ));
+        assert!(matches!(
+            RangeProof::new::<_, Blake2b>(&mut rng, &params, &[], &[], 8),
+            Err(BulletproofsError::NoValues)
+        ));
+
+        // A proof created for values out of range by a cheating prover doesn't verify. Here the value doesn't fit in
+        // 8 bits but its lower 8 bits are used as the bits
+        let value = 300u64;
+        let proof =
+            RangeProof::new::<_, Blake2b>(&mut rng, &params, &[value & 255], &blindings[..1], 8)
+                .unwrap();
+        let commitment = params.commit(&Fr::from(value), &blindings[0]);
+        assert!(proof.verify::<Blake2b>(&params, &[commitment], 8).is_err());
+    }
+}
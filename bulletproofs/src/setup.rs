@@ -0,0 +1,95 @@
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::to_bytes;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    cfg_into_iter,
+    io::{Read, Write},
+    vec::Vec,
+};
+use digest::Digest;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::error::BulletproofsError;
+use dock_crypto_utils::{
+    hashing_utils::affine_group_elem_from_try_and_incr, msm::variable_base_msm, serde_utils::*,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Public parameters for the range proof and the inner product argument. The values are committed as
+/// `g * value + h * blinding`. All bases are created by hashing a public label so no one knows the discrete log
+/// of any base wrt any other.
+#[serde_as]
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct SetupParams<G: AffineCurve> {
+    #[serde_as(as = "AffineGroupBytes")]
+    pub g: G,
+    #[serde_as(as = "AffineGroupBytes")]
+    pub h: G,
+    /// Base for the inner product in the inner product argument
+    #[serde_as(as = "AffineGroupBytes")]
+    pub u: G,
+    /// Bases for the vectors of bits
+    #[serde_as(as = "Vec<AffineGroupBytes>")]
+    pub gs: Vec<G>,
+    #[serde_as(as = "Vec<AffineGroupBytes>")]
+    pub hs: Vec<G>,
+}
+
+impl<G: AffineCurve> SetupParams<G> {
+    /// Create params supporting range proofs over a total of `size` bits, like 4 values of 64 bits each when `size`
+    /// is 256. `size` must be a power of 2.
+    pub fn new<D: Digest>(label: &[u8], size: usize) -> Self {
+        assert!(size.is_power_of_two());
+        let g = affine_group_elem_from_try_and_incr::<G, D>(
+            &to_bytes![label, " : g".as_bytes()].unwrap(),
+        );
+        let h = affine_group_elem_from_try_and_incr::<G, D>(
+            &to_bytes![label, " : h".as_bytes()].unwrap(),
+        );
+        let u = affine_group_elem_from_try_and_incr::<G, D>(
+            &to_bytes![label, " : u".as_bytes()].unwrap(),
+        );
+        let gs = cfg_into_iter!(0..size)
+            .map(|i| {
+                affine_group_elem_from_try_and_incr::<G, D>(
+                    &to_bytes![label, " : gs_".as_bytes(), i as u64].unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let hs = cfg_into_iter!(0..size)
+            .map(|i| {
+                affine_group_elem_from_try_and_incr::<G, D>(
+                    &to_bytes![label, " : hs_".as_bytes(), i as u64].unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        Self { g, h, u, gs, hs }
+    }
+
+    /// Maximum number of bits over all values that a range proof can be created for
+    pub fn supported_size(&self) -> usize {
+        self.gs.len()
+    }
+
+    /// Commit to the `value` as `g * value + h * blinding`
+    pub fn commit(&self, value: &G::ScalarField, blinding: &G::ScalarField) -> G {
+        variable_base_msm(&[self.g, self.h], &[*value, *blinding]).into_affine()
+    }
+
+    /// Returns the first `size` bases of `gs` and `hs`
+    pub fn bases(&self, size: usize) -> Result<(&[G], &[G]), BulletproofsError> {
+        if size > self.supported_size() {
+            return Err(BulletproofsError::InsufficientBasesInParams(
+                self.supported_size(),
+                size,
+            ));
+        }
+        Ok((&self.gs[0..size], &self.hs[0..size]))
+    }
+}
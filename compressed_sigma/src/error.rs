@@ -10,6 +10,8 @@ pub enum CompSigmaError {
     Serialization(SerializationError),
     WrongRecursionLevel,
     FaultyParameterSize,
+    NotASetMember,
+    SetSizeNotSupportedByParams(usize, usize),
 }
 
 impl From<SerializationError> for CompSigmaError {
@@ -11,5 +11,6 @@ pub mod compressed_linear_form;
 pub mod error;
 #[macro_use]
 pub mod partial_knowledge;
+pub mod set_membership;
 pub mod sponge;
 pub mod transforms;
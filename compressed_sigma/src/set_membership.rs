@@ -0,0 +1,498 @@
+//! Proof that the value committed in a Pedersen commitment is a member of a public set. Given a commitment
+//! `C = g * m + h * r` and a set `{v_1, v_2, ..., v_n}`, the prover proves that `m = v_j` for some `j` without
+//! revealing `j` by proving knowledge of the discrete log of 1 of the `n` elements `P_i = C - g * v_i` in base `h`.
+//! This is the proof of partial knowledge (1-out-of-n) of section 4 of the paper "Compressing Proofs of k-Out-Of-n"
+//! where the homomorphisms of all set members are amortized into one and the response is compressed as in
+//! section 3 so the proof size is logarithmic in the set size.
+//!
+//! The knowledge of opening of `C` must be proved separately, like with a Schnorr protocol, and the challenge
+//! used here should be bound to `C`.
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, One, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    cfg_into_iter,
+    collections::BTreeMap,
+    io::{Read, Write},
+    rand::RngCore,
+    vec,
+    vec::Vec,
+    UniformRand,
+};
+use digest::Digest;
+
+use crate::compressed_homomorphism::{RandomCommitment, Response};
+use crate::error::CompSigmaError;
+use crate::partial_knowledge::single;
+use crate::utils::get_n_powers;
+use dock_crypto_utils::{
+    ec::batch_normalize_projective_into_affine,
+    hashing_utils::{affine_group_elem_from_try_and_incr, field_elem_from_try_and_incr},
+    msm::variable_base_msm,
+    secret::Secret,
+    transcript::Transcript,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Public parameters for proving set membership of the value committed with bases `g` and `h`
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct SetMembershipParams<G: AffineCurve> {
+    /// Base for the committed value
+    pub g: G,
+    /// Base for the randomness of the commitment
+    pub h: G,
+    /// Bases for committing to the new witnesses of the proof of partial knowledge
+    pub gs: Vec<G>,
+}
+
+/// Protocol for proving membership in a set. Created by the prover after committing to the member.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetMembershipProtocol<G: AffineCurve> {
+    /// Commitment to the new witnesses, referred as `P` in the paper
+    pub comm_witnesses: G,
+    pub rand_comm: RandomCommitment<G>,
+    /// Bases of the amortized homomorphism
+    amortized_hom: Vec<G>,
+    /// The new witnesses, referred as `Y` in the paper, padded and followed by the randomness of `comm_witnesses`
+    new_witnesses: Secret<Vec<G::ScalarField>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct SetMembershipProof<G: AffineCurve> {
+    pub comm_witnesses: G,
+    pub A_hat: G,
+    pub t: G,
+    pub response: Response<G>,
+}
+
+impl<G: AffineCurve> SetMembershipParams<G> {
+    /// Generate params for sets of size up to `max_set_size` by hashing `label`
+    pub fn new<D: Digest>(label: &[u8], max_set_size: usize) -> Self {
+        assert!(max_set_size > 1);
+        let g = affine_group_elem_from_try_and_incr::<G, D>(
+            &to_bytes![label, " : g".as_bytes()].unwrap(),
+        );
+        let h = affine_group_elem_from_try_and_incr::<G, D>(
+            &to_bytes![label, " : h".as_bytes()].unwrap(),
+        );
+        let gs = cfg_into_iter!(0..Self::bases_count(max_set_size))
+            .map(|i| {
+                affine_group_elem_from_try_and_incr::<G, D>(
+                    &to_bytes![label, " : gs_".as_bytes(), i as u64].unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        Self { g, h, gs }
+    }
+
+    /// Maximum size of the set supported by these params
+    pub fn max_set_size(&self) -> usize {
+        self.gs.len() / 2
+    }
+
+    /// Commit to the `member` as `g * member + h * randomness`
+    pub fn commit(&self, member: &G::ScalarField, randomness: &G::ScalarField) -> G {
+        variable_base_msm(&[self.g, self.h], &[*member, *randomness]).into_affine()
+    }
+
+    /// Bases used to commit to the new witnesses for a set of size `set_size`
+    pub fn bases_for_set(&self, set_size: usize) -> Result<&[G], CompSigmaError> {
+        if set_size < 2 {
+            return Err(CompSigmaError::FaultyParameterSize);
+        }
+        let count = Self::bases_count(set_size);
+        if count > self.gs.len() {
+            return Err(CompSigmaError::SetSizeNotSupportedByParams(
+                set_size,
+                self.max_set_size(),
+            ));
+        }
+        Ok(&self.gs[..count])
+    }
+
+    /// There are `2 * set_size - 1` new witnesses and 1 randomness for their commitment and the total is
+    /// padded to a power of 2 for compression
+    fn bases_count(set_size: usize) -> usize {
+        (2 * set_size).next_power_of_two()
+    }
+}
+
+impl<G: AffineCurve> SetMembershipProtocol<G> {
+    /// `commitment` is the commitment to `member` with `randomness` created using `SetMembershipParams::commit`.
+    pub fn init<R: RngCore, D: Digest>(
+        rng: &mut R,
+        params: &SetMembershipParams<G>,
+        set: &[G::ScalarField],
+        commitment: &G,
+        member: &G::ScalarField,
+        randomness: &G::ScalarField,
+    ) -> Result<Self, CompSigmaError> {
+        let gs = params.bases_for_set(set.len())?;
+        let index = set
+            .iter()
+            .position(|v| v == member)
+            .ok_or(CompSigmaError::NotASetMember)?;
+
+        // Only the discrete log of `P_index = C - g * member = h * randomness` is known
+        let mut known_x = BTreeMap::new();
+        known_x.insert(index, randomness);
+        let mut new_witnesses = single::create_new_witnesses(set.len(), known_x);
+        new_witnesses.resize(gs.len() - 1, G::ScalarField::zero());
+        new_witnesses.push(G::ScalarField::rand(rng));
+        let comm_witnesses = variable_base_msm(gs, &new_witnesses).into_affine();
+
+        let (amortized_hom, _) = amortized_homomorphism_and_evaluation::<G, D>(
+            params,
+            set,
+            commitment,
+            &comm_witnesses,
+            gs.len(),
+        );
+        let rand_comm = RandomCommitment::new(rng, gs, &amortized_hom, None)?;
+        Ok(Self {
+            comm_witnesses,
+            rand_comm,
+            amortized_hom: amortized_hom.0,
+            new_witnesses: Secret::new(new_witnesses),
+        })
+    }
+
+    pub fn challenge_contribution<W: Write>(&self, writer: W) -> Result<(), CompSigmaError> {
+        compute_challenge_contribution(
+            &self.comm_witnesses,
+            &self.rand_comm.A_hat,
+            &self.rand_comm.t,
+            writer,
+        )
+    }
+
+    pub fn append_to_transcript(&self, transcript: &mut Transcript) -> Result<(), CompSigmaError> {
+        compute_transcript_contribution(
+            &self.comm_witnesses,
+            &self.rand_comm.A_hat,
+            &self.rand_comm.t,
+            transcript,
+        )
+    }
+
+    pub fn gen_proof<D: Digest>(
+        self,
+        params: &SetMembershipParams<G>,
+        challenge: &G::ScalarField,
+    ) -> Result<SetMembershipProof<G>, CompSigmaError> {
+        let gs = &params.gs[..self.amortized_hom.len()];
+        let response = self.rand_comm.response::<D, _>(
+            gs,
+            &single::Hom(self.amortized_hom.clone()),
+            self.new_witnesses.expose(),
+            challenge,
+        )?;
+        Ok(SetMembershipProof {
+            comm_witnesses: self.comm_witnesses,
+            A_hat: self.rand_comm.A_hat,
+            t: self.rand_comm.t,
+            response,
+        })
+    }
+}
+
+impl<G: AffineCurve> SetMembershipProof<G> {
+    pub fn challenge_contribution<W: Write>(&self, writer: W) -> Result<(), CompSigmaError> {
+        compute_challenge_contribution(&self.comm_witnesses, &self.A_hat, &self.t, writer)
+    }
+
+    pub fn append_to_transcript(&self, transcript: &mut Transcript) -> Result<(), CompSigmaError> {
+        compute_transcript_contribution(&self.comm_witnesses, &self.A_hat, &self.t, transcript)
+    }
+
+    /// Verify that the value committed in `commitment` is a member of `set`
+    pub fn verify<D: Digest>(
+        &self,
+        params: &SetMembershipParams<G>,
+        set: &[G::ScalarField],
+        commitment: &G,
+        challenge: &G::ScalarField,
+    ) -> Result<(), CompSigmaError> {
+        let gs = params.bases_for_set(set.len())?;
+        let (amortized_hom, evaluation) = amortized_homomorphism_and_evaluation::<G, D>(
+            params,
+            set,
+            commitment,
+            &self.comm_witnesses,
+            gs.len(),
+        );
+        self.response.is_valid::<D, _>(
+            gs,
+            &self.comm_witnesses,
+            &evaluation,
+            &amortized_hom,
+            &self.A_hat,
+            &self.t,
+            challenge,
+        )
+    }
+}
+
+fn compute_challenge_contribution<G: AffineCurve, W: Write>(
+    comm_witnesses: &G,
+    A_hat: &G,
+    t: &G,
+    mut writer: W,
+) -> Result<(), CompSigmaError> {
+    comm_witnesses.serialize_unchecked(&mut writer)?;
+    A_hat.serialize_unchecked(&mut writer)?;
+    t.serialize_unchecked(writer)?;
+    Ok(())
+}
+
+fn compute_transcript_contribution<G: AffineCurve>(
+    comm_witnesses: &G,
+    A_hat: &G,
+    t: &G,
+    transcript: &mut Transcript,
+) -> Result<(), CompSigmaError> {
+    transcript.append_point(b"comm_witnesses", comm_witnesses)?;
+    transcript.append_point(b"A_hat", A_hat)?;
+    transcript.append_point(b"t", t)?;
+    Ok(())
+}
+
+/// Amortize the homomorphisms `f_i` of all `n` set members into `f = \sum_i rho^i * f_i` and their evaluations `P_i`
+/// into `\sum_i rho^i * P_i`. `f_i` is the homomorphism created by `single::Hom::new(h, P_i, 1, n, i)` where
+/// `P_i = C - g * v_i`. Rather than creating each `f_i` and scaling it which needs `O(n^2)` scalar multiplications,
+/// the bases of `f` are computed directly as for `j` in `1..n`, the `j`-th base is
+/// `\sum_i rho^i * -(i+1)^j * P_i = C * -\sum_i rho^i * (i+1)^j + g * \sum_i rho^i * (i+1)^j * v_i`
+/// and the remaining `n` bases are `h * rho^i`. `f` is then padded with 0s to have size `size`.
+fn amortized_homomorphism_and_evaluation<G: AffineCurve, D: Digest>(
+    params: &SetMembershipParams<G>,
+    set: &[G::ScalarField],
+    commitment: &G,
+    comm_witnesses: &G,
+    size: usize,
+) -> (single::Hom<G>, G) {
+    let n = set.len();
+    let rho_powers = generate_randomness::<G, D>(params, set, commitment, comm_witnesses);
+    let indices = (1..=n)
+        .map(|i| G::ScalarField::from(i as u64))
+        .collect::<Vec<_>>();
+
+    // `powers[i]` is `rho^i * (i+1)^j` in iteration `j`
+    let mut powers = rho_powers.clone();
+    let mut bases = Vec::with_capacity(2 * n - 1);
+    for _ in 1..n {
+        let mut c_coeff = G::ScalarField::zero();
+        let mut g_coeff = G::ScalarField::zero();
+        for i in 0..n {
+            powers[i] *= indices[i];
+            c_coeff -= powers[i];
+            g_coeff += powers[i] * set[i];
+        }
+        bases.push(commitment.mul(c_coeff.into_repr()) + params.g.mul(g_coeff.into_repr()));
+    }
+    for r in rho_powers.iter() {
+        bases.push(params.h.mul(r.into_repr()));
+    }
+    let mut bases = batch_normalize_projective_into_affine(bases);
+    bases.resize(size, G::zero());
+
+    // \sum_i rho^i * P_i = C * \sum_i rho^i - g * \sum_i rho^i * v_i
+    let rho_sum = rho_powers.iter().fold(G::ScalarField::zero(), |a, r| a + r);
+    let rho_v_sum = rho_powers
+        .iter()
+        .zip(set.iter())
+        .fold(G::ScalarField::zero(), |a, (r, v)| a + *r * v);
+    let evaluation = commitment.mul(rho_sum.into_repr()) - params.g.mul(rho_v_sum.into_repr());
+    (single::Hom(bases), evaluation.into_affine())
+}
+
+/// Create a random `rho` by hashing the public values and return its `n` powers as `[1, rho, rho^2, ..., rho^{n-1}]`
+fn generate_randomness<G: AffineCurve, D: Digest>(
+    params: &SetMembershipParams<G>,
+    set: &[G::ScalarField],
+    commitment: &G,
+    comm_witnesses: &G,
+) -> Vec<G::ScalarField> {
+    let mut bytes = vec![];
+    params.g.serialize(&mut bytes).unwrap();
+    params.h.serialize(&mut bytes).unwrap();
+    commitment.serialize(&mut bytes).unwrap();
+    comm_witnesses.serialize(&mut bytes).unwrap();
+    for v in set {
+        v.serialize(&mut bytes).unwrap();
+    }
+    let rho = field_elem_from_try_and_incr::<G::ScalarField, D>(&bytes);
+    let mut rho_powers = get_n_powers(rho, set.len() - 1);
+    rho_powers.insert(0, G::ScalarField::one());
+    rho_powers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transforms::Homomorphism;
+    use ark_bls12_381::{Bls12_381, G1Affine};
+    use ark_ec::PairingEngine;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+    use blake2::Blake2b;
+    use std::time::Instant;
+
+    type Fr = <Bls12_381 as PairingEngine>::Fr;
+
+    #[test]
+    fn amortized_homomorphism() {
+        // The directly computed amortized homomorphism is same as the one created by amortizing homomorphisms
+        // of each member
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let params = SetMembershipParams::<G1Affine>::new::<Blake2b>(b"test", 10);
+        for n in [2, 3, 7, 10] {
+            let set = (0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let commitment = params.commit(&set[1], &Fr::rand(&mut rng));
+            let comm_witnesses =
+                <Bls12_381 as PairingEngine>::G1Projective::rand(&mut rng).into_affine();
+            let size = params.bases_for_set(n).unwrap().len();
+            let (f, evaluation) = amortized_homomorphism_and_evaluation::<_, Blake2b>(
+                &params,
+                &set,
+                &commitment,
+                &comm_witnesses,
+                size,
+            );
+
+            let Ps = set
+                .iter()
+                .map(|v| (commitment.into_projective() - params.g.mul(v.into_repr())).into_affine())
+                .collect::<Vec<_>>();
+            let fs = single::create_homomorphisms(params.h, Ps.clone(), n, 1);
+            let rho_powers =
+                generate_randomness::<_, Blake2b>(&params, &set, &commitment, &comm_witnesses);
+            let expected_f = crate::amortized_homomorphisms::AmortizeHomomorphisms::<_, _>::new_homomorphism_from_given_randomness(&fs, &rho_powers).pad(size);
+            assert_eq!(f.0, expected_f.0);
+            assert_eq!(
+                evaluation,
+                variable_base_msm(&Ps, &rho_powers).into_affine()
+            );
+        }
+    }
+
+    #[test]
+    fn set_membership() {
+        fn check(rng: &mut StdRng, params: &SetMembershipParams<G1Affine>, n: usize) {
+            let set = (0..n).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            let member = set[n / 2];
+            let randomness = Fr::rand(rng);
+            let commitment = params.commit(&member, &randomness);
+
+            let start = Instant::now();
+            let protocol = SetMembershipProtocol::init::<_, Blake2b>(
+                rng,
+                params,
+                &set,
+                &commitment,
+                &member,
+                &randomness,
+            )
+            .unwrap();
+            let mut chal_bytes_prover = vec![];
+            protocol
+                .challenge_contribution(&mut chal_bytes_prover)
+                .unwrap();
+            let challenge = field_elem_from_try_and_incr::<Fr, Blake2b>(&chal_bytes_prover);
+            let proof = protocol.gen_proof::<Blake2b>(params, &challenge).unwrap();
+            let proving_time = start.elapsed();
+
+            let start = Instant::now();
+            let mut chal_bytes_verifier = vec![];
+            proof
+                .challenge_contribution(&mut chal_bytes_verifier)
+                .unwrap();
+            assert_eq!(chal_bytes_prover, chal_bytes_verifier);
+            proof
+                .verify::<Blake2b>(params, &set, &commitment, &challenge)
+                .unwrap();
+            let verifying_time = start.elapsed();
+
+            let mut bytes = vec![];
+            proof.serialize(&mut bytes).unwrap();
+            let deserialized = SetMembershipProof::<G1Affine>::deserialize(&bytes[..]).unwrap();
+            assert_eq!(deserialized, proof);
+            println!(
+                "For set of size {}, proof size is {} bytes, proving time is {:?} and verifying time is {:?}",
+                n,
+                bytes.len(),
+                proving_time,
+                verifying_time
+            );
+
+            // Proof doesn't verify for a different set, commitment or challenge
+            let mut other_set = set.clone();
+            other_set[n / 2] = Fr::rand(rng);
+            assert!(proof
+                .verify::<Blake2b>(params, &other_set, &commitment, &challenge)
+                .is_err());
+            let other_commitment = params.commit(&Fr::rand(rng), &randomness);
+            assert!(proof
+                .verify::<Blake2b>(params, &set, &other_commitment, &challenge)
+                .is_err());
+            assert!(proof
+                .verify::<Blake2b>(params, &set, &commitment, &Fr::rand(rng))
+                .is_err());
+        }
+
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let params = SetMembershipParams::<G1Affine>::new::<Blake2b>(b"test", 300);
+        assert_eq!(params.max_set_size(), 512);
+        for n in [2, 3, 4, 5, 8, 15, 30, 64, 100, 300] {
+            check(&mut rng, &params, n);
+        }
+    }
+
+    #[test]
+    fn non_member() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let params = SetMembershipParams::<G1Affine>::new::<Blake2b>(b"test", 10);
+        let set = (0..10).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let member = Fr::rand(&mut rng);
+        let randomness = Fr::rand(&mut rng);
+        let commitment = params.commit(&member, &randomness);
+        assert!(matches!(
+            SetMembershipProtocol::init::<_, Blake2b>(
+                &mut rng,
+                &params,
+                &set,
+                &commitment,
+                &member,
+                &randomness,
+            ),
+            Err(CompSigmaError::NotASetMember)
+        ));
+
+        // Prover uses a member of the set but the commitment is to a non-member
+        let protocol = SetMembershipProtocol::init::<_, Blake2b>(
+            &mut rng,
+            &params,
+            &set,
+            &commitment,
+            &set[3],
+            &randomness,
+        )
+        .unwrap();
+        let challenge = Fr::rand(&mut rng);
+        let proof = protocol.gen_proof::<Blake2b>(&params, &challenge).unwrap();
+        assert!(proof
+            .verify::<Blake2b>(&params, &set, &commitment, &challenge)
+            .is_err());
+
+        // Set sizes not supported
+        assert!(matches!(
+            params.bases_for_set(1),
+            Err(CompSigmaError::FaultyParameterSize)
+        ));
+        assert!(matches!(
+            params.bases_for_set(17),
+            Err(CompSigmaError::SetSizeNotSupportedByParams(17, 16))
+        ));
+    }
+}
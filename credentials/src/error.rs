@@ -0,0 +1,21 @@
+use ark_std::fmt::Debug;
+use proof_system::error::ProofSystemError;
+
+#[derive(Debug)]
+pub enum CredentialError {
+    /// The message index is not less than the number of messages in the credential
+    MessageIndexOutOfBounds(usize, usize),
+    /// Revocation id and expiry are declared at the same message index
+    SameRevocationIdAndExpiryIndex(usize),
+    /// The number of messages is different from the number declared in the schema
+    IncorrectNumberOfMessages(usize, usize),
+    RevocationIdCannotBeRevealed(usize),
+    ExpiryCannotBeRevealed(usize),
+    ProofSystemError(ProofSystemError),
+}
+
+impl From<ProofSystemError> for CredentialError {
+    fn from(e: ProofSystemError) -> Self {
+        Self::ProofSystemError(e)
+    }
+}
@@ -0,0 +1,31 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Higher level helpers over the [`proof_system`] crate for commonly used credential flows.
+//!
+//! Currently, supports revocable BBS+ credentials with an expiry. The issuer declares in a [`RevocableCredentialSchema`]
+//! which of the signed messages is the revocation id and which is the expiry. The revocation id is added to a
+//! (positive or universal) accumulator by the revocation authority and removed from it on revocation. The expiry
+//! is an integer encoded message like a timestamp or a date encoded using [`bbs_plus::encoding`].
+//!
+//! Using the schema, the holder and verifier create the [`ProofSpec`] without manually wiring the statements and
+//! meta-statements. The [`ProofSpec`] contains the following statements in order:
+//! 1. proof of knowledge of the BBS+ signature, revealing the messages chosen by the holder,
+//! 2. membership of the revocation id in the accumulator,
+//! 3. bound check (using LegoGroth16) proving that the expiry is not before the verifier's current time,
+//!
+//! and the witness equalities between the revocation id and expiry messages of the signature and the witnesses
+//! of the other 2 statements. The holder creates the matching [`Witnesses`] using the schema as well.
+//!
+//! Neither the revocation id nor the expiry can be revealed as revealing them makes the holder's proofs linkable.
+//!
+//! [`RevocableCredentialSchema`]: crate::revocable::RevocableCredentialSchema
+//! [`ProofSpec`]: proof_system::proof_spec::ProofSpec
+//! [`Witnesses`]: proof_system::witness::Witnesses
+
+pub mod error;
+pub mod revocable;
+
+pub mod prelude {
+    pub use crate::error::CredentialError;
+    pub use crate::revocable::*;
+}
@@ -0,0 +1,321 @@
+//! Revocable BBS+ credentials where one message is the revocation id, a member of an accumulator, and
+//! one message is the expiry which is proven to be not before the verifier's current time.
+
+use ark_ec::{AffineCurve, PairingEngine};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    collections::{BTreeMap, BTreeSet},
+    io::{Read, Write},
+    vec,
+    vec::Vec,
+};
+use serde::{Deserialize, Serialize};
+
+use bbs_plus::prelude::{PublicKeyG2, SignatureG1, SignatureParamsG1};
+use proof_system::meta_statement::{EqualWitnesses, MetaStatements};
+use proof_system::proof_spec::ProofSpec;
+use proof_system::statement::{
+    accumulator::AccumulatorMembership,
+    bbs_plus::PoKBBSSignatureG1,
+    bound_check_legogroth16::{
+        BoundCheckLegoGroth16Prover, BoundCheckLegoGroth16Verifier, ProvingKey, VerifyingKey,
+    },
+    Statement, Statements,
+};
+use proof_system::witness::{
+    Membership as MembershipWit, PoKBBSSignatureG1 as PoKBBSSignatureG1Wit, Witness, Witnesses,
+};
+use vb_accumulator::prelude::{
+    MembershipProvingKey, MembershipWitness, PublicKey as AccumPublicKey,
+    SetupParams as AccumParams,
+};
+
+use crate::error::CredentialError;
+
+/// Index of the statement for proof of knowledge of the BBS+ signature in the `ProofSpec`
+pub const SIGNATURE_STATEMENT_INDEX: usize = 0;
+/// Index of the statement for accumulator membership of the revocation id in the `ProofSpec`
+pub const REVOCATION_STATEMENT_INDEX: usize = 1;
+/// Index of the statement for bound check of the expiry in the `ProofSpec`
+pub const EXPIRY_STATEMENT_INDEX: usize = 2;
+
+/// Upper bound used in the bound check of the expiry. The expiry is only checked to be not before the
+/// verifier's current time.
+pub const MAX_EXPIRY: u64 = u64::MAX;
+
+/// Declared by the issuer and describes which of the signed messages is the revocation id and which is the expiry.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    CanonicalSerialize,
+    CanonicalDeserialize,
+    Serialize,
+    Deserialize,
+)]
+pub struct RevocableCredentialSchema {
+    /// Number of messages signed in the credential
+    pub message_count: usize,
+    /// Index of the message that is the revocation id
+    pub revocation_id_index: usize,
+    /// Index of the message that is the expiry
+    pub expiry_index: usize,
+}
+
+/// Public parameters of the issuer, i.e. the BBS+ signature params and public key, and of the revocation
+/// authority, i.e. the accumulator params, public key and membership proving key.
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct IssuerPublicParams<E: PairingEngine> {
+    pub signature_params: SignatureParamsG1<E>,
+    pub signature_public_key: PublicKeyG2<E>,
+    pub accumulator_params: AccumParams<E>,
+    pub accumulator_public_key: AccumPublicKey<E::G2Affine>,
+    pub membership_proving_key: MembershipProvingKey<E::G1Affine>,
+}
+
+impl RevocableCredentialSchema {
+    pub fn new(
+        message_count: usize,
+        revocation_id_index: usize,
+        expiry_index: usize,
+    ) -> Result<Self, CredentialError> {
+        let schema = Self {
+            message_count,
+            revocation_id_index,
+            expiry_index,
+        };
+        schema.validate()?;
+        Ok(schema)
+    }
+
+    /// Check that the revocation id and expiry indices are different and refer to signed messages
+    pub fn validate(&self) -> Result<(), CredentialError> {
+        for idx in [self.revocation_id_index, self.expiry_index] {
+            if idx >= self.message_count {
+                return Err(CredentialError::MessageIndexOutOfBounds(
+                    idx,
+                    self.message_count,
+                ));
+            }
+        }
+        if self.revocation_id_index == self.expiry_index {
+            return Err(CredentialError::SameRevocationIdAndExpiryIndex(
+                self.expiry_index,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check that the messages can be signed for a credential of this schema. Used by the issuer before signing.
+    pub fn validate_messages<F>(&self, messages: &[F]) -> Result<(), CredentialError> {
+        self.validate()?;
+        if messages.len() != self.message_count {
+            return Err(CredentialError::IncorrectNumberOfMessages(
+                messages.len(),
+                self.message_count,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Meta statements for the equality of the revocation id and expiry with the witnesses of the accumulator
+    /// membership and bound check statements respectively
+    pub fn meta_statements(&self) -> MetaStatements {
+        let mut meta_statements = MetaStatements::new();
+        meta_statements.add_witness_equality(EqualWitnesses(
+            vec![
+                (SIGNATURE_STATEMENT_INDEX, self.revocation_id_index),
+                (REVOCATION_STATEMENT_INDEX, 0),
+            ]
+            .into_iter()
+            .collect(),
+        ));
+        meta_statements.add_witness_equality(EqualWitnesses(
+            vec![
+                (SIGNATURE_STATEMENT_INDEX, self.expiry_index),
+                (EXPIRY_STATEMENT_INDEX, 0),
+            ]
+            .into_iter()
+            .collect(),
+        ));
+        meta_statements
+    }
+
+    /// `ProofSpec` used by the holder to create a proof. `revealed_messages` are the messages disclosed to
+    /// the verifier and `now` is the verifier's current time, encoded the same way as the expiry.
+    pub fn prover_proof_spec<E: PairingEngine, G: AffineCurve>(
+        &self,
+        params: &IssuerPublicParams<E>,
+        accumulator_value: E::G1Affine,
+        revealed_messages: BTreeMap<usize, E::Fr>,
+        now: u64,
+        snark_proving_key: ProvingKey<E>,
+        context: Option<Vec<u8>>,
+    ) -> Result<ProofSpec<E, G>, CredentialError> {
+        let bound_check = BoundCheckLegoGroth16Prover::new_statement_from_params(
+            now,
+            MAX_EXPIRY,
+            snark_proving_key,
+        )?;
+        self.proof_spec(
+            params,
+            accumulator_value,
+            revealed_messages,
+            bound_check,
+            context,
+        )
+    }
+
+    /// `ProofSpec` used by the verifier to verify a proof. The arguments must be same as the ones used by
+    /// the holder except the verifying key which should correspond to the holder's proving key.
+    pub fn verifier_proof_spec<E: PairingEngine, G: AffineCurve>(
+        &self,
+        params: &IssuerPublicParams<E>,
+        accumulator_value: E::G1Affine,
+        revealed_messages: BTreeMap<usize, E::Fr>,
+        now: u64,
+        snark_verifying_key: VerifyingKey<E>,
+        context: Option<Vec<u8>>,
+    ) -> Result<ProofSpec<E, G>, CredentialError> {
+        let bound_check = BoundCheckLegoGroth16Verifier::new_statement_from_params(
+            now,
+            MAX_EXPIRY,
+            snark_verifying_key,
+        )?;
+        self.proof_spec(
+            params,
+            accumulator_value,
+            revealed_messages,
+            bound_check,
+            context,
+        )
+    }
+
+    /// Witnesses used by the holder to create a proof. `revealed_indices` must be the indices of the messages
+    /// revealed in the `ProofSpec`.
+    pub fn witnesses<E: PairingEngine>(
+        &self,
+        signature: SignatureG1<E>,
+        messages: &[E::Fr],
+        revealed_indices: &BTreeSet<usize>,
+        membership_witness: MembershipWitness<E::G1Affine>,
+    ) -> Result<Witnesses<E>, CredentialError> {
+        self.validate_messages(messages)?;
+        self.validate_revealed_indices(revealed_indices.iter())?;
+        let unrevealed_messages = messages
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !revealed_indices.contains(i))
+            .map(|(i, m)| (i, *m))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut witnesses = Witnesses::new();
+        witnesses.add(PoKBBSSignatureG1Wit::new_as_witness(
+            signature,
+            unrevealed_messages,
+        ));
+        witnesses.add(MembershipWit::new_as_witness(
+            messages[self.revocation_id_index],
+            membership_witness,
+        ));
+        witnesses.add(Witness::BoundCheckLegoGroth16(messages[self.expiry_index]));
+        Ok(witnesses)
+    }
+
+    fn proof_spec<E: PairingEngine, G: AffineCurve>(
+        &self,
+        params: &IssuerPublicParams<E>,
+        accumulator_value: E::G1Affine,
+        revealed_messages: BTreeMap<usize, E::Fr>,
+        bound_check: Statement<E, G>,
+        context: Option<Vec<u8>>,
+    ) -> Result<ProofSpec<E, G>, CredentialError> {
+        self.validate()?;
+        self.validate_revealed_indices(revealed_messages.keys())?;
+
+        let mut statements = Statements::new();
+        statements.add(PoKBBSSignatureG1::new_statement_from_params(
+            params.signature_params.clone(),
+            params.signature_public_key.clone(),
+            revealed_messages,
+        ));
+        statements.add(AccumulatorMembership::new_statement_from_params(
+            params.accumulator_params.clone(),
+            params.accumulator_public_key.clone(),
+            params.membership_proving_key.clone(),
+            accumulator_value,
+        ));
+        statements.add(bound_check);
+
+        let proof_spec = ProofSpec::new(statements, self.meta_statements(), vec![], context);
+        proof_spec.validate()?;
+        Ok(proof_spec)
+    }
+
+    fn validate_revealed_indices<'a>(
+        &self,
+        mut revealed_indices: impl Iterator<Item = &'a usize>,
+    ) -> Result<(), CredentialError> {
+        revealed_indices.try_for_each(|i| {
+            if *i >= self.message_count {
+                Err(CredentialError::MessageIndexOutOfBounds(
+                    *i,
+                    self.message_count,
+                ))
+            } else if *i == self.revocation_id_index {
+                Err(CredentialError::RevocationIdCannotBeRevealed(*i))
+            } else if *i == self.expiry_index {
+                Err(CredentialError::ExpiryCannotBeRevealed(*i))
+            } else {
+                Ok(())
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_validation() {
+        assert!(RevocableCredentialSchema::new(5, 1, 3).is_ok());
+        assert!(matches!(
+            RevocableCredentialSchema::new(5, 5, 3),
+            Err(CredentialError::MessageIndexOutOfBounds(5, 5))
+        ));
+        assert!(matches!(
+            RevocableCredentialSchema::new(5, 1, 7),
+            Err(CredentialError::MessageIndexOutOfBounds(7, 5))
+        ));
+        assert!(matches!(
+            RevocableCredentialSchema::new(5, 2, 2),
+            Err(CredentialError::SameRevocationIdAndExpiryIndex(2))
+        ));
+
+        let schema = RevocableCredentialSchema::new(5, 1, 3).unwrap();
+        assert!(schema.validate_messages(&[0u64; 5]).is_ok());
+        assert!(matches!(
+            schema.validate_messages(&[0u64; 4]),
+            Err(CredentialError::IncorrectNumberOfMessages(4, 5))
+        ));
+        assert!(schema.validate_revealed_indices([0, 2, 4].iter()).is_ok());
+        assert!(matches!(
+            schema.validate_revealed_indices([0, 1].iter()),
+            Err(CredentialError::RevocationIdCannotBeRevealed(1))
+        ));
+        assert!(matches!(
+            schema.validate_revealed_indices([3].iter()),
+            Err(CredentialError::ExpiryCannotBeRevealed(3))
+        ));
+        assert!(matches!(
+            schema.validate_revealed_indices([5].iter()),
+            Err(CredentialError::MessageIndexOutOfBounds(5, 5))
+        ));
+    }
+}
@@ -0,0 +1,153 @@
+use ark_bls12_381::{Bls12_381, G1Affine};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::collections::{BTreeMap, BTreeSet};
+use ark_std::{rand::prelude::StdRng, rand::SeedableRng, UniformRand};
+
+use bbs_plus::encoding::{date_to_bound, encode_date};
+use credentials::prelude::*;
+use proof_system::prelude::{generate_snark_srs_bound_check, ProofSpec, Witnesses};
+use test_utils::accumulators::setup_positive_accum;
+use test_utils::bbs_plus::*;
+use test_utils::{test_serialization, Fr, ProofG1};
+use vb_accumulator::prelude::{Accumulator, MembershipProvingKey};
+
+#[test]
+fn revocable_credential_with_expiry() {
+    // Issuer signs a credential with a revocation id and an expiry, revocation id is added to the accumulator.
+    // Holder proves that the credential is neither revoked nor expired using the schema
+    let mut rng = StdRng::seed_from_u64(0u64);
+
+    let schema = RevocableCredentialSchema::new(6, 2, 4).unwrap();
+    test_serialization!(RevocableCredentialSchema, schema);
+
+    let mut msgs: Vec<Fr> = (0..schema.message_count)
+        .map(|_| Fr::rand(&mut rng))
+        .collect();
+    let revocation_id = msgs[schema.revocation_id_index];
+    msgs[schema.expiry_index] = encode_date(2030, 1, 1).unwrap();
+    schema.validate_messages(&msgs).unwrap();
+    let (sig_params, sig_keypair, sig) = sig_setup_given_messages(&mut rng, &msgs);
+
+    let (accum_params, accum_keypair, mut accumulator, mut state) = setup_positive_accum(&mut rng);
+    let mem_prk = MembershipProvingKey::generate_using_rng(&mut rng);
+    accumulator = accumulator
+        .add(revocation_id, &accum_keypair.secret_key, &mut state)
+        .unwrap();
+    let mem_wit = accumulator
+        .get_membership_witness(&revocation_id, &accum_keypair.secret_key, &state)
+        .unwrap();
+
+    let params = IssuerPublicParams {
+        signature_params: sig_params,
+        signature_public_key: sig_keypair.public_key.clone(),
+        accumulator_params: accum_params,
+        accumulator_public_key: accum_keypair.public_key.clone(),
+        membership_proving_key: mem_prk,
+    };
+    test_serialization!(IssuerPublicParams<Bls12_381>, params);
+
+    // Verifier sets up LegoGroth16 public parameters for bound check circuit
+    let snark_pk = generate_snark_srs_bound_check::<Bls12_381, _>(&mut rng).unwrap();
+
+    let now = date_to_bound(2024, 6, 1).unwrap();
+    let mut revealed_msgs = BTreeMap::new();
+    revealed_msgs.insert(0, msgs[0]);
+    let revealed_indices = revealed_msgs.keys().cloned().collect::<BTreeSet<_>>();
+
+    let prover_proof_spec = schema
+        .prover_proof_spec::<Bls12_381, G1Affine>(
+            &params,
+            *accumulator.value(),
+            revealed_msgs.clone(),
+            now,
+            snark_pk.clone(),
+            None,
+        )
+        .unwrap();
+    test_serialization!(ProofSpec<Bls12_381, G1Affine>, prover_proof_spec);
+
+    let witnesses = schema
+        .witnesses(sig.clone(), &msgs, &revealed_indices, mem_wit.clone())
+        .unwrap();
+    test_serialization!(Witnesses<Bls12_381>, witnesses);
+
+    let nonce = Some(b"test-nonce".to_vec());
+    let proof = ProofG1::new(
+        &mut rng,
+        prover_proof_spec.clone(),
+        witnesses.clone(),
+        nonce.clone(),
+    )
+    .unwrap();
+    test_serialization!(ProofG1, proof);
+
+    let verifier_proof_spec = schema
+        .verifier_proof_spec::<Bls12_381, G1Affine>(
+            &params,
+            *accumulator.value(),
+            revealed_msgs.clone(),
+            now,
+            snark_pk.vk.clone(),
+            None,
+        )
+        .unwrap();
+    test_serialization!(ProofSpec<Bls12_381, G1Affine>, verifier_proof_spec);
+    proof
+        .clone()
+        .verify(verifier_proof_spec, nonce.clone())
+        .unwrap();
+
+    // Proof doesn't verify if the verifier's current time is after the expiry
+    let later = date_to_bound(2031, 1, 1).unwrap();
+    let verifier_proof_spec_later = schema
+        .verifier_proof_spec::<Bls12_381, G1Affine>(
+            &params,
+            *accumulator.value(),
+            revealed_msgs.clone(),
+            later,
+            snark_pk.vk.clone(),
+            None,
+        )
+        .unwrap();
+    assert!(proof
+        .clone()
+        .verify(verifier_proof_spec_later, nonce.clone())
+        .is_err());
+
+    // Proof doesn't verify once the credential is revoked
+    accumulator = accumulator
+        .remove(&revocation_id, &accum_keypair.secret_key, &mut state)
+        .unwrap();
+    let verifier_proof_spec_revoked = schema
+        .verifier_proof_spec::<Bls12_381, G1Affine>(
+            &params,
+            *accumulator.value(),
+            revealed_msgs.clone(),
+            now,
+            snark_pk.vk.clone(),
+            None,
+        )
+        .unwrap();
+    assert!(proof.verify(verifier_proof_spec_revoked, nonce).is_err());
+
+    // Revocation id and expiry cannot be revealed
+    let mut revealed_msgs_with_rev_id = revealed_msgs.clone();
+    revealed_msgs_with_rev_id.insert(schema.revocation_id_index, revocation_id);
+    assert!(matches!(
+        schema.prover_proof_spec::<Bls12_381, G1Affine>(
+            &params,
+            *accumulator.value(),
+            revealed_msgs_with_rev_id,
+            now,
+            snark_pk.clone(),
+            None,
+        ),
+        Err(CredentialError::RevocationIdCannotBeRevealed(2))
+    ));
+    let mut revealed_indices_with_expiry = revealed_indices.clone();
+    revealed_indices_with_expiry.insert(schema.expiry_index);
+    assert!(matches!(
+        schema.witnesses(sig, &msgs, &revealed_indices_with_expiry, mem_wit),
+        Err(CredentialError::ExpiryCannotBeRevealed(4))
+    ));
+}
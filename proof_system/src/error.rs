@@ -1,6 +1,8 @@
 use ark_serialize::SerializationError;
 use ark_std::{collections::BTreeSet, fmt::Debug, string::String, vec::Vec};
 use bbs_plus::error::BBSPlusError;
+use bulletproofs::error::BulletproofsError;
+use compressed_sigma::error::CompSigmaError;
 use legogroth16::circom::CircomError;
 use legogroth16::error::Error as LegoGroth16Error;
 use saver::error::SaverError;
@@ -13,12 +15,21 @@ pub enum ProofSystemError {
     WitnessIncompatibleWithStatement(usize, String, String),
     ProofIncompatibleWithStatement(usize, String, String),
     ProofIncompatibleWithBBSPlusProtocol,
+    ProofIncompatibleWithIetfBBSProtocol,
     ProofIncompatibleWithSchnorrProtocol,
     ProofIncompatibleWithAccumulatorMembershipProtocol,
     ProofIncompatibleWithAccumulatorNonMembershipProtocol,
     ProofIncompatibleWithSaverProtocol,
     ProofIncompatibleWithBoundCheckProtocol,
+    ProofIncompatibleWithSetMembershipProtocol,
+    ProofIncompatibleWithInequalityProtocol,
+    ProofIncompatibleWithBoundCheckBulletproofsProtocol,
+    ProofIncompatibleWithBoundCheckSmcProtocol,
+    ProofIncompatibleWithDiscreteLogEqualityAcrossGroupsProtocol,
+    ProofIncompatibleWithPseudonymProtocol,
+    ProofIncompatibleWithHolderBindingProtocol,
     BBSPlusProtocolMessageAbsent(usize, usize),
+    IetfBBSProtocolMessageAbsent(usize, usize),
     SubProtocolNotReadyToGenerateChallenge(usize),
     SubProtocolAlreadyInitialized(usize),
     SubProtocolNotReadyToGenerateProof(usize),
@@ -29,6 +40,34 @@ pub enum ProofSystemError {
     IncompatibleAccumulatorSetupParamAtIndex(usize),
     IncompatibleSaverSetupParamAtIndex(usize),
     IncompatibleBoundCheckSetupParamAtIndex(usize),
+    IncompatibleSetMembershipSetupParamAtIndex(usize),
+    IncompatibleBulletproofsSetupParamAtIndex(usize),
+    IncompatibleSmcSetupParamAtIndex(usize),
+    IncompatibleCrossGroupCommKeySetupParamAtIndex(usize),
+    /// Commitment key for the inequality statement at the given index must have 2 bases but has the given number of bases
+    IncorrectCommitmentKeySizeForInequality(usize, usize),
+    /// Witness of the inequality statement at the given index is equal to the public value
+    InequalityWitnessEqualToPublicValue(usize),
+    /// Bulletproofs bound check statement at the given index has the given number of bounds but a different number
+    /// of witnesses were given
+    BoundCheckBulletproofsIncorrectWitnessCount(usize, usize),
+    /// Witness at the given index of the Bulletproofs bound check statement at the given index is not within its bounds
+    BoundCheckBulletproofsWitnessNotInBounds(usize, usize),
+    /// Base of the set-membership check params must be at least 2
+    SmcInvalidBase(u64),
+    /// Witness of the set-membership check based bound check statement at the given index is not within the bounds
+    BoundCheckSmcWitnessNotInBounds(usize),
+    /// Responses for the digits do not combine to the response for the witness of the statement at the given index
+    BoundCheckSmcInvalidDigitResponses(usize),
+    /// Witness of the set-membership check based bound check statement at the given index is not in a witness
+    /// equality with a witness of another kind of statement
+    BoundCheckSmcWitnessNotInEquality(usize),
+    /// The group of the pseudonym of the statement at the given index has no hash to curve to create the base
+    /// from the scope
+    PseudonymGroupWithoutHashToCurve(usize),
+    /// Holder binding statement at the index must have exactly one of a public key in the group of the proof and
+    /// a public key in the other group
+    InvalidHolderBindingStatement(usize),
     /// This error indicates that some witnesses that were required to be equal are not equal
     WitnessResponseNotEqual(usize, usize),
     Serialization(SerializationError),
@@ -57,6 +96,8 @@ pub enum ProofSystemError {
     BoundCheckMaxNotGreaterThanMin,
     IncompatibleR1CSSetupParamAtIndex(usize),
     CircomError(CircomError),
+    CompSigmaError(CompSigmaError),
+    BulletproofsError(BulletproofsError),
     R1CSInsufficientPrivateInputs(usize, usize),
     InvalidWitnessEquality,
     /// Witness is being used a zero knowledge proof (bound check, accumulator, etc) while also being
@@ -105,3 +146,15 @@ impl From<CircomError> for ProofSystemError {
         Self::CircomError(e)
     }
 }
+
+impl From<CompSigmaError> for ProofSystemError {
+    fn from(e: CompSigmaError) -> Self {
+        Self::CompSigmaError(e)
+    }
+}
+
+impl From<BulletproofsError> for ProofSystemError {
+    fn from(e: BulletproofsError) -> Self {
+        Self::BulletproofsError(e)
+    }
+}
@@ -98,6 +98,8 @@
 //! Currently supports
 //! - proof of knowledge of a BBS+ signature and signed messages
 //! - proof of knowledge of multiple BBS+ signature and equality of certain messages
+//! - proof of knowledge of a BBS signature as per the [IETF draft](https://datatracker.ietf.org/doc/draft-irtf-cfrg-bbs-signatures/)
+//!   and signed messages. This can be combined with all the other statements just like BBS+
 //! - proof of knowledge of accumulator membership and non-membership
 //! - proof of knowledge of Pedersen commitment opening.
 //! - proof of knowledge of BBS+ signature(s) and that certain message(s) satisfy given bounds (range proof)
@@ -108,6 +110,11 @@
 //!   commitment to the witness (signed messages in our case). This commitment allows us to prove that the witness in
 //!   the proof protocol are the same as the signed messages using the Schnorr proof of knowledge protocol.
 //!
+//! The challenge of a [`Proof`] is derived from a transcript to which the nonce, the context of the [`ProofSpec`] and
+//! the challenge contribution of each [`Statement`] are appended with labels. A caller can pass its own transcript,
+//! like one with an application specific domain separator, or one in compatibility mode to create and verify proofs
+//! whose challenge is computed from the concatenation of these values as done previously.
+//!
 //! See following tests for examples:
 //!
 //! - test `pok_of_3_bbs_plus_sig_and_message_equality` proves knowledge of 3 BBS+ signatures and also that certain
@@ -131,6 +138,31 @@
 //!   `pok_of_knowledge_in_pedersen_commitment_and_equality_with_commitment_key_reuse` shows use of [`SetupParams`]
 //!   when the same commitment key is reused in several commitments and test `pok_of_bbs_plus_sig_and_verifiable_encryption_of_many_messages`
 //!   shows use of [`SetupParams`] when several messages are used in verifiable encryption for the same decryptor.
+//! - test `pok_of_ietf_bbs_and_bbs_plus_sig_and_message_equality` proves knowledge of a BBS signature as per the
+//!   IETF draft and a BBS+ signature and that certain messages are equal among them.
+//! - test `pok_of_bbs_plus_sig_and_pseudonym` shows how a prover can reveal a pseudonym, created from a signed message
+//!   and a verifier specific scope, such that the verifier can recognize the prover across proofs but different verifiers
+//!   cannot link the prover's proofs. The verifier creates the base of the pseudonym from the scope, see module
+//!   [`statement::pseudonym`].
+//! - tests `pok_of_bbs_plus_sig_bound_to_holder_public_key` and `pok_of_bbs_plus_sig_bound_to_holder_public_key_in_other_group`
+//!   show binding a credential to a holder's (device) public key by proving that a message signed with BBS+ is the secret
+//!   key of that public key without revealing either. The public key can be in the group of the proof or in a group of a
+//!   different order like secp256k1, see module [`statement::holder_binding`].
+//! - test `pok_of_bbs_plus_sig_and_set_membership` shows proving that a message signed with BBS+ is one of the values of
+//!   a public set without revealing which one.
+//! - test `pok_of_bbs_plus_sig_and_inequality_with_public_value` shows proving that a message signed with BBS+ is not
+//!   equal to a public value, like a deny-list entry, without using a SNARK.
+//! - test `pok_of_bbs_plus_sig_and_bounded_messages_using_bulletproofs` shows proving that several messages signed with
+//!   BBS+ satisfy their bounds using a single aggregated Bulletproofs range proof which, unlike LegoGroth16, does not need
+//!   a trusted setup.
+//! - test `pok_of_bbs_plus_sig_and_bounded_message_using_set_membership_check` shows proving that a message signed with
+//!   BBS+ satisfies some bounds by decomposing it into digits and proving knowledge of the verifier's BBS+ signature on
+//!   each digit. This suits small ranges like ages and dates.
+//! - tests `pok_of_bbs_plus_sig_and_discrete_log_in_other_group` and `pok_of_bbs_plus_sig_and_discrete_log_in_group_of_different_order`
+//!   show proving that a message signed with BBS+ is the secret key of a public key in another group, whose order can
+//!   differ, using bit-decomposition. This binds a credential to a holder's key on a different curve.
+//! - test `pok_of_bbs_plus_sig_and_accumulator_with_transcript` shows creating and verifying proofs using a caller provided
+//!   transcript, including one in compatibility mode.
 //! - For R1CS/Circom, see various tests like using less than, not-equals comparison operators on messages signed with BBS+, proving
 //!   that the preimage of an MiMC hash is the message signed with BBS+, sum of certain signed messages (from same or different signatures)
 //!   is bounded by a given value, etc [here](tests/r1cs). The Circom compiler output and circuits are [here](tests/r1cs/circom).
@@ -24,44 +24,90 @@ use crate::statement_proof::StatementProof;
 use crate::sub_protocols::accumulator::{
     AccumulatorMembershipSubProtocol, AccumulatorNonMembershipSubProtocol,
 };
+use crate::sub_protocols::bbs::PoKIetfBBSSigG1SubProtocol;
 use crate::sub_protocols::bbs_plus::PoKBBSSigG1SubProtocol;
+use crate::sub_protocols::bound_check_bulletproofs::BoundCheckBulletproofsProtocol;
 use crate::sub_protocols::bound_check_legogroth16::BoundCheckProtocol;
+use crate::sub_protocols::bound_check_smc::BoundCheckSmcProtocol;
+use crate::sub_protocols::discrete_log_equality_across_groups::DiscreteLogEqualityAcrossGroupsSubProtocol;
+use crate::sub_protocols::holder_binding::HolderBindingSubProtocol;
+use crate::sub_protocols::inequality::InequalitySubProtocol;
+use crate::sub_protocols::pseudonym::PseudonymSubProtocol;
 use crate::sub_protocols::r1cs_legogorth16::R1CSLegogroth16Protocol;
 use crate::sub_protocols::saver::SaverProtocol;
 use crate::sub_protocols::schnorr::SchnorrProtocol;
-use dock_crypto_utils::hashing_utils::field_elem_from_try_and_incr;
+use crate::sub_protocols::set_membership::SetMembershipSubProtocol;
+use dock_crypto_utils::transcript::Transcript;
 use serde::{Deserialize, Serialize};
 
-/// Created by the prover and verified by the verifier
+/// Domain separator of the transcript used by [`Proof::new`] and [`Proof::verify`]
+pub const PROOF_TRANSCRIPT_LABEL: &[u8] = b"proof_system::Proof";
+
+const NONCE_LABEL: &[u8] = b"nonce";
+const CONTEXT_LABEL: &[u8] = b"context";
+const CHALLENGE_LABEL: &[u8] = b"challenge";
+
+/// Created by the prover and verified by the verifier. `G2` is the other group of the `DiscreteLogEqualityAcrossGroups`
+/// statements, as in the `ProofSpec`, and can have a different order than `G`, like secp256k1.
 #[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize)]
 #[serde(bound = "")]
-pub struct Proof<E: PairingEngine, G: AffineCurve, D: Digest>(
-    pub Vec<StatementProof<E, G>>,
+pub struct Proof<
+    E: PairingEngine,
+    G: AffineCurve,
+    D: Digest,
+    G2: AffineCurve = <E as PairingEngine>::G2Affine,
+>(
+    pub Vec<StatementProof<E, G, G2>>,
     pub Option<Vec<u8>>,
     PhantomData<D>,
 );
 
-impl<E: PairingEngine, G: AffineCurve, D: Digest> PartialEq for Proof<E, G, D> {
+impl<E: PairingEngine, G: AffineCurve, D: Digest, G2: AffineCurve> PartialEq
+    for Proof<E, G, D, G2>
+{
     fn eq(&self, other: &Self) -> bool {
         self.0 == other.0
     }
 }
 
-impl<E, G, D> Proof<E, G, D>
+impl<E, G, D, G2> Proof<E, G, D, G2>
 where
     E: PairingEngine,
     G: AffineCurve<ScalarField = E::Fr>,
     D: Digest,
+    G2: AffineCurve,
 {
     /// Create a new proof. `nonce` is random data that needs to be hashed into the proof and
     /// it must be kept same while creating and verifying the proof. One use of `nonce` is for replay
     /// protection, here the prover might have chosen its nonce to prevent the verifier from reusing
     /// the proof as its own or the verifier might want to require the user to create fresh proof.
+    /// The challenge is derived from a [`Transcript`] with the domain separator [`PROOF_TRANSCRIPT_LABEL`].
     pub fn new<R: RngCore>(
         rng: &mut R,
-        proof_spec: ProofSpec<E, G>,
+        proof_spec: ProofSpec<E, G, G2>,
         witnesses: Witnesses<E>,
         nonce: Option<Vec<u8>>,
+    ) -> Result<Self, ProofSystemError> {
+        Self::new_with_transcript(
+            rng,
+            proof_spec,
+            witnesses,
+            nonce,
+            Transcript::new(PROOF_TRANSCRIPT_LABEL),
+        )
+    }
+
+    /// Create a new proof like [`Proof::new`] but derive the challenge from the given transcript. The
+    /// transcript can have a different domain separator or already contain application specific data, or it can
+    /// be in compatibility mode to create proofs with challenges computed as before transcripts were introduced,
+    /// i.e. from the concatenation of the nonce, context and the challenge contributions of the statements. The
+    /// verifier must use [`Proof::verify_with_transcript`] with an identical transcript.
+    pub fn new_with_transcript<R: RngCore>(
+        rng: &mut R,
+        proof_spec: ProofSpec<E, G, G2>,
+        witnesses: Witnesses<E>,
+        nonce: Option<Vec<u8>>,
+        mut transcript: Transcript,
     ) -> Result<Self, ProofSystemError> {
         proof_spec.validate()?;
 
@@ -95,7 +141,7 @@ where
             proof_spec.derive_commitment_keys()?;
 
         let mut sub_protocols =
-            Vec::<SubProtocol<E, G>>::with_capacity(proof_spec.statements.0.len());
+            Vec::<SubProtocol<E, G, G2>>::with_capacity(proof_spec.statements.0.len());
 
         // Initialize sub-protocols for each statement
         for (s_idx, (statement, witness)) in proof_spec
@@ -135,6 +181,157 @@ where
                         ))
                     }
                 },
+                Statement::PoKIetfBBSSignatureG1(s) => match witness {
+                    Witness::PoKIetfBBSSignatureG1(w) => {
+                        // Prepare blindings for this BBS signature proof
+                        let mut blindings_map = BTreeMap::new();
+                        for k in w.unrevealed_messages.keys() {
+                            match blindings.remove(&(s_idx, *k)) {
+                                Some(b) => blindings_map.insert(*k, b),
+                                None => None,
+                            };
+                        }
+                        let sig_params = s.get_sig_params(&proof_spec.setup_params, s_idx)?;
+                        let pk = s.get_public_key(&proof_spec.setup_params, s_idx)?;
+                        let mut sp = PoKIetfBBSSigG1SubProtocol::new(
+                            s_idx,
+                            &s.revealed_messages,
+                            &s.header,
+                            &s.ciphersuite_id,
+                            sig_params,
+                            pk,
+                        );
+                        sp.init(rng, blindings_map, w)?;
+                        sub_protocols.push(SubProtocol::PoKIetfBBSSignatureG1(sp));
+                    }
+                    _ => {
+                        return Err(ProofSystemError::WitnessIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", witness),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::SetMembership(s) => match witness {
+                    Witness::SetMembership(w) => {
+                        let blinding = blindings.remove(&(s_idx, 0));
+                        let params = s.get_params(&proof_spec.setup_params, s_idx)?;
+                        let mut sp = SetMembershipSubProtocol::new(s_idx, &s.set, params);
+                        sp.init::<_, D>(rng, blinding, w)?;
+                        sub_protocols.push(SubProtocol::SetMembership(sp));
+                    }
+                    _ => {
+                        return Err(ProofSystemError::WitnessIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", witness),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::Inequality(s) => match witness {
+                    Witness::Inequality(w) => {
+                        let blinding = blindings.remove(&(s_idx, 0));
+                        let comm_key = s.get_comm_key(&proof_spec.setup_params, s_idx)?;
+                        let mut sp = InequalitySubProtocol::new(s_idx, s.inequal_to, comm_key);
+                        sp.init(rng, blinding, w)?;
+                        sub_protocols.push(SubProtocol::Inequality(sp));
+                    }
+                    _ => {
+                        return Err(ProofSystemError::WitnessIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", witness),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::BoundCheckBulletproofs(s) => match witness {
+                    Witness::BoundCheckBulletproofs(w) => {
+                        let mut blindings_map = BTreeMap::new();
+                        for i in 0..s.bounds.len() {
+                            match blindings.remove(&(s_idx, i)) {
+                                Some(b) => blindings_map.insert(i, b),
+                                None => None,
+                            };
+                        }
+                        let params = s.get_params(&proof_spec.setup_params, s_idx)?;
+                        let mut sp = BoundCheckBulletproofsProtocol::new(s_idx, &s.bounds, params);
+                        sp.init::<_, D>(rng, blindings_map, w)?;
+                        sub_protocols.push(SubProtocol::BoundCheckBulletproofs(sp));
+                    }
+                    _ => {
+                        return Err(ProofSystemError::WitnessIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", witness),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::BoundCheckSmc(s) => match witness {
+                    Witness::BoundCheckSmc(w) => {
+                        let blinding = blindings.remove(&(s_idx, 0));
+                        let params = s.get_params(&proof_spec.setup_params, s_idx)?;
+                        let mut sp = BoundCheckSmcProtocol::new(s_idx, s.min, s.max, params);
+                        sp.init(rng, blinding, w)?;
+                        sub_protocols.push(SubProtocol::BoundCheckSmc(sp));
+                    }
+                    _ => {
+                        return Err(ProofSystemError::WitnessIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", witness),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::DiscreteLogEqualityAcrossGroups(s) => match witness {
+                    Witness::DiscreteLogEqualityAcrossGroups(w) => {
+                        let blinding = blindings.remove(&(s_idx, 0));
+                        let comm_key = s.get_comm_key(&proof_spec.setup_params, s_idx)?;
+                        let mut sp = DiscreteLogEqualityAcrossGroupsSubProtocol::new(
+                            s_idx, s.y, s.num_bits, comm_key,
+                        );
+                        sp.init(rng, blinding, w)?;
+                        sub_protocols.push(SubProtocol::DiscreteLogEqualityAcrossGroups(sp));
+                    }
+                    _ => {
+                        return Err(ProofSystemError::WitnessIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", witness),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::Pseudonym(s) => match witness {
+                    Witness::Pseudonym(w) => {
+                        let blinding = blindings.remove(&(s_idx, 0));
+                        let mut sp =
+                            PseudonymSubProtocol::new(s_idx, s.get_base(s_idx)?, s.pseudonym);
+                        sp.init(rng, blinding, w)?;
+                        sub_protocols.push(SubProtocol::Pseudonym(sp));
+                    }
+                    _ => {
+                        return Err(ProofSystemError::WitnessIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", witness),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::HolderBinding(s) => match witness {
+                    Witness::HolderBinding(w) => {
+                        let blinding = blindings.remove(&(s_idx, 0));
+                        let mut sp =
+                            HolderBindingSubProtocol::new(s_idx, s, &proof_spec.setup_params)?;
+                        sp.init(rng, blinding, w)?;
+                        sub_protocols.push(SubProtocol::HolderBinding(sp));
+                    }
+                    _ => {
+                        return Err(ProofSystemError::WitnessIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", witness),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
                 Statement::AccumulatorMembership(s) => match witness {
                     Witness::AccumulatorMembership(w) => {
                         let blinding = blindings.remove(&(s_idx, 0));
@@ -301,26 +498,20 @@ where
         }
 
         // Get nonce's and context's challenge contribution
-        let mut challenge_bytes = vec![];
-        if let Some(n) = nonce.as_ref() {
-            challenge_bytes.extend_from_slice(n)
-        }
-        if let Some(ctx) = &proof_spec.context {
-            challenge_bytes.extend_from_slice(ctx);
-        }
+        Self::append_nonce_and_context(&mut transcript, &nonce, &proof_spec.context);
 
         // Get each sub-protocol's challenge contribution
         for p in sub_protocols.iter() {
-            p.challenge_contribution(&mut challenge_bytes)?;
+            p.append_to_transcript(&mut transcript)?;
         }
 
         // Generate the challenge
-        let challenge = Self::generate_challenge_from_bytes(&challenge_bytes);
+        let challenge = transcript.challenge_scalar::<E::Fr, D>(CHALLENGE_LABEL);
 
         // Get each sub-protocol's proof
         let mut statement_proofs = Vec::with_capacity(sub_protocols.len());
         for mut p in sub_protocols {
-            statement_proofs.push(p.gen_proof_contribution(&challenge)?);
+            statement_proofs.push(p.gen_proof_contribution::<D>(&challenge)?);
         }
         Ok(Self(statement_proofs, nonce, PhantomData))
     }
@@ -328,8 +519,19 @@ where
     /// Verify the `Proof` given the `ProofSpec` and `nonce`
     pub fn verify(
         self,
-        proof_spec: ProofSpec<E, G>,
+        proof_spec: ProofSpec<E, G, G2>,
         nonce: Option<Vec<u8>>,
+    ) -> Result<(), ProofSystemError> {
+        self.verify_with_transcript(proof_spec, nonce, Transcript::new(PROOF_TRANSCRIPT_LABEL))
+    }
+
+    /// Verify the `Proof` created by [`Proof::new_with_transcript`] given the `ProofSpec`, `nonce` and a
+    /// transcript identical to the one used by the prover
+    pub fn verify_with_transcript(
+        self,
+        proof_spec: ProofSpec<E, G, G2>,
+        nonce: Option<Vec<u8>>,
+        mut transcript: Transcript,
     ) -> Result<(), ProofSystemError> {
         proof_spec.validate()?;
 
@@ -366,13 +568,7 @@ where
             vec![None; witness_equalities.len()];
 
         // Get nonce's and context's challenge contribution
-        let mut challenge_bytes = vec![];
-        if let Some(n) = nonce.as_ref() {
-            challenge_bytes.extend_from_slice(n)
-        }
-        if let Some(ctx) = &proof_spec.context {
-            challenge_bytes.extend_from_slice(ctx);
-        }
+        Self::append_nonce_and_context(&mut transcript, &nonce, &proof_spec.context);
 
         // Get challenge contribution for each statement and check if response is equal for all witnesses.
         for (s_idx, (statement, proof)) in proof_spec
@@ -403,10 +599,104 @@ where
                                 }
                             }
                         }
-                        p.challenge_contribution(
+                        p.append_to_transcript(&s.revealed_messages, sig_params, &mut transcript)?;
+                    }
+                    _ => {
+                        return Err(ProofSystemError::ProofIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", proof),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::PoKIetfBBSSignatureG1(s) => match proof {
+                    StatementProof::PoKIetfBBSSignatureG1(p) => {
+                        let revealed_msg_ids = s.revealed_messages.keys().map(|k| *k).collect();
+                        let sig_params = s.get_sig_params(&proof_spec.setup_params, s_idx)?;
+                        // Check witness equalities for this statement.
+                        for i in 0..sig_params.supported_message_count() {
+                            let w_ref = (s_idx, i);
+                            for j in 0..witness_equalities.len() {
+                                if witness_equalities[j].contains(&w_ref) {
+                                    let resp = p.get_resp_for_message(i, &revealed_msg_ids)?;
+                                    Self::check_response_for_equality(
+                                        s_idx,
+                                        i,
+                                        j,
+                                        &mut responses_for_equalities,
+                                        resp,
+                                    )?;
+                                }
+                            }
+                        }
+                        let pk = s.get_public_key(&proof_spec.setup_params, s_idx)?;
+                        let sp = PoKIetfBBSSigG1SubProtocol::new(
+                            s_idx,
                             &s.revealed_messages,
+                            &s.header,
+                            &s.ciphersuite_id,
                             sig_params,
-                            &mut challenge_bytes,
+                            pk,
+                        );
+                        sp.append_proof_to_transcript(p, &mut transcript)?;
+                    }
+                    _ => {
+                        return Err(ProofSystemError::ProofIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", proof),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::SetMembership(s) => match proof {
+                    StatementProof::SetMembership(p) => {
+                        for i in 0..witness_equalities.len() {
+                            if witness_equalities[i].contains(&(s_idx, 0)) {
+                                let resp = p.get_schnorr_response_for_member()?;
+                                Self::check_response_for_equality(
+                                    s_idx,
+                                    0,
+                                    i,
+                                    &mut responses_for_equalities,
+                                    resp,
+                                )?;
+                            }
+                        }
+                        let params = s.get_params(&proof_spec.setup_params, s_idx)?;
+                        SetMembershipSubProtocol::compute_transcript_contribution(
+                            params,
+                            p,
+                            &mut transcript,
+                        )?;
+                    }
+                    _ => {
+                        return Err(ProofSystemError::ProofIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", proof),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::Inequality(s) => match proof {
+                    StatementProof::Inequality(p) => {
+                        for i in 0..witness_equalities.len() {
+                            if witness_equalities[i].contains(&(s_idx, 0)) {
+                                let resp = p.get_schnorr_response_for_message()?;
+                                Self::check_response_for_equality(
+                                    s_idx,
+                                    0,
+                                    i,
+                                    &mut responses_for_equalities,
+                                    resp,
+                                )?;
+                            }
+                        }
+                        let comm_key = s.get_comm_key(&proof_spec.setup_params, s_idx)?;
+                        InequalitySubProtocol::compute_transcript_contribution(
+                            comm_key,
+                            &s.inequal_to,
+                            p,
+                            &mut transcript,
                         )?;
                     }
                     _ => {
@@ -417,6 +707,150 @@ where
                         ))
                     }
                 },
+                Statement::BoundCheckBulletproofs(s) => match proof {
+                    StatementProof::BoundCheckBulletproofs(p) => {
+                        for i in 0..witness_equalities.len() {
+                            for j in 0..s.bounds.len() {
+                                if witness_equalities[i].contains(&(s_idx, j)) {
+                                    let resp = p.get_schnorr_response_for_message(j)?;
+                                    Self::check_response_for_equality(
+                                        s_idx,
+                                        j,
+                                        i,
+                                        &mut responses_for_equalities,
+                                        resp,
+                                    )?;
+                                }
+                            }
+                        }
+                        let params = s.get_params(&proof_spec.setup_params, s_idx)?;
+                        BoundCheckBulletproofsProtocol::compute_transcript_contribution(
+                            params,
+                            p,
+                            &mut transcript,
+                        )?;
+                    }
+                    _ => {
+                        return Err(ProofSystemError::ProofIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", proof),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::BoundCheckSmc(s) => match proof {
+                    StatementProof::BoundCheckSmc(p) => {
+                        for i in 0..witness_equalities.len() {
+                            if witness_equalities[i].contains(&(s_idx, 0)) {
+                                let resp = p.get_schnorr_response_for_message();
+                                Self::check_response_for_equality(
+                                    s_idx,
+                                    0,
+                                    i,
+                                    &mut responses_for_equalities,
+                                    resp,
+                                )?;
+                            }
+                        }
+                        let params = s.get_params(&proof_spec.setup_params, s_idx)?;
+                        BoundCheckSmcProtocol::compute_transcript_contribution(
+                            params,
+                            p,
+                            &mut transcript,
+                        )?;
+                    }
+                    _ => {
+                        return Err(ProofSystemError::ProofIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", proof),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::DiscreteLogEqualityAcrossGroups(s) => match proof {
+                    StatementProof::DiscreteLogEqualityAcrossGroups(p) => {
+                        for i in 0..witness_equalities.len() {
+                            if witness_equalities[i].contains(&(s_idx, 0)) {
+                                let resp = p.get_schnorr_response_for_message()?;
+                                Self::check_response_for_equality(
+                                    s_idx,
+                                    0,
+                                    i,
+                                    &mut responses_for_equalities,
+                                    resp,
+                                )?;
+                            }
+                        }
+                        let comm_key = s.get_comm_key(&proof_spec.setup_params, s_idx)?;
+                        DiscreteLogEqualityAcrossGroupsSubProtocol::<G, G2>::compute_transcript_contribution(
+                            &comm_key,
+                            &s.y,
+                            p,
+                            &mut transcript,
+                        )?;
+                    }
+                    _ => {
+                        return Err(ProofSystemError::ProofIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", proof),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::Pseudonym(s) => match proof {
+                    StatementProof::Pseudonym(p) => {
+                        for i in 0..witness_equalities.len() {
+                            if witness_equalities[i].contains(&(s_idx, 0)) {
+                                let resp = p.response.get_response(0)?;
+                                Self::check_response_for_equality(
+                                    s_idx,
+                                    0,
+                                    i,
+                                    &mut responses_for_equalities,
+                                    resp,
+                                )?;
+                            }
+                        }
+                        PseudonymSubProtocol::<E>::compute_transcript_contribution(
+                            &s.get_base(s_idx)?,
+                            &s.pseudonym,
+                            p,
+                            &mut transcript,
+                        )?;
+                    }
+                    _ => {
+                        return Err(ProofSystemError::ProofIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", proof),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::HolderBinding(s) => match proof {
+                    StatementProof::HolderBinding(p) => {
+                        for i in 0..witness_equalities.len() {
+                            if witness_equalities[i].contains(&(s_idx, 0)) {
+                                let resp = p.get_schnorr_response_for_secret_key()?;
+                                Self::check_response_for_equality(
+                                    s_idx,
+                                    0,
+                                    i,
+                                    &mut responses_for_equalities,
+                                    resp,
+                                )?;
+                            }
+                        }
+                        HolderBindingSubProtocol::new(s_idx, s, &proof_spec.setup_params)?
+                            .compute_transcript_contribution(p, &mut transcript)?;
+                    }
+                    _ => {
+                        return Err(ProofSystemError::ProofIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", proof),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
                 Statement::AccumulatorMembership(s) => match proof {
                     StatementProof::AccumulatorMembership(p) => {
                         for i in 0..witness_equalities.len() {
@@ -436,12 +870,12 @@ where
                         let params = s.get_params(&proof_spec.setup_params, s_idx)?;
                         let pk = s.get_public_key(&proof_spec.setup_params, s_idx)?;
                         let prk = s.get_proving_key(&proof_spec.setup_params, s_idx)?;
-                        p.challenge_contribution(
+                        p.append_to_transcript(
                             &s.accumulator_value,
                             pk,
                             params,
                             prk,
-                            &mut challenge_bytes,
+                            &mut transcript,
                         )?;
                     }
                     _ => {
@@ -471,12 +905,12 @@ where
                         let params = s.get_params(&proof_spec.setup_params, s_idx)?;
                         let pk = s.get_public_key(&proof_spec.setup_params, s_idx)?;
                         let prk = s.get_proving_key(&proof_spec.setup_params, s_idx)?;
-                        p.challenge_contribution(
+                        p.append_to_transcript(
                             &s.accumulator_value,
                             pk,
                             params,
                             prk,
-                            &mut challenge_bytes,
+                            &mut transcript,
                         )?;
                     }
                     _ => {
@@ -506,11 +940,11 @@ where
                             }
                         }
 
-                        SchnorrProtocol::compute_challenge_contribution(
+                        SchnorrProtocol::compute_transcript_contribution(
                             comm_key,
                             &s.commitment,
                             &p.t,
-                            &mut challenge_bytes,
+                            &mut transcript,
                         )?;
                     }
                     _ => {
@@ -537,12 +971,12 @@ where
                         }
                         let ek_comm_key = ek_comm.get(s_idx).unwrap();
                         let cc_keys = chunked_comm.get(s_idx).unwrap();
-                        SaverProtocol::compute_challenge_contribution(
+                        SaverProtocol::compute_transcript_contribution(
                             ek_comm_key,
                             &cc_keys.0,
                             &cc_keys.1,
                             p,
-                            &mut challenge_bytes,
+                            &mut transcript,
                         )?;
                     }
                     _ => {
@@ -569,10 +1003,10 @@ where
                         }
 
                         let comm_key = bound_check_comm.get(s_idx).unwrap();
-                        BoundCheckProtocol::compute_challenge_contribution(
+                        BoundCheckProtocol::compute_transcript_contribution(
                             comm_key,
                             &p,
-                            &mut challenge_bytes,
+                            &mut transcript,
                         )?;
                     }
                     _ => {
@@ -601,10 +1035,10 @@ where
                             }
                         }
 
-                        R1CSLegogroth16Protocol::compute_challenge_contribution(
+                        R1CSLegogroth16Protocol::compute_transcript_contribution(
                             r1cs_comm_keys.get(s_idx).unwrap(),
                             &p,
-                            &mut challenge_bytes,
+                            &mut transcript,
                         )?;
                     }
                     _ => {
@@ -635,7 +1069,7 @@ where
         }
 
         // Verifier independently generates challenge
-        let challenge = Self::generate_challenge_from_bytes(&challenge_bytes);
+        let challenge = transcript.challenge_scalar::<E::Fr, D>(CHALLENGE_LABEL);
 
         // Verify the proof for each statement
         for (s_idx, (statement, proof)) in proof_spec
@@ -666,6 +1100,126 @@ where
                         ))
                     }
                 },
+                Statement::PoKIetfBBSSignatureG1(s) => match proof {
+                    StatementProof::PoKIetfBBSSignatureG1(ref _p) => {
+                        let sig_params = s.get_sig_params(&proof_spec.setup_params, s_idx)?;
+                        let pk = s.get_public_key(&proof_spec.setup_params, s_idx)?;
+                        let sp = PoKIetfBBSSigG1SubProtocol::new(
+                            s_idx,
+                            &s.revealed_messages,
+                            &s.header,
+                            &s.ciphersuite_id,
+                            sig_params,
+                            pk,
+                        );
+                        sp.verify_proof_contribution(&challenge, &proof)?
+                    }
+                    _ => {
+                        return Err(ProofSystemError::ProofIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", proof),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::SetMembership(s) => match proof {
+                    StatementProof::SetMembership(ref _p) => {
+                        let params = s.get_params(&proof_spec.setup_params, s_idx)?;
+                        let sp = SetMembershipSubProtocol::new(s_idx, &s.set, params);
+                        sp.verify_proof_contribution::<E, D, G2>(&challenge, &proof)?
+                    }
+                    _ => {
+                        return Err(ProofSystemError::ProofIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", proof),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::Inequality(s) => match proof {
+                    StatementProof::Inequality(ref _p) => {
+                        let comm_key = s.get_comm_key(&proof_spec.setup_params, s_idx)?;
+                        let sp = InequalitySubProtocol::new(s_idx, s.inequal_to, comm_key);
+                        sp.verify_proof_contribution(&challenge, &proof)?
+                    }
+                    _ => {
+                        return Err(ProofSystemError::ProofIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", proof),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::BoundCheckBulletproofs(s) => match proof {
+                    StatementProof::BoundCheckBulletproofs(ref _p) => {
+                        let params = s.get_params(&proof_spec.setup_params, s_idx)?;
+                        let sp = BoundCheckBulletproofsProtocol::new(s_idx, &s.bounds, params);
+                        sp.verify_proof_contribution::<E, D, G2>(&challenge, &proof)?
+                    }
+                    _ => {
+                        return Err(ProofSystemError::ProofIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", proof),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::BoundCheckSmc(s) => match proof {
+                    StatementProof::BoundCheckSmc(ref _p) => {
+                        let params = s.get_params(&proof_spec.setup_params, s_idx)?;
+                        let sp = BoundCheckSmcProtocol::new(s_idx, s.min, s.max, params);
+                        sp.verify_proof_contribution(&challenge, &proof)?
+                    }
+                    _ => {
+                        return Err(ProofSystemError::ProofIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", proof),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::DiscreteLogEqualityAcrossGroups(s) => match proof {
+                    StatementProof::DiscreteLogEqualityAcrossGroups(ref _p) => {
+                        let comm_key = s.get_comm_key(&proof_spec.setup_params, s_idx)?;
+                        let sp = DiscreteLogEqualityAcrossGroupsSubProtocol::new(
+                            s_idx, s.y, s.num_bits, comm_key,
+                        );
+                        sp.verify_proof_contribution(&challenge, &proof)?
+                    }
+                    _ => {
+                        return Err(ProofSystemError::ProofIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", proof),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::Pseudonym(s) => match proof {
+                    StatementProof::Pseudonym(ref _p) => {
+                        let sp = PseudonymSubProtocol::new(s_idx, s.get_base(s_idx)?, s.pseudonym);
+                        sp.verify_proof_contribution(&challenge, &proof)?
+                    }
+                    _ => {
+                        return Err(ProofSystemError::ProofIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", proof),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
+                Statement::HolderBinding(s) => match proof {
+                    StatementProof::HolderBinding(ref _p) => {
+                        let sp = HolderBindingSubProtocol::new(s_idx, s, &proof_spec.setup_params)?;
+                        sp.verify_proof_contribution(&challenge, &proof)?
+                    }
+                    _ => {
+                        return Err(ProofSystemError::ProofIncompatibleWithStatement(
+                            s_idx,
+                            format!("{:?}", proof),
+                            format!("{:?}", s),
+                        ))
+                    }
+                },
                 Statement::AccumulatorMembership(s) => match proof {
                     StatementProof::AccumulatorMembership(ref _p) => {
                         let params = s.get_params(&proof_spec.setup_params, s_idx)?;
@@ -811,13 +1365,16 @@ where
         Ok(())
     }
 
-    pub fn statement_proof(&self, index: usize) -> Result<&StatementProof<E, G>, ProofSystemError> {
+    pub fn statement_proof(
+        &self,
+        index: usize,
+    ) -> Result<&StatementProof<E, G, G2>, ProofSystemError> {
         self.statement_proofs()
             .get(index)
             .ok_or(ProofSystemError::InvalidStatementProofIndex(index))
     }
 
-    pub fn statement_proofs(&self) -> &[StatementProof<E, G>] {
+    pub fn statement_proofs(&self) -> &[StatementProof<E, G, G2>] {
         &self.0
     }
 
@@ -843,9 +1400,16 @@ where
         Ok(())
     }
 
-    /// Hash bytes to a field element. This is vulnerable to timing attack and is only used input
-    /// is public anyway like when generating setup parameters or challenge
-    fn generate_challenge_from_bytes(bytes: &[u8]) -> E::Fr {
-        field_elem_from_try_and_incr::<E::Fr, D>(bytes)
+    fn append_nonce_and_context(
+        transcript: &mut Transcript,
+        nonce: &Option<Vec<u8>>,
+        context: &Option<Vec<u8>>,
+    ) {
+        if let Some(n) = nonce {
+            transcript.append_message(NONCE_LABEL, n);
+        }
+        if let Some(ctx) = context {
+            transcript.append_message(CONTEXT_LABEL, ctx);
+        }
     }
 }
@@ -22,14 +22,19 @@ use serde::{Deserialize, Serialize};
 
 /// Describes the relations that need to proven. This is created independently by the prover and verifier and must
 /// be agreed upon and be same before creating a `Proof`. Represented as collection of `Statement`s and `MetaStatement`s.
+/// `G2` is the other group of the `DiscreteLogEqualityAcrossGroups` statements.
 #[derive(
     Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
 )]
 #[serde(bound = "")]
-pub struct ProofSpec<E: PairingEngine, G: AffineCurve> {
-    pub statements: Statements<E, G>,
+pub struct ProofSpec<
+    E: PairingEngine,
+    G: AffineCurve,
+    G2: AffineCurve = <E as PairingEngine>::G2Affine,
+> {
+    pub statements: Statements<E, G, G2>,
     pub meta_statements: MetaStatements,
-    pub setup_params: Vec<SetupParams<E, G>>,
+    pub setup_params: Vec<SetupParams<E, G, G2>>,
     /// `context` is any arbitrary data that needs to be hashed into the proof and it must be kept
     /// same while creating and verifying the proof. Eg of `context` are the purpose of
     /// the proof or the verifier's identity or some verifier-specific identity of the holder
@@ -37,15 +42,16 @@ pub struct ProofSpec<E: PairingEngine, G: AffineCurve> {
     pub context: Option<Vec<u8>>,
 }
 
-impl<E, G> ProofSpec<E, G>
+impl<E, G, G2> ProofSpec<E, G, G2>
 where
     E: PairingEngine,
     G: AffineCurve,
+    G2: AffineCurve,
 {
     pub fn new(
-        statements: Statements<E, G>,
+        statements: Statements<E, G, G2>,
         meta_statements: MetaStatements,
-        setup_params: Vec<SetupParams<E, G>>,
+        setup_params: Vec<SetupParams<E, G, G2>>,
         context: Option<Vec<u8>>,
     ) -> Self {
         Self {
@@ -56,7 +62,7 @@ where
         }
     }
 
-    pub fn add_statement(&mut self, statement: Statement<E, G>) -> usize {
+    pub fn add_statement(&mut self, statement: Statement<E, G, G2>) -> usize {
         self.statements.add(statement)
     }
 
@@ -77,6 +83,11 @@ where
                         revealed_wit_refs.insert((i, *k));
                     }
                 }
+                Statement::PoKIetfBBSSignatureG1(s) => {
+                    for k in s.revealed_messages.keys() {
+                        revealed_wit_refs.insert((i, *k));
+                    }
+                }
                 _ => continue,
             }
         }
@@ -96,6 +107,25 @@ where
                 }
             }
         }
+
+        // The witness of a set-membership check based bound check has no commitment and is bound to a value
+        // only by being equal to a witness of another kind of statement, like a signed message.
+        let mut equalities = None;
+        for (i, st) in self.statements.0.iter().enumerate() {
+            if let Statement::BoundCheckSmc(_) = st {
+                let equalities = equalities
+                    .get_or_insert_with(|| self.meta_statements.disjoint_witness_equalities());
+                let is_bound = equalities.iter().any(|eq| {
+                    eq.0.contains(&(i, 0))
+                        && eq.0.iter().any(|(j, _)| {
+                            matches!(self.statements.0.get(*j), Some(s) if !matches!(s, Statement::BoundCheckSmc(_)))
+                        })
+                });
+                if !is_bound {
+                    return Err(ProofSystemError::BoundCheckSmcWitnessNotInEquality(i));
+                }
+            }
+        }
         Ok(())
     }
 
@@ -255,10 +285,11 @@ where
     }
 }
 
-impl<E, G> Default for ProofSpec<E, G>
+impl<E, G, G2> Default for ProofSpec<E, G, G2>
 where
     E: PairingEngine,
     G: AffineCurve,
+    G2: AffineCurve,
 {
     fn default() -> Self {
         Self {
@@ -8,6 +8,8 @@
 use ark_ec::{AffineCurve, PairingEngine};
 use ark_std::vec::Vec;
 use bbs_plus::prelude::{PublicKeyG2 as BBSPublicKeyG2, SignatureParamsG1 as BBSSignatureParamsG1};
+use bulletproofs::setup::SetupParams as BulletproofsSetupParams;
+use compressed_sigma::set_membership::SetMembershipParams;
 use legogroth16::circom::R1CS;
 use legogroth16::data_structures::{
     ProvingKey as LegoSnarkProvingKey, VerifyingKey as LegoSnarkVerifyingKey,
@@ -17,6 +19,7 @@ use saver::prelude::{
     VerifyingKey as SaverSnarkVerifyingKey,
 };
 use saver::saver_groth16::Groth16VerifyingKeyBytes;
+use schnorr_pok::discrete_log_equality_across_groups::CrossGroupCommitmentKey;
 use vb_accumulator::prelude::{
     MembershipProvingKey, NonMembershipProvingKey, PublicKey as AccumPublicKey,
     SetupParams as AccumParams,
@@ -26,13 +29,21 @@ use dock_crypto_utils::serde_utils::{AffineGroupBytes, FieldBytes};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
-use crate::util::{LegoProvingKeyBytes, LegoVerifyingKeyBytes, R1CSBytes};
+use crate::statement::bound_check_smc::SmcParams;
+use crate::util::{
+    LegoProvingKeyBytes, LegoVerifyingKeyBytes, R1CSBytes, SetMembershipParamsBytes,
+};
 
-/// Holds (public) setup parameters of different protocols.
+/// Holds (public) setup parameters of different protocols. `G2` is the other group of the
+/// `DiscreteLogEqualityAcrossGroups` statements.
 #[serde_as]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(bound = "")]
-pub enum SetupParams<E: PairingEngine, G: AffineCurve> {
+pub enum SetupParams<
+    E: PairingEngine,
+    G: AffineCurve,
+    G2: AffineCurve = <E as PairingEngine>::G2Affine,
+> {
     BBSPlusSignatureParams(BBSSignatureParamsG1<E>),
     BBSPlusPublicKey(BBSPublicKeyG2<E>),
     VbAccumulatorParams(AccumParams<E>),
@@ -50,6 +61,11 @@ pub enum SetupParams<E: PairingEngine, G: AffineCurve> {
     R1CS(#[serde_as(as = "R1CSBytes")] R1CS<E>),
     Bytes(Vec<u8>),
     FieldElemVec(#[serde_as(as = "Vec<FieldBytes>")] Vec<E::Fr>),
+    SetMembershipParams(#[serde_as(as = "SetMembershipParamsBytes")] SetMembershipParams<G>),
+    BulletproofsSetupParams(BulletproofsSetupParams<G>),
+    SmcParams(SmcParams<E>),
+    /// Commitment key for `DiscreteLogEqualityAcrossGroups` statements
+    CrossGroupCommitmentKey(CrossGroupCommitmentKey<G, G2>),
 }
 
 macro_rules! extract_param {
@@ -77,7 +93,9 @@ mod serialization {
     use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
     use ark_std::io::{Read, Write};
 
-    impl<E: PairingEngine, G: AffineCurve> CanonicalSerialize for SetupParams<E, G> {
+    impl<E: PairingEngine, G: AffineCurve, G2: AffineCurve> CanonicalSerialize
+        for SetupParams<E, G, G2>
+    {
         fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
             match self {
                 Self::BBSPlusSignatureParams(s) => {
@@ -148,6 +166,22 @@ mod serialization {
                     CanonicalSerialize::serialize(&16u8, &mut writer)?;
                     CanonicalSerialize::serialize(s, &mut writer)
                 }
+                Self::SetMembershipParams(s) => {
+                    CanonicalSerialize::serialize(&17u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
+                Self::BulletproofsSetupParams(s) => {
+                    CanonicalSerialize::serialize(&18u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
+                Self::SmcParams(s) => {
+                    CanonicalSerialize::serialize(&19u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
+                Self::CrossGroupCommitmentKey(s) => {
+                    CanonicalSerialize::serialize(&20u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
             }
         }
 
@@ -172,6 +206,10 @@ mod serialization {
                 Self::R1CS(s) => 14u8.serialized_size() + s.serialized_size(),
                 Self::Bytes(s) => 15u8.serialized_size() + s.serialized_size(),
                 Self::FieldElemVec(s) => 16u8.serialized_size() + s.serialized_size(),
+                Self::SetMembershipParams(s) => 17u8.serialized_size() + s.serialized_size(),
+                Self::BulletproofsSetupParams(s) => 18u8.serialized_size() + s.serialized_size(),
+                Self::SmcParams(s) => 19u8.serialized_size() + s.serialized_size(),
+                Self::CrossGroupCommitmentKey(s) => 20u8.serialized_size() + s.serialized_size(),
             }
         }
 
@@ -248,6 +286,22 @@ mod serialization {
                     CanonicalSerialize::serialize_uncompressed(&16u8, &mut writer)?;
                     CanonicalSerialize::serialize_uncompressed(s, &mut writer)
                 }
+                Self::SetMembershipParams(s) => {
+                    CanonicalSerialize::serialize_uncompressed(&17u8, &mut writer)?;
+                    CanonicalSerialize::serialize_uncompressed(s, &mut writer)
+                }
+                Self::BulletproofsSetupParams(s) => {
+                    CanonicalSerialize::serialize_uncompressed(&18u8, &mut writer)?;
+                    CanonicalSerialize::serialize_uncompressed(s, &mut writer)
+                }
+                Self::SmcParams(s) => {
+                    CanonicalSerialize::serialize_uncompressed(&19u8, &mut writer)?;
+                    CanonicalSerialize::serialize_uncompressed(s, &mut writer)
+                }
+                Self::CrossGroupCommitmentKey(s) => {
+                    CanonicalSerialize::serialize_uncompressed(&20u8, &mut writer)?;
+                    CanonicalSerialize::serialize_uncompressed(s, &mut writer)
+                }
             }
         }
 
@@ -321,6 +375,22 @@ mod serialization {
                     CanonicalSerialize::serialize_unchecked(&16u8, &mut writer)?;
                     CanonicalSerialize::serialize_unchecked(s, &mut writer)
                 }
+                Self::SetMembershipParams(s) => {
+                    CanonicalSerialize::serialize_unchecked(&17u8, &mut writer)?;
+                    CanonicalSerialize::serialize_unchecked(s, &mut writer)
+                }
+                Self::BulletproofsSetupParams(s) => {
+                    CanonicalSerialize::serialize_unchecked(&18u8, &mut writer)?;
+                    CanonicalSerialize::serialize_unchecked(s, &mut writer)
+                }
+                Self::SmcParams(s) => {
+                    CanonicalSerialize::serialize_unchecked(&19u8, &mut writer)?;
+                    CanonicalSerialize::serialize_unchecked(s, &mut writer)
+                }
+                Self::CrossGroupCommitmentKey(s) => {
+                    CanonicalSerialize::serialize_unchecked(&20u8, &mut writer)?;
+                    CanonicalSerialize::serialize_unchecked(s, &mut writer)
+                }
             }
         }
 
@@ -347,11 +417,21 @@ mod serialization {
                 Self::R1CS(s) => 14u8.uncompressed_size() + s.uncompressed_size(),
                 Self::Bytes(s) => 15u8.uncompressed_size() + s.uncompressed_size(),
                 Self::FieldElemVec(s) => 16u8.uncompressed_size() + s.uncompressed_size(),
+                Self::SetMembershipParams(s) => 17u8.uncompressed_size() + s.uncompressed_size(),
+                Self::BulletproofsSetupParams(s) => {
+                    18u8.uncompressed_size() + s.uncompressed_size()
+                }
+                Self::SmcParams(s) => 19u8.uncompressed_size() + s.uncompressed_size(),
+                Self::CrossGroupCommitmentKey(s) => {
+                    20u8.uncompressed_size() + s.uncompressed_size()
+                }
             }
         }
     }
 
-    impl<E: PairingEngine, G: AffineCurve> CanonicalDeserialize for SetupParams<E, G> {
+    impl<E: PairingEngine, G: AffineCurve, G2: AffineCurve> CanonicalDeserialize
+        for SetupParams<E, G, G2>
+    {
         fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
             let t: u8 = CanonicalDeserialize::deserialize(&mut reader)?;
             match t {
@@ -402,6 +482,18 @@ mod serialization {
                 16u8 => Ok(Self::FieldElemVec(CanonicalDeserialize::deserialize(
                     &mut reader,
                 )?)),
+                17u8 => Ok(Self::SetMembershipParams(
+                    CanonicalDeserialize::deserialize(&mut reader)?,
+                )),
+                18u8 => Ok(Self::BulletproofsSetupParams(
+                    CanonicalDeserialize::deserialize(&mut reader)?,
+                )),
+                19u8 => Ok(Self::SmcParams(CanonicalDeserialize::deserialize(
+                    &mut reader,
+                )?)),
+                20u8 => Ok(Self::CrossGroupCommitmentKey(
+                    CanonicalDeserialize::deserialize(&mut reader)?,
+                )),
                 _ => Err(SerializationError::InvalidData),
             }
         }
@@ -459,6 +551,18 @@ mod serialization {
                 16u8 => Ok(Self::FieldElemVec(
                     CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
                 )),
+                17u8 => Ok(Self::SetMembershipParams(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
+                18u8 => Ok(Self::BulletproofsSetupParams(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
+                19u8 => Ok(Self::SmcParams(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
+                20u8 => Ok(Self::CrossGroupCommitmentKey(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
                 _ => Err(SerializationError::InvalidData),
             }
         }
@@ -516,6 +620,18 @@ mod serialization {
                 16u8 => Ok(Self::FieldElemVec(
                     CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
                 )),
+                17u8 => Ok(Self::SetMembershipParams(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
+                18u8 => Ok(Self::BulletproofsSetupParams(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
+                19u8 => Ok(Self::SmcParams(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
+                20u8 => Ok(Self::CrossGroupCommitmentKey(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
                 _ => Err(SerializationError::InvalidData),
             }
         }
@@ -49,12 +49,12 @@ pub struct AccumulatorNonMembership<E: PairingEngine> {
 
 impl<E: PairingEngine> AccumulatorMembership<E> {
     /// Create a statement by passing the accumulator params, public key and proving key directly.
-    pub fn new_statement_from_params<G: AffineCurve>(
+    pub fn new_statement_from_params<G: AffineCurve, G2: AffineCurve>(
         params: AccumParams<E>,
         public_key: PublicKey<E::G2Affine>,
         proving_key: MembershipProvingKey<E::G1Affine>,
         accumulator_value: E::G1Affine,
-    ) -> Statement<E, G> {
+    ) -> Statement<E, G, G2> {
         Statement::AccumulatorMembership(Self {
             accumulator_value,
             params: Some(params),
@@ -67,12 +67,12 @@ impl<E: PairingEngine> AccumulatorMembership<E> {
     }
 
     /// Create a statement by passing the indices of accumulator params, public key and proving key in `SetupParams`.
-    pub fn new_statement_from_params_ref<G: AffineCurve>(
+    pub fn new_statement_from_params_ref<G: AffineCurve, G2: AffineCurve>(
         params_ref: usize,
         public_key_ref: usize,
         proving_key_ref: usize,
         accumulator_value: E::G1Affine,
-    ) -> Statement<E, G> {
+    ) -> Statement<E, G, G2> {
         Statement::AccumulatorMembership(Self {
             accumulator_value,
             params: None,
@@ -85,9 +85,9 @@ impl<E: PairingEngine> AccumulatorMembership<E> {
     }
 
     /// Get accumulator params for the statement index `s_idx` either from `self` or from given `setup_params`
-    pub fn get_params<'a, G: AffineCurve>(
+    pub fn get_params<'a, G: AffineCurve, G2: AffineCurve>(
         &'a self,
-        setup_params: &'a [SetupParams<E, G>],
+        setup_params: &'a [SetupParams<E, G, G2>],
         st_idx: usize,
     ) -> Result<&'a AccumParams<E>, ProofSystemError> {
         extract_param!(
@@ -101,9 +101,9 @@ impl<E: PairingEngine> AccumulatorMembership<E> {
     }
 
     /// Get publci key for the statement index `s_idx` either from `self` or from given `setup_params`
-    pub fn get_public_key<'a, G: AffineCurve>(
+    pub fn get_public_key<'a, G: AffineCurve, G2: AffineCurve>(
         &'a self,
-        setup_params: &'a [SetupParams<E, G>],
+        setup_params: &'a [SetupParams<E, G, G2>],
         st_idx: usize,
     ) -> Result<&'a PublicKey<E::G2Affine>, ProofSystemError> {
         extract_param!(
@@ -117,9 +117,9 @@ impl<E: PairingEngine> AccumulatorMembership<E> {
     }
 
     /// Get membership proving key for the statement index `s_idx` either from `self` or from given `setup_params`
-    pub fn get_proving_key<'a, G: AffineCurve>(
+    pub fn get_proving_key<'a, G: AffineCurve, G2: AffineCurve>(
         &'a self,
-        setup_params: &'a [SetupParams<E, G>],
+        setup_params: &'a [SetupParams<E, G, G2>],
         st_idx: usize,
     ) -> Result<&'a MembershipProvingKey<E::G1Affine>, ProofSystemError> {
         extract_param!(
@@ -134,12 +134,12 @@ impl<E: PairingEngine> AccumulatorMembership<E> {
 }
 
 impl<E: PairingEngine> AccumulatorNonMembership<E> {
-    pub fn new_statement_from_params<G: AffineCurve>(
+    pub fn new_statement_from_params<G: AffineCurve, G2: AffineCurve>(
         params: AccumParams<E>,
         public_key: PublicKey<E::G2Affine>,
         proving_key: NonMembershipProvingKey<E::G1Affine>,
         accumulator_value: E::G1Affine,
-    ) -> Statement<E, G> {
+    ) -> Statement<E, G, G2> {
         Statement::AccumulatorNonMembership(Self {
             accumulator_value,
             params: Some(params),
@@ -151,12 +151,12 @@ impl<E: PairingEngine> AccumulatorNonMembership<E> {
         })
     }
 
-    pub fn new_statement_from_params_ref<G: AffineCurve>(
+    pub fn new_statement_from_params_ref<G: AffineCurve, G2: AffineCurve>(
         params_ref: usize,
         public_key_ref: usize,
         proving_key_ref: usize,
         accumulator_value: E::G1Affine,
-    ) -> Statement<E, G> {
+    ) -> Statement<E, G, G2> {
         Statement::AccumulatorNonMembership(Self {
             accumulator_value,
             params: None,
@@ -168,9 +168,9 @@ impl<E: PairingEngine> AccumulatorNonMembership<E> {
         })
     }
 
-    pub fn get_params<'a, G: AffineCurve>(
+    pub fn get_params<'a, G: AffineCurve, G2: AffineCurve>(
         &'a self,
-        setup_params: &'a [SetupParams<E, G>],
+        setup_params: &'a [SetupParams<E, G, G2>],
         st_idx: usize,
     ) -> Result<&'a AccumParams<E>, ProofSystemError> {
         extract_param!(
@@ -183,9 +183,9 @@ impl<E: PairingEngine> AccumulatorNonMembership<E> {
         )
     }
 
-    pub fn get_public_key<'a, G: AffineCurve>(
+    pub fn get_public_key<'a, G: AffineCurve, G2: AffineCurve>(
         &'a self,
-        setup_params: &'a [SetupParams<E, G>],
+        setup_params: &'a [SetupParams<E, G, G2>],
         st_idx: usize,
     ) -> Result<&'a PublicKey<E::G2Affine>, ProofSystemError> {
         extract_param!(
@@ -198,9 +198,9 @@ impl<E: PairingEngine> AccumulatorNonMembership<E> {
         )
     }
 
-    pub fn get_proving_key<'a, G: AffineCurve>(
+    pub fn get_proving_key<'a, G: AffineCurve, G2: AffineCurve>(
         &'a self,
-        setup_params: &'a [SetupParams<E, G>],
+        setup_params: &'a [SetupParams<E, G, G2>],
         st_idx: usize,
     ) -> Result<&'a NonMembershipProvingKey<E::G1Affine>, ProofSystemError> {
         extract_param!(
@@ -0,0 +1,116 @@
+use ark_ec::{AffineCurve, PairingEngine};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    collections::BTreeMap,
+    io::{Read, Write},
+    vec::Vec,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, Same};
+
+use crate::error::ProofSystemError;
+use crate::setup_params::SetupParams;
+use crate::statement::Statement;
+use bbs_plus::bbs::Ciphersuite;
+use bbs_plus::prelude::{PublicKeyG2, SignatureParamsG1};
+use dock_crypto_utils::serde_utils::*;
+
+/// Public values like setup params, public key, header and revealed messages for proving knowledge of
+/// BBS signature as per the IETF draft. The signature params and public key are same as for BBS+ and
+/// thus the same `SetupParams` are used. The `domain`, which binds the signature to the public key, params and
+/// the header, is not part of the statement but computed from them by the prover and the verifier with the
+/// ciphersuite identified by `ciphersuite_id`.
+#[serde_as]
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct PoKIetfBBSSignatureG1<E: PairingEngine> {
+    /// Messages being revealed.
+    #[serde_as(as = "BTreeMap<Same, FieldBytes>")]
+    pub revealed_messages: BTreeMap<usize, E::Fr>,
+    /// Header the signature was created with
+    pub header: Vec<u8>,
+    /// Identifier of the ciphersuite the signature was created with, `Ciphersuite::CIPHERSUITE_ID`
+    pub ciphersuite_id: Vec<u8>,
+    /// If the statement was created by passing the signature params directly, then it will not be None
+    pub signature_params: Option<SignatureParamsG1<E>>,
+    /// If the statement was created by passing the public key params directly, then it will not be None
+    pub public_key: Option<PublicKeyG2<E>>,
+    /// If the statement was created by passing the index of signature params in `SetupParams`, then it will not be None
+    pub signature_params_ref: Option<usize>,
+    /// If the statement was created by passing the index of public key in `SetupParams`, then it will not be None
+    pub public_key_ref: Option<usize>,
+}
+
+impl<E: PairingEngine> PoKIetfBBSSignatureG1<E> {
+    /// Create a statement by passing the signature parameters and public key directly. The signature was
+    /// created with the ciphersuite `C`.
+    pub fn new_statement_from_params<C: Ciphersuite<E>, G: AffineCurve, G2: AffineCurve>(
+        signature_params: SignatureParamsG1<E>,
+        public_key: PublicKeyG2<E>,
+        header: Vec<u8>,
+        revealed_messages: BTreeMap<usize, E::Fr>,
+    ) -> Statement<E, G, G2> {
+        Statement::PoKIetfBBSSignatureG1(Self {
+            revealed_messages,
+            header,
+            ciphersuite_id: C::CIPHERSUITE_ID.to_vec(),
+            signature_params: Some(signature_params),
+            public_key: Some(public_key),
+            signature_params_ref: None,
+            public_key_ref: None,
+        })
+    }
+
+    /// Create a statement by passing the indices of signature parameters and public key in `SetupParams`.
+    /// The signature was created with the ciphersuite `C`.
+    pub fn new_statement_from_params_ref<C: Ciphersuite<E>, G: AffineCurve, G2: AffineCurve>(
+        signature_params_ref: usize,
+        public_key_ref: usize,
+        header: Vec<u8>,
+        revealed_messages: BTreeMap<usize, E::Fr>,
+    ) -> Statement<E, G, G2> {
+        Statement::PoKIetfBBSSignatureG1(Self {
+            revealed_messages,
+            header,
+            ciphersuite_id: C::CIPHERSUITE_ID.to_vec(),
+            signature_params: None,
+            public_key: None,
+            signature_params_ref: Some(signature_params_ref),
+            public_key_ref: Some(public_key_ref),
+        })
+    }
+
+    /// Get signature params for the statement index `s_idx` either from `self` or from given `setup_params`.
+    pub fn get_sig_params<'a, G: AffineCurve, G2: AffineCurve>(
+        &'a self,
+        setup_params: &'a [SetupParams<E, G, G2>],
+        st_idx: usize,
+    ) -> Result<&'a SignatureParamsG1<E>, ProofSystemError> {
+        extract_param!(
+            setup_params,
+            &self.signature_params,
+            self.signature_params_ref,
+            BBSPlusSignatureParams,
+            IncompatibleBBSPlusSetupParamAtIndex,
+            st_idx
+        )
+    }
+
+    /// Get public key for the statement index `s_idx` either from `self` or from given `setup_params`.
+    pub fn get_public_key<'a, G: AffineCurve, G2: AffineCurve>(
+        &'a self,
+        setup_params: &'a [SetupParams<E, G, G2>],
+        st_idx: usize,
+    ) -> Result<&'a PublicKeyG2<E>, ProofSystemError> {
+        extract_param!(
+            setup_params,
+            &self.public_key,
+            self.public_key_ref,
+            BBSPlusPublicKey,
+            IncompatibleBBSPlusSetupParamAtIndex,
+            st_idx
+        )
+    }
+}
@@ -35,11 +35,11 @@ pub struct PoKBBSSignatureG1<E: PairingEngine> {
 
 impl<E: PairingEngine> PoKBBSSignatureG1<E> {
     /// Create a statement by passing the signature parameters and public key directly.
-    pub fn new_statement_from_params<G: AffineCurve>(
+    pub fn new_statement_from_params<G: AffineCurve, G2: AffineCurve>(
         signature_params: SignatureParamsG1<E>,
         public_key: PublicKeyG2<E>,
         revealed_messages: BTreeMap<usize, E::Fr>,
-    ) -> Statement<E, G> {
+    ) -> Statement<E, G, G2> {
         Statement::PoKBBSSignatureG1(Self {
             revealed_messages,
             signature_params: Some(signature_params),
@@ -50,11 +50,11 @@ impl<E: PairingEngine> PoKBBSSignatureG1<E> {
     }
 
     /// Create a statement by passing the indices of signature parameters and public key in `SetupParams`.
-    pub fn new_statement_from_params_ref<G: AffineCurve>(
+    pub fn new_statement_from_params_ref<G: AffineCurve, G2: AffineCurve>(
         signature_params_ref: usize,
         public_key_ref: usize,
         revealed_messages: BTreeMap<usize, E::Fr>,
-    ) -> Statement<E, G> {
+    ) -> Statement<E, G, G2> {
         Statement::PoKBBSSignatureG1(Self {
             revealed_messages,
             signature_params: None,
@@ -65,9 +65,9 @@ impl<E: PairingEngine> PoKBBSSignatureG1<E> {
     }
 
     /// Get signature params for the statement index `s_idx` either from `self` or from given `setup_params`.
-    pub fn get_sig_params<'a, G: AffineCurve>(
+    pub fn get_sig_params<'a, G: AffineCurve, G2: AffineCurve>(
         &'a self,
-        setup_params: &'a [SetupParams<E, G>],
+        setup_params: &'a [SetupParams<E, G, G2>],
         st_idx: usize,
     ) -> Result<&'a SignatureParamsG1<E>, ProofSystemError> {
         extract_param!(
@@ -81,9 +81,9 @@ impl<E: PairingEngine> PoKBBSSignatureG1<E> {
     }
 
     /// Get public key for the statement index `s_idx` either from `self` or from given `setup_params`.
-    pub fn get_public_key<'a, G: AffineCurve>(
+    pub fn get_public_key<'a, G: AffineCurve, G2: AffineCurve>(
         &'a self,
-        setup_params: &'a [SetupParams<E, G>],
+        setup_params: &'a [SetupParams<E, G, G2>],
         st_idx: usize,
     ) -> Result<&'a PublicKeyG2<E>, ProofSystemError> {
         extract_param!(
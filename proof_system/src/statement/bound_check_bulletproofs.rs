@@ -0,0 +1,70 @@
+use ark_ec::{AffineCurve, PairingEngine};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Write},
+    vec::Vec,
+};
+use bulletproofs::setup::SetupParams as BulletproofsSetupParams;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ProofSystemError;
+use crate::setup_params::SetupParams;
+use crate::statement::Statement;
+use crate::sub_protocols::bound_check_bulletproofs::BoundCheckBulletproofsProtocol;
+
+/// Proving that each witness satisfies publicly known bounds inclusively (<=, >=) using an aggregated Bulletproofs
+/// range proof. Unlike `BoundCheckLegoGroth16Prover`, this does not need a trusted setup and the same statement is
+/// used by both the prover and the verifier. The `i`-th witness must satisfy the `i`-th bound `(min, max)`.
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct BoundCheckBulletproofs<G: AffineCurve> {
+    /// Inclusive bounds `(min, max)` for each witness
+    pub bounds: Vec<(u64, u64)>,
+    pub params: Option<BulletproofsSetupParams<G>>,
+    pub params_ref: Option<usize>,
+}
+
+impl<G: AffineCurve> BoundCheckBulletproofs<G> {
+    /// Create a statement by passing the Bulletproofs setup params directly
+    pub fn new_statement_from_params<E: PairingEngine, G2: AffineCurve>(
+        params: BulletproofsSetupParams<G>,
+        bounds: Vec<(u64, u64)>,
+    ) -> Result<Statement<E, G, G2>, ProofSystemError> {
+        BoundCheckBulletproofsProtocol::<G>::validate_bounds(&bounds)?;
+        Ok(Statement::BoundCheckBulletproofs(Self {
+            bounds,
+            params: Some(params),
+            params_ref: None,
+        }))
+    }
+
+    /// Create a statement by passing an index to the Bulletproofs setup params in the `setup_params`
+    pub fn new_statement_from_params_ref<E: PairingEngine, G2: AffineCurve>(
+        params_ref: usize,
+        bounds: Vec<(u64, u64)>,
+    ) -> Result<Statement<E, G, G2>, ProofSystemError> {
+        BoundCheckBulletproofsProtocol::<G>::validate_bounds(&bounds)?;
+        Ok(Statement::BoundCheckBulletproofs(Self {
+            bounds,
+            params: None,
+            params_ref: Some(params_ref),
+        }))
+    }
+
+    pub fn get_params<'a, E: PairingEngine, G2: AffineCurve>(
+        &'a self,
+        setup_params: &'a [SetupParams<E, G, G2>],
+        st_idx: usize,
+    ) -> Result<&'a BulletproofsSetupParams<G>, ProofSystemError> {
+        extract_param!(
+            setup_params,
+            &self.params,
+            self.params_ref,
+            BulletproofsSetupParams,
+            IncompatibleBulletproofsSetupParamAtIndex,
+            st_idx
+        )
+    }
+}
@@ -41,11 +41,11 @@ pub struct BoundCheckLegoGroth16Verifier<E: PairingEngine> {
 }
 
 impl<E: PairingEngine> BoundCheckLegoGroth16Prover<E> {
-    pub fn new_statement_from_params<G: AffineCurve>(
+    pub fn new_statement_from_params<G: AffineCurve, G2: AffineCurve>(
         min: u64,
         max: u64,
         snark_proving_key: ProvingKey<E>,
-    ) -> Result<Statement<E, G>, ProofSystemError> {
+    ) -> Result<Statement<E, G, G2>, ProofSystemError> {
         BoundCheckProtocol::validate_verification_key(&snark_proving_key.vk)?;
         BoundCheckProtocol::<E>::validate_bounds(min, max)?;
 
@@ -57,11 +57,11 @@ impl<E: PairingEngine> BoundCheckLegoGroth16Prover<E> {
         }))
     }
 
-    pub fn new_statement_from_params_ref<G: AffineCurve>(
+    pub fn new_statement_from_params_ref<G: AffineCurve, G2: AffineCurve>(
         min: u64,
         max: u64,
         snark_proving_key_ref: usize,
-    ) -> Result<Statement<E, G>, ProofSystemError> {
+    ) -> Result<Statement<E, G, G2>, ProofSystemError> {
         BoundCheckProtocol::<E>::validate_bounds(min, max)?;
         Ok(Statement::BoundCheckLegoGroth16Prover(Self {
             min,
@@ -71,9 +71,9 @@ impl<E: PairingEngine> BoundCheckLegoGroth16Prover<E> {
         }))
     }
 
-    pub fn get_proving_key<'a, G: AffineCurve>(
+    pub fn get_proving_key<'a, G: AffineCurve, G2: AffineCurve>(
         &'a self,
-        setup_params: &'a [SetupParams<E, G>],
+        setup_params: &'a [SetupParams<E, G, G2>],
         st_idx: usize,
     ) -> Result<&'a ProvingKey<E>, ProofSystemError> {
         extract_param!(
@@ -88,11 +88,11 @@ impl<E: PairingEngine> BoundCheckLegoGroth16Prover<E> {
 }
 
 impl<E: PairingEngine> BoundCheckLegoGroth16Verifier<E> {
-    pub fn new_statement_from_params<G: AffineCurve>(
+    pub fn new_statement_from_params<G: AffineCurve, G2: AffineCurve>(
         min: u64,
         max: u64,
         snark_verifying_key: VerifyingKey<E>,
-    ) -> Result<Statement<E, G>, ProofSystemError> {
+    ) -> Result<Statement<E, G, G2>, ProofSystemError> {
         BoundCheckProtocol::validate_verification_key(&snark_verifying_key)?;
         BoundCheckProtocol::<E>::validate_bounds(min, max)?;
 
@@ -104,11 +104,11 @@ impl<E: PairingEngine> BoundCheckLegoGroth16Verifier<E> {
         }))
     }
 
-    pub fn new_statement_from_params_ref<G: AffineCurve>(
+    pub fn new_statement_from_params_ref<G: AffineCurve, G2: AffineCurve>(
         min: u64,
         max: u64,
         snark_verifying_key_ref: usize,
-    ) -> Result<Statement<E, G>, ProofSystemError> {
+    ) -> Result<Statement<E, G, G2>, ProofSystemError> {
         BoundCheckProtocol::<E>::validate_bounds(min, max)?;
         Ok(Statement::BoundCheckLegoGroth16Verifier(Self {
             min,
@@ -118,9 +118,9 @@ impl<E: PairingEngine> BoundCheckLegoGroth16Verifier<E> {
         }))
     }
 
-    pub fn get_verifying_key<'a, G: AffineCurve>(
+    pub fn get_verifying_key<'a, G: AffineCurve, G2: AffineCurve>(
         &'a self,
-        setup_params: &'a [SetupParams<E, G>],
+        setup_params: &'a [SetupParams<E, G, G2>],
         st_idx: usize,
     ) -> Result<&'a VerifyingKey<E>, ProofSystemError> {
         extract_param!(
@@ -147,26 +147,32 @@ mod tests {
         let snark_pk = generate_snark_srs_bound_check::<Bls12_381, _>(&mut rng).unwrap();
         assert!(BoundCheckLegoGroth16Prover::new_statement_from_params::<
             <Bls12_381 as PairingEngine>::G1Affine,
+            <Bls12_381 as PairingEngine>::G2Affine,
         >(5, 5, snark_pk.clone())
         .is_err());
         assert!(BoundCheckLegoGroth16Verifier::new_statement_from_params::<
             <Bls12_381 as PairingEngine>::G1Affine,
+            <Bls12_381 as PairingEngine>::G2Affine,
         >(5, 5, snark_pk.vk.clone())
         .is_err());
         assert!(BoundCheckLegoGroth16Prover::new_statement_from_params::<
             <Bls12_381 as PairingEngine>::G1Affine,
+            <Bls12_381 as PairingEngine>::G2Affine,
         >(5, 4, snark_pk.clone())
         .is_err());
         assert!(BoundCheckLegoGroth16Verifier::new_statement_from_params::<
             <Bls12_381 as PairingEngine>::G1Affine,
+            <Bls12_381 as PairingEngine>::G2Affine,
         >(5, 4, snark_pk.vk.clone())
         .is_err());
         assert!(BoundCheckLegoGroth16Prover::new_statement_from_params::<
             <Bls12_381 as PairingEngine>::G1Affine,
+            <Bls12_381 as PairingEngine>::G2Affine,
         >(5, 6, snark_pk.clone())
         .is_ok());
         assert!(BoundCheckLegoGroth16Verifier::new_statement_from_params::<
             <Bls12_381 as PairingEngine>::G1Affine,
+            <Bls12_381 as PairingEngine>::G2Affine,
         >(5, 6, snark_pk.vk.clone())
         .is_ok());
     }
@@ -0,0 +1,135 @@
+use ark_ec::{AffineCurve, PairingEngine};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Write},
+    rand::RngCore,
+    vec::Vec,
+};
+use bbs_plus::prelude::{KeypairG2, PublicKeyG2, SignatureG1, SignatureParamsG1};
+use serde::{Deserialize, Serialize};
+
+use crate::error::ProofSystemError;
+use crate::setup_params::SetupParams;
+use crate::statement::Statement;
+use crate::sub_protocols::bound_check_smc::BoundCheckSmcProtocol;
+
+/// Public params for the set-membership check based range proof. These are created by the verifier who signs each
+/// digit of the base, i.e. `0, 1, ..., base-1`, with BBS+ such that the prover can prove that each digit of its value
+/// is signed.
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct SmcParams<E: PairingEngine> {
+    /// Signature params for signing a single message
+    pub sig_params: SignatureParamsG1<E>,
+    pub public_key: PublicKeyG2<E>,
+    /// Signature on each digit, i.e. `digit_signatures[i]` is on `i`. The number of signatures is the base.
+    pub digit_signatures: Vec<SignatureG1<E>>,
+}
+
+impl<E: PairingEngine> SmcParams<E> {
+    /// Create params for the given `base` by signing each digit with the given keypair. `sig_params` must support
+    /// a single message.
+    pub fn new<R: RngCore>(
+        rng: &mut R,
+        base: u16,
+        sig_params: SignatureParamsG1<E>,
+        keypair: &KeypairG2<E>,
+    ) -> Result<Self, ProofSystemError> {
+        if base < 2 {
+            return Err(ProofSystemError::SmcInvalidBase(base as u64));
+        }
+        let digit_signatures = (0..base)
+            .map(|i| {
+                SignatureG1::new(
+                    rng,
+                    &[E::Fr::from(i as u64)],
+                    &keypair.secret_key,
+                    &sig_params,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            sig_params,
+            public_key: keypair.public_key.clone(),
+            digit_signatures,
+        })
+    }
+
+    pub fn base(&self) -> u64 {
+        self.digit_signatures.len() as u64
+    }
+
+    /// Verify the signatures on all digits. Used by the prover to check params created by the verifier.
+    pub fn verify(&self) -> Result<(), ProofSystemError> {
+        for (i, sig) in self.digit_signatures.iter().enumerate() {
+            sig.verify(&[E::Fr::from(i as u64)], &self.public_key, &self.sig_params)?;
+        }
+        Ok(())
+    }
+}
+
+/// Proving knowledge of message that satisfies given bounds, i.e. `min <= message <= max` using the set-membership
+/// check based range proof of [CCS08](https://link.springer.com/chapter/10.1007/978-3-540-89255-7_15). The message
+/// is decomposed into digits of the base of `params` and knowledge of the verifier's signature on each digit is
+/// proved. Needs no trusted setup and suits small ranges.
+///
+/// The proof has no commitment to the message, it only has the response for the message. So the message must be
+/// in a witness equality with a witness of another kind of statement, like a message signed with BBS+, which commits
+/// to it. `ProofSpec::validate`, which is called when creating and verifying a proof, fails otherwise.
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct BoundCheckSmc<E: PairingEngine> {
+    pub min: u64,
+    pub max: u64,
+    pub params: Option<SmcParams<E>>,
+    pub params_ref: Option<usize>,
+}
+
+impl<E: PairingEngine> BoundCheckSmc<E> {
+    pub fn new_statement_from_params<G: AffineCurve, G2: AffineCurve>(
+        min: u64,
+        max: u64,
+        params: SmcParams<E>,
+    ) -> Result<Statement<E, G, G2>, ProofSystemError> {
+        BoundCheckSmcProtocol::<E>::validate_bounds(min, max)?;
+        Ok(Statement::BoundCheckSmc(Self {
+            min,
+            max,
+            params: Some(params),
+            params_ref: None,
+        }))
+    }
+
+    pub fn new_statement_from_params_ref<G: AffineCurve, G2: AffineCurve>(
+        min: u64,
+        max: u64,
+        params_ref: usize,
+    ) -> Result<Statement<E, G, G2>, ProofSystemError> {
+        BoundCheckSmcProtocol::<E>::validate_bounds(min, max)?;
+        Ok(Statement::BoundCheckSmc(Self {
+            min,
+            max,
+            params: None,
+            params_ref: Some(params_ref),
+        }))
+    }
+
+    pub fn get_params<'a, G: AffineCurve, G2: AffineCurve>(
+        &'a self,
+        setup_params: &'a [SetupParams<E, G, G2>],
+        st_idx: usize,
+    ) -> Result<&'a SmcParams<E>, ProofSystemError> {
+        extract_param!(
+            setup_params,
+            &self.params,
+            self.params_ref,
+            SmcParams,
+            IncompatibleSmcSetupParamAtIndex,
+            st_idx
+        )
+    }
+}
@@ -0,0 +1,81 @@
+use ark_ec::{AffineCurve, PairingEngine};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use schnorr_pok::discrete_log_equality_across_groups::{
+    CrossGroupCommitmentKey, PoKDiscreteLogEqualityAcrossGroupsProtocol,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::error::ProofSystemError;
+use crate::setup_params::SetupParams;
+use crate::statement::Statement;
+use dock_crypto_utils::serde_utils::*;
+
+/// Proving that the witness is an integer of at most `num_bits` bits and is the discrete log of the public value
+/// `y = g2 * witness` in another group `G2` whose order can be different from the group `G1` of the proof, like
+/// secp256k1 or ed25519 when `G1` is a group of BLS12-381. The witness is committed in `G1` as `C = g1 * witness + h1 * r`
+/// and bit-decomposition proves that the same integer is committed in `C` and `y`, while a Schnorr proof of the
+/// opening of `C` allows proving equality with other witnesses.
+#[serde_as]
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct DiscreteLogEqualityAcrossGroups<G1: AffineCurve, G2: AffineCurve> {
+    /// The public value `g2 * witness` in `G2`
+    #[serde_as(as = "AffineGroupBytes")]
+    pub y: G2,
+    /// The witness is less than `2^num_bits`
+    pub num_bits: u16,
+    /// Commitment keys `(g1, h1)` in `G1` and `(g2, h2)` in `G2`
+    pub comm_key: Option<CrossGroupCommitmentKey<G1, G2>>,
+    pub comm_key_ref: Option<usize>,
+}
+
+impl<G1: AffineCurve, G2: AffineCurve> DiscreteLogEqualityAcrossGroups<G1, G2> {
+    /// Create a statement by passing the commitment key directly
+    pub fn new_statement_from_params<E: PairingEngine>(
+        y: G2,
+        num_bits: u16,
+        comm_key: CrossGroupCommitmentKey<G1, G2>,
+    ) -> Result<Statement<E, G1, G2>, ProofSystemError> {
+        PoKDiscreteLogEqualityAcrossGroupsProtocol::<G1, G2>::validate_num_bits(num_bits)?;
+        Ok(Statement::DiscreteLogEqualityAcrossGroups(Self {
+            y,
+            num_bits,
+            comm_key: Some(comm_key),
+            comm_key_ref: None,
+        }))
+    }
+
+    /// Create a statement by passing an index to the commitment key in the `setup_params`
+    pub fn new_statement_from_params_ref<E: PairingEngine>(
+        y: G2,
+        num_bits: u16,
+        comm_key_ref: usize,
+    ) -> Result<Statement<E, G1, G2>, ProofSystemError> {
+        PoKDiscreteLogEqualityAcrossGroupsProtocol::<G1, G2>::validate_num_bits(num_bits)?;
+        Ok(Statement::DiscreteLogEqualityAcrossGroups(Self {
+            y,
+            num_bits,
+            comm_key: None,
+            comm_key_ref: Some(comm_key_ref),
+        }))
+    }
+
+    pub fn get_comm_key<'a, E: PairingEngine>(
+        &'a self,
+        setup_params: &'a [SetupParams<E, G1, G2>],
+        st_idx: usize,
+    ) -> Result<&'a CrossGroupCommitmentKey<G1, G2>, ProofSystemError> {
+        extract_param!(
+            setup_params,
+            &self.comm_key,
+            self.comm_key_ref,
+            CrossGroupCommitmentKey,
+            IncompatibleCrossGroupCommKeySetupParamAtIndex,
+            st_idx
+        )
+    }
+}
@@ -0,0 +1,107 @@
+//! Holder binding is proving knowledge of the secret key `x` of a public key, like a device key, such that the
+//! credential can be bound to the holder of that key. Proving that `x` is a message signed in a credential is done
+//! by using a witness equality between the secret key, which is the only witness of this statement, and the signed
+//! message thus neither the secret key nor the message is revealed.
+//!
+//! The public key can be in the group of the proof, like G1 of BLS12-381, as `public_key = base * x`, or in another
+//! group `G2` whose order can be different, like secp256k1 or ed25519, as `public_key = g2 * x`. In the latter case,
+//! the secret key must be an integer of at most `num_bits` bits and is proven to be the same in both groups using
+//! bit-decomposition, as in [`DiscreteLogEqualityAcrossGroups`].
+
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use schnorr_pok::discrete_log_equality_across_groups::{
+    CrossGroupCommitmentKey, PoKDiscreteLogEqualityAcrossGroupsProtocol,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::error::ProofSystemError;
+use crate::statement::discrete_log_equality_across_groups::DiscreteLogEqualityAcrossGroups;
+use crate::statement::Statement;
+use dock_crypto_utils::serde_utils::*;
+
+/// Public key of the holder in the group of the proof
+#[serde_as]
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct SameGroupPublicKey<G: AffineCurve> {
+    /// The base `g` in `public_key = g * x`
+    #[serde_as(as = "AffineGroupBytes")]
+    pub base: G,
+    #[serde_as(as = "AffineGroupBytes")]
+    pub public_key: G,
+}
+
+/// Proving knowledge of the secret key of the holder's public key. Exactly one of `same_group` and `other_group` is set
+/// depending on the group of the public key.
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct HolderBinding<G1: AffineCurve, G2: AffineCurve> {
+    /// Public key in the group `G1` of the proof, knowledge of its secret key is proven with a Schnorr protocol
+    pub same_group: Option<SameGroupPublicKey<G1>>,
+    /// Public key in the group `G2` as `y` along with the maximum bit size of the secret key and the commitment key
+    pub other_group: Option<DiscreteLogEqualityAcrossGroups<G1, G2>>,
+}
+
+impl<G1: AffineCurve, G2: AffineCurve> HolderBinding<G1, G2> {
+    /// Create a statement for the public key `base * secret_key` in the group of the proof
+    pub fn new_statement_from_params<E: PairingEngine>(
+        base: G1,
+        public_key: G1,
+    ) -> Statement<E, G1, G2> {
+        Statement::HolderBinding(Self {
+            same_group: Some(SameGroupPublicKey { base, public_key }),
+            other_group: None,
+        })
+    }
+
+    /// Create a statement for the public key `comm_key.g2 * secret_key` in the group `G2` where the secret key has
+    /// at most `num_bits` bits
+    pub fn new_statement_for_other_group_from_params<E: PairingEngine>(
+        public_key: G2,
+        num_bits: u16,
+        comm_key: CrossGroupCommitmentKey<G1, G2>,
+    ) -> Result<Statement<E, G1, G2>, ProofSystemError> {
+        PoKDiscreteLogEqualityAcrossGroupsProtocol::<G1, G2>::validate_num_bits(num_bits)?;
+        Ok(Statement::HolderBinding(Self {
+            same_group: None,
+            other_group: Some(DiscreteLogEqualityAcrossGroups {
+                y: public_key,
+                num_bits,
+                comm_key: Some(comm_key),
+                comm_key_ref: None,
+            }),
+        }))
+    }
+
+    /// Same as [`HolderBinding::new_statement_for_other_group_from_params`] but the commitment key is at index
+    /// `comm_key_ref` in the `setup_params`
+    pub fn new_statement_for_other_group_from_params_ref<E: PairingEngine>(
+        public_key: G2,
+        num_bits: u16,
+        comm_key_ref: usize,
+    ) -> Result<Statement<E, G1, G2>, ProofSystemError> {
+        PoKDiscreteLogEqualityAcrossGroupsProtocol::<G1, G2>::validate_num_bits(num_bits)?;
+        Ok(Statement::HolderBinding(Self {
+            same_group: None,
+            other_group: Some(DiscreteLogEqualityAcrossGroups {
+                y: public_key,
+                num_bits,
+                comm_key: None,
+                comm_key_ref: Some(comm_key_ref),
+            }),
+        }))
+    }
+}
+
+/// Used by the holder to create the public key for the given base and secret key
+pub fn compute_public_key<G: AffineCurve>(base: &G, secret_key: &G::ScalarField) -> G {
+    base.mul(secret_key.into_repr()).into_affine()
+}
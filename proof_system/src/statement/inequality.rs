@@ -0,0 +1,90 @@
+use ark_ec::{AffineCurve, PairingEngine};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Write},
+    vec::Vec,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::error::ProofSystemError;
+use crate::setup_params::SetupParams;
+use crate::statement::Statement;
+use dock_crypto_utils::serde_utils::*;
+
+/// Proving that the witness is not equal to a public value, like a signed message not being a specific entry
+/// of a deny-list, without revealing the witness. The witness `m` is committed in a Pedersen commitment
+/// `C = g * m + h * r` and the prover shows that `m - inequal_to` is invertible by proving knowledge of its
+/// inverse. Does not need a SNARK.
+#[serde_as]
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct Inequality<G: AffineCurve> {
+    /// The public value the witness is not equal to
+    #[serde_as(as = "FieldBytes")]
+    pub inequal_to: G::ScalarField,
+    /// Commitment key `[g, h]` for committing to the witness. The discrete log of `h` wrt `g` must not be known to
+    /// the prover
+    #[serde_as(as = "Option<Vec<AffineGroupBytes>>")]
+    pub comm_key: Option<Vec<G>>,
+    pub comm_key_ref: Option<usize>,
+}
+
+impl<G: AffineCurve> Inequality<G> {
+    /// Create a statement by passing the commitment key directly
+    pub fn new_statement_from_params<E: PairingEngine, G2: AffineCurve>(
+        comm_key: Vec<G>,
+        inequal_to: G::ScalarField,
+    ) -> Statement<E, G, G2> {
+        Statement::Inequality(Self {
+            inequal_to,
+            comm_key: Some(comm_key),
+            comm_key_ref: None,
+        })
+    }
+
+    /// Create a statement by passing an index to the commitment key in the `setup_params`
+    pub fn new_statement_from_params_ref<E: PairingEngine, G2: AffineCurve>(
+        comm_key_ref: usize,
+        inequal_to: G::ScalarField,
+    ) -> Statement<E, G, G2> {
+        Statement::Inequality(Self {
+            inequal_to,
+            comm_key: None,
+            comm_key_ref: Some(comm_key_ref),
+        })
+    }
+
+    /// Get the commitment key and check that it has 2 bases
+    pub fn get_comm_key<'a, E: PairingEngine, G2: AffineCurve>(
+        &'a self,
+        setup_params: &'a [SetupParams<E, G, G2>],
+        st_idx: usize,
+    ) -> Result<&'a [G], ProofSystemError> {
+        let comm_key = self.extract_comm_key(setup_params, st_idx)?;
+        if comm_key.len() != 2 {
+            return Err(ProofSystemError::IncorrectCommitmentKeySizeForInequality(
+                st_idx,
+                comm_key.len(),
+            ));
+        }
+        Ok(comm_key)
+    }
+
+    fn extract_comm_key<'a, E: PairingEngine, G2: AffineCurve>(
+        &'a self,
+        setup_params: &'a [SetupParams<E, G, G2>],
+        st_idx: usize,
+    ) -> Result<&'a Vec<G>, ProofSystemError> {
+        extract_param!(
+            setup_params,
+            &self.comm_key,
+            self.comm_key_ref,
+            PedersenCommitmentKey,
+            IncompatiblePedCommSetupParamAtIndex,
+            st_idx
+        )
+    }
+}
@@ -7,18 +7,31 @@ use ark_std::{
 use serde::{Deserialize, Serialize};
 
 pub mod accumulator;
+pub mod bbs;
 pub mod bbs_plus;
+pub mod bound_check_bulletproofs;
 pub mod bound_check_legogroth16;
+pub mod bound_check_smc;
+pub mod discrete_log_equality_across_groups;
+pub mod holder_binding;
+pub mod inequality;
 pub mod ped_comm;
+pub mod pseudonym;
 pub mod r1cs_legogroth16;
 pub mod saver;
+pub mod set_membership;
 
 pub use serialization::*;
 
-/// Type of relation being proved and the public values for the relation
+/// Type of relation being proved and the public values for the relation. `G2` is the other group of the
+/// `DiscreteLogEqualityAcrossGroups` statements.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(bound = "")]
-pub enum Statement<E: PairingEngine, G: AffineCurve> {
+pub enum Statement<
+    E: PairingEngine,
+    G: AffineCurve,
+    G2: AffineCurve = <E as PairingEngine>::G2Affine,
+> {
     /// For proof of knowledge of BBS+ signature
     PoKBBSSignatureG1(bbs_plus::PoKBBSSignatureG1<E>),
     /// For proof of knowledge of committed elements in a Pedersen commitment
@@ -39,6 +52,24 @@ pub enum Statement<E: PairingEngine, G: AffineCurve> {
     R1CSCircomProver(r1cs_legogroth16::R1CSCircomProver<E>),
     /// Used by verifier to verify proof that witness satisfies constraints given by an R1CS (generated by Circom), using LegoGroth16
     R1CSCircomVerifier(r1cs_legogroth16::R1CSCircomVerifier<E>),
+    /// For proof of knowledge of BBS signature as per the IETF draft
+    PoKIetfBBSSignatureG1(bbs::PoKIetfBBSSignatureG1<E>),
+    /// For proving that the witness is a member of a public set
+    SetMembership(set_membership::SetMembership<G>),
+    /// For proving that the witness is not equal to a public value
+    Inequality(inequality::Inequality<G>),
+    /// For proving that witnesses satisfy publicly known bounds inclusively (<=, >=) using Bulletproofs
+    BoundCheckBulletproofs(bound_check_bulletproofs::BoundCheckBulletproofs<G>),
+    /// For proving that the witness satisfies publicly known bounds inclusively (<=, >=) using set-membership check
+    BoundCheckSmc(bound_check_smc::BoundCheckSmc<E>),
+    /// For proving that the witness is a bounded integer which is the discrete log of a public value in another group
+    DiscreteLogEqualityAcrossGroups(
+        discrete_log_equality_across_groups::DiscreteLogEqualityAcrossGroups<G, G2>,
+    ),
+    /// For proving that a pseudonym is created from a scope and a secret
+    Pseudonym(pseudonym::Pseudonym<E>),
+    /// For proving knowledge of the secret key of the holder's public key in the group of the proof or another group
+    HolderBinding(holder_binding::HolderBinding<G, G2>),
 }
 
 /// A collection of statements
@@ -46,21 +77,23 @@ pub enum Statement<E: PairingEngine, G: AffineCurve> {
     Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
 )]
 #[serde(bound = "")]
-pub struct Statements<E, G>(pub Vec<Statement<E, G>>)
+pub struct Statements<E, G, G2 = <E as PairingEngine>::G2Affine>(pub Vec<Statement<E, G, G2>>)
 where
     E: PairingEngine,
-    G: AffineCurve;
+    G: AffineCurve,
+    G2: AffineCurve;
 
-impl<E, G> Statements<E, G>
+impl<E, G, G2> Statements<E, G, G2>
 where
     E: PairingEngine,
     G: AffineCurve,
+    G2: AffineCurve,
 {
     pub fn new() -> Self {
         Self(Vec::new())
     }
 
-    pub fn add(&mut self, item: Statement<E, G>) -> usize {
+    pub fn add(&mut self, item: Statement<E, G, G2>) -> usize {
         self.0.push(item);
         self.0.len() - 1
     }
@@ -77,7 +110,7 @@ where
 mod serialization {
     use super::*;
 
-    impl<E: PairingEngine, G: AffineCurve> CanonicalSerialize for Statement<E, G> {
+    impl<E: PairingEngine, G: AffineCurve, G2: AffineCurve> CanonicalSerialize for Statement<E, G, G2> {
         fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
             match self {
                 Self::PoKBBSSignatureG1(s) => {
@@ -120,6 +153,38 @@ mod serialization {
                     CanonicalSerialize::serialize(&9u8, &mut writer)?;
                     CanonicalSerialize::serialize(s, &mut writer)
                 }
+                Self::PoKIetfBBSSignatureG1(s) => {
+                    CanonicalSerialize::serialize(&10u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
+                Self::SetMembership(s) => {
+                    CanonicalSerialize::serialize(&11u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
+                Self::Inequality(s) => {
+                    CanonicalSerialize::serialize(&12u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
+                Self::BoundCheckBulletproofs(s) => {
+                    CanonicalSerialize::serialize(&13u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
+                Self::BoundCheckSmc(s) => {
+                    CanonicalSerialize::serialize(&14u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
+                Self::DiscreteLogEqualityAcrossGroups(s) => {
+                    CanonicalSerialize::serialize(&15u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
+                Self::Pseudonym(s) => {
+                    CanonicalSerialize::serialize(&16u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
+                Self::HolderBinding(s) => {
+                    CanonicalSerialize::serialize(&17u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
             }
         }
 
@@ -137,6 +202,16 @@ mod serialization {
                 }
                 Self::R1CSCircomProver(s) => 8u8.serialized_size() + s.serialized_size(),
                 Self::R1CSCircomVerifier(s) => 97u8.serialized_size() + s.serialized_size(),
+                Self::PoKIetfBBSSignatureG1(s) => 10u8.serialized_size() + s.serialized_size(),
+                Self::SetMembership(s) => 11u8.serialized_size() + s.serialized_size(),
+                Self::Inequality(s) => 12u8.serialized_size() + s.serialized_size(),
+                Self::BoundCheckBulletproofs(s) => 13u8.serialized_size() + s.serialized_size(),
+                Self::BoundCheckSmc(s) => 14u8.serialized_size() + s.serialized_size(),
+                Self::DiscreteLogEqualityAcrossGroups(s) => {
+                    15u8.serialized_size() + s.serialized_size()
+                }
+                Self::Pseudonym(s) => 16u8.serialized_size() + s.serialized_size(),
+                Self::HolderBinding(s) => 17u8.serialized_size() + s.serialized_size(),
             }
         }
 
@@ -185,6 +260,38 @@ mod serialization {
                     9u8.serialize_uncompressed(&mut writer)?;
                     s.serialize_uncompressed(&mut writer)
                 }
+                Self::PoKIetfBBSSignatureG1(s) => {
+                    10u8.serialize_uncompressed(&mut writer)?;
+                    s.serialize_uncompressed(&mut writer)
+                }
+                Self::SetMembership(s) => {
+                    11u8.serialize_uncompressed(&mut writer)?;
+                    s.serialize_uncompressed(&mut writer)
+                }
+                Self::Inequality(s) => {
+                    12u8.serialize_uncompressed(&mut writer)?;
+                    s.serialize_uncompressed(&mut writer)
+                }
+                Self::BoundCheckBulletproofs(s) => {
+                    13u8.serialize_uncompressed(&mut writer)?;
+                    s.serialize_uncompressed(&mut writer)
+                }
+                Self::BoundCheckSmc(s) => {
+                    14u8.serialize_uncompressed(&mut writer)?;
+                    s.serialize_uncompressed(&mut writer)
+                }
+                Self::DiscreteLogEqualityAcrossGroups(s) => {
+                    15u8.serialize_uncompressed(&mut writer)?;
+                    s.serialize_uncompressed(&mut writer)
+                }
+                Self::Pseudonym(s) => {
+                    16u8.serialize_uncompressed(&mut writer)?;
+                    s.serialize_uncompressed(&mut writer)
+                }
+                Self::HolderBinding(s) => {
+                    17u8.serialize_uncompressed(&mut writer)?;
+                    s.serialize_uncompressed(&mut writer)
+                }
             }
         }
 
@@ -230,6 +337,38 @@ mod serialization {
                     9u8.serialize_unchecked(&mut writer)?;
                     s.serialize_unchecked(&mut writer)
                 }
+                Self::PoKIetfBBSSignatureG1(s) => {
+                    10u8.serialize_unchecked(&mut writer)?;
+                    s.serialize_unchecked(&mut writer)
+                }
+                Self::SetMembership(s) => {
+                    11u8.serialize_unchecked(&mut writer)?;
+                    s.serialize_unchecked(&mut writer)
+                }
+                Self::Inequality(s) => {
+                    12u8.serialize_unchecked(&mut writer)?;
+                    s.serialize_unchecked(&mut writer)
+                }
+                Self::BoundCheckBulletproofs(s) => {
+                    13u8.serialize_unchecked(&mut writer)?;
+                    s.serialize_unchecked(&mut writer)
+                }
+                Self::BoundCheckSmc(s) => {
+                    14u8.serialize_unchecked(&mut writer)?;
+                    s.serialize_unchecked(&mut writer)
+                }
+                Self::DiscreteLogEqualityAcrossGroups(s) => {
+                    15u8.serialize_unchecked(&mut writer)?;
+                    s.serialize_unchecked(&mut writer)
+                }
+                Self::Pseudonym(s) => {
+                    16u8.serialize_unchecked(&mut writer)?;
+                    s.serialize_unchecked(&mut writer)
+                }
+                Self::HolderBinding(s) => {
+                    17u8.serialize_unchecked(&mut writer)?;
+                    s.serialize_unchecked(&mut writer)
+                }
             }
         }
 
@@ -251,11 +390,23 @@ mod serialization {
                 }
                 Self::R1CSCircomProver(s) => 8u8.uncompressed_size() + s.uncompressed_size(),
                 Self::R1CSCircomVerifier(s) => 9u8.uncompressed_size() + s.uncompressed_size(),
+                Self::PoKIetfBBSSignatureG1(s) => 10u8.uncompressed_size() + s.uncompressed_size(),
+                Self::SetMembership(s) => 11u8.uncompressed_size() + s.uncompressed_size(),
+                Self::Inequality(s) => 12u8.uncompressed_size() + s.uncompressed_size(),
+                Self::BoundCheckBulletproofs(s) => 13u8.uncompressed_size() + s.uncompressed_size(),
+                Self::BoundCheckSmc(s) => 14u8.uncompressed_size() + s.uncompressed_size(),
+                Self::DiscreteLogEqualityAcrossGroups(s) => {
+                    15u8.uncompressed_size() + s.uncompressed_size()
+                }
+                Self::Pseudonym(s) => 16u8.uncompressed_size() + s.uncompressed_size(),
+                Self::HolderBinding(s) => 17u8.uncompressed_size() + s.uncompressed_size(),
             }
         }
     }
 
-    impl<E: PairingEngine, G: AffineCurve> CanonicalDeserialize for Statement<E, G> {
+    impl<E: PairingEngine, G: AffineCurve, G2: AffineCurve> CanonicalDeserialize
+        for Statement<E, G, G2>
+    {
         fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
             let t: u8 = CanonicalDeserialize::deserialize(&mut reader)?;
             match t {
@@ -289,6 +440,30 @@ mod serialization {
                 9u8 => Ok(Self::R1CSCircomVerifier(CanonicalDeserialize::deserialize(
                     &mut reader,
                 )?)),
+                10u8 => Ok(Self::PoKIetfBBSSignatureG1(
+                    CanonicalDeserialize::deserialize(&mut reader)?,
+                )),
+                11u8 => Ok(Self::SetMembership(CanonicalDeserialize::deserialize(
+                    &mut reader,
+                )?)),
+                12u8 => Ok(Self::Inequality(CanonicalDeserialize::deserialize(
+                    &mut reader,
+                )?)),
+                13u8 => Ok(Self::BoundCheckBulletproofs(
+                    CanonicalDeserialize::deserialize(&mut reader)?,
+                )),
+                14u8 => Ok(Self::BoundCheckSmc(CanonicalDeserialize::deserialize(
+                    &mut reader,
+                )?)),
+                15u8 => Ok(Self::DiscreteLogEqualityAcrossGroups(
+                    CanonicalDeserialize::deserialize(&mut reader)?,
+                )),
+                16u8 => Ok(Self::Pseudonym(CanonicalDeserialize::deserialize(
+                    &mut reader,
+                )?)),
+                17u8 => Ok(Self::HolderBinding(CanonicalDeserialize::deserialize(
+                    &mut reader,
+                )?)),
                 _ => Err(SerializationError::InvalidData),
             }
         }
@@ -325,6 +500,30 @@ mod serialization {
                 9u8 => Ok(Self::R1CSCircomVerifier(
                     CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
                 )),
+                10u8 => Ok(Self::PoKIetfBBSSignatureG1(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
+                11u8 => Ok(Self::SetMembership(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
+                12u8 => Ok(Self::Inequality(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
+                13u8 => Ok(Self::BoundCheckBulletproofs(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
+                14u8 => Ok(Self::BoundCheckSmc(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
+                15u8 => Ok(Self::DiscreteLogEqualityAcrossGroups(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
+                16u8 => Ok(Self::Pseudonym(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
+                17u8 => Ok(Self::HolderBinding(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
                 _ => Err(SerializationError::InvalidData),
             }
         }
@@ -361,6 +560,30 @@ mod serialization {
                 9u8 => Ok(Self::R1CSCircomVerifier(
                     CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
                 )),
+                10u8 => Ok(Self::PoKIetfBBSSignatureG1(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
+                11u8 => Ok(Self::SetMembership(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
+                12u8 => Ok(Self::Inequality(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
+                13u8 => Ok(Self::BoundCheckBulletproofs(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
+                14u8 => Ok(Self::BoundCheckSmc(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
+                15u8 => Ok(Self::DiscreteLogEqualityAcrossGroups(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
+                16u8 => Ok(Self::Pseudonym(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
+                17u8 => Ok(Self::HolderBinding(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
                 _ => Err(SerializationError::InvalidData),
             }
         }
@@ -370,6 +593,8 @@ mod serialization {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ::bbs_plus::bbs::Bls12381Sha256;
+    use ::bbs_plus::prelude::{KeypairG2, SignatureParamsG1};
     use ark_bls12_381::Bls12_381;
     use ark_bls12_381::{fr::Fr, g1::G1Projective as G1Proj};
     use ark_ec::msm::VariableBaseMSM;
@@ -380,9 +605,15 @@ mod tests {
         rand::{rngs::StdRng, SeedableRng},
         UniformRand,
     };
+    use blake2::Blake2b;
+    use bulletproofs::setup::SetupParams as BulletproofsSetupParams;
+    use compressed_sigma::set_membership::SetMembershipParams;
+    use discrete_log_equality_across_groups::DiscreteLogEqualityAcrossGroups;
+    use schnorr_pok::discrete_log_equality_across_groups::CrossGroupCommitmentKey;
     use test_utils::test_serialization;
     use test_utils::{
         accumulators::{setup_positive_accum, setup_universal_accum},
+        bbs::ietf_bbs_sig_setup,
         bbs_plus::sig_setup,
     };
     use vb_accumulator::prelude::{Accumulator, MembershipProvingKey, NonMembershipProvingKey};
@@ -417,6 +648,7 @@ mod tests {
 
         let stmt_2 = accumulator::AccumulatorMembership::new_statement_from_params::<
             <Bls12_381 as PairingEngine>::G1Affine,
+            <Bls12_381 as PairingEngine>::G2Affine,
         >(
             pos_params.clone(),
             pos_keypair.public_key.clone(),
@@ -430,6 +662,7 @@ mod tests {
 
         let stmt_3 = accumulator::AccumulatorNonMembership::new_statement_from_params::<
             <Bls12_381 as PairingEngine>::G1Affine,
+            <Bls12_381 as PairingEngine>::G2Affine,
         >(
             uni_params.clone(),
             uni_keypair.public_key.clone(),
@@ -455,5 +688,122 @@ mod tests {
 
         statements.add(stmt_4);
         test_serialization!(Statements<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, statements);
+
+        let (_, params_2, keypair_2, _) = ietf_bbs_sig_setup(&mut rng, 5, b"header");
+        let mut revealed = BTreeMap::new();
+        revealed.insert(1, Fr::rand(&mut rng));
+        let stmt_5 = bbs::PoKIetfBBSSignatureG1::new_statement_from_params::<Bls12381Sha256, _, _>(
+            params_2,
+            keypair_2.public_key.clone(),
+            b"header".to_vec(),
+            revealed,
+        );
+        test_serialization!(Statement<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, stmt_5);
+
+        statements.add(stmt_5);
+        test_serialization!(Statements<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, statements);
+
+        let set_params = SetMembershipParams::<<Bls12_381 as PairingEngine>::G1Affine>::new::<
+            Blake2b,
+        >(b"test", 5);
+        let stmt_8 = set_membership::SetMembership::new_statement_from_params::<Bls12_381, _>(
+            set_params,
+            (0..5).map(|_| Fr::rand(&mut rng)).collect(),
+        );
+        test_serialization!(Statement<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, stmt_8);
+
+        statements.add(stmt_8);
+        test_serialization!(Statements<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, statements);
+
+        let stmt_9 = inequality::Inequality::new_statement_from_params::<Bls12_381, _>(
+            vec![
+                G1Proj::rand(&mut rng).into_affine(),
+                G1Proj::rand(&mut rng).into_affine(),
+            ],
+            Fr::rand(&mut rng),
+        );
+        test_serialization!(Statement<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, stmt_9);
+
+        statements.add(stmt_9);
+        test_serialization!(Statements<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, statements);
+
+        let bp_params = BulletproofsSetupParams::<<Bls12_381 as PairingEngine>::G1Affine>::new::<
+            Blake2b,
+        >(b"test", 64);
+        let stmt_10 =
+            bound_check_bulletproofs::BoundCheckBulletproofs::new_statement_from_params::<
+                Bls12_381,
+                _,
+            >(bp_params, vec![(10, 100), (5, 1000)])
+            .unwrap();
+        test_serialization!(Statement<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, stmt_10);
+
+        statements.add(stmt_10);
+        test_serialization!(Statements<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, statements);
+
+        let smc_sig_params = SignatureParamsG1::<Bls12_381>::generate_using_rng(&mut rng, 1);
+        let smc_keypair = KeypairG2::<Bls12_381>::generate_using_rng(&mut rng, &smc_sig_params);
+        let smc_params =
+            bound_check_smc::SmcParams::new(&mut rng, 4, smc_sig_params, &smc_keypair).unwrap();
+        let stmt_11 = bound_check_smc::BoundCheckSmc::new_statement_from_params::<
+            <Bls12_381 as PairingEngine>::G1Affine,
+            <Bls12_381 as PairingEngine>::G2Affine,
+        >(10, 100, smc_params)
+        .unwrap();
+        test_serialization!(Statement<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, stmt_11);
+
+        statements.add(stmt_11);
+        test_serialization!(Statements<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, statements);
+
+        let cross_group_comm_key = CrossGroupCommitmentKey::<
+            <Bls12_381 as PairingEngine>::G1Affine,
+            <Bls12_381 as PairingEngine>::G2Affine,
+        >::new::<Blake2b>(b"test");
+        let stmt_12 = DiscreteLogEqualityAcrossGroups::new_statement_from_params::<Bls12_381>(
+            <Bls12_381 as PairingEngine>::G2Projective::rand(&mut rng).into_affine(),
+            64,
+            cross_group_comm_key,
+        )
+        .unwrap();
+        test_serialization!(Statement<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, stmt_12);
+
+        statements.add(stmt_12);
+        test_serialization!(Statements<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, statements);
+
+        let stmt_13 = pseudonym::Pseudonym::<Bls12_381>::new_statement::<
+            <Bls12_381 as PairingEngine>::G1Affine,
+            <Bls12_381 as PairingEngine>::G2Affine,
+        >(
+            b"test-scope".to_vec(),
+            b"TEST-PSEUDONYM_XMD:SHA-256_SSWU_RO_".to_vec(),
+            <Bls12_381 as PairingEngine>::G1Projective::rand(&mut rng).into_affine(),
+        );
+        test_serialization!(Statement<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, stmt_13);
+
+        statements.add(stmt_13);
+        test_serialization!(Statements<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, statements);
+
+        let stmt_14 = holder_binding::HolderBinding::new_statement_from_params::<Bls12_381>(
+            G1Proj::rand(&mut rng).into_affine(),
+            G1Proj::rand(&mut rng).into_affine(),
+        );
+        test_serialization!(Statement<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, stmt_14);
+
+        statements.add(stmt_14);
+        test_serialization!(Statements<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, statements);
+
+        let stmt_15 =
+            holder_binding::HolderBinding::new_statement_for_other_group_from_params_ref::<
+                Bls12_381,
+            >(
+                <Bls12_381 as PairingEngine>::G2Projective::rand(&mut rng).into_affine(),
+                64,
+                0,
+            )
+            .unwrap();
+        test_serialization!(Statement<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, stmt_15);
+
+        statements.add(stmt_15);
+        test_serialization!(Statements<Bls12_381, <Bls12_381 as PairingEngine>::G1Affine>, statements);
     }
 }
@@ -30,10 +30,10 @@ pub struct PedersenCommitment<G: AffineCurve> {
 
 /// Create a `Statement` variant for proving knowledge of committed elements in a Pedersen commitment
 impl<G: AffineCurve> PedersenCommitment<G> {
-    pub fn new_statement_from_params<E: PairingEngine>(
+    pub fn new_statement_from_params<E: PairingEngine, G2: AffineCurve>(
         key: Vec<G>,
         commitment: G,
-    ) -> Statement<E, G> {
+    ) -> Statement<E, G, G2> {
         Statement::PedersenCommitment(Self {
             commitment,
             key: Some(key),
@@ -41,10 +41,10 @@ impl<G: AffineCurve> PedersenCommitment<G> {
         })
     }
 
-    pub fn new_statement_from_params_refs<E: PairingEngine>(
+    pub fn new_statement_from_params_refs<E: PairingEngine, G2: AffineCurve>(
         key_ref: usize,
         commitment: G,
-    ) -> Statement<E, G> {
+    ) -> Statement<E, G, G2> {
         Statement::PedersenCommitment(Self {
             commitment,
             key: None,
@@ -52,9 +52,9 @@ impl<G: AffineCurve> PedersenCommitment<G> {
         })
     }
 
-    pub fn get_commitment_key<'a, E: PairingEngine>(
+    pub fn get_commitment_key<'a, E: PairingEngine, G2: AffineCurve>(
         &'a self,
-        setup_params: &'a [SetupParams<E, G>],
+        setup_params: &'a [SetupParams<E, G, G2>],
         st_idx: usize,
     ) -> Result<&'a Vec<G>, ProofSystemError> {
         extract_param!(
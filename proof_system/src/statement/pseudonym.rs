@@ -0,0 +1,70 @@
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Write},
+    vec::Vec,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use sha2::Sha256;
+
+use crate::error::ProofSystemError;
+use crate::statement::Statement;
+use dock_crypto_utils::hashing_utils::affine_group_elem_from_hash_to_curve_if_supported;
+use dock_crypto_utils::serde_utils::*;
+
+/// Proving that `pseudonym = base * secret` where `base` is created by hashing a scope (like the verifier's
+/// identifier) and the `secret` is a witness, usually a signed message that is proven equal to the `secret`
+/// using a witness equality. As the pseudonym for a scope is always the same for a secret, a verifier
+/// can recognize repeat provers, but pseudonyms for different scopes are not linkable.
+///
+/// The statement contains the scope and not the base so the prover and the verifier both create the base
+/// from the scope, see [`base_for_scope`].
+#[serde_as]
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct Pseudonym<E: PairingEngine> {
+    /// The scope which is hashed to get the base
+    pub scope: Vec<u8>,
+    /// Domain separation tag for hashing the scope
+    pub dst: Vec<u8>,
+    #[serde_as(as = "AffineGroupBytes")]
+    pub pseudonym: E::G1Affine,
+}
+
+impl<E: PairingEngine> Pseudonym<E> {
+    /// Create a statement by passing the scope and the domain separation tag used to hash it
+    pub fn new_statement<G: AffineCurve, G2: AffineCurve>(
+        scope: Vec<u8>,
+        dst: Vec<u8>,
+        pseudonym: E::G1Affine,
+    ) -> Statement<E, G, G2> {
+        Statement::Pseudonym(Self {
+            scope,
+            dst,
+            pseudonym,
+        })
+    }
+
+    /// Get the base by hashing the scope
+    pub fn get_base(&self, st_idx: usize) -> Result<E::G1Affine, ProofSystemError> {
+        base_for_scope::<E>(&self.scope, &self.dst)
+            .ok_or(ProofSystemError::PseudonymGroupWithoutHashToCurve(st_idx))
+    }
+}
+
+/// Get the base for the scope using the hash to curve of RFC 9380 with SHA-256 and domain separation tag `dst`,
+/// like `MY-APP-PSEUDONYM_XMD:SHA-256_SSWU_RO_`, so that the prover and verifier (or other implementations)
+/// get the same base without trusting each other. Returns `None` if hash to curve is not implemented for group G1
+/// of the pairing.
+pub fn base_for_scope<E: PairingEngine>(scope: &[u8], dst: &[u8]) -> Option<E::G1Affine> {
+    affine_group_elem_from_hash_to_curve_if_supported::<E::G1Affine, Sha256>(scope, dst)
+}
+
+/// Used by the prover to create the pseudonym for the given base and secret
+pub fn compute_pseudonym<G: AffineCurve>(base: &G, secret: &G::ScalarField) -> G {
+    base.mul(secret.into_repr()).into_affine()
+}
@@ -43,11 +43,11 @@ pub struct R1CSCircomVerifier<E: PairingEngine> {
 }
 
 impl<E: PairingEngine> R1CSCircomProver<E> {
-    pub fn new_statement_from_params<G: AffineCurve>(
+    pub fn new_statement_from_params<G: AffineCurve, G2: AffineCurve>(
         r1cs: R1CS<E>,
         wasm_bytes: Vec<u8>,
         snark_proving_key: ProvingKey<E>,
-    ) -> Result<Statement<E, G>, ProofSystemError> {
+    ) -> Result<Statement<E, G, G2>, ProofSystemError> {
         Ok(Statement::R1CSCircomProver(Self {
             r1cs: Some(r1cs),
             r1cs_ref: None,
@@ -58,11 +58,11 @@ impl<E: PairingEngine> R1CSCircomProver<E> {
         }))
     }
 
-    pub fn new_statement_from_params_ref<G: AffineCurve>(
+    pub fn new_statement_from_params_ref<G: AffineCurve, G2: AffineCurve>(
         r1cs_ref: usize,
         wasm_bytes_ref: usize,
         snark_proving_key_ref: usize,
-    ) -> Result<Statement<E, G>, ProofSystemError> {
+    ) -> Result<Statement<E, G, G2>, ProofSystemError> {
         Ok(Statement::R1CSCircomProver(Self {
             r1cs: None,
             r1cs_ref: Some(r1cs_ref),
@@ -73,9 +73,9 @@ impl<E: PairingEngine> R1CSCircomProver<E> {
         }))
     }
 
-    pub fn get_r1cs<'a, G: AffineCurve>(
+    pub fn get_r1cs<'a, G: AffineCurve, G2: AffineCurve>(
         &'a self,
-        setup_params: &'a [SetupParams<E, G>],
+        setup_params: &'a [SetupParams<E, G, G2>],
         st_idx: usize,
     ) -> Result<&'a R1CS<E>, ProofSystemError> {
         extract_param!(
@@ -88,9 +88,9 @@ impl<E: PairingEngine> R1CSCircomProver<E> {
         )
     }
 
-    pub fn get_wasm_bytes<'a, G: AffineCurve>(
+    pub fn get_wasm_bytes<'a, G: AffineCurve, G2: AffineCurve>(
         &'a self,
-        setup_params: &'a [SetupParams<E, G>],
+        setup_params: &'a [SetupParams<E, G, G2>],
         st_idx: usize,
     ) -> Result<&'a Vec<u8>, ProofSystemError> {
         extract_param!(
@@ -103,9 +103,9 @@ impl<E: PairingEngine> R1CSCircomProver<E> {
         )
     }
 
-    pub fn get_proving_key<'a, G: AffineCurve>(
+    pub fn get_proving_key<'a, G: AffineCurve, G2: AffineCurve>(
         &'a self,
-        setup_params: &'a [SetupParams<E, G>],
+        setup_params: &'a [SetupParams<E, G, G2>],
         st_idx: usize,
     ) -> Result<&'a ProvingKey<E>, ProofSystemError> {
         extract_param!(
@@ -120,10 +120,10 @@ impl<E: PairingEngine> R1CSCircomProver<E> {
 }
 
 impl<E: PairingEngine> R1CSCircomVerifier<E> {
-    pub fn new_statement_from_params<G: AffineCurve>(
+    pub fn new_statement_from_params<G: AffineCurve, G2: AffineCurve>(
         public_inputs: Vec<E::Fr>,
         snark_verifying_key: VerifyingKey<E>,
-    ) -> Result<Statement<E, G>, ProofSystemError> {
+    ) -> Result<Statement<E, G, G2>, ProofSystemError> {
         Ok(Statement::R1CSCircomVerifier(Self {
             public_inputs: Some(public_inputs),
             public_inputs_ref: None,
@@ -132,10 +132,10 @@ impl<E: PairingEngine> R1CSCircomVerifier<E> {
         }))
     }
 
-    pub fn new_statement_from_params_ref<G: AffineCurve>(
+    pub fn new_statement_from_params_ref<G: AffineCurve, G2: AffineCurve>(
         public_inputs_ref: usize,
         snark_verifying_key_ref: usize,
-    ) -> Result<Statement<E, G>, ProofSystemError> {
+    ) -> Result<Statement<E, G, G2>, ProofSystemError> {
         Ok(Statement::R1CSCircomVerifier(Self {
             public_inputs: None,
             public_inputs_ref: Some(public_inputs_ref),
@@ -144,9 +144,9 @@ impl<E: PairingEngine> R1CSCircomVerifier<E> {
         }))
     }
 
-    pub fn get_public_inputs<'a, G: AffineCurve>(
+    pub fn get_public_inputs<'a, G: AffineCurve, G2: AffineCurve>(
         &'a self,
-        setup_params: &'a [SetupParams<E, G>],
+        setup_params: &'a [SetupParams<E, G, G2>],
         st_idx: usize,
     ) -> Result<&'a Vec<E::Fr>, ProofSystemError> {
         extract_param!(
@@ -159,9 +159,9 @@ impl<E: PairingEngine> R1CSCircomVerifier<E> {
         )
     }
 
-    pub fn get_verifying_key<'a, G: AffineCurve>(
+    pub fn get_verifying_key<'a, G: AffineCurve, G2: AffineCurve>(
         &'a self,
-        setup_params: &'a [SetupParams<E, G>],
+        setup_params: &'a [SetupParams<E, G, G2>],
         st_idx: usize,
     ) -> Result<&'a VerifyingKey<E>, ProofSystemError> {
         extract_param!(
@@ -0,0 +1,74 @@
+use ark_ec::{AffineCurve, PairingEngine};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Write},
+    vec::Vec,
+};
+use compressed_sigma::set_membership::SetMembershipParams;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::error::ProofSystemError;
+use crate::setup_params::SetupParams;
+use crate::statement::Statement;
+use crate::util::SetMembershipParamsBytes;
+use dock_crypto_utils::serde_utils::*;
+
+/// Proving that the witness is one of the values of a public set, like a signed message being one of a list of
+/// countries, without revealing which one. The witness is committed in a Pedersen commitment and a proof of
+/// partial knowledge (1-out-of-n) shows that the commitment opens to one of the set's values. The proof size
+/// is logarithmic in the size of the set.
+#[serde_as]
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct SetMembership<G: AffineCurve> {
+    /// The set with at least 2 values
+    #[serde_as(as = "Vec<FieldBytes>")]
+    pub set: Vec<G::ScalarField>,
+    #[serde_as(as = "Option<SetMembershipParamsBytes>")]
+    pub params: Option<SetMembershipParams<G>>,
+    pub params_ref: Option<usize>,
+}
+
+impl<G: AffineCurve> SetMembership<G> {
+    /// Create a statement by passing the params directly. The params should support sets at least as large as `set`
+    pub fn new_statement_from_params<E: PairingEngine, G2: AffineCurve>(
+        params: SetMembershipParams<G>,
+        set: Vec<G::ScalarField>,
+    ) -> Statement<E, G, G2> {
+        Statement::SetMembership(Self {
+            set,
+            params: Some(params),
+            params_ref: None,
+        })
+    }
+
+    /// Create a statement by passing an index to the params in the `setup_params`
+    pub fn new_statement_from_params_ref<E: PairingEngine, G2: AffineCurve>(
+        params_ref: usize,
+        set: Vec<G::ScalarField>,
+    ) -> Statement<E, G, G2> {
+        Statement::SetMembership(Self {
+            set,
+            params: None,
+            params_ref: Some(params_ref),
+        })
+    }
+
+    pub fn get_params<'a, E: PairingEngine, G2: AffineCurve>(
+        &'a self,
+        setup_params: &'a [SetupParams<E, G, G2>],
+        st_idx: usize,
+    ) -> Result<&'a SetMembershipParams<G>, ProofSystemError> {
+        extract_param!(
+            setup_params,
+            &self.params,
+            self.params_ref,
+            SetMembershipParams,
+            IncompatibleSetMembershipSetupParamAtIndex,
+            st_idx
+        )
+    }
+}
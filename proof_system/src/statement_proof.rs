@@ -1,22 +1,32 @@
 use ark_ec::{AffineCurve, PairingEngine};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use ark_std::io::{Read, Write};
+use bbs_plus::bbs::PoKOfSignatureG1Proof as IetfBBSPoKOfSignatureG1Proof;
 use bbs_plus::prelude::PoKOfSignatureG1Proof;
+use bulletproofs::range_proof::RangeProof;
+use compressed_sigma::set_membership::SetMembershipProof as SetMembershipOrProof;
 use dock_crypto_utils::serde_utils::*;
 use saver::encryption::Ciphertext;
-use schnorr_pok::SchnorrResponse;
+use schnorr_pok::{
+    discrete_log_equality_across_groups::PoKDiscreteLogEqualityAcrossGroupsProof,
+    error::SchnorrError, SchnorrResponse,
+};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use vb_accumulator::prelude::{MembershipProof, NonMembershipProof};
 
 use crate::error::ProofSystemError;
-use crate::util::{LegoProofBytes, ProofBytes};
+use crate::util::{LegoProofBytes, ProofBytes, SetMembershipOrProofBytes};
 pub use serialization::*;
 
-/// Proof corresponding to one `Statement`
+/// Proof corresponding to one `Statement`. `G2` is the other group of the `DiscreteLogEqualityAcrossGroups` statements.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(bound = "")]
-pub enum StatementProof<E: PairingEngine, G: AffineCurve> {
+pub enum StatementProof<
+    E: PairingEngine,
+    G: AffineCurve,
+    G2: AffineCurve = <E as PairingEngine>::G2Affine,
+> {
     PoKBBSSignatureG1(PoKOfSignatureG1Proof<E>),
     AccumulatorMembership(MembershipProof<E>),
     AccumulatorNonMembership(NonMembershipProof<E>),
@@ -24,6 +34,14 @@ pub enum StatementProof<E: PairingEngine, G: AffineCurve> {
     Saver(SaverProof<E>),
     BoundCheckLegoGroth16(BoundCheckLegoGroth16Proof<E>),
     R1CSLegoGroth16(R1CSLegoGroth16Proof<E>),
+    PoKIetfBBSSignatureG1(IetfBBSPoKOfSignatureG1Proof<E>),
+    SetMembership(SetMembershipProof<G>),
+    Inequality(InequalityProof<G>),
+    BoundCheckBulletproofs(BoundCheckBulletproofsProof<G>),
+    BoundCheckSmc(BoundCheckSmcProof<E>),
+    DiscreteLogEqualityAcrossGroups(DiscreteLogEqualityAcrossGroupsProof<G, G2>),
+    Pseudonym(PedersenCommitmentProof<E::G1Affine>),
+    HolderBinding(HolderBindingProof<G, G2>),
 }
 
 #[serde_as]
@@ -107,17 +125,161 @@ impl<E: PairingEngine> R1CSLegoGroth16Proof<E> {
     }
 }
 
+#[serde_as]
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct SetMembershipProof<G: AffineCurve> {
+    /// Pedersen commitment to the member
+    #[serde_as(as = "AffineGroupBytes")]
+    pub commitment: G,
+    /// Proof of knowledge of the opening of `commitment`
+    pub sp: PedersenCommitmentProof<G>,
+    /// Proof that `commitment` opens to a member of the set
+    #[serde_as(as = "SetMembershipOrProofBytes")]
+    pub or_proof: SetMembershipOrProof<G>,
+}
+
+impl<G: AffineCurve> SetMembershipProof<G> {
+    pub fn get_schnorr_response_for_member(&self) -> Result<&G::ScalarField, ProofSystemError> {
+        self.sp.response.get_response(0).map_err(|e| e.into())
+    }
+}
+
+#[serde_as]
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct InequalityProof<G: AffineCurve> {
+    /// Pedersen commitment `C = g * m + h * r` to the witness `m`
+    #[serde_as(as = "AffineGroupBytes")]
+    pub commitment: G,
+    /// Proof of knowledge of the opening of `commitment`
+    pub sp: PedersenCommitmentProof<G>,
+    /// Proof of knowledge of `k = 1/(m - v)` and `-r * k` in `g = (C - g * v) * k + h * (-r * k)` where `v` is the
+    /// public value
+    pub sp_inverse: PedersenCommitmentProof<G>,
+}
+
+impl<G: AffineCurve> InequalityProof<G> {
+    pub fn get_schnorr_response_for_message(&self) -> Result<&G::ScalarField, ProofSystemError> {
+        self.sp.response.get_response(0).map_err(|e| e.into())
+    }
+}
+
+#[serde_as]
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct BoundCheckBulletproofsProof<G: AffineCurve> {
+    /// Pedersen commitments `g * m_i + h * r_i` to the witnesses, with `g` and `h` from the Bulletproofs setup params
+    #[serde_as(as = "Vec<AffineGroupBytes>")]
+    pub commitments: Vec<G>,
+    /// Proofs of knowledge of the openings of `commitments`, one for each commitment
+    pub sp: Vec<PedersenCommitmentProof<G>>,
+    /// Range proof for the values `m_i - min_i` and `max_i - m_i` which are committed in `C_i - g * min_i` and
+    /// `g * max_i - C_i` respectively
+    pub range_proof: RangeProof<G>,
+}
+
+impl<G: AffineCurve> BoundCheckBulletproofsProof<G> {
+    pub fn get_schnorr_response_for_message(
+        &self,
+        index: usize,
+    ) -> Result<&G::ScalarField, ProofSystemError> {
+        self.sp
+            .get(index)
+            .ok_or(SchnorrError::IndexOutOfBounds(index, self.sp.len()))?
+            .response
+            .get_response(0)
+            .map_err(|e| e.into())
+    }
+}
+
+#[serde_as]
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct BoundCheckSmcProof<E: PairingEngine> {
+    /// Response of the Schnorr protocol for the witness
+    #[serde_as(as = "FieldBytes")]
+    pub resp_for_witness: E::Fr,
+    /// Proofs of knowledge of signatures on the digits of `m - min`, least significant digit first
+    pub min_digit_proofs: Vec<PoKOfSignatureG1Proof<E>>,
+    /// Proofs of knowledge of signatures on the digits of `m - (max + 1) + u^l`, least significant digit first
+    pub max_digit_proofs: Vec<PoKOfSignatureG1Proof<E>>,
+}
+
+impl<E: PairingEngine> BoundCheckSmcProof<E> {
+    pub fn get_schnorr_response_for_message(&self) -> &E::Fr {
+        &self.resp_for_witness
+    }
+}
+
+#[serde_as]
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct DiscreteLogEqualityAcrossGroupsProof<G1: AffineCurve, G2: AffineCurve> {
+    /// Pedersen commitment `C = g1 * m + h1 * r` to the witness `m`
+    #[serde_as(as = "AffineGroupBytes")]
+    pub commitment: G1,
+    /// Proof of knowledge of the opening of `commitment`
+    pub sp: PedersenCommitmentProof<G1>,
+    /// Proof that the integer committed in `commitment` is the discrete log of the public value in `G2`
+    pub cross_group_proof: PoKDiscreteLogEqualityAcrossGroupsProof<G1, G2>,
+}
+
+impl<G1: AffineCurve, G2: AffineCurve> DiscreteLogEqualityAcrossGroupsProof<G1, G2> {
+    pub fn get_schnorr_response_for_message(&self) -> Result<&G1::ScalarField, ProofSystemError> {
+        self.sp.response.get_response(0).map_err(|e| e.into())
+    }
+}
+
+/// Proof for the `HolderBinding` statement. Only the proof for the group of the holder's public key is set.
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct HolderBindingProof<G1: AffineCurve, G2: AffineCurve> {
+    /// Proof of knowledge of the secret key of the public key in the group of the proof
+    pub same_group: Option<PedersenCommitmentProof<G1>>,
+    /// Proof of knowledge of the secret key of the public key in the other group
+    pub other_group: Option<DiscreteLogEqualityAcrossGroupsProof<G1, G2>>,
+}
+
+impl<G1: AffineCurve, G2: AffineCurve> HolderBindingProof<G1, G2> {
+    pub fn get_schnorr_response_for_secret_key(
+        &self,
+    ) -> Result<&G1::ScalarField, ProofSystemError> {
+        match (&self.same_group, &self.other_group) {
+            (Some(p), None) => p.response.get_response(0).map_err(|e| e.into()),
+            (None, Some(p)) => p.get_schnorr_response_for_message(),
+            _ => Err(ProofSystemError::ProofIncompatibleWithHolderBindingProtocol),
+        }
+    }
+}
+
 mod serialization {
     use super::{
         AffineCurve, CanonicalDeserialize, CanonicalSerialize, PairingEngine, Read,
         SerializationError, StatementProof, Write,
     };
 
-    impl<E: PairingEngine, G: AffineCurve> CanonicalSerialize for StatementProof<E, G> {
+    impl<E: PairingEngine, G: AffineCurve, G2: AffineCurve> CanonicalSerialize
+        for StatementProof<E, G, G2>
+    {
         impl_serialize!();
     }
 
-    impl<E: PairingEngine, G: AffineCurve> CanonicalDeserialize for StatementProof<E, G> {
+    impl<E: PairingEngine, G: AffineCurve, G2: AffineCurve> CanonicalDeserialize
+        for StatementProof<E, G, G2>
+    {
         impl_deserialize!();
     }
 }
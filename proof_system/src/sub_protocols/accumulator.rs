@@ -3,6 +3,7 @@ use crate::statement_proof::StatementProof;
 use ark_ec::{AffineCurve, PairingEngine};
 use ark_std::io::Write;
 use ark_std::rand::RngCore;
+use dock_crypto_utils::transcript::Transcript;
 use vb_accumulator::prelude::{
     MembershipProofProtocol, MembershipProvingKey, NonMembershipProofProtocol,
     NonMembershipProvingKey, PublicKey, SetupParams as AccumParams,
@@ -84,10 +85,29 @@ impl<'a, E: PairingEngine> AccumulatorMembershipSubProtocol<'a, E> {
         Ok(())
     }
 
-    pub fn gen_proof_contribution<G: AffineCurve>(
+    pub fn append_to_transcript(
+        &self,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        if self.protocol.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        self.protocol.as_ref().unwrap().append_to_transcript(
+            &self.accumulator_value,
+            self.public_key,
+            self.params,
+            self.proving_key,
+            transcript,
+        )?;
+        Ok(())
+    }
+
+    pub fn gen_proof_contribution<G: AffineCurve, G2: AffineCurve>(
         &mut self,
         challenge: &E::Fr,
-    ) -> Result<StatementProof<E, G>, ProofSystemError> {
+    ) -> Result<StatementProof<E, G, G2>, ProofSystemError> {
         if self.protocol.is_none() {
             return Err(ProofSystemError::SubProtocolNotReadyToGenerateProof(
                 self.id,
@@ -98,10 +118,10 @@ impl<'a, E: PairingEngine> AccumulatorMembershipSubProtocol<'a, E> {
         Ok(StatementProof::AccumulatorMembership(proof))
     }
 
-    pub fn verify_proof_contribution<G: AffineCurve>(
+    pub fn verify_proof_contribution<G: AffineCurve, G2: AffineCurve>(
         &self,
         challenge: &E::Fr,
-        proof: &StatementProof<E, G>,
+        proof: &StatementProof<E, G, G2>,
     ) -> Result<(), ProofSystemError> {
         match proof {
             StatementProof::AccumulatorMembership(p) => {
@@ -175,10 +195,29 @@ impl<'a, E: PairingEngine> AccumulatorNonMembershipSubProtocol<'a, E> {
         Ok(())
     }
 
-    pub fn gen_proof_contribution<G: AffineCurve>(
+    pub fn append_to_transcript(
+        &self,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        if self.protocol.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        self.protocol.as_ref().unwrap().append_to_transcript(
+            &self.accumulator_value,
+            self.public_key,
+            self.params,
+            self.proving_key,
+            transcript,
+        )?;
+        Ok(())
+    }
+
+    pub fn gen_proof_contribution<G: AffineCurve, G2: AffineCurve>(
         &mut self,
         challenge: &E::Fr,
-    ) -> Result<StatementProof<E, G>, ProofSystemError> {
+    ) -> Result<StatementProof<E, G, G2>, ProofSystemError> {
         if self.protocol.is_none() {
             return Err(ProofSystemError::SubProtocolNotReadyToGenerateProof(
                 self.id,
@@ -189,10 +228,10 @@ impl<'a, E: PairingEngine> AccumulatorNonMembershipSubProtocol<'a, E> {
         Ok(StatementProof::AccumulatorNonMembership(proof))
     }
 
-    pub fn verify_proof_contribution<G: AffineCurve>(
+    pub fn verify_proof_contribution<G: AffineCurve, G2: AffineCurve>(
         &self,
         challenge: &E::Fr,
-        proof: &StatementProof<E, G>,
+        proof: &StatementProof<E, G, G2>,
     ) -> Result<(), ProofSystemError> {
         match proof {
             StatementProof::AccumulatorNonMembership(p) => {
@@ -0,0 +1,261 @@
+use ark_ec::{AffineCurve, PairingEngine};
+use ark_serialize::{CanonicalSerialize, SerializationError};
+use ark_std::rand::RngCore;
+use ark_std::{
+    collections::{BTreeMap, BTreeSet},
+    io::Write,
+    vec::Vec,
+};
+use bbs_plus::bbs::{
+    calculate_domain_for_ciphersuite, PoKOfSignatureG1Proof, PoKOfSignatureG1Protocol,
+};
+use bbs_plus::prelude::{PublicKeyG2, SignatureParamsG1};
+use dock_crypto_utils::transcript::Transcript;
+
+use crate::error::ProofSystemError;
+use crate::statement_proof::StatementProof;
+
+/// Sub-protocol for proof of knowledge of BBS signature as per the IETF draft. The challenge contribution
+/// contains the draft's items, the public key and the header but uses this crate's serialization. The `domain`
+/// is computed from the public key, params and header with the ciphersuite identified by `ciphersuite_id`
+/// whenever needed so that it always matches them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoKIetfBBSSigG1SubProtocol<'a, E: PairingEngine> {
+    pub id: usize,
+    pub revealed_messages: &'a BTreeMap<usize, E::Fr>,
+    pub header: &'a [u8],
+    pub ciphersuite_id: &'a [u8],
+    pub signature_params: &'a SignatureParamsG1<E>,
+    pub public_key: &'a PublicKeyG2<E>,
+    pub protocol: Option<PoKOfSignatureG1Protocol<E>>,
+}
+
+impl<'a, E: PairingEngine> PoKIetfBBSSigG1SubProtocol<'a, E> {
+    pub fn new(
+        id: usize,
+        revealed_messages: &'a BTreeMap<usize, E::Fr>,
+        header: &'a [u8],
+        ciphersuite_id: &'a [u8],
+        signature_params: &'a SignatureParamsG1<E>,
+        public_key: &'a PublicKeyG2<E>,
+    ) -> Self {
+        Self {
+            id,
+            revealed_messages,
+            header,
+            ciphersuite_id,
+            signature_params,
+            public_key,
+            protocol: None,
+        }
+    }
+
+    pub fn init<R: RngCore>(
+        &mut self,
+        rng: &mut R,
+        blindings: BTreeMap<usize, E::Fr>,
+        mut witness: crate::witness::PoKIetfBBSSignatureG1<E>,
+    ) -> Result<(), ProofSystemError> {
+        if self.protocol.is_some() {
+            return Err(ProofSystemError::SubProtocolAlreadyInitialized(self.id));
+        }
+        // Create messages from revealed messages in statement and unrevealed in witness
+        let mut messages = Vec::with_capacity(self.signature_params.supported_message_count());
+        let mut revealed_indices = BTreeSet::new();
+        for i in 0..self.signature_params.supported_message_count() {
+            if witness.unrevealed_messages.contains_key(&i) {
+                messages.push(witness.unrevealed_messages.remove(&i).unwrap());
+            } else if self.revealed_messages.contains_key(&i) {
+                revealed_indices.insert(i);
+                messages.push(*self.revealed_messages.get(&i).unwrap());
+            } else {
+                return Err(ProofSystemError::IetfBBSProtocolMessageAbsent(self.id, i));
+            }
+        }
+        let protocol = PoKOfSignatureG1Protocol::init_with_domain(
+            rng,
+            &witness.signature,
+            self.signature_params,
+            self.domain()?,
+            &messages,
+            blindings,
+            revealed_indices,
+        )?;
+        self.protocol = Some(protocol);
+        Ok(())
+    }
+
+    pub fn challenge_contribution<W: Write>(&self, writer: W) -> Result<(), ProofSystemError> {
+        if self.protocol.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        let protocol = self.protocol.as_ref().unwrap();
+        self.compute_challenge_contribution(
+            &protocol.A_bar,
+            &protocol.B_bar,
+            &protocol.D,
+            &protocol.sc_comm_1.t,
+            &protocol.sc_comm_2.t,
+            writer,
+        )
+    }
+
+    pub fn append_to_transcript(
+        &self,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        if self.protocol.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        let protocol = self.protocol.as_ref().unwrap();
+        self.compute_transcript_contribution(
+            &protocol.A_bar,
+            &protocol.B_bar,
+            &protocol.D,
+            &protocol.sc_comm_1.t,
+            &protocol.sc_comm_2.t,
+            transcript,
+        )
+    }
+
+    pub fn gen_proof_contribution<G: AffineCurve, G2: AffineCurve>(
+        &mut self,
+        challenge: &E::Fr,
+    ) -> Result<StatementProof<E, G, G2>, ProofSystemError> {
+        if self.protocol.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateProof(
+                self.id,
+            ));
+        }
+        let protocol = self.protocol.take().unwrap();
+        let proof = protocol.gen_proof(challenge)?;
+        Ok(StatementProof::PoKIetfBBSSignatureG1(proof))
+    }
+
+    pub fn verify_proof_contribution<G: AffineCurve, G2: AffineCurve>(
+        &self,
+        challenge: &E::Fr,
+        proof: &StatementProof<E, G, G2>,
+    ) -> Result<(), ProofSystemError> {
+        match proof {
+            StatementProof::PoKIetfBBSSignatureG1(p) => {
+                p.verify_with_domain(
+                    self.revealed_messages,
+                    challenge,
+                    self.public_key,
+                    self.signature_params,
+                    &self.domain()?,
+                )?;
+                Ok(())
+            }
+            _ => Err(ProofSystemError::ProofIncompatibleWithIetfBBSProtocol),
+        }
+    }
+
+    /// Challenge contribution of a proof created by this protocol. Used by the verifier.
+    pub fn challenge_contribution_for_proof<W: Write>(
+        &self,
+        proof: &PoKOfSignatureG1Proof<E>,
+        writer: W,
+    ) -> Result<(), ProofSystemError> {
+        self.compute_challenge_contribution(
+            &proof.A_bar,
+            &proof.B_bar,
+            &proof.D,
+            &proof.T1,
+            &proof.T2,
+            writer,
+        )
+    }
+
+    /// Append the challenge contribution of a proof created by this protocol to the transcript. Used by the verifier.
+    pub fn append_proof_to_transcript(
+        &self,
+        proof: &PoKOfSignatureG1Proof<E>,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        self.compute_transcript_contribution(
+            &proof.A_bar,
+            &proof.B_bar,
+            &proof.D,
+            &proof.T1,
+            &proof.T2,
+            transcript,
+        )
+    }
+
+    /// Serializes the revealed messages, the public key, the header, the randomized signature, the Schnorr
+    /// commitments and the `domain`.
+    fn compute_challenge_contribution<W: Write>(
+        &self,
+        a_bar: &E::G1Affine,
+        b_bar: &E::G1Affine,
+        d: &E::G1Affine,
+        t1: &E::G1Affine,
+        t2: &E::G1Affine,
+        mut writer: W,
+    ) -> Result<(), ProofSystemError> {
+        // NOTE: Using `_unchecked` variants for serialization for speed
+        (self.revealed_messages.len() as u64).serialize_unchecked(&mut writer)?;
+        for (i, msg) in self.revealed_messages {
+            (*i as u64).serialize_unchecked(&mut writer)?;
+            msg.serialize_unchecked(&mut writer)?;
+        }
+        self.public_key.serialize_unchecked(&mut writer)?;
+        (self.header.len() as u64).serialize_unchecked(&mut writer)?;
+        writer
+            .write_all(self.header)
+            .map_err(SerializationError::from)?;
+        a_bar.serialize_unchecked(&mut writer)?;
+        b_bar.serialize_unchecked(&mut writer)?;
+        d.serialize_unchecked(&mut writer)?;
+        t1.serialize_unchecked(&mut writer)?;
+        t2.serialize_unchecked(&mut writer)?;
+        self.domain()?.serialize_unchecked(&mut writer)?;
+        Ok(())
+    }
+
+    /// Append the values of [`Self::compute_challenge_contribution`] to the transcript with a label each. The
+    /// lengths are appended as well so that the transcript gets the same bytes in compatibility mode.
+    fn compute_transcript_contribution(
+        &self,
+        a_bar: &E::G1Affine,
+        b_bar: &E::G1Affine,
+        d: &E::G1Affine,
+        t1: &E::G1Affine,
+        t2: &E::G1Affine,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        transcript.append_message(
+            b"revealed_msg_count",
+            &(self.revealed_messages.len() as u64).to_le_bytes(),
+        );
+        for (i, msg) in self.revealed_messages {
+            transcript.append_message(b"revealed_msg_index", &(*i as u64).to_le_bytes());
+            transcript.append_scalar(b"revealed_msg", msg)?;
+        }
+        transcript.append_point(b"public_key", &self.public_key.0)?;
+        transcript.append_message(b"header_length", &(self.header.len() as u64).to_le_bytes());
+        transcript.append_message(b"header", self.header);
+        transcript.append_point(b"A_bar", a_bar)?;
+        transcript.append_point(b"B_bar", b_bar)?;
+        transcript.append_point(b"D", d)?;
+        transcript.append_point(b"T1", t1)?;
+        transcript.append_point(b"T2", t2)?;
+        transcript.append_scalar(b"domain", &self.domain()?)?;
+        Ok(())
+    }
+
+    fn domain(&self) -> Result<E::Fr, ProofSystemError> {
+        Ok(calculate_domain_for_ciphersuite(
+            self.ciphersuite_id,
+            self.public_key,
+            self.signature_params,
+            self.header,
+        )?)
+    }
+}
@@ -7,6 +7,7 @@ use ark_std::{
 };
 use bbs_plus::prelude::{PublicKeyG2, SignatureParamsG1};
 use bbs_plus::proof::PoKOfSignatureG1Protocol;
+use dock_crypto_utils::transcript::Transcript;
 
 use crate::error::ProofSystemError;
 use crate::statement_proof::StatementProof;
@@ -84,10 +85,27 @@ impl<'a, E: PairingEngine> PoKBBSSigG1SubProtocol<'a, E> {
         Ok(())
     }
 
-    pub fn gen_proof_contribution<G: AffineCurve>(
+    pub fn append_to_transcript(
+        &self,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        if self.protocol.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        self.protocol.as_ref().unwrap().append_to_transcript(
+            self.revealed_messages,
+            self.signature_params,
+            transcript,
+        )?;
+        Ok(())
+    }
+
+    pub fn gen_proof_contribution<G: AffineCurve, G2: AffineCurve>(
         &mut self,
         challenge: &E::Fr,
-    ) -> Result<StatementProof<E, G>, ProofSystemError> {
+    ) -> Result<StatementProof<E, G, G2>, ProofSystemError> {
         if self.protocol.is_none() {
             return Err(ProofSystemError::SubProtocolNotReadyToGenerateProof(
                 self.id,
@@ -98,10 +116,10 @@ impl<'a, E: PairingEngine> PoKBBSSigG1SubProtocol<'a, E> {
         Ok(StatementProof::PoKBBSSignatureG1(proof))
     }
 
-    pub fn verify_proof_contribution<G: AffineCurve>(
+    pub fn verify_proof_contribution<G: AffineCurve, G2: AffineCurve>(
         &self,
         challenge: &E::Fr,
-        proof: &StatementProof<E, G>,
+        proof: &StatementProof<E, G, G2>,
     ) -> Result<(), ProofSystemError> {
         match proof {
             StatementProof::PoKBBSSignatureG1(p) => {
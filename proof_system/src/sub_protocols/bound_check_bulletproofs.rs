@@ -0,0 +1,300 @@
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_serialize::CanonicalSerialize;
+use ark_std::{collections::BTreeMap, io::Write, rand::RngCore, vec, vec::Vec, UniformRand};
+use bulletproofs::{range_proof::RangeProof, setup::SetupParams as BulletproofsSetupParams};
+use digest::Digest;
+use dock_crypto_utils::secret::Secret;
+use dock_crypto_utils::transcript::Transcript;
+use schnorr_pok::SchnorrCommitment;
+use zeroize::Zeroize;
+
+use crate::error::ProofSystemError;
+use crate::statement_proof::{
+    BoundCheckBulletproofsProof, PedersenCommitmentProof, StatementProof,
+};
+use crate::sub_protocols::schnorr::SchnorrProtocol;
+
+/// Proves that each witness `m_i` satisfies `min_i <= m_i <= max_i`. Each witness is committed as
+/// `C_i = g * m_i + h * r_i` and knowledge of its opening is proved using a Schnorr protocol. An aggregated range proof
+/// then proves that `m_i - min_i` and `max_i - m_i` lie in `[0, 2^n)` where `n` is such that `max_i - min_i < 2^n`.
+/// The commitments to these values are `C_i - g * min_i` and `g * max_i - C_i` which the verifier can compute
+/// from `C_i`, so the range proof is bound to the same witnesses as the Schnorr protocols.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoundCheckBulletproofsProtocol<'a, G: AffineCurve> {
+    pub id: usize,
+    pub bounds: &'a [(u64, u64)],
+    pub params: &'a BulletproofsSetupParams<G>,
+    pub commitments: Option<Vec<G>>,
+    /// Schnorr protocols for the openings of the commitments, one for each witness
+    pub sc: Option<Vec<SchnorrCommitment<G>>>,
+    /// The witnesses and the randomness of their commitments
+    pub witnesses: Option<Secret<Vec<[G::ScalarField; 2]>>>,
+    pub range_proof: Option<RangeProof<G>>,
+}
+
+impl<'a, G: AffineCurve> BoundCheckBulletproofsProtocol<'a, G> {
+    pub fn new(
+        id: usize,
+        bounds: &'a [(u64, u64)],
+        params: &'a BulletproofsSetupParams<G>,
+    ) -> Self {
+        Self {
+            id,
+            bounds,
+            params,
+            commitments: None,
+            sc: None,
+            witnesses: None,
+            range_proof: None,
+        }
+    }
+
+    /// `blindings` are the randomness for the witnesses where the key is the index of the witness. Blindings not
+    /// given are generated.
+    pub fn init<R: RngCore, D: Digest>(
+        &mut self,
+        rng: &mut R,
+        mut blindings: BTreeMap<usize, G::ScalarField>,
+        witnesses: Vec<G::ScalarField>,
+    ) -> Result<(), ProofSystemError> {
+        if self.sc.is_some() {
+            return Err(ProofSystemError::SubProtocolAlreadyInitialized(self.id));
+        }
+        if witnesses.len() != self.bounds.len() {
+            return Err(
+                ProofSystemError::BoundCheckBulletproofsIncorrectWitnessCount(
+                    self.id,
+                    witnesses.len(),
+                ),
+            );
+        }
+        let bases = [self.params.g, self.params.h];
+        let mut values = Vec::with_capacity(2 * witnesses.len());
+        let mut range_proof_blindings = Vec::with_capacity(2 * witnesses.len());
+        let mut commitments = Vec::with_capacity(witnesses.len());
+        let mut sc = Vec::with_capacity(witnesses.len());
+        let mut wits = Vec::with_capacity(witnesses.len());
+        for (i, (w, (min, max))) in witnesses.into_iter().zip(self.bounds.iter()).enumerate() {
+            let v = Self::to_u64(&w).filter(|v| min <= v && v <= max).ok_or(
+                ProofSystemError::BoundCheckBulletproofsWitnessNotInBounds(self.id, i),
+            )?;
+            let randomness = G::ScalarField::rand(rng);
+            values.push(v - min);
+            values.push(max - v);
+            range_proof_blindings.push(randomness);
+            range_proof_blindings.push(-randomness);
+            commitments.push(self.params.commit(&w, &randomness));
+            sc.push(SchnorrCommitment::new(
+                &bases,
+                vec![
+                    blindings
+                        .remove(&i)
+                        .unwrap_or_else(|| G::ScalarField::rand(rng)),
+                    G::ScalarField::rand(rng),
+                ],
+            ));
+            wits.push([w, randomness]);
+        }
+        let n = Self::bit_size(self.bounds);
+        self.range_proof = Some(RangeProof::new::<_, D>(
+            rng,
+            self.params,
+            &values,
+            &range_proof_blindings,
+            n,
+        )?);
+        self.commitments = Some(commitments);
+        self.sc = Some(sc);
+        self.witnesses = Some(Secret::new(wits));
+        Ok(())
+    }
+
+    pub fn challenge_contribution<W: Write>(&self, mut writer: W) -> Result<(), ProofSystemError> {
+        if self.sc.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        let bases = [self.params.g, self.params.h];
+        for (c, sc) in self
+            .commitments
+            .as_ref()
+            .unwrap()
+            .iter()
+            .zip(self.sc.as_ref().unwrap())
+        {
+            SchnorrProtocol::compute_challenge_contribution(&bases, c, &sc.t, &mut writer)?;
+        }
+        self.range_proof
+            .as_ref()
+            .unwrap()
+            .serialize_unchecked(writer)?;
+        Ok(())
+    }
+
+    pub fn append_to_transcript(
+        &self,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        if self.sc.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        let bases = [self.params.g, self.params.h];
+        for (c, sc) in self
+            .commitments
+            .as_ref()
+            .unwrap()
+            .iter()
+            .zip(self.sc.as_ref().unwrap())
+        {
+            SchnorrProtocol::compute_transcript_contribution(&bases, c, &sc.t, transcript)?;
+        }
+        append_range_proof(self.range_proof.as_ref().unwrap(), transcript)
+    }
+
+    pub fn gen_proof_contribution<E: PairingEngine, G2: AffineCurve>(
+        &mut self,
+        challenge: &G::ScalarField,
+    ) -> Result<StatementProof<E, G, G2>, ProofSystemError> {
+        if self.sc.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateProof(
+                self.id,
+            ));
+        }
+        let sc = self.sc.take().unwrap();
+        let mut sp = Vec::with_capacity(sc.len());
+        for (sc, w) in sc
+            .into_iter()
+            .zip(self.witnesses.as_ref().unwrap().expose())
+        {
+            let response = sc.response(w, challenge)?;
+            sp.push(PedersenCommitmentProof::new(sc.t, response));
+        }
+        Ok(StatementProof::BoundCheckBulletproofs(
+            BoundCheckBulletproofsProof {
+                commitments: self.commitments.take().unwrap(),
+                sp,
+                range_proof: self.range_proof.take().unwrap(),
+            },
+        ))
+    }
+
+    pub fn verify_proof_contribution<E: PairingEngine, D: Digest, G2: AffineCurve>(
+        &self,
+        challenge: &G::ScalarField,
+        proof: &StatementProof<E, G, G2>,
+    ) -> Result<(), ProofSystemError> {
+        match proof {
+            StatementProof::BoundCheckBulletproofs(p) => {
+                if p.commitments.len() != self.bounds.len() || p.sp.len() != self.bounds.len() {
+                    return Err(
+                        ProofSystemError::ProofIncompatibleWithBoundCheckBulletproofsProtocol,
+                    );
+                }
+                let bases = [self.params.g, self.params.h];
+                for (c, sp) in p.commitments.iter().zip(p.sp.iter()) {
+                    sp.response.is_valid(&bases, c, &sp.t, challenge)?;
+                }
+                let g = &self.params.g;
+                let mut range_proof_commitments = Vec::with_capacity(2 * self.bounds.len());
+                for (c, (min, max)) in p.commitments.iter().zip(self.bounds.iter()) {
+                    let c = c.into_projective();
+                    range_proof_commitments.push(c - g.mul(G::ScalarField::from(*min).into_repr()));
+                    range_proof_commitments.push(g.mul(G::ScalarField::from(*max).into_repr()) - c);
+                }
+                p.range_proof.verify::<D>(
+                    self.params,
+                    &G::Projective::batch_normalization_into_affine(&range_proof_commitments),
+                    Self::bit_size(self.bounds),
+                )?;
+                Ok(())
+            }
+            _ => Err(ProofSystemError::ProofIncompatibleWithBoundCheckBulletproofsProtocol),
+        }
+    }
+
+    /// Challenge contribution of a proof created by this protocol. Used by the verifier.
+    pub fn compute_challenge_contribution<W: Write>(
+        params: &BulletproofsSetupParams<G>,
+        proof: &BoundCheckBulletproofsProof<G>,
+        mut writer: W,
+    ) -> Result<(), ProofSystemError> {
+        let bases = [params.g, params.h];
+        for (c, sp) in proof.commitments.iter().zip(proof.sp.iter()) {
+            SchnorrProtocol::compute_challenge_contribution(&bases, c, &sp.t, &mut writer)?;
+        }
+        proof.range_proof.serialize_unchecked(writer)?;
+        Ok(())
+    }
+
+    /// Append the values of [`BoundCheckBulletproofsProtocol::compute_challenge_contribution`] to the transcript
+    /// with a label each
+    pub fn compute_transcript_contribution(
+        params: &BulletproofsSetupParams<G>,
+        proof: &BoundCheckBulletproofsProof<G>,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        let bases = [params.g, params.h];
+        for (c, sp) in proof.commitments.iter().zip(proof.sp.iter()) {
+            SchnorrProtocol::compute_transcript_contribution(&bases, c, &sp.t, transcript)?;
+        }
+        append_range_proof(&proof.range_proof, transcript)
+    }
+
+    pub fn validate_bounds(bounds: &[(u64, u64)]) -> Result<(), ProofSystemError> {
+        if bounds.is_empty() {
+            return Err(ProofSystemError::InvalidStatement);
+        }
+        if bounds.iter().any(|(min, max)| max <= min) {
+            return Err(ProofSystemError::BoundCheckMaxNotGreaterThanMin);
+        }
+        Ok(())
+    }
+
+    /// Number of bits `n` such that `max - min < 2^n` for all bounds. This is a power of 2 as required by the
+    /// range proof.
+    pub fn bit_size(bounds: &[(u64, u64)]) -> usize {
+        let max_diff = bounds.iter().map(|(min, max)| max - min).max().unwrap_or(1);
+        ((u64::BITS - max_diff.leading_zeros()) as usize).next_power_of_two()
+    }
+
+    /// Returns the field element as `u64` if it is small enough
+    fn to_u64(elem: &G::ScalarField) -> Option<u64> {
+        let repr = elem.into_repr();
+        let limbs = repr.as_ref();
+        if limbs[1..].iter().all(|l| *l == 0) {
+            Some(limbs[0])
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, G: AffineCurve> Zeroize for BoundCheckBulletproofsProtocol<'a, G> {
+    fn zeroize(&mut self) {
+        self.sc
+            .as_mut()
+            .map(|sc| sc.iter_mut().for_each(|c| c.zeroize()));
+        self.witnesses.as_mut().map(|w| w.zeroize());
+    }
+}
+
+impl<'a, G: AffineCurve> Drop for BoundCheckBulletproofsProtocol<'a, G> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// The range proof is appended as a single message as it is the proof of another protocol
+fn append_range_proof<G: AffineCurve>(
+    range_proof: &RangeProof<G>,
+    transcript: &mut Transcript,
+) -> Result<(), ProofSystemError> {
+    let mut bytes = Vec::new();
+    range_proof.serialize_unchecked(&mut bytes)?;
+    transcript.append_message(b"range_proof", &bytes);
+    Ok(())
+}
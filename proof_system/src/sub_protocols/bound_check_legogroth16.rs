@@ -13,6 +13,7 @@ use ark_std::rand::Rng;
 use ark_std::{
     cmp::Ordering, collections::BTreeMap, io::Write, rand::RngCore, vec, vec::Vec, UniformRand,
 };
+use dock_crypto_utils::transcript::Transcript;
 use legogroth16::{
     create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
     PreparedVerifyingKey, Proof, ProvingKey, VerifyingKey,
@@ -120,11 +121,23 @@ impl<'a, E: PairingEngine> BoundCheckProtocol<'a, E> {
         Ok(())
     }
 
+    pub fn append_to_transcript(
+        &self,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        if self.sp.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        self.sp.as_ref().unwrap().append_to_transcript(transcript)
+    }
+
     /// Generate responses for the Schnorr protocol
-    pub fn gen_proof_contribution<G: AffineCurve>(
+    pub fn gen_proof_contribution<G: AffineCurve, G2: AffineCurve>(
         &mut self,
         challenge: &E::Fr,
-    ) -> Result<StatementProof<E, G>, ProofSystemError> {
+    ) -> Result<StatementProof<E, G, G2>, ProofSystemError> {
         if self.sp.is_none() {
             return Err(ProofSystemError::SubProtocolNotReadyToGenerateProof(
                 self.id,
@@ -186,6 +199,20 @@ impl<'a, E: PairingEngine> BoundCheckProtocol<'a, E> {
         Ok(())
     }
 
+    /// Append the values of [`Self::compute_challenge_contribution`] to the transcript with a label each
+    pub fn compute_transcript_contribution(
+        comm_key: &[E::G1Affine],
+        proof: &BoundCheckLegoGroth16Proof<E>,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        SchnorrProtocol::compute_transcript_contribution(
+            comm_key,
+            &proof.snark_proof.d,
+            &proof.sp.t,
+            transcript,
+        )
+    }
+
     pub fn validate_bounds(min: u64, max: u64) -> Result<(), ProofSystemError> {
         if max <= min {
             return Err(ProofSystemError::BoundCheckMaxNotGreaterThanMin);
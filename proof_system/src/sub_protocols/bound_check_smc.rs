@@ -0,0 +1,343 @@
+use ark_ec::{AffineCurve, PairingEngine};
+use ark_ff::{PrimeField, Zero};
+use ark_std::{
+    collections::{BTreeMap, BTreeSet},
+    io::Write,
+    rand::RngCore,
+    vec::Vec,
+    UniformRand,
+};
+use bbs_plus::proof::{PoKOfSignatureG1Proof, PoKOfSignatureG1Protocol};
+use dock_crypto_utils::secret::Secret;
+use dock_crypto_utils::transcript::Transcript;
+use zeroize::Zeroize;
+
+use crate::error::ProofSystemError;
+use crate::statement::bound_check_smc::SmcParams;
+use crate::statement_proof::{BoundCheckSmcProof, StatementProof};
+
+/// Proves that the witness `m` satisfies `min <= m <= max` where `max - min < u^l` for base `u` and `l` digits. The
+/// prover decomposes `m - min` and `m - (max + 1) + u^l` into `l` digits each and proves knowledge of the verifier's
+/// signature on each digit, which shows that both values lie in `[0, u^l)`. The blindings of the digits are chosen
+/// such that `sum_j(u^j * b_j)` is the blinding `b` of `m`, so the responses for the digits satisfy
+/// `sum_j(u^j * resp_j) = resp_m - c * min` and `sum_j(u^j * resp'_j) = resp_m - c * (max + 1 - u^l)` where
+/// `resp_m = b + c * m` is the response for the witness and `c` is the challenge.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoundCheckSmcProtocol<'a, E: PairingEngine> {
+    pub id: usize,
+    pub min: u64,
+    pub max: u64,
+    pub params: &'a SmcParams<E>,
+    /// The witness and its blinding
+    pub witness: Option<Secret<[E::Fr; 2]>>,
+    /// Protocols for the signatures on the digits of `m - min`
+    pub min_digit_protocols: Option<Vec<PoKOfSignatureG1Protocol<E>>>,
+    /// Protocols for the signatures on the digits of `m - (max + 1) + u^l`
+    pub max_digit_protocols: Option<Vec<PoKOfSignatureG1Protocol<E>>>,
+}
+
+impl<'a, E: PairingEngine> BoundCheckSmcProtocol<'a, E> {
+    pub fn new(id: usize, min: u64, max: u64, params: &'a SmcParams<E>) -> Self {
+        Self {
+            id,
+            min,
+            max,
+            params,
+            witness: None,
+            min_digit_protocols: None,
+            max_digit_protocols: None,
+        }
+    }
+
+    /// `blinding` is the randomness for the witness and is generated if not given
+    pub fn init<R: RngCore>(
+        &mut self,
+        rng: &mut R,
+        blinding: Option<E::Fr>,
+        witness: E::Fr,
+    ) -> Result<(), ProofSystemError> {
+        if self.witness.is_some() {
+            return Err(ProofSystemError::SubProtocolAlreadyInitialized(self.id));
+        }
+        let value = Self::to_u64(&witness)
+            .filter(|v| self.min <= *v && *v <= self.max)
+            .ok_or(ProofSystemError::BoundCheckSmcWitnessNotInBounds(self.id))?;
+        let blinding = blinding.unwrap_or_else(|| E::Fr::rand(rng));
+        let base = self.base()?;
+        let (digit_count, base_pow) = Self::digit_count(self.min, self.max, base);
+
+        let min_digits = Self::decompose((value - self.min) as u128, base, digit_count);
+        let max_digits = Self::decompose(
+            value as u128 + base_pow - self.max as u128 - 1,
+            base,
+            digit_count,
+        );
+        self.min_digit_protocols = Some(self.init_digit_protocols(rng, &blinding, min_digits)?);
+        self.max_digit_protocols = Some(self.init_digit_protocols(rng, &blinding, max_digits)?);
+        self.witness = Some(Secret::new([witness, blinding]));
+        Ok(())
+    }
+
+    pub fn challenge_contribution<W: Write>(&self, mut writer: W) -> Result<(), ProofSystemError> {
+        if self.witness.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        let revealed = BTreeMap::new();
+        for p in self
+            .min_digit_protocols
+            .as_ref()
+            .unwrap()
+            .iter()
+            .chain(self.max_digit_protocols.as_ref().unwrap().iter())
+        {
+            p.challenge_contribution(&revealed, &self.params.sig_params, &mut writer)?;
+        }
+        Ok(())
+    }
+
+    pub fn append_to_transcript(
+        &self,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        if self.witness.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        let revealed = BTreeMap::new();
+        for p in self
+            .min_digit_protocols
+            .as_ref()
+            .unwrap()
+            .iter()
+            .chain(self.max_digit_protocols.as_ref().unwrap().iter())
+        {
+            p.append_to_transcript(&revealed, &self.params.sig_params, transcript)?;
+        }
+        Ok(())
+    }
+
+    pub fn gen_proof_contribution<G: AffineCurve, G2: AffineCurve>(
+        &mut self,
+        challenge: &E::Fr,
+    ) -> Result<StatementProof<E, G, G2>, ProofSystemError> {
+        if self.witness.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateProof(
+                self.id,
+            ));
+        }
+        let [witness, blinding] = *self.witness.as_ref().unwrap().expose();
+        let min_digit_proofs = self
+            .min_digit_protocols
+            .take()
+            .unwrap()
+            .into_iter()
+            .map(|p| p.gen_proof(challenge))
+            .collect::<Result<Vec<_>, _>>()?;
+        let max_digit_proofs = self
+            .max_digit_protocols
+            .take()
+            .unwrap()
+            .into_iter()
+            .map(|p| p.gen_proof(challenge))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(StatementProof::BoundCheckSmc(BoundCheckSmcProof {
+            resp_for_witness: blinding + witness * challenge,
+            min_digit_proofs,
+            max_digit_proofs,
+        }))
+    }
+
+    pub fn verify_proof_contribution<G: AffineCurve, G2: AffineCurve>(
+        &self,
+        challenge: &E::Fr,
+        proof: &StatementProof<E, G, G2>,
+    ) -> Result<(), ProofSystemError> {
+        match proof {
+            StatementProof::BoundCheckSmc(p) => {
+                let base = self.base()?;
+                let (digit_count, base_pow) = Self::digit_count(self.min, self.max, base);
+                if p.min_digit_proofs.len() != digit_count
+                    || p.max_digit_proofs.len() != digit_count
+                {
+                    return Err(ProofSystemError::ProofIncompatibleWithBoundCheckSmcProtocol);
+                }
+                let expected_min = p.resp_for_witness - *challenge * E::Fr::from(self.min);
+                let expected_max = p.resp_for_witness
+                    - *challenge * (E::Fr::from(self.max as u128 + 1) - E::Fr::from(base_pow));
+                if self.combine_digit_responses(&p.min_digit_proofs, challenge, base)?
+                    != expected_min
+                    || self.combine_digit_responses(&p.max_digit_proofs, challenge, base)?
+                        != expected_max
+                {
+                    return Err(ProofSystemError::BoundCheckSmcInvalidDigitResponses(
+                        self.id,
+                    ));
+                }
+                Ok(())
+            }
+            _ => Err(ProofSystemError::ProofIncompatibleWithBoundCheckSmcProtocol),
+        }
+    }
+
+    /// Challenge contribution of a proof created by this protocol. Used by the verifier.
+    pub fn compute_challenge_contribution<W: Write>(
+        params: &SmcParams<E>,
+        proof: &BoundCheckSmcProof<E>,
+        mut writer: W,
+    ) -> Result<(), ProofSystemError> {
+        let revealed = BTreeMap::new();
+        for p in proof
+            .min_digit_proofs
+            .iter()
+            .chain(proof.max_digit_proofs.iter())
+        {
+            p.challenge_contribution(&revealed, &params.sig_params, &mut writer)?;
+        }
+        Ok(())
+    }
+
+    /// Append the values of [`BoundCheckSmcProtocol::compute_challenge_contribution`] to the transcript with a
+    /// label each
+    pub fn compute_transcript_contribution(
+        params: &SmcParams<E>,
+        proof: &BoundCheckSmcProof<E>,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        let revealed = BTreeMap::new();
+        for p in proof
+            .min_digit_proofs
+            .iter()
+            .chain(proof.max_digit_proofs.iter())
+        {
+            p.append_to_transcript(&revealed, &params.sig_params, transcript)?;
+        }
+        Ok(())
+    }
+
+    pub fn validate_bounds(min: u64, max: u64) -> Result<(), ProofSystemError> {
+        if max <= min {
+            return Err(ProofSystemError::BoundCheckMaxNotGreaterThanMin);
+        }
+        Ok(())
+    }
+
+    /// Returns the smallest number of digits `l` such that `max - min < base^l`, and `base^l`
+    pub fn digit_count(min: u64, max: u64, base: u64) -> (usize, u128) {
+        let mut count = 1;
+        let mut base_pow = base as u128;
+        while base_pow <= (max - min) as u128 {
+            base_pow *= base as u128;
+            count += 1;
+        }
+        (count, base_pow)
+    }
+
+    fn base(&self) -> Result<u64, ProofSystemError> {
+        let base = self.params.base();
+        if base < 2 {
+            return Err(ProofSystemError::SmcInvalidBase(base));
+        }
+        Ok(base)
+    }
+
+    /// Create protocols for proving knowledge of signatures on the given digits. The blinding of the least
+    /// significant digit is chosen such that the weighted sum of the blindings of all digits is `blinding`.
+    fn init_digit_protocols<R: RngCore>(
+        &self,
+        rng: &mut R,
+        blinding: &E::Fr,
+        digits: Vec<u64>,
+    ) -> Result<Vec<PoKOfSignatureG1Protocol<E>>, ProofSystemError> {
+        let base = E::Fr::from(self.params.base());
+        let mut digit_blindings = Vec::with_capacity(digits.len());
+        digit_blindings.push(E::Fr::zero());
+        let mut weighted_sum = E::Fr::zero();
+        let mut base_pow = base;
+        for _ in 1..digits.len() {
+            let b = E::Fr::rand(rng);
+            weighted_sum += base_pow * b;
+            base_pow *= base;
+            digit_blindings.push(b);
+        }
+        digit_blindings[0] = *blinding - weighted_sum;
+
+        digits
+            .into_iter()
+            .zip(digit_blindings.into_iter())
+            .map(|(d, b)| {
+                PoKOfSignatureG1Protocol::init(
+                    rng,
+                    &self.params.digit_signatures[d as usize],
+                    &self.params.sig_params,
+                    &[E::Fr::from(d)],
+                    BTreeMap::from([(0, b)]),
+                    BTreeSet::new(),
+                )
+                .map_err(|e| e.into())
+            })
+            .collect()
+    }
+
+    /// Verify the proofs of knowledge of signatures on the digits and return `sum_j(u^j * resp_j)`
+    fn combine_digit_responses(
+        &self,
+        proofs: &[PoKOfSignatureG1Proof<E>],
+        challenge: &E::Fr,
+        base: u64,
+    ) -> Result<E::Fr, ProofSystemError> {
+        let revealed = BTreeMap::new();
+        let revealed_indices = BTreeSet::new();
+        let base = E::Fr::from(base);
+        let mut base_pow = E::Fr::from(1u64);
+        let mut sum = E::Fr::zero();
+        for p in proofs {
+            p.verify(
+                &revealed,
+                challenge,
+                &self.params.public_key,
+                &self.params.sig_params,
+            )?;
+            sum += base_pow * p.get_resp_for_message(0, &revealed_indices)?;
+            base_pow *= base;
+        }
+        Ok(sum)
+    }
+
+    /// Digits of `value` in the given base, least significant first
+    fn decompose(mut value: u128, base: u64, count: usize) -> Vec<u64> {
+        let base = base as u128;
+        (0..count)
+            .map(|_| {
+                let d = (value % base) as u64;
+                value /= base;
+                d
+            })
+            .collect()
+    }
+
+    /// Returns the field element as `u64` if it is small enough
+    fn to_u64(elem: &E::Fr) -> Option<u64> {
+        let repr = elem.into_repr();
+        let limbs = repr.as_ref();
+        if limbs[1..].iter().all(|l| *l == 0) {
+            Some(limbs[0])
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, E: PairingEngine> Zeroize for BoundCheckSmcProtocol<'a, E> {
+    fn zeroize(&mut self) {
+        self.witness.as_mut().map(|w| w.zeroize());
+    }
+}
+
+impl<'a, E: PairingEngine> Drop for BoundCheckSmcProtocol<'a, E> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
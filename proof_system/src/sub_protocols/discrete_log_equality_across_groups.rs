@@ -0,0 +1,257 @@
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{PrimeField, Zero};
+use ark_std::{io::Write, rand::RngCore, vec, UniformRand};
+use dock_crypto_utils::secret::Secret;
+use dock_crypto_utils::transcript::Transcript;
+use schnorr_pok::discrete_log_equality_across_groups::{
+    CrossGroupCommitmentKey, PoKDiscreteLogEqualityAcrossGroupsProtocol, ShortChallenge,
+};
+use schnorr_pok::SchnorrCommitment;
+use zeroize::Zeroize;
+
+use crate::error::ProofSystemError;
+use crate::statement_proof::{
+    DiscreteLogEqualityAcrossGroupsProof, PedersenCommitmentProof, StatementProof,
+};
+use crate::sub_protocols::schnorr::SchnorrProtocol;
+
+/// Proves that the witness `m` has at most `num_bits` bits and `y = g2 * m` in group `G2`. The witness is committed
+/// in `G1` as `C = g1 * m + h1 * r` and knowledge of its opening is proved using a Schnorr protocol. The
+/// bit-decomposition protocol from `schnorr_pok` proves that the same integer is committed in `C` and `y`, where the
+/// blinding of `y` is 0. It uses the 128 least significant bits of the challenge of the proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiscreteLogEqualityAcrossGroupsSubProtocol<'a, G1: AffineCurve, G2: AffineCurve> {
+    pub id: usize,
+    pub y: G2,
+    pub num_bits: u16,
+    pub comm_key: &'a CrossGroupCommitmentKey<G1, G2>,
+    pub commitment: Option<G1>,
+    /// Schnorr protocol for the opening of the commitment
+    pub sc: Option<SchnorrCommitment<G1>>,
+    /// Protocol for proving that the same integer is committed in both groups
+    pub cross_group_protocol: Option<PoKDiscreteLogEqualityAcrossGroupsProtocol<G1, G2>>,
+    /// The witness and the randomness of the commitment
+    pub witnesses: Option<Secret<[G1::ScalarField; 2]>>,
+}
+
+impl<'a, G1: AffineCurve, G2: AffineCurve> DiscreteLogEqualityAcrossGroupsSubProtocol<'a, G1, G2> {
+    pub fn new(
+        id: usize,
+        y: G2,
+        num_bits: u16,
+        comm_key: &'a CrossGroupCommitmentKey<G1, G2>,
+    ) -> Self {
+        Self {
+            id,
+            y,
+            num_bits,
+            comm_key,
+            commitment: None,
+            sc: None,
+            cross_group_protocol: None,
+            witnesses: None,
+        }
+    }
+
+    /// `blinding` is the randomness for the witness and is generated if not given
+    pub fn init<R: RngCore>(
+        &mut self,
+        rng: &mut R,
+        blinding: Option<G1::ScalarField>,
+        witness: G1::ScalarField,
+    ) -> Result<(), ProofSystemError> {
+        if self.sc.is_some() {
+            return Err(ProofSystemError::SubProtocolAlreadyInitialized(self.id));
+        }
+        let randomness = G1::ScalarField::rand(rng);
+        self.cross_group_protocol = Some(PoKDiscreteLogEqualityAcrossGroupsProtocol::init(
+            rng,
+            &witness,
+            &randomness,
+            &G2::ScalarField::zero(),
+            self.comm_key,
+            self.num_bits,
+        )?);
+        self.commitment = Some(
+            (self.comm_key.g1.mul(witness.into_repr())
+                + self.comm_key.h1.mul(randomness.into_repr()))
+            .into_affine(),
+        );
+        self.sc = Some(SchnorrCommitment::new(
+            &[self.comm_key.g1, self.comm_key.h1],
+            vec![
+                blinding.unwrap_or_else(|| G1::ScalarField::rand(rng)),
+                G1::ScalarField::rand(rng),
+            ],
+        ));
+        self.witnesses = Some(Secret::new([witness, randomness]));
+        Ok(())
+    }
+
+    pub fn challenge_contribution<W: Write>(&self, mut writer: W) -> Result<(), ProofSystemError> {
+        if self.sc.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        let commitment = self.commitment.as_ref().unwrap();
+        SchnorrProtocol::compute_challenge_contribution(
+            &[self.comm_key.g1, self.comm_key.h1],
+            commitment,
+            &self.sc.as_ref().unwrap().t,
+            &mut writer,
+        )?;
+        self.cross_group_protocol
+            .as_ref()
+            .unwrap()
+            .challenge_contribution(self.comm_key, commitment, &self.y, writer)?;
+        Ok(())
+    }
+
+    pub fn append_to_transcript(
+        &self,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        if self.sc.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        let commitment = self.commitment.as_ref().unwrap();
+        SchnorrProtocol::compute_transcript_contribution(
+            &[self.comm_key.g1, self.comm_key.h1],
+            commitment,
+            &self.sc.as_ref().unwrap().t,
+            transcript,
+        )?;
+        self.cross_group_protocol
+            .as_ref()
+            .unwrap()
+            .append_to_transcript(self.comm_key, commitment, &self.y, transcript)?;
+        Ok(())
+    }
+
+    pub fn gen_proof_contribution<E: PairingEngine>(
+        &mut self,
+        challenge: &G1::ScalarField,
+    ) -> Result<StatementProof<E, G1, G2>, ProofSystemError> {
+        Ok(StatementProof::DiscreteLogEqualityAcrossGroups(
+            self.gen_proof_contribution_as_struct(challenge)?,
+        ))
+    }
+
+    pub fn gen_proof_contribution_as_struct(
+        &mut self,
+        challenge: &G1::ScalarField,
+    ) -> Result<DiscreteLogEqualityAcrossGroupsProof<G1, G2>, ProofSystemError> {
+        if self.sc.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateProof(
+                self.id,
+            ));
+        }
+        let sc = self.sc.take().unwrap();
+        let response = sc.response(self.witnesses.as_ref().unwrap().expose(), challenge)?;
+        let cross_group_proof = self
+            .cross_group_protocol
+            .take()
+            .unwrap()
+            .gen_proof(&ShortChallenge::from_field(challenge));
+        Ok(DiscreteLogEqualityAcrossGroupsProof {
+            commitment: self.commitment.take().unwrap(),
+            sp: PedersenCommitmentProof::new(sc.t, response),
+            cross_group_proof,
+        })
+    }
+
+    pub fn verify_proof_contribution<E: PairingEngine>(
+        &self,
+        challenge: &G1::ScalarField,
+        proof: &StatementProof<E, G1, G2>,
+    ) -> Result<(), ProofSystemError> {
+        match proof {
+            StatementProof::DiscreteLogEqualityAcrossGroups(p) => {
+                self.verify_proof_contribution_as_struct(challenge, p)
+            }
+            _ => {
+                Err(ProofSystemError::ProofIncompatibleWithDiscreteLogEqualityAcrossGroupsProtocol)
+            }
+        }
+    }
+
+    pub fn verify_proof_contribution_as_struct(
+        &self,
+        challenge: &G1::ScalarField,
+        proof: &DiscreteLogEqualityAcrossGroupsProof<G1, G2>,
+    ) -> Result<(), ProofSystemError> {
+        proof.sp.response.is_valid(
+            &[self.comm_key.g1, self.comm_key.h1],
+            &proof.commitment,
+            &proof.sp.t,
+            challenge,
+        )?;
+        proof.cross_group_proof.verify(
+            self.comm_key,
+            &proof.commitment,
+            &self.y,
+            self.num_bits,
+            &ShortChallenge::from_field(challenge),
+        )?;
+        Ok(())
+    }
+
+    /// Challenge contribution of a proof created by this protocol. Used by the verifier.
+    pub fn compute_challenge_contribution<W: Write>(
+        comm_key: &CrossGroupCommitmentKey<G1, G2>,
+        y: &G2,
+        proof: &DiscreteLogEqualityAcrossGroupsProof<G1, G2>,
+        mut writer: W,
+    ) -> Result<(), ProofSystemError> {
+        SchnorrProtocol::compute_challenge_contribution(
+            &[comm_key.g1, comm_key.h1],
+            &proof.commitment,
+            &proof.sp.t,
+            &mut writer,
+        )?;
+        proof
+            .cross_group_proof
+            .challenge_contribution(comm_key, &proof.commitment, y, writer)?;
+        Ok(())
+    }
+
+    /// Append the values of [`Self::compute_challenge_contribution`] to the transcript with a label each
+    pub fn compute_transcript_contribution(
+        comm_key: &CrossGroupCommitmentKey<G1, G2>,
+        y: &G2,
+        proof: &DiscreteLogEqualityAcrossGroupsProof<G1, G2>,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        SchnorrProtocol::compute_transcript_contribution(
+            &[comm_key.g1, comm_key.h1],
+            &proof.commitment,
+            &proof.sp.t,
+            transcript,
+        )?;
+        proof
+            .cross_group_proof
+            .append_to_transcript(comm_key, &proof.commitment, y, transcript)?;
+        Ok(())
+    }
+}
+
+impl<'a, G1: AffineCurve, G2: AffineCurve> Zeroize
+    for DiscreteLogEqualityAcrossGroupsSubProtocol<'a, G1, G2>
+{
+    fn zeroize(&mut self) {
+        self.sc.as_mut().map(|c| c.zeroize());
+        self.cross_group_protocol.as_mut().map(|p| p.zeroize());
+        self.witnesses.as_mut().map(|w| w.zeroize());
+    }
+}
+
+impl<'a, G1: AffineCurve, G2: AffineCurve> Drop
+    for DiscreteLogEqualityAcrossGroupsSubProtocol<'a, G1, G2>
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
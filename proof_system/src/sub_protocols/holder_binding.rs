@@ -0,0 +1,176 @@
+use ark_ec::{AffineCurve, PairingEngine};
+use ark_std::{collections::BTreeMap, io::Write, rand::RngCore, slice, vec};
+use dock_crypto_utils::transcript::Transcript;
+
+use crate::error::ProofSystemError;
+use crate::setup_params::SetupParams;
+use crate::statement::holder_binding::HolderBinding;
+use crate::statement_proof::{HolderBindingProof, StatementProof};
+use crate::sub_protocols::discrete_log_equality_across_groups::DiscreteLogEqualityAcrossGroupsSubProtocol;
+use crate::sub_protocols::schnorr::SchnorrProtocol;
+
+/// Proves knowledge of the secret key of the holder's public key. When the public key is in the group of the proof,
+/// a Schnorr protocol with the public key's base as the only base is used, otherwise the protocol of
+/// `DiscreteLogEqualityAcrossGroups` is used.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HolderBindingSubProtocol<'a, G1: AffineCurve, G2: AffineCurve> {
+    SameGroup(SchnorrProtocol<'a, G1>),
+    OtherGroup(DiscreteLogEqualityAcrossGroupsSubProtocol<'a, G1, G2>),
+}
+
+impl<'a, G1: AffineCurve, G2: AffineCurve> HolderBindingSubProtocol<'a, G1, G2> {
+    pub fn new<E: PairingEngine>(
+        id: usize,
+        statement: &'a HolderBinding<G1, G2>,
+        setup_params: &'a [SetupParams<E, G1, G2>],
+    ) -> Result<Self, ProofSystemError> {
+        match (&statement.same_group, &statement.other_group) {
+            (Some(k), None) => Ok(Self::SameGroup(SchnorrProtocol::new(
+                id,
+                slice::from_ref(&k.base),
+                k.public_key,
+            ))),
+            (None, Some(s)) => Ok(Self::OtherGroup(
+                DiscreteLogEqualityAcrossGroupsSubProtocol::new(
+                    id,
+                    s.y,
+                    s.num_bits,
+                    s.get_comm_key(setup_params, id)?,
+                ),
+            )),
+            _ => Err(ProofSystemError::InvalidHolderBindingStatement(id)),
+        }
+    }
+
+    /// `blinding` is the randomness for the secret key and is generated if not given
+    pub fn init<R: RngCore>(
+        &mut self,
+        rng: &mut R,
+        blinding: Option<G1::ScalarField>,
+        secret_key: G1::ScalarField,
+    ) -> Result<(), ProofSystemError> {
+        match self {
+            Self::SameGroup(sp) => {
+                let mut blindings = BTreeMap::new();
+                if let Some(b) = blinding {
+                    blindings.insert(0, b);
+                }
+                sp.init(rng, blindings, vec![secret_key])
+            }
+            Self::OtherGroup(sp) => sp.init(rng, blinding, secret_key),
+        }
+    }
+
+    pub fn challenge_contribution<W: Write>(&self, writer: W) -> Result<(), ProofSystemError> {
+        match self {
+            Self::SameGroup(sp) => sp.challenge_contribution(writer),
+            Self::OtherGroup(sp) => sp.challenge_contribution(writer),
+        }
+    }
+
+    pub fn append_to_transcript(
+        &self,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        match self {
+            Self::SameGroup(sp) => sp.append_to_transcript(transcript),
+            Self::OtherGroup(sp) => sp.append_to_transcript(transcript),
+        }
+    }
+
+    pub fn gen_proof_contribution<E: PairingEngine>(
+        &mut self,
+        challenge: &G1::ScalarField,
+    ) -> Result<StatementProof<E, G1, G2>, ProofSystemError> {
+        let proof = match self {
+            Self::SameGroup(sp) => HolderBindingProof {
+                same_group: Some(sp.gen_proof_contribution_as_struct(challenge)?),
+                other_group: None,
+            },
+            Self::OtherGroup(sp) => HolderBindingProof {
+                same_group: None,
+                other_group: Some(sp.gen_proof_contribution_as_struct(challenge)?),
+            },
+        };
+        Ok(StatementProof::HolderBinding(proof))
+    }
+
+    pub fn verify_proof_contribution<E: PairingEngine>(
+        &self,
+        challenge: &G1::ScalarField,
+        proof: &StatementProof<E, G1, G2>,
+    ) -> Result<(), ProofSystemError> {
+        match (self, proof) {
+            (
+                Self::SameGroup(sp),
+                StatementProof::HolderBinding(HolderBindingProof {
+                    same_group: Some(p),
+                    other_group: None,
+                }),
+            ) => sp.verify_proof_contribution_as_struct(challenge, p),
+            (
+                Self::OtherGroup(sp),
+                StatementProof::HolderBinding(HolderBindingProof {
+                    same_group: None,
+                    other_group: Some(p),
+                }),
+            ) => sp.verify_proof_contribution_as_struct(challenge, p),
+            _ => Err(ProofSystemError::ProofIncompatibleWithHolderBindingProtocol),
+        }
+    }
+
+    /// Challenge contribution of a proof created by this protocol. Used by the verifier.
+    pub fn compute_challenge_contribution<W: Write>(
+        &self,
+        proof: &HolderBindingProof<G1, G2>,
+        writer: W,
+    ) -> Result<(), ProofSystemError> {
+        match (self, &proof.same_group, &proof.other_group) {
+            (Self::SameGroup(sp), Some(p), None) => {
+                SchnorrProtocol::compute_challenge_contribution(
+                    sp.commitment_key,
+                    &sp.commitment,
+                    &p.t,
+                    writer,
+                )
+            }
+            (Self::OtherGroup(sp), None, Some(p)) => {
+                DiscreteLogEqualityAcrossGroupsSubProtocol::compute_challenge_contribution(
+                    sp.comm_key,
+                    &sp.y,
+                    p,
+                    writer,
+                )
+            }
+            _ => Err(ProofSystemError::ProofIncompatibleWithHolderBindingProtocol),
+        }
+    }
+
+    /// Append the values of [`HolderBindingSubProtocol::compute_challenge_contribution`] to the transcript with a
+    /// label each
+    pub fn compute_transcript_contribution(
+        &self,
+        proof: &HolderBindingProof<G1, G2>,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        match (self, &proof.same_group, &proof.other_group) {
+            (Self::SameGroup(sp), Some(p), None) => {
+                SchnorrProtocol::compute_transcript_contribution(
+                    sp.commitment_key,
+                    &sp.commitment,
+                    &p.t,
+                    transcript,
+                )
+            }
+            (Self::OtherGroup(sp), None, Some(p)) => {
+                DiscreteLogEqualityAcrossGroupsSubProtocol::compute_transcript_contribution(
+                    sp.comm_key,
+                    &sp.y,
+                    p,
+                    transcript,
+                )
+            }
+            _ => Err(ProofSystemError::ProofIncompatibleWithHolderBindingProtocol),
+        }
+    }
+}
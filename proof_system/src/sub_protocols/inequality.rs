@@ -0,0 +1,233 @@
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{Field, PrimeField};
+use ark_std::{io::Write, rand::RngCore, vec, UniformRand};
+use dock_crypto_utils::secret::Secret;
+use dock_crypto_utils::transcript::Transcript;
+use schnorr_pok::SchnorrCommitment;
+use zeroize::Zeroize;
+
+use crate::error::ProofSystemError;
+use crate::statement_proof::{InequalityProof, PedersenCommitmentProof, StatementProof};
+use crate::sub_protocols::schnorr::SchnorrProtocol;
+
+/// Proves that the witness `m` is not equal to the public value `v`. The witness is committed as `C = g * m + h * r`
+/// and knowledge of its opening is proved using a Schnorr protocol. Another Schnorr protocol proves knowledge of
+/// `k = 1/(m - v)` and `-r * k` such that `g = (C - g * v) * k + h * (-r * k)`. If `m = v`, then `C - g * v = h * r`
+/// and the prover would need to know the discrete log of `g` wrt `h` to create the second proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InequalitySubProtocol<'a, G: AffineCurve> {
+    pub id: usize,
+    pub inequal_to: G::ScalarField,
+    /// Commitment key `[g, h]`
+    pub comm_key: &'a [G],
+    pub commitment: Option<G>,
+    /// Schnorr protocol for the opening of the commitment
+    pub sc: Option<SchnorrCommitment<G>>,
+    /// Schnorr protocol for the inverse of `m - v`
+    pub sc_inverse: Option<SchnorrCommitment<G>>,
+    /// The witness and the randomness of the commitment
+    pub witnesses: Option<Secret<[G::ScalarField; 2]>>,
+    /// The inverse of `m - v` and the negation of its product with the randomness of the commitment
+    pub inverse_witnesses: Option<Secret<[G::ScalarField; 2]>>,
+}
+
+impl<'a, G: AffineCurve> InequalitySubProtocol<'a, G> {
+    pub fn new(id: usize, inequal_to: G::ScalarField, comm_key: &'a [G]) -> Self {
+        Self {
+            id,
+            inequal_to,
+            comm_key,
+            commitment: None,
+            sc: None,
+            sc_inverse: None,
+            witnesses: None,
+            inverse_witnesses: None,
+        }
+    }
+
+    /// `blinding` is the randomness for the witness and is generated if not given
+    pub fn init<R: RngCore>(
+        &mut self,
+        rng: &mut R,
+        blinding: Option<G::ScalarField>,
+        witness: G::ScalarField,
+    ) -> Result<(), ProofSystemError> {
+        if self.sc.is_some() {
+            return Err(ProofSystemError::SubProtocolAlreadyInitialized(self.id));
+        }
+        let inverse = (witness - self.inequal_to).inverse().ok_or(
+            ProofSystemError::InequalityWitnessEqualToPublicValue(self.id),
+        )?;
+        let randomness = G::ScalarField::rand(rng);
+        let commitment = (self.comm_key[0].mul(witness.into_repr())
+            + self.comm_key[1].mul(randomness.into_repr()))
+        .into_affine();
+        let inverse_bases = Self::inverse_bases(self.comm_key, &self.inequal_to, &commitment);
+
+        self.sc = Some(SchnorrCommitment::new(
+            self.comm_key,
+            vec![
+                blinding.unwrap_or_else(|| G::ScalarField::rand(rng)),
+                G::ScalarField::rand(rng),
+            ],
+        ));
+        self.sc_inverse = Some(SchnorrCommitment::new(
+            &inverse_bases,
+            vec![G::ScalarField::rand(rng), G::ScalarField::rand(rng)],
+        ));
+        self.commitment = Some(commitment);
+        self.witnesses = Some(Secret::new([witness, randomness]));
+        self.inverse_witnesses = Some(Secret::new([inverse, -(randomness * inverse)]));
+        Ok(())
+    }
+
+    pub fn challenge_contribution<W: Write>(&self, mut writer: W) -> Result<(), ProofSystemError> {
+        if self.sc.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        let commitment = self.commitment.as_ref().unwrap();
+        SchnorrProtocol::compute_challenge_contribution(
+            self.comm_key,
+            commitment,
+            &self.sc.as_ref().unwrap().t,
+            &mut writer,
+        )?;
+        SchnorrProtocol::compute_challenge_contribution(
+            &Self::inverse_bases(self.comm_key, &self.inequal_to, commitment),
+            &self.comm_key[0],
+            &self.sc_inverse.as_ref().unwrap().t,
+            writer,
+        )
+    }
+
+    pub fn append_to_transcript(
+        &self,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        if self.sc.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        let commitment = self.commitment.as_ref().unwrap();
+        SchnorrProtocol::compute_transcript_contribution(
+            self.comm_key,
+            commitment,
+            &self.sc.as_ref().unwrap().t,
+            transcript,
+        )?;
+        SchnorrProtocol::compute_transcript_contribution(
+            &Self::inverse_bases(self.comm_key, &self.inequal_to, commitment),
+            &self.comm_key[0],
+            &self.sc_inverse.as_ref().unwrap().t,
+            transcript,
+        )
+    }
+
+    pub fn gen_proof_contribution<E: PairingEngine, G2: AffineCurve>(
+        &mut self,
+        challenge: &G::ScalarField,
+    ) -> Result<StatementProof<E, G, G2>, ProofSystemError> {
+        if self.sc.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateProof(
+                self.id,
+            ));
+        }
+        let sc = self.sc.take().unwrap();
+        let sc_inverse = self.sc_inverse.take().unwrap();
+        let response = sc.response(self.witnesses.as_ref().unwrap().expose(), challenge)?;
+        let response_inverse =
+            sc_inverse.response(self.inverse_witnesses.as_ref().unwrap().expose(), challenge)?;
+        Ok(StatementProof::Inequality(InequalityProof {
+            commitment: self.commitment.take().unwrap(),
+            sp: PedersenCommitmentProof::new(sc.t, response),
+            sp_inverse: PedersenCommitmentProof::new(sc_inverse.t, response_inverse),
+        }))
+    }
+
+    pub fn verify_proof_contribution<E: PairingEngine, G2: AffineCurve>(
+        &self,
+        challenge: &G::ScalarField,
+        proof: &StatementProof<E, G, G2>,
+    ) -> Result<(), ProofSystemError> {
+        match proof {
+            StatementProof::Inequality(p) => {
+                p.sp.response
+                    .is_valid(self.comm_key, &p.commitment, &p.sp.t, challenge)?;
+                p.sp_inverse.response.is_valid(
+                    &Self::inverse_bases(self.comm_key, &self.inequal_to, &p.commitment),
+                    &self.comm_key[0],
+                    &p.sp_inverse.t,
+                    challenge,
+                )?;
+                Ok(())
+            }
+            _ => Err(ProofSystemError::ProofIncompatibleWithInequalityProtocol),
+        }
+    }
+
+    /// Challenge contribution of a proof created by this protocol. Used by the verifier.
+    pub fn compute_challenge_contribution<W: Write>(
+        comm_key: &[G],
+        inequal_to: &G::ScalarField,
+        proof: &InequalityProof<G>,
+        mut writer: W,
+    ) -> Result<(), ProofSystemError> {
+        SchnorrProtocol::compute_challenge_contribution(
+            comm_key,
+            &proof.commitment,
+            &proof.sp.t,
+            &mut writer,
+        )?;
+        SchnorrProtocol::compute_challenge_contribution(
+            &Self::inverse_bases(comm_key, inequal_to, &proof.commitment),
+            &comm_key[0],
+            &proof.sp_inverse.t,
+            writer,
+        )
+    }
+
+    /// Append the values of [`InequalitySubProtocol::compute_challenge_contribution`] to the transcript with a label each
+    pub fn compute_transcript_contribution(
+        comm_key: &[G],
+        inequal_to: &G::ScalarField,
+        proof: &InequalityProof<G>,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        SchnorrProtocol::compute_transcript_contribution(
+            comm_key,
+            &proof.commitment,
+            &proof.sp.t,
+            transcript,
+        )?;
+        SchnorrProtocol::compute_transcript_contribution(
+            &Self::inverse_bases(comm_key, inequal_to, &proof.commitment),
+            &comm_key[0],
+            &proof.sp_inverse.t,
+            transcript,
+        )
+    }
+
+    /// Bases `[C - g * v, h]` of the proof of knowledge of the inverse
+    fn inverse_bases(comm_key: &[G], inequal_to: &G::ScalarField, commitment: &G) -> [G; 2] {
+        let c = commitment.into_projective() - comm_key[0].mul(inequal_to.into_repr());
+        [c.into_affine(), comm_key[1]]
+    }
+}
+
+impl<'a, G: AffineCurve> Zeroize for InequalitySubProtocol<'a, G> {
+    fn zeroize(&mut self) {
+        self.sc.as_mut().map(|c| c.zeroize());
+        self.sc_inverse.as_mut().map(|c| c.zeroize());
+        self.witnesses.as_mut().map(|w| w.zeroize());
+        self.inverse_witnesses.as_mut().map(|w| w.zeroize());
+    }
+}
+
+impl<'a, G: AffineCurve> Drop for InequalitySubProtocol<'a, G> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
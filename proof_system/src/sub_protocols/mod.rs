@@ -1,13 +1,23 @@
 pub mod accumulator;
+pub mod bbs;
 pub mod bbs_plus;
+pub mod bound_check_bulletproofs;
 pub mod bound_check_legogroth16;
+pub mod bound_check_smc;
+pub mod discrete_log_equality_across_groups;
+pub mod holder_binding;
+pub mod inequality;
+pub mod pseudonym;
 pub mod r1cs_legogorth16;
 pub mod saver;
 pub mod schnorr;
+pub mod set_membership;
 
 use crate::error::ProofSystemError;
 use ark_ec::{AffineCurve, PairingEngine};
 use ark_std::io::Write;
+use digest::Digest;
+use dock_crypto_utils::transcript::Transcript;
 
 use crate::statement_proof::StatementProof;
 use crate::sub_protocols::bound_check_legogroth16::BoundCheckProtocol;
@@ -15,9 +25,14 @@ use crate::sub_protocols::r1cs_legogorth16::R1CSLegogroth16Protocol;
 use accumulator::{AccumulatorMembershipSubProtocol, AccumulatorNonMembershipSubProtocol};
 
 /// Various sub-protocols that are executed to create a `StatementProof` which are then combined to
-/// form a `Proof`
+/// form a `Proof`. `G2` is the other group of the `DiscreteLogEqualityAcrossGroups` statements.
 #[derive(Clone, Debug, PartialEq)]
-pub enum SubProtocol<'a, E: PairingEngine, G: AffineCurve> {
+pub enum SubProtocol<
+    'a,
+    E: PairingEngine,
+    G: AffineCurve,
+    G2: AffineCurve = <E as PairingEngine>::G2Affine,
+> {
     PoKBBSSignatureG1(self::bbs_plus::PoKBBSSigG1SubProtocol<'a, E>),
     AccumulatorMembership(AccumulatorMembershipSubProtocol<'a, E>),
     AccumulatorNonMembership(AccumulatorNonMembershipSubProtocol<'a, E>),
@@ -27,6 +42,22 @@ pub enum SubProtocol<'a, E: PairingEngine, G: AffineCurve> {
     /// For range proof using LegoGroth16
     BoundCheckProtocol(BoundCheckProtocol<'a, E>),
     R1CSLegogroth16Protocol(R1CSLegogroth16Protocol<'a, E>),
+    PoKIetfBBSSignatureG1(self::bbs::PoKIetfBBSSigG1SubProtocol<'a, E>),
+    SetMembership(self::set_membership::SetMembershipSubProtocol<'a, G>),
+    Inequality(self::inequality::InequalitySubProtocol<'a, G>),
+    /// For range proof using Bulletproofs
+    BoundCheckBulletproofs(self::bound_check_bulletproofs::BoundCheckBulletproofsProtocol<'a, G>),
+    /// For range proof using set-membership check
+    BoundCheckSmc(self::bound_check_smc::BoundCheckSmcProtocol<'a, E>),
+    DiscreteLogEqualityAcrossGroups(
+        self::discrete_log_equality_across_groups::DiscreteLogEqualityAcrossGroupsSubProtocol<
+            'a,
+            G,
+            G2,
+        >,
+    ),
+    Pseudonym(self::pseudonym::PseudonymSubProtocol<E>),
+    HolderBinding(self::holder_binding::HolderBindingSubProtocol<'a, G, G2>),
 }
 
 pub trait ProofSubProtocol<E: PairingEngine, G: AffineCurve<ScalarField = E::Fr>> {
@@ -37,7 +68,9 @@ pub trait ProofSubProtocol<E: PairingEngine, G: AffineCurve<ScalarField = E::Fr>
     ) -> Result<StatementProof<E, G>, ProofSystemError>;
 }
 
-impl<'a, E: PairingEngine, G: AffineCurve<ScalarField = E::Fr>> SubProtocol<'a, E, G> {
+impl<'a, E: PairingEngine, G: AffineCurve<ScalarField = E::Fr>, G2: AffineCurve>
+    SubProtocol<'a, E, G, G2>
+{
     pub fn challenge_contribution<W: Write>(&self, writer: W) -> Result<(), ProofSystemError> {
         match self {
             SubProtocol::PoKBBSSignatureG1(s) => s.challenge_contribution(writer),
@@ -47,13 +80,47 @@ impl<'a, E: PairingEngine, G: AffineCurve<ScalarField = E::Fr>> SubProtocol<'a,
             SubProtocol::Saver(s) => s.challenge_contribution(writer),
             SubProtocol::BoundCheckProtocol(s) => s.challenge_contribution(writer),
             SubProtocol::R1CSLegogroth16Protocol(s) => s.challenge_contribution(writer),
+            SubProtocol::PoKIetfBBSSignatureG1(s) => s.challenge_contribution(writer),
+            SubProtocol::SetMembership(s) => s.challenge_contribution(writer),
+            SubProtocol::Inequality(s) => s.challenge_contribution(writer),
+            SubProtocol::BoundCheckBulletproofs(s) => s.challenge_contribution(writer),
+            SubProtocol::BoundCheckSmc(s) => s.challenge_contribution(writer),
+            SubProtocol::DiscreteLogEqualityAcrossGroups(s) => s.challenge_contribution(writer),
+            SubProtocol::Pseudonym(s) => s.challenge_contribution(writer),
+            SubProtocol::HolderBinding(s) => s.challenge_contribution(writer),
         }
     }
 
-    pub fn gen_proof_contribution(
+    /// Append the group elements and field elements of the challenge contribution to the transcript with a label
+    /// each. In compatibility mode, the transcript gets the same bytes as [`SubProtocol::challenge_contribution`].
+    pub fn append_to_transcript(
+        &self,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        match self {
+            SubProtocol::PoKBBSSignatureG1(s) => s.append_to_transcript(transcript),
+            SubProtocol::AccumulatorMembership(s) => s.append_to_transcript(transcript),
+            SubProtocol::AccumulatorNonMembership(s) => s.append_to_transcript(transcript),
+            SubProtocol::PoKDiscreteLogs(s) => s.append_to_transcript(transcript),
+            SubProtocol::Saver(s) => s.append_to_transcript(transcript),
+            SubProtocol::BoundCheckProtocol(s) => s.append_to_transcript(transcript),
+            SubProtocol::R1CSLegogroth16Protocol(s) => s.append_to_transcript(transcript),
+            SubProtocol::PoKIetfBBSSignatureG1(s) => s.append_to_transcript(transcript),
+            SubProtocol::SetMembership(s) => s.append_to_transcript(transcript),
+            SubProtocol::Inequality(s) => s.append_to_transcript(transcript),
+            SubProtocol::BoundCheckBulletproofs(s) => s.append_to_transcript(transcript),
+            SubProtocol::BoundCheckSmc(s) => s.append_to_transcript(transcript),
+            SubProtocol::DiscreteLogEqualityAcrossGroups(s) => s.append_to_transcript(transcript),
+            SubProtocol::Pseudonym(s) => s.append_to_transcript(transcript),
+            SubProtocol::HolderBinding(s) => s.append_to_transcript(transcript),
+        }
+    }
+
+    /// `D` is the hash function used by sub-protocols which need to create challenges internally
+    pub fn gen_proof_contribution<D: Digest>(
         &mut self,
         challenge: &E::Fr,
-    ) -> Result<StatementProof<E, G>, ProofSystemError> {
+    ) -> Result<StatementProof<E, G, G2>, ProofSystemError> {
         match self {
             SubProtocol::PoKBBSSignatureG1(s) => s.gen_proof_contribution(challenge),
             SubProtocol::AccumulatorMembership(s) => s.gen_proof_contribution(challenge),
@@ -62,6 +129,14 @@ impl<'a, E: PairingEngine, G: AffineCurve<ScalarField = E::Fr>> SubProtocol<'a,
             SubProtocol::Saver(s) => s.gen_proof_contribution(challenge),
             SubProtocol::BoundCheckProtocol(s) => s.gen_proof_contribution(challenge),
             SubProtocol::R1CSLegogroth16Protocol(s) => s.gen_proof_contribution(challenge),
+            SubProtocol::PoKIetfBBSSignatureG1(s) => s.gen_proof_contribution(challenge),
+            SubProtocol::SetMembership(s) => s.gen_proof_contribution::<E, D, G2>(challenge),
+            SubProtocol::Inequality(s) => s.gen_proof_contribution(challenge),
+            SubProtocol::BoundCheckBulletproofs(s) => s.gen_proof_contribution(challenge),
+            SubProtocol::BoundCheckSmc(s) => s.gen_proof_contribution(challenge),
+            SubProtocol::DiscreteLogEqualityAcrossGroups(s) => s.gen_proof_contribution(challenge),
+            SubProtocol::Pseudonym(s) => s.gen_proof_contribution(challenge),
+            SubProtocol::HolderBinding(s) => s.gen_proof_contribution(challenge),
         }
     }
 }
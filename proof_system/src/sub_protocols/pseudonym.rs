@@ -0,0 +1,168 @@
+use ark_ec::{AffineCurve, PairingEngine};
+use ark_std::{io::Write, rand::RngCore, slice, vec, UniformRand};
+use dock_crypto_utils::secret::Secret;
+use dock_crypto_utils::transcript::Transcript;
+use schnorr_pok::SchnorrCommitment;
+use zeroize::Zeroize;
+
+use crate::error::ProofSystemError;
+use crate::statement_proof::{PedersenCommitmentProof, StatementProof};
+use crate::sub_protocols::schnorr::SchnorrProtocol;
+
+/// Proves knowledge of the secret in the pseudonym using a Schnorr protocol with the scope's base as the only base.
+/// The base is created from the scope by the prover and the verifier and is thus owned by the protocol.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PseudonymSubProtocol<E: PairingEngine> {
+    pub id: usize,
+    pub base: E::G1Affine,
+    pub pseudonym: E::G1Affine,
+    pub commitment_to_randomness: Option<SchnorrCommitment<E::G1Affine>>,
+    pub secret: Option<Secret<E::Fr>>,
+}
+
+impl<E: PairingEngine> PseudonymSubProtocol<E> {
+    pub fn new(id: usize, base: E::G1Affine, pseudonym: E::G1Affine) -> Self {
+        Self {
+            id,
+            base,
+            pseudonym,
+            commitment_to_randomness: None,
+            secret: None,
+        }
+    }
+
+    /// `blinding` is the randomness for the secret and is generated if not given
+    pub fn init<R: RngCore>(
+        &mut self,
+        rng: &mut R,
+        blinding: Option<E::Fr>,
+        secret: E::Fr,
+    ) -> Result<(), ProofSystemError> {
+        if self.commitment_to_randomness.is_some() {
+            return Err(ProofSystemError::SubProtocolAlreadyInitialized(self.id));
+        }
+        self.commitment_to_randomness = Some(SchnorrCommitment::new(
+            slice::from_ref(&self.base),
+            vec![blinding.unwrap_or_else(|| E::Fr::rand(rng))],
+        ));
+        self.secret = Some(Secret::new(secret));
+        Ok(())
+    }
+
+    pub fn challenge_contribution<W: Write>(&self, writer: W) -> Result<(), ProofSystemError> {
+        if self.commitment_to_randomness.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        SchnorrProtocol::compute_challenge_contribution(
+            slice::from_ref(&self.base),
+            &self.pseudonym,
+            &self.commitment_to_randomness.as_ref().unwrap().t,
+            writer,
+        )
+    }
+
+    pub fn append_to_transcript(
+        &self,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        if self.commitment_to_randomness.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        SchnorrProtocol::compute_transcript_contribution(
+            slice::from_ref(&self.base),
+            &self.pseudonym,
+            &self.commitment_to_randomness.as_ref().unwrap().t,
+            transcript,
+        )
+    }
+
+    pub fn gen_proof_contribution<G: AffineCurve, G2: AffineCurve>(
+        &mut self,
+        challenge: &E::Fr,
+    ) -> Result<StatementProof<E, G, G2>, ProofSystemError> {
+        if self.commitment_to_randomness.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateProof(
+                self.id,
+            ));
+        }
+        let commitment = self.commitment_to_randomness.take().unwrap();
+        let response = commitment.response(
+            slice::from_ref(self.secret.as_ref().unwrap().expose()),
+            challenge,
+        )?;
+        Ok(StatementProof::Pseudonym(PedersenCommitmentProof::new(
+            commitment.t,
+            response,
+        )))
+    }
+
+    pub fn verify_proof_contribution<G: AffineCurve, G2: AffineCurve>(
+        &self,
+        challenge: &E::Fr,
+        proof: &StatementProof<E, G, G2>,
+    ) -> Result<(), ProofSystemError> {
+        match proof {
+            StatementProof::Pseudonym(p) => p
+                .response
+                .is_valid(
+                    slice::from_ref(&self.base),
+                    &self.pseudonym,
+                    &p.t,
+                    challenge,
+                )
+                .map_err(|e| e.into()),
+            _ => Err(ProofSystemError::ProofIncompatibleWithPseudonymProtocol),
+        }
+    }
+
+    /// Challenge contribution of a proof created by this protocol. Used by the verifier.
+    pub fn compute_challenge_contribution<W: Write>(
+        base: &E::G1Affine,
+        pseudonym: &E::G1Affine,
+        proof: &PedersenCommitmentProof<E::G1Affine>,
+        writer: W,
+    ) -> Result<(), ProofSystemError> {
+        SchnorrProtocol::compute_challenge_contribution(
+            slice::from_ref(base),
+            pseudonym,
+            &proof.t,
+            writer,
+        )
+    }
+
+    /// Append the values of [`PseudonymSubProtocol::compute_challenge_contribution`] to the transcript with a label each
+    pub fn compute_transcript_contribution(
+        base: &E::G1Affine,
+        pseudonym: &E::G1Affine,
+        proof: &PedersenCommitmentProof<E::G1Affine>,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        SchnorrProtocol::compute_transcript_contribution(
+            slice::from_ref(base),
+            pseudonym,
+            &proof.t,
+            transcript,
+        )
+    }
+}
+
+impl<E: PairingEngine> Zeroize for PseudonymSubProtocol<E> {
+    fn zeroize(&mut self) {
+        if let Some(c) = self.commitment_to_randomness.as_mut() {
+            c.zeroize();
+        }
+        if let Some(s) = self.secret.as_mut() {
+            s.zeroize();
+        }
+    }
+}
+
+impl<E: PairingEngine> Drop for PseudonymSubProtocol<E> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
@@ -8,6 +8,7 @@ use ark_std::io::Write;
 use ark_std::rand::RngCore;
 use ark_std::vec::Vec;
 use ark_std::UniformRand;
+use dock_crypto_utils::transcript::Transcript;
 use legogroth16::circom::{CircomCircuit, WitnessCalculator, R1CS};
 use legogroth16::{
     create_random_proof, prepare_verifying_key, verify_proof, PreparedVerifyingKey, Proof,
@@ -99,11 +100,23 @@ impl<'a, E: PairingEngine> R1CSLegogroth16Protocol<'a, E> {
         Ok(())
     }
 
+    pub fn append_to_transcript(
+        &self,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        if self.sp.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        self.sp.as_ref().unwrap().append_to_transcript(transcript)
+    }
+
     /// Generate responses for the Schnorr protocol
-    pub fn gen_proof_contribution<G: AffineCurve>(
+    pub fn gen_proof_contribution<G: AffineCurve, G2: AffineCurve>(
         &mut self,
         challenge: &E::Fr,
-    ) -> Result<StatementProof<E, G>, ProofSystemError> {
+    ) -> Result<StatementProof<E, G, G2>, ProofSystemError> {
         if self.sp.is_none() {
             return Err(ProofSystemError::SubProtocolNotReadyToGenerateProof(
                 self.id,
@@ -161,6 +174,20 @@ impl<'a, E: PairingEngine> R1CSLegogroth16Protocol<'a, E> {
         Ok(())
     }
 
+    /// Append the values of [`Self::compute_challenge_contribution`] to the transcript with a label each
+    pub fn compute_transcript_contribution(
+        comm_key: &[E::G1Affine],
+        proof: &R1CSLegoGroth16Proof<E>,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        SchnorrProtocol::compute_transcript_contribution(
+            comm_key,
+            &proof.snark_proof.d,
+            &proof.sp.t,
+            transcript,
+        )
+    }
+
     pub fn schnorr_comm_key(vk: &VerifyingKey<E>) -> Vec<E::G1Affine> {
         vk.get_commitment_key_for_witnesses()
     }
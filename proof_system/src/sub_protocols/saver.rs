@@ -14,6 +14,9 @@ use saver::prelude::{ChunkedCommitmentGens, EncryptionGens, EncryptionKey, Provi
 use saver::setup::PreparedEncryptionGens;
 use saver::utils::decompose;
 
+use dock_crypto_utils::secret::Secret;
+use dock_crypto_utils::transcript::Transcript;
+
 /// Apart from the SAVER protocol (encryption and snark proof), this also runs 3 Schnorr proof of knowledge protocols
 #[derive(Clone, Debug, PartialEq)]
 pub struct SaverProtocol<'a, E: PairingEngine> {
@@ -114,16 +117,13 @@ impl<'a, E: PairingEngine> SaverProtocol<'a, E> {
         )?;
 
         // blinding used for `H` in both commitments
-        let h_blinding = E::Fr::rand(rng);
+        let h_blinding = Secret::new(E::Fr::rand(rng));
 
         // blinding used to prove knowledge of message in `comm_combined`. The caller of this method ensures
         // that this will be same as the one used proving knowledge of the corresponding message in BBS+
         // signature, thus allowing them to be proved equal.
-        let blinding_combined_message = if blinding_combined_message.is_none() {
-            E::Fr::rand(rng)
-        } else {
-            blinding_combined_message.unwrap()
-        };
+        let blinding_combined_message =
+            Secret::new(blinding_combined_message.unwrap_or_else(|| E::Fr::rand(rng)));
 
         // Initialize the 3 Schnorr protocols
 
@@ -131,11 +131,16 @@ impl<'a, E: PairingEngine> SaverProtocol<'a, E> {
             .chunked_commitment_gens
             .G
             .mul(message.into_repr())
-            .add(&(self.chunked_commitment_gens.H.mul(h_blinding.into_repr())))
+            .add(
+                &(self
+                    .chunked_commitment_gens
+                    .H
+                    .mul(h_blinding.expose().into_repr())),
+            )
             .into_affine();
         let comm_chunks = ChunkedCommitment::<E::G1Affine>::get_commitment_given_commitment_key(
             &message,
-            &h_blinding,
+            h_blinding.expose(),
             self.chunk_bit_size,
             &ck_comm_chunks,
         )?;
@@ -150,20 +155,31 @@ impl<'a, E: PairingEngine> SaverProtocol<'a, E> {
         let mut sp_chunks = SchnorrProtocol::new(10000, ck_comm_chunks, comm_chunks);
         let mut sp_combined = SchnorrProtocol::new(10000, ck_comm_combined, comm_combined);
 
-        let blindings_chunks = (0..message_chunks.len())
-            .map(|i| (i, E::Fr::rand(rng)))
-            .collect::<BTreeMap<usize, E::Fr>>();
+        // Same blindings for the chunks in both commitments so that they can be proved equal
+        let blindings_chunks = Secret::new(
+            (0..message_chunks.len())
+                .map(|_| E::Fr::rand(rng))
+                .collect::<Vec<_>>(),
+        );
+        let blindings_chunks_map = || {
+            blindings_chunks
+                .expose()
+                .iter()
+                .copied()
+                .enumerate()
+                .collect::<BTreeMap<usize, E::Fr>>()
+        };
         let mut sp_ciphertext_wit = message_chunks.clone();
-        sp_ciphertext_wit.push(randomness_enc);
-        sp_ciphertext.init(rng, blindings_chunks.clone(), sp_ciphertext_wit)?;
+        sp_ciphertext_wit.push(*randomness_enc.expose());
+        sp_ciphertext.init(rng, blindings_chunks_map(), sp_ciphertext_wit)?;
 
         let mut sp_chunks_wit = message_chunks.clone();
-        sp_chunks_wit.push(h_blinding);
-        sp_chunks.init(rng, blindings_chunks, sp_chunks_wit)?;
+        sp_chunks_wit.push(*h_blinding.expose());
+        sp_chunks.init(rng, blindings_chunks_map(), sp_chunks_wit)?;
 
         let mut blinding = BTreeMap::new();
-        blinding.insert(0, blinding_combined_message);
-        sp_combined.init(rng, blinding, vec![message, h_blinding])?;
+        blinding.insert(0, *blinding_combined_message.expose());
+        sp_combined.init(rng, blinding, vec![message, *h_blinding.expose()])?;
 
         self.ciphertext = Some(ciphertext);
         self.snark_proof = Some(proof);
@@ -194,11 +210,35 @@ impl<'a, E: PairingEngine> SaverProtocol<'a, E> {
         Ok(())
     }
 
+    pub fn append_to_transcript(
+        &self,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        if self.ciphertext.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        self.sp_ciphertext
+            .as_ref()
+            .unwrap()
+            .append_to_transcript(transcript)?;
+        self.sp_chunks
+            .as_ref()
+            .unwrap()
+            .append_to_transcript(transcript)?;
+        self.sp_combined
+            .as_ref()
+            .unwrap()
+            .append_to_transcript(transcript)?;
+        Ok(())
+    }
+
     /// Generate responses for the 3 Schnorr protocols
-    pub fn gen_proof_contribution<G: AffineCurve>(
+    pub fn gen_proof_contribution<G: AffineCurve, G2: AffineCurve>(
         &mut self,
         challenge: &E::Fr,
-    ) -> Result<StatementProof<E, G>, ProofSystemError> {
+    ) -> Result<StatementProof<E, G, G2>, ProofSystemError> {
         if self.ciphertext.is_none() {
             return Err(ProofSystemError::SubProtocolNotReadyToGenerateProof(
                 self.id,
@@ -318,6 +358,34 @@ impl<'a, E: PairingEngine> SaverProtocol<'a, E> {
         Ok(())
     }
 
+    /// Append the values of [`SaverProtocol::compute_challenge_contribution`] to the transcript with a label each
+    pub fn compute_transcript_contribution(
+        ck_comm_ct: &[E::G1Affine],
+        ck_comm_chunks: &[E::G1Affine],
+        ck_comm_combined: &[E::G1Affine],
+        proof: &SaverProof<E>,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        SchnorrProtocol::compute_transcript_contribution(
+            ck_comm_ct,
+            &proof.ciphertext.commitment,
+            &proof.sp_ciphertext.t,
+            transcript,
+        )?;
+        SchnorrProtocol::compute_transcript_contribution(
+            ck_comm_chunks,
+            &proof.comm_chunks,
+            &proof.sp_chunks.t,
+            transcript,
+        )?;
+        SchnorrProtocol::compute_transcript_contribution(
+            ck_comm_combined,
+            &proof.comm_combined,
+            &proof.sp_combined.t,
+            transcript,
+        )
+    }
+
     pub fn validate_encryption_key(
         chunk_bit_size: u8,
         encryption_key: &EncryptionKey<E>,
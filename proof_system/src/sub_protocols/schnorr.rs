@@ -2,23 +2,22 @@ use ark_ec::{AffineCurve, PairingEngine};
 use ark_serialize::CanonicalSerialize;
 use ark_std::rand::RngCore;
 use ark_std::UniformRand;
-use ark_std::{cfg_iter_mut, collections::BTreeMap, io::Write, vec::Vec};
+use ark_std::{collections::BTreeMap, io::Write, vec::Vec};
+use dock_crypto_utils::secret::Secret;
+use dock_crypto_utils::transcript::Transcript;
 use schnorr_pok::{SchnorrChallengeContributor, SchnorrCommitment};
 use zeroize::Zeroize;
 
 use crate::error::ProofSystemError;
 use crate::statement_proof::{PedersenCommitmentProof, StatementProof};
 
-#[cfg(feature = "parallel")]
-use rayon::prelude::*;
-
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SchnorrProtocol<'a, G: AffineCurve> {
     pub id: usize,
     pub commitment_key: &'a [G],
     pub commitment: G,
     pub commitment_to_randomness: Option<SchnorrCommitment<G>>,
-    pub witnesses: Option<Vec<G::ScalarField>>,
+    pub witnesses: Option<Secret<Vec<G::ScalarField>>>,
 }
 
 impl<'a, G: AffineCurve> SchnorrProtocol<'a, G> {
@@ -51,7 +50,7 @@ impl<'a, G: AffineCurve> SchnorrProtocol<'a, G> {
             .collect::<Vec<_>>();
         self.commitment_to_randomness =
             Some(SchnorrCommitment::new(&self.commitment_key, blindings));
-        self.witnesses = Some(witnesses);
+        self.witnesses = Some(Secret::new(witnesses));
         Ok(())
     }
 
@@ -70,10 +69,27 @@ impl<'a, G: AffineCurve> SchnorrProtocol<'a, G> {
         Ok(())
     }
 
-    pub fn gen_proof_contribution<E: PairingEngine>(
+    pub fn append_to_transcript(
+        &self,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        if self.commitment_to_randomness.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        Self::compute_transcript_contribution(
+            self.commitment_key,
+            &self.commitment,
+            &self.commitment_to_randomness.as_ref().unwrap().t,
+            transcript,
+        )
+    }
+
+    pub fn gen_proof_contribution<E: PairingEngine, G2: AffineCurve>(
         &mut self,
         challenge: &G::ScalarField,
-    ) -> Result<StatementProof<E, G>, ProofSystemError> {
+    ) -> Result<StatementProof<E, G, G2>, ProofSystemError> {
         Ok(StatementProof::PedersenCommitment(
             self.gen_proof_contribution_as_struct(challenge)?,
         ))
@@ -89,14 +105,15 @@ impl<'a, G: AffineCurve> SchnorrProtocol<'a, G> {
             ));
         }
         let commitment = self.commitment_to_randomness.take().unwrap();
-        let responses = commitment.response(self.witnesses.as_ref().unwrap(), challenge)?;
+        let responses =
+            commitment.response(self.witnesses.as_ref().unwrap().expose(), challenge)?;
         Ok(PedersenCommitmentProof::new(commitment.t, responses))
     }
 
-    pub fn verify_proof_contribution<E: PairingEngine>(
+    pub fn verify_proof_contribution<E: PairingEngine, G2: AffineCurve>(
         &self,
         challenge: &G::ScalarField,
-        proof: &StatementProof<E, G>,
+        proof: &StatementProof<E, G, G2>,
     ) -> Result<(), ProofSystemError> {
         match proof {
             StatementProof::PedersenCommitment(p) => {
@@ -128,14 +145,25 @@ impl<'a, G: AffineCurve> SchnorrProtocol<'a, G> {
         t.serialize_unchecked(writer)?;
         Ok(())
     }
+
+    /// Append the values of [`SchnorrProtocol::compute_challenge_contribution`] to the transcript with a label each
+    pub fn compute_transcript_contribution(
+        bases: &[G],
+        y: &G,
+        t: &G,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        transcript.append_points(b"bases", bases)?;
+        transcript.append_point(b"y", y)?;
+        transcript.append_point(b"t", t)?;
+        Ok(())
+    }
 }
 
 impl<'a, G: AffineCurve> Zeroize for SchnorrProtocol<'a, G> {
     fn zeroize(&mut self) {
         self.commitment_to_randomness.as_mut().map(|c| c.zeroize());
-        self.witnesses
-            .as_mut()
-            .map(|w| cfg_iter_mut!(w).for_each(|v| v.zeroize()));
+        self.witnesses.as_mut().map(|w| w.zeroize());
     }
 }
 
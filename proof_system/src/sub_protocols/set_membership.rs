@@ -0,0 +1,206 @@
+use ark_ec::{AffineCurve, PairingEngine};
+use ark_std::{io::Write, rand::RngCore, vec, UniformRand};
+use compressed_sigma::set_membership::{SetMembershipParams, SetMembershipProtocol};
+use digest::Digest;
+use dock_crypto_utils::secret::Secret;
+use dock_crypto_utils::transcript::Transcript;
+use schnorr_pok::SchnorrCommitment;
+use zeroize::Zeroize;
+
+use crate::error::ProofSystemError;
+use crate::statement_proof::{PedersenCommitmentProof, SetMembershipProof, StatementProof};
+use crate::sub_protocols::schnorr::SchnorrProtocol;
+
+/// Commits to the member using a Pedersen commitment, runs a Schnorr protocol for proving knowledge of the
+/// member and randomness in the commitment and a proof of partial knowledge for proving that the commitment
+/// opens to one of the set's values.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetMembershipSubProtocol<'a, G: AffineCurve> {
+    pub id: usize,
+    pub set: &'a [G::ScalarField],
+    pub params: &'a SetMembershipParams<G>,
+    pub commitment: Option<G>,
+    pub sc: Option<SchnorrCommitment<G>>,
+    /// The member and the randomness of the commitment
+    pub witnesses: Option<Secret<[G::ScalarField; 2]>>,
+    pub protocol: Option<SetMembershipProtocol<G>>,
+}
+
+impl<'a, G: AffineCurve> SetMembershipSubProtocol<'a, G> {
+    pub fn new(id: usize, set: &'a [G::ScalarField], params: &'a SetMembershipParams<G>) -> Self {
+        Self {
+            id,
+            set,
+            params,
+            commitment: None,
+            sc: None,
+            witnesses: None,
+            protocol: None,
+        }
+    }
+
+    /// `blinding` is the randomness for the member and is generated if not given
+    pub fn init<R: RngCore, D: Digest>(
+        &mut self,
+        rng: &mut R,
+        blinding: Option<G::ScalarField>,
+        member: G::ScalarField,
+    ) -> Result<(), ProofSystemError> {
+        if self.sc.is_some() {
+            return Err(ProofSystemError::SubProtocolAlreadyInitialized(self.id));
+        }
+        let randomness = G::ScalarField::rand(rng);
+        let commitment = self.params.commit(&member, &randomness);
+        let protocol = SetMembershipProtocol::init::<_, D>(
+            rng,
+            self.params,
+            self.set,
+            &commitment,
+            &member,
+            &randomness,
+        )?;
+        let blindings = vec![
+            blinding.unwrap_or_else(|| G::ScalarField::rand(rng)),
+            G::ScalarField::rand(rng),
+        ];
+        self.sc = Some(SchnorrCommitment::new(
+            &[self.params.g, self.params.h],
+            blindings,
+        ));
+        self.commitment = Some(commitment);
+        self.witnesses = Some(Secret::new([member, randomness]));
+        self.protocol = Some(protocol);
+        Ok(())
+    }
+
+    pub fn challenge_contribution<W: Write>(&self, mut writer: W) -> Result<(), ProofSystemError> {
+        if self.sc.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        SchnorrProtocol::compute_challenge_contribution(
+            &[self.params.g, self.params.h],
+            self.commitment.as_ref().unwrap(),
+            &self.sc.as_ref().unwrap().t,
+            &mut writer,
+        )?;
+        self.protocol
+            .as_ref()
+            .unwrap()
+            .challenge_contribution(writer)?;
+        Ok(())
+    }
+
+    pub fn append_to_transcript(
+        &self,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        if self.sc.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateChallenge(
+                self.id,
+            ));
+        }
+        SchnorrProtocol::compute_transcript_contribution(
+            &[self.params.g, self.params.h],
+            self.commitment.as_ref().unwrap(),
+            &self.sc.as_ref().unwrap().t,
+            transcript,
+        )?;
+        self.protocol
+            .as_ref()
+            .unwrap()
+            .append_to_transcript(transcript)?;
+        Ok(())
+    }
+
+    pub fn gen_proof_contribution<E: PairingEngine, D: Digest, G2: AffineCurve>(
+        &mut self,
+        challenge: &G::ScalarField,
+    ) -> Result<StatementProof<E, G, G2>, ProofSystemError> {
+        if self.sc.is_none() {
+            return Err(ProofSystemError::SubProtocolNotReadyToGenerateProof(
+                self.id,
+            ));
+        }
+        let sc = self.sc.take().unwrap();
+        let response = sc.response(self.witnesses.as_ref().unwrap().expose(), challenge)?;
+        let or_proof = self
+            .protocol
+            .take()
+            .unwrap()
+            .gen_proof::<D>(self.params, challenge)?;
+        Ok(StatementProof::SetMembership(SetMembershipProof {
+            commitment: self.commitment.take().unwrap(),
+            sp: PedersenCommitmentProof::new(sc.t, response),
+            or_proof,
+        }))
+    }
+
+    pub fn verify_proof_contribution<E: PairingEngine, D: Digest, G2: AffineCurve>(
+        &self,
+        challenge: &G::ScalarField,
+        proof: &StatementProof<E, G, G2>,
+    ) -> Result<(), ProofSystemError> {
+        match proof {
+            StatementProof::SetMembership(p) => {
+                p.sp.response.is_valid(
+                    &[self.params.g, self.params.h],
+                    &p.commitment,
+                    &p.sp.t,
+                    challenge,
+                )?;
+                p.or_proof
+                    .verify::<D>(self.params, self.set, &p.commitment, challenge)?;
+                Ok(())
+            }
+            _ => Err(ProofSystemError::ProofIncompatibleWithSetMembershipProtocol),
+        }
+    }
+
+    /// Challenge contribution of a proof created by this protocol. Used by the verifier.
+    pub fn compute_challenge_contribution<W: Write>(
+        params: &SetMembershipParams<G>,
+        proof: &SetMembershipProof<G>,
+        mut writer: W,
+    ) -> Result<(), ProofSystemError> {
+        SchnorrProtocol::compute_challenge_contribution(
+            &[params.g, params.h],
+            &proof.commitment,
+            &proof.sp.t,
+            &mut writer,
+        )?;
+        proof.or_proof.challenge_contribution(writer)?;
+        Ok(())
+    }
+
+    /// Append the values of [`SetMembershipSubProtocol::compute_challenge_contribution`] to the transcript with
+    /// a label each
+    pub fn compute_transcript_contribution(
+        params: &SetMembershipParams<G>,
+        proof: &SetMembershipProof<G>,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofSystemError> {
+        SchnorrProtocol::compute_transcript_contribution(
+            &[params.g, params.h],
+            &proof.commitment,
+            &proof.sp.t,
+            transcript,
+        )?;
+        proof.or_proof.append_to_transcript(transcript)?;
+        Ok(())
+    }
+}
+
+impl<'a, G: AffineCurve> Zeroize for SetMembershipSubProtocol<'a, G> {
+    fn zeroize(&mut self) {
+        self.sc.as_mut().map(|c| c.zeroize());
+        self.witnesses.as_mut().map(|w| w.zeroize());
+    }
+}
+
+impl<'a, G: AffineCurve> Drop for SetMembershipSubProtocol<'a, G> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
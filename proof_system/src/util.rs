@@ -31,6 +31,38 @@ macro_rules! impl_serialize {
                     CanonicalSerialize::serialize(&6u8, &mut writer)?;
                     CanonicalSerialize::serialize(s, &mut writer)
                 }
+                Self::PoKIetfBBSSignatureG1(s) => {
+                    CanonicalSerialize::serialize(&7u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
+                Self::SetMembership(s) => {
+                    CanonicalSerialize::serialize(&8u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
+                Self::Inequality(s) => {
+                    CanonicalSerialize::serialize(&9u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
+                Self::BoundCheckBulletproofs(s) => {
+                    CanonicalSerialize::serialize(&10u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
+                Self::BoundCheckSmc(s) => {
+                    CanonicalSerialize::serialize(&11u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
+                Self::DiscreteLogEqualityAcrossGroups(s) => {
+                    CanonicalSerialize::serialize(&12u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
+                Self::Pseudonym(s) => {
+                    CanonicalSerialize::serialize(&13u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
+                Self::HolderBinding(s) => {
+                    CanonicalSerialize::serialize(&14u8, &mut writer)?;
+                    CanonicalSerialize::serialize(s, &mut writer)
+                }
             }
         }
 
@@ -43,6 +75,16 @@ macro_rules! impl_serialize {
                 Self::Saver(s) => 4u8.serialized_size() + s.serialized_size(),
                 Self::BoundCheckLegoGroth16(s) => 5u8.serialized_size() + s.serialized_size(),
                 Self::R1CSLegoGroth16(s) => 6u8.serialized_size() + s.serialized_size(),
+                Self::PoKIetfBBSSignatureG1(s) => 7u8.serialized_size() + s.serialized_size(),
+                Self::SetMembership(s) => 8u8.serialized_size() + s.serialized_size(),
+                Self::Inequality(s) => 9u8.serialized_size() + s.serialized_size(),
+                Self::BoundCheckBulletproofs(s) => 10u8.serialized_size() + s.serialized_size(),
+                Self::BoundCheckSmc(s) => 11u8.serialized_size() + s.serialized_size(),
+                Self::DiscreteLogEqualityAcrossGroups(s) => {
+                    12u8.serialized_size() + s.serialized_size()
+                }
+                Self::Pseudonym(s) => 13u8.serialized_size() + s.serialized_size(),
+                Self::HolderBinding(s) => 14u8.serialized_size() + s.serialized_size(),
             }
         }
 
@@ -79,6 +121,38 @@ macro_rules! impl_serialize {
                     6u8.serialize_uncompressed(&mut writer)?;
                     s.serialize_uncompressed(&mut writer)
                 }
+                Self::PoKIetfBBSSignatureG1(s) => {
+                    7u8.serialize_uncompressed(&mut writer)?;
+                    s.serialize_uncompressed(&mut writer)
+                }
+                Self::SetMembership(s) => {
+                    8u8.serialize_uncompressed(&mut writer)?;
+                    s.serialize_uncompressed(&mut writer)
+                }
+                Self::Inequality(s) => {
+                    9u8.serialize_uncompressed(&mut writer)?;
+                    s.serialize_uncompressed(&mut writer)
+                }
+                Self::BoundCheckBulletproofs(s) => {
+                    10u8.serialize_uncompressed(&mut writer)?;
+                    s.serialize_uncompressed(&mut writer)
+                }
+                Self::BoundCheckSmc(s) => {
+                    11u8.serialize_uncompressed(&mut writer)?;
+                    s.serialize_uncompressed(&mut writer)
+                }
+                Self::DiscreteLogEqualityAcrossGroups(s) => {
+                    12u8.serialize_uncompressed(&mut writer)?;
+                    s.serialize_uncompressed(&mut writer)
+                }
+                Self::Pseudonym(s) => {
+                    13u8.serialize_uncompressed(&mut writer)?;
+                    s.serialize_uncompressed(&mut writer)
+                }
+                Self::HolderBinding(s) => {
+                    14u8.serialize_uncompressed(&mut writer)?;
+                    s.serialize_uncompressed(&mut writer)
+                }
             }
         }
 
@@ -112,6 +186,38 @@ macro_rules! impl_serialize {
                     6u8.serialize_unchecked(&mut writer)?;
                     s.serialize_unchecked(&mut writer)
                 }
+                Self::PoKIetfBBSSignatureG1(s) => {
+                    7u8.serialize_unchecked(&mut writer)?;
+                    s.serialize_unchecked(&mut writer)
+                }
+                Self::SetMembership(s) => {
+                    8u8.serialize_unchecked(&mut writer)?;
+                    s.serialize_unchecked(&mut writer)
+                }
+                Self::Inequality(s) => {
+                    9u8.serialize_unchecked(&mut writer)?;
+                    s.serialize_unchecked(&mut writer)
+                }
+                Self::BoundCheckBulletproofs(s) => {
+                    10u8.serialize_unchecked(&mut writer)?;
+                    s.serialize_unchecked(&mut writer)
+                }
+                Self::BoundCheckSmc(s) => {
+                    11u8.serialize_unchecked(&mut writer)?;
+                    s.serialize_unchecked(&mut writer)
+                }
+                Self::DiscreteLogEqualityAcrossGroups(s) => {
+                    12u8.serialize_unchecked(&mut writer)?;
+                    s.serialize_unchecked(&mut writer)
+                }
+                Self::Pseudonym(s) => {
+                    13u8.serialize_unchecked(&mut writer)?;
+                    s.serialize_unchecked(&mut writer)
+                }
+                Self::HolderBinding(s) => {
+                    14u8.serialize_unchecked(&mut writer)?;
+                    s.serialize_unchecked(&mut writer)
+                }
             }
         }
 
@@ -126,6 +232,16 @@ macro_rules! impl_serialize {
                 Self::Saver(s) => 4u8.uncompressed_size() + s.uncompressed_size(),
                 Self::BoundCheckLegoGroth16(s) => 5u8.uncompressed_size() + s.uncompressed_size(),
                 Self::R1CSLegoGroth16(s) => 6u8.uncompressed_size() + s.uncompressed_size(),
+                Self::PoKIetfBBSSignatureG1(s) => 7u8.uncompressed_size() + s.uncompressed_size(),
+                Self::SetMembership(s) => 8u8.uncompressed_size() + s.uncompressed_size(),
+                Self::Inequality(s) => 9u8.uncompressed_size() + s.uncompressed_size(),
+                Self::BoundCheckBulletproofs(s) => 10u8.uncompressed_size() + s.uncompressed_size(),
+                Self::BoundCheckSmc(s) => 11u8.uncompressed_size() + s.uncompressed_size(),
+                Self::DiscreteLogEqualityAcrossGroups(s) => {
+                    12u8.uncompressed_size() + s.uncompressed_size()
+                }
+                Self::Pseudonym(s) => 13u8.uncompressed_size() + s.uncompressed_size(),
+                Self::HolderBinding(s) => 14u8.uncompressed_size() + s.uncompressed_size(),
             }
         }
     };
@@ -156,6 +272,30 @@ macro_rules! impl_deserialize {
                 6u8 => Ok(Self::R1CSLegoGroth16(CanonicalDeserialize::deserialize(
                     &mut reader,
                 )?)),
+                7u8 => Ok(Self::PoKIetfBBSSignatureG1(
+                    CanonicalDeserialize::deserialize(&mut reader)?,
+                )),
+                8u8 => Ok(Self::SetMembership(CanonicalDeserialize::deserialize(
+                    &mut reader,
+                )?)),
+                9u8 => Ok(Self::Inequality(CanonicalDeserialize::deserialize(
+                    &mut reader,
+                )?)),
+                10u8 => Ok(Self::BoundCheckBulletproofs(
+                    CanonicalDeserialize::deserialize(&mut reader)?,
+                )),
+                11u8 => Ok(Self::BoundCheckSmc(CanonicalDeserialize::deserialize(
+                    &mut reader,
+                )?)),
+                12u8 => Ok(Self::DiscreteLogEqualityAcrossGroups(
+                    CanonicalDeserialize::deserialize(&mut reader)?,
+                )),
+                13u8 => Ok(Self::Pseudonym(CanonicalDeserialize::deserialize(
+                    &mut reader,
+                )?)),
+                14u8 => Ok(Self::HolderBinding(CanonicalDeserialize::deserialize(
+                    &mut reader,
+                )?)),
                 _ => Err(SerializationError::InvalidData),
             }
         }
@@ -183,6 +323,30 @@ macro_rules! impl_deserialize {
                 6u8 => Ok(Self::R1CSLegoGroth16(
                     CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
                 )),
+                7u8 => Ok(Self::PoKIetfBBSSignatureG1(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
+                8u8 => Ok(Self::SetMembership(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
+                9u8 => Ok(Self::Inequality(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
+                10u8 => Ok(Self::BoundCheckBulletproofs(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
+                11u8 => Ok(Self::BoundCheckSmc(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
+                12u8 => Ok(Self::DiscreteLogEqualityAcrossGroups(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
+                13u8 => Ok(Self::Pseudonym(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
+                14u8 => Ok(Self::HolderBinding(
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+                )),
                 _ => Err(SerializationError::InvalidData),
             }
         }
@@ -210,6 +374,30 @@ macro_rules! impl_deserialize {
                 6u8 => Ok(Self::R1CSLegoGroth16(
                     CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
                 )),
+                7u8 => Ok(Self::PoKIetfBBSSignatureG1(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
+                8u8 => Ok(Self::SetMembership(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
+                9u8 => Ok(Self::Inequality(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
+                10u8 => Ok(Self::BoundCheckBulletproofs(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
+                11u8 => Ok(Self::BoundCheckSmc(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
+                12u8 => Ok(Self::DiscreteLogEqualityAcrossGroups(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
+                13u8 => Ok(Self::Pseudonym(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
+                14u8 => Ok(Self::HolderBinding(
+                    CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+                )),
                 _ => Err(SerializationError::InvalidData),
             }
         }
@@ -226,3 +414,6 @@ impl_for_groth16_struct!(ProofBytes);
 impl_for_groth16_struct!(LegoProofBytes);
 
 impl_for_groth16_struct!(R1CSBytes);
+
+impl_for_groth16_struct!(SetMembershipParamsBytes);
+impl_for_groth16_struct!(SetMembershipOrProofBytes);
@@ -8,6 +8,7 @@ use ark_std::{
     string::String,
     vec::Vec,
 };
+use bbs_plus::bbs::SignatureG1 as IetfBBSSignatureG1;
 use bbs_plus::signature::SignatureG1 as BBSSignatureG1;
 use dock_crypto_utils::serde_utils::*;
 use serde::{Deserialize, Serialize};
@@ -32,6 +33,21 @@ pub enum Witness<E: PairingEngine> {
     /// Message whose bounds are checked
     BoundCheckLegoGroth16(#[serde_as(as = "FieldBytes")] E::Fr),
     R1CSLegoGroth16(R1CSCircomWitness<E>),
+    PoKIetfBBSSignatureG1(PoKIetfBBSSignatureG1<E>),
+    /// Member of the set
+    SetMembership(#[serde_as(as = "FieldBytes")] E::Fr),
+    /// Value which is not equal to the public value
+    Inequality(#[serde_as(as = "FieldBytes")] E::Fr),
+    /// Messages whose bounds are checked, in the same order as the bounds in the statement
+    BoundCheckBulletproofs(#[serde_as(as = "Vec<FieldBytes>")] Vec<E::Fr>),
+    /// Message whose bounds are checked using set-membership check
+    BoundCheckSmc(#[serde_as(as = "FieldBytes")] E::Fr),
+    /// Integer which is the discrete log of the public value in the other group
+    DiscreteLogEqualityAcrossGroups(#[serde_as(as = "FieldBytes")] E::Fr),
+    /// Secret from which the pseudonym is created
+    Pseudonym(#[serde_as(as = "FieldBytes")] E::Fr),
+    /// Secret key of the holder's public key
+    HolderBinding(#[serde_as(as = "FieldBytes")] E::Fr),
 }
 
 #[derive(
@@ -69,6 +85,33 @@ impl<E: PairingEngine> Drop for PoKBBSSignatureG1<E> {
     }
 }
 
+/// Secret data when proving knowledge of BBS sig as per the IETF draft
+#[serde_as]
+#[derive(
+    Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct PoKIetfBBSSignatureG1<E: PairingEngine> {
+    pub signature: IetfBBSSignatureG1<E>,
+    #[serde_as(as = "BTreeMap<Same, FieldBytes>")]
+    pub unrevealed_messages: BTreeMap<usize, E::Fr>,
+}
+
+impl<E: PairingEngine> Zeroize for PoKIetfBBSSignatureG1<E> {
+    fn zeroize(&mut self) {
+        self.signature.zeroize();
+        self.unrevealed_messages
+            .values_mut()
+            .for_each(|v| v.zeroize());
+    }
+}
+
+impl<E: PairingEngine> Drop for PoKIetfBBSSignatureG1<E> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
 /// Secret data when proving accumulator membership
 #[serde_as]
 #[derive(
@@ -187,6 +230,19 @@ impl<E: PairingEngine> PoKBBSSignatureG1<E> {
     }
 }
 
+impl<E: PairingEngine> PoKIetfBBSSignatureG1<E> {
+    /// Create a `Witness` variant for proving knowledge of BBS signature as per the IETF draft
+    pub fn new_as_witness(
+        signature: IetfBBSSignatureG1<E>,
+        unrevealed_messages: BTreeMap<usize, E::Fr>,
+    ) -> Witness<E> {
+        Witness::PoKIetfBBSSignatureG1(PoKIetfBBSSignatureG1 {
+            signature,
+            unrevealed_messages,
+        })
+    }
+}
+
 impl<E: PairingEngine> Membership<E> {
     /// Create a `Witness` variant for proving membership in accumulator
     pub fn new_as_witness(element: E::Fr, witness: MembershipWitness<E::G1Affine>) -> Witness<E> {
@@ -276,6 +332,7 @@ mod tests {
         rand::{rngs::StdRng, SeedableRng},
         UniformRand,
     };
+    use test_utils::bbs::ietf_bbs_sig_setup;
     use test_utils::bbs_plus::sig_setup;
     use test_utils::test_serialization;
 
@@ -328,5 +385,59 @@ mod tests {
 
         witnesses.add(wit_4);
         test_serialization!(Witnesses<Bls12_381>, witnesses);
+
+        let (msgs, _, _, sig) = ietf_bbs_sig_setup(&mut rng, 5, b"header");
+        let wit_5 = PoKIetfBBSSignatureG1::new_as_witness(
+            sig,
+            msgs.into_iter()
+                .enumerate()
+                .collect::<BTreeMap<usize, Fr>>(),
+        );
+        test_serialization!(Witness<Bls12_381>, wit_5);
+
+        witnesses.add(wit_5);
+        test_serialization!(Witnesses<Bls12_381>, witnesses);
+
+        let wit_8 = Witness::SetMembership(Fr::rand(&mut rng));
+        test_serialization!(Witness<Bls12_381>, wit_8);
+
+        witnesses.add(wit_8);
+        test_serialization!(Witnesses<Bls12_381>, witnesses);
+
+        let wit_9 = Witness::Inequality(Fr::rand(&mut rng));
+        test_serialization!(Witness<Bls12_381>, wit_9);
+
+        witnesses.add(wit_9);
+        test_serialization!(Witnesses<Bls12_381>, witnesses);
+
+        let wit_10 = Witness::BoundCheckBulletproofs(vec![Fr::from(10u64), Fr::from(20u64)]);
+        test_serialization!(Witness<Bls12_381>, wit_10);
+
+        witnesses.add(wit_10);
+        test_serialization!(Witnesses<Bls12_381>, witnesses);
+
+        let wit_11 = Witness::BoundCheckSmc(Fr::from(30u64));
+        test_serialization!(Witness<Bls12_381>, wit_11);
+
+        witnesses.add(wit_11);
+        test_serialization!(Witnesses<Bls12_381>, witnesses);
+
+        let wit_12 = Witness::DiscreteLogEqualityAcrossGroups(Fr::from(u64::MAX));
+        test_serialization!(Witness<Bls12_381>, wit_12);
+
+        witnesses.add(wit_12);
+        test_serialization!(Witnesses<Bls12_381>, witnesses);
+
+        let wit_13 = Witness::Pseudonym(Fr::rand(&mut rng));
+        test_serialization!(Witness<Bls12_381>, wit_13);
+
+        witnesses.add(wit_13);
+        test_serialization!(Witnesses<Bls12_381>, witnesses);
+
+        let wit_14 = Witness::HolderBinding(Fr::rand(&mut rng));
+        test_serialization!(Witness<Bls12_381>, wit_14);
+
+        witnesses.add(wit_14);
+        test_serialization!(Witnesses<Bls12_381>, witnesses);
     }
 }
@@ -5,8 +5,10 @@ use ark_ff::PrimeField;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::collections::{BTreeMap, BTreeSet};
 use ark_std::{rand::prelude::StdRng, rand::SeedableRng, UniformRand};
-use bbs_plus::prelude::SignatureG1;
+use bbs_plus::prelude::{KeypairG2, SignatureG1, SignatureParamsG1};
+use blake2::Blake2b;
 use dock_crypto_utils::msm::variable_base_msm;
+use dock_crypto_utils::transcript::Transcript;
 use vb_accumulator::prelude::{Accumulator, MembershipProvingKey, NonMembershipProvingKey};
 
 use proof_system::prelude::{EqualWitnesses, MetaStatements, Witness, WitnessRef, Witnesses};
@@ -1252,6 +1254,64 @@ fn pok_of_bbs_plus_sig_with_reusing_setup_params() {
     proof.verify(proof_spec.clone(), None).unwrap();
 }
 
+#[test]
+fn pok_of_bbs_plus_sigs_with_extended_params() {
+    // Prove knowledge of 2 BBS+ signatures by the same signer, one created before the signer extended its
+    // params with more messages and one after, and that the 1st message is same in both.
+    let mut rng = StdRng::seed_from_u64(0u64);
+
+    let label = b"test-params";
+    let old_msg_count = 4;
+    let new_msg_count = 6;
+    let old_params = SignatureParamsG1::<Bls12_381>::new::<Blake2b>(label, old_msg_count);
+    let keypair = KeypairG2::<Bls12_381>::generate_using_rng(&mut rng, &old_params);
+    let old_msgs: Vec<Fr> = (0..old_msg_count).map(|_| Fr::rand(&mut rng)).collect();
+    let old_sig =
+        SignatureG1::<Bls12_381>::new(&mut rng, &old_msgs, &keypair.secret_key, &old_params)
+            .unwrap();
+
+    let params = old_params.extend::<Blake2b>(label, new_msg_count).unwrap();
+    let mut new_msgs: Vec<Fr> = (0..new_msg_count).map(|_| Fr::rand(&mut rng)).collect();
+    new_msgs[0] = old_msgs[0];
+    let new_sig =
+        SignatureG1::<Bls12_381>::new(&mut rng, &new_msgs, &keypair.secret_key, &params).unwrap();
+
+    let mut statements = Statements::new();
+    statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+        params.prefix(old_msg_count).unwrap(),
+        keypair.public_key.clone(),
+        BTreeMap::new(),
+    ));
+    statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+        params.clone(),
+        keypair.public_key.clone(),
+        BTreeMap::new(),
+    ));
+
+    let mut meta_statements = MetaStatements::new();
+    meta_statements.add_witness_equality(EqualWitnesses(
+        vec![(0, 0), (1, 0)]
+            .into_iter()
+            .collect::<BTreeSet<WitnessRef>>(),
+    ));
+
+    let proof_spec = ProofSpec::new(statements, meta_statements, vec![], None);
+    proof_spec.validate().unwrap();
+
+    let mut witnesses = Witnesses::new();
+    witnesses.add(PoKSignatureBBSG1Wit::new_as_witness(
+        old_sig,
+        old_msgs.into_iter().enumerate().collect(),
+    ));
+    witnesses.add(PoKSignatureBBSG1Wit::new_as_witness(
+        new_sig,
+        new_msgs.into_iter().enumerate().collect(),
+    ));
+
+    let proof = ProofG1::new(&mut rng, proof_spec.clone(), witnesses, None).unwrap();
+    proof.verify(proof_spec, None).unwrap();
+}
+
 #[test]
 fn proof_spec_validation() {
     // Catch invalid proof spec like with invalid witness equality or revealing a message while also referencing it in witness equality
@@ -1326,3 +1386,118 @@ fn proof_spec_validation() {
     let ps_3 = ProofSpec::new(statements_3, meta_statements_3, vec![], None);
     assert!(ps_3.validate().is_err());
 }
+
+#[test]
+fn pok_of_bbs_plus_sig_and_accumulator_with_transcript() {
+    // Challenge of the proof is derived from a caller provided transcript, either labelled or in compatibility mode
+    let mut rng = StdRng::seed_from_u64(0u64);
+
+    let msg_count = 6;
+    let (msgs, sig_params, sig_keypair, sig) = sig_setup(&mut rng, msg_count);
+
+    let (accum_params, accum_keypair, mut accumulator, mut state) = setup_positive_accum(&mut rng);
+    let prk = MembershipProvingKey::generate_using_rng(&mut rng);
+    let member_idx = 1;
+    accumulator = accumulator
+        .add(msgs[member_idx], &accum_keypair.secret_key, &mut state)
+        .unwrap();
+    let mem_wit = accumulator
+        .get_membership_witness(&msgs[member_idx], &accum_keypair.secret_key, &state)
+        .unwrap();
+
+    let mut statements = Statements::new();
+    statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+        sig_params.clone(),
+        sig_keypair.public_key.clone(),
+        BTreeMap::new(),
+    ));
+    statements.add(AccumulatorMembershipStmt::new_statement_from_params(
+        accum_params.clone(),
+        accum_keypair.public_key.clone(),
+        prk.clone(),
+        accumulator.value().clone(),
+    ));
+
+    let mut meta_statements = MetaStatements::new();
+    meta_statements.add_witness_equality(EqualWitnesses(
+        vec![(0, member_idx), (1, 0)]
+            .into_iter()
+            .collect::<BTreeSet<WitnessRef>>(),
+    ));
+
+    let context = Some(b"test".to_vec());
+    let proof_spec = ProofSpec::new(statements, meta_statements, vec![], context);
+    proof_spec.validate().unwrap();
+
+    let mut witnesses = Witnesses::new();
+    witnesses.add(PoKSignatureBBSG1Wit::new_as_witness(
+        sig.clone(),
+        msgs.clone().into_iter().enumerate().collect(),
+    ));
+    witnesses.add(MembershipWit::new_as_witness(msgs[member_idx], mem_wit));
+
+    let nonce = Some(b"test-nonce".to_vec());
+
+    // Proof with the challenge computed over the concatenation of the nonce, context and the challenge
+    // contributions, as done before transcripts were introduced
+    let proof = ProofG1::new_with_transcript(
+        &mut rng,
+        proof_spec.clone(),
+        witnesses.clone(),
+        nonce.clone(),
+        Transcript::new_compatible(),
+    )
+    .unwrap();
+    test_serialization!(ProofG1, proof);
+    proof
+        .clone()
+        .verify_with_transcript(
+            proof_spec.clone(),
+            nonce.clone(),
+            Transcript::new_compatible(),
+        )
+        .unwrap();
+    // The default transcript is labelled so the proof does not verify with it
+    assert!(proof.verify(proof_spec.clone(), nonce.clone()).is_err());
+
+    // Proof with an application specific transcript
+    let create_transcript = |app_data: &[u8]| {
+        let mut transcript = Transcript::new(b"test-app");
+        transcript.append_message(b"app-data", app_data);
+        transcript
+    };
+    let proof = ProofG1::new_with_transcript(
+        &mut rng,
+        proof_spec.clone(),
+        witnesses.clone(),
+        nonce.clone(),
+        create_transcript(b"data"),
+    )
+    .unwrap();
+    test_serialization!(ProofG1, proof);
+    proof
+        .clone()
+        .verify_with_transcript(
+            proof_spec.clone(),
+            nonce.clone(),
+            create_transcript(b"data"),
+        )
+        .unwrap();
+    assert!(proof
+        .clone()
+        .verify_with_transcript(
+            proof_spec.clone(),
+            nonce.clone(),
+            create_transcript(b"other-data")
+        )
+        .is_err());
+    assert!(proof
+        .clone()
+        .verify_with_transcript(
+            proof_spec.clone(),
+            nonce.clone(),
+            Transcript::new_compatible()
+        )
+        .is_err());
+    assert!(proof.verify(proof_spec, nonce).is_err());
+}
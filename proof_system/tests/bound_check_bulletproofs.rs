@@ -0,0 +1,192 @@
+use ark_bls12_381::{Bls12_381, G1Affine, G2Affine};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::collections::{BTreeMap, BTreeSet};
+use ark_std::{rand::prelude::StdRng, rand::SeedableRng, UniformRand};
+use blake2::Blake2b;
+use bulletproofs::setup::SetupParams as BulletproofsSetupParams;
+use proof_system::prelude::{
+    EqualWitnesses, MetaStatements, ProofSystemError, Witness, WitnessRef, Witnesses,
+};
+use proof_system::proof_spec::ProofSpec;
+use proof_system::setup_params::SetupParams;
+use proof_system::statement::{
+    bbs_plus::PoKBBSSignatureG1 as PoKSignatureBBSG1Stmt,
+    bound_check_bulletproofs::BoundCheckBulletproofs as BoundCheckBulletproofsStmt, Statements,
+};
+use proof_system::witness::PoKBBSSignatureG1 as PoKSignatureBBSG1Wit;
+use test_utils::bbs_plus::*;
+use test_utils::{test_serialization, Fr, ProofG1};
+
+#[test]
+fn pok_of_bbs_plus_sig_and_bounded_messages_using_bulletproofs() {
+    // Prove knowledge of a BBS+ signature and that some of the messages satisfy bounds, i.e. min <= message <= max,
+    // using a single aggregated Bulletproofs range proof
+    let mut rng = StdRng::seed_from_u64(0u64);
+
+    let msg_count = 6;
+    let mut msgs = (0..msg_count)
+        .map(|_| Fr::rand(&mut rng))
+        .collect::<Vec<_>>();
+    let bounded_msg_indices = [1, 3, 4];
+    let bounds = vec![(10u64, 100u64), (1000, 1 << 30), (0, u64::MAX)];
+    msgs[1] = Fr::from(50u64);
+    msgs[3] = Fr::from(1000u64);
+    msgs[4] = Fr::from(u64::MAX - 5);
+    let (sig_params, keypair, sig) = sig_setup_given_messages(&mut rng, &msgs);
+
+    // Supports 3 values as each bound check needs 2 values of 64 bits each and these are padded to 8 values
+    let bp_params = BulletproofsSetupParams::<G1Affine>::new::<Blake2b>(b"test", 512);
+
+    let mut meta_statements = MetaStatements::new();
+    for (j, i) in bounded_msg_indices.iter().enumerate() {
+        meta_statements.add_witness_equality(EqualWitnesses(
+            vec![(0, *i), (1, j)]
+                .into_iter()
+                .collect::<BTreeSet<WitnessRef>>(),
+        ));
+    }
+    test_serialization!(MetaStatements, meta_statements);
+
+    let mut witnesses = Witnesses::new();
+    witnesses.add(PoKSignatureBBSG1Wit::new_as_witness(
+        sig.clone(),
+        msgs.clone().into_iter().enumerate().collect(),
+    ));
+    witnesses.add(Witness::BoundCheckBulletproofs(
+        bounded_msg_indices.iter().map(|i| msgs[*i]).collect(),
+    ));
+    test_serialization!(Witnesses<Bls12_381>, witnesses);
+
+    let create_statements = |bound_check_stmt| {
+        let mut statements = Statements::new();
+        statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+            sig_params.clone(),
+            keypair.public_key.clone(),
+            BTreeMap::new(),
+        ));
+        statements.add(bound_check_stmt);
+        statements
+    };
+
+    let statements = create_statements(
+        BoundCheckBulletproofsStmt::new_statement_from_params(bp_params.clone(), bounds.clone())
+            .unwrap(),
+    );
+    test_serialization!(Statements<Bls12_381, G1Affine>, statements);
+
+    let proof_spec = ProofSpec::new(statements, meta_statements.clone(), vec![], None);
+    proof_spec.validate().unwrap();
+    test_serialization!(ProofSpec<Bls12_381, G1Affine>, proof_spec);
+
+    let nonce = Some(b"test-nonce".to_vec());
+    let proof = ProofG1::new(
+        &mut rng,
+        proof_spec.clone(),
+        witnesses.clone(),
+        nonce.clone(),
+    )
+    .unwrap();
+    test_serialization!(ProofG1, proof);
+    proof.verify(proof_spec, nonce.clone()).unwrap();
+
+    // Same but the Bulletproofs params are passed as setup params
+    let all_setup_params = vec![SetupParams::BulletproofsSetupParams(bp_params.clone())];
+    test_serialization!(Vec<SetupParams<Bls12_381, G1Affine>>, all_setup_params);
+
+    let statements = create_statements(
+        BoundCheckBulletproofsStmt::new_statement_from_params_ref(0, bounds.clone()).unwrap(),
+    );
+    test_serialization!(Statements<Bls12_381, G1Affine>, statements);
+
+    let proof_spec = ProofSpec::new(
+        statements,
+        meta_statements.clone(),
+        all_setup_params.clone(),
+        None,
+    );
+    proof_spec.validate().unwrap();
+    test_serialization!(ProofSpec<Bls12_381, G1Affine>, proof_spec);
+
+    let proof = ProofG1::new(
+        &mut rng,
+        proof_spec.clone(),
+        witnesses.clone(),
+        nonce.clone(),
+    )
+    .unwrap();
+    test_serialization!(ProofG1, proof);
+    proof.clone().verify(proof_spec, nonce.clone()).unwrap();
+
+    // Proof doesn't verify for different bounds
+    let mut other_bounds = bounds.clone();
+    other_bounds[0] = (10, 49);
+    let statements = create_statements(
+        BoundCheckBulletproofsStmt::new_statement_from_params_ref(0, other_bounds.clone()).unwrap(),
+    );
+    let proof_spec_other_bounds = ProofSpec::new(
+        statements,
+        meta_statements.clone(),
+        all_setup_params.clone(),
+        None,
+    );
+    assert!(proof
+        .verify(proof_spec_other_bounds.clone(), nonce.clone())
+        .is_err());
+
+    // Proof cannot be created when a message is not within its bounds
+    assert!(matches!(
+        ProofG1::new(
+            &mut rng,
+            proof_spec_other_bounds,
+            witnesses.clone(),
+            nonce.clone()
+        ),
+        Err(ProofSystemError::BoundCheckBulletproofsWitnessNotInBounds(
+            1, 0
+        ))
+    ));
+
+    // Proof cannot be created when the params do not have enough bases for all the values
+    let statements = create_statements(
+        BoundCheckBulletproofsStmt::new_statement_from_params(
+            BulletproofsSetupParams::<G1Affine>::new::<Blake2b>(b"test", 256),
+            bounds.clone(),
+        )
+        .unwrap(),
+    );
+    let proof_spec_small_params = ProofSpec::new(statements, meta_statements.clone(), vec![], None);
+    assert!(matches!(
+        ProofG1::new(
+            &mut rng,
+            proof_spec_small_params,
+            witnesses.clone(),
+            nonce.clone()
+        ),
+        Err(ProofSystemError::BulletproofsError(_))
+    ));
+
+    // Witness count must match the number of bounds
+    let mut witnesses_fewer = Witnesses::new();
+    witnesses_fewer.add(PoKSignatureBBSG1Wit::new_as_witness(
+        sig,
+        msgs.clone().into_iter().enumerate().collect(),
+    ));
+    witnesses_fewer.add(Witness::BoundCheckBulletproofs(vec![msgs[1], msgs[3]]));
+    let statements = create_statements(
+        BoundCheckBulletproofsStmt::new_statement_from_params_ref(0, bounds.clone()).unwrap(),
+    );
+    let proof_spec = ProofSpec::new(statements, meta_statements, all_setup_params, None);
+    assert!(matches!(
+        ProofG1::new(&mut rng, proof_spec, witnesses_fewer, nonce),
+        Err(ProofSystemError::BoundCheckBulletproofsIncorrectWitnessCount(1, 2))
+    ));
+
+    // Max must be greater than min
+    assert!(matches!(
+        BoundCheckBulletproofsStmt::<G1Affine>::new_statement_from_params_ref::<Bls12_381, G2Affine>(
+            0,
+            vec![(10, 10)]
+        ),
+        Err(ProofSystemError::BoundCheckMaxNotGreaterThanMin)
+    ));
+}
@@ -0,0 +1,209 @@
+use ark_bls12_381::{Bls12_381, G1Affine, G2Affine};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::collections::{BTreeMap, BTreeSet};
+use ark_std::{rand::prelude::StdRng, rand::SeedableRng, UniformRand};
+use bbs_plus::prelude::{KeypairG2, SignatureParamsG1};
+use proof_system::prelude::{
+    EqualWitnesses, MetaStatements, ProofSystemError, Witness, WitnessRef, Witnesses,
+};
+use proof_system::proof_spec::ProofSpec;
+use proof_system::setup_params::SetupParams;
+use proof_system::statement::{
+    bbs_plus::PoKBBSSignatureG1 as PoKSignatureBBSG1Stmt,
+    bound_check_smc::{BoundCheckSmc as BoundCheckSmcStmt, SmcParams},
+    Statements,
+};
+use proof_system::witness::PoKBBSSignatureG1 as PoKSignatureBBSG1Wit;
+use test_utils::bbs_plus::*;
+use test_utils::{test_serialization, Fr, ProofG1};
+
+#[test]
+fn pok_of_bbs_plus_sig_and_bounded_message_using_set_membership_check() {
+    // Prove knowledge of a BBS+ signature and that a specific message satisfies some bounds, i.e. min <= message <= max,
+    // by proving that each digit of the message is signed by the verifier
+    let mut rng = StdRng::seed_from_u64(0u64);
+
+    let msg_count = 5;
+    let mut msgs = (0..msg_count)
+        .map(|_| Fr::rand(&mut rng))
+        .collect::<Vec<_>>();
+    let bounded_msg_idx = 2;
+    // Like an age
+    msgs[bounded_msg_idx] = Fr::from(35u64);
+    let (sig_params, keypair, sig) = sig_setup_given_messages(&mut rng, &msgs);
+
+    // Verifier creates the params by signing the digits
+    let smc_sig_params = SignatureParamsG1::<Bls12_381>::generate_using_rng(&mut rng, 1);
+    let smc_keypair = KeypairG2::<Bls12_381>::generate_using_rng(&mut rng, &smc_sig_params);
+    let smc_params = SmcParams::new(&mut rng, 8, smc_sig_params, &smc_keypair).unwrap();
+    smc_params.verify().unwrap();
+
+    let mut meta_statements = MetaStatements::new();
+    meta_statements.add_witness_equality(EqualWitnesses(
+        vec![(0, bounded_msg_idx), (1, 0)]
+            .into_iter()
+            .collect::<BTreeSet<WitnessRef>>(),
+    ));
+    test_serialization!(MetaStatements, meta_statements);
+
+    let mut witnesses = Witnesses::new();
+    witnesses.add(PoKSignatureBBSG1Wit::new_as_witness(
+        sig.clone(),
+        msgs.clone().into_iter().enumerate().collect(),
+    ));
+    witnesses.add(Witness::BoundCheckSmc(msgs[bounded_msg_idx]));
+    test_serialization!(Witnesses<Bls12_381>, witnesses);
+
+    let create_statements = |bound_check_stmt| {
+        let mut statements = Statements::new();
+        statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+            sig_params.clone(),
+            keypair.public_key.clone(),
+            BTreeMap::new(),
+        ));
+        statements.add(bound_check_stmt);
+        statements
+    };
+
+    let nonce = Some(b"test-nonce".to_vec());
+    for (min, max) in [(18, 65), (35, 36), (0, 35), (20, 1000)] {
+        let statements = create_statements(
+            BoundCheckSmcStmt::new_statement_from_params(min, max, smc_params.clone()).unwrap(),
+        );
+        test_serialization!(Statements<Bls12_381, G1Affine>, statements);
+
+        let proof_spec = ProofSpec::new(statements, meta_statements.clone(), vec![], None);
+        proof_spec.validate().unwrap();
+        test_serialization!(ProofSpec<Bls12_381, G1Affine>, proof_spec);
+
+        let proof = ProofG1::new(
+            &mut rng,
+            proof_spec.clone(),
+            witnesses.clone(),
+            nonce.clone(),
+        )
+        .unwrap();
+        test_serialization!(ProofG1, proof);
+        proof.verify(proof_spec, nonce.clone()).unwrap();
+    }
+
+    // Same but the params are passed as setup params
+    let all_setup_params = vec![SetupParams::SmcParams(smc_params.clone())];
+    test_serialization!(Vec<SetupParams<Bls12_381, G1Affine>>, all_setup_params);
+
+    let statements =
+        create_statements(BoundCheckSmcStmt::new_statement_from_params_ref(18, 65, 0).unwrap());
+    test_serialization!(Statements<Bls12_381, G1Affine>, statements);
+
+    let proof_spec = ProofSpec::new(
+        statements,
+        meta_statements.clone(),
+        all_setup_params.clone(),
+        None,
+    );
+    proof_spec.validate().unwrap();
+    test_serialization!(ProofSpec<Bls12_381, G1Affine>, proof_spec);
+
+    let proof = ProofG1::new(
+        &mut rng,
+        proof_spec.clone(),
+        witnesses.clone(),
+        nonce.clone(),
+    )
+    .unwrap();
+    test_serialization!(ProofG1, proof);
+    proof.clone().verify(proof_spec, nonce.clone()).unwrap();
+
+    // Proof doesn't verify for different bounds
+    let statements =
+        create_statements(BoundCheckSmcStmt::new_statement_from_params_ref(36, 65, 0).unwrap());
+    let proof_spec_other_bounds = ProofSpec::new(
+        statements,
+        meta_statements.clone(),
+        all_setup_params.clone(),
+        None,
+    );
+    assert!(proof
+        .clone()
+        .verify(proof_spec_other_bounds.clone(), nonce.clone())
+        .is_err());
+
+    // Proof cannot be created when the message is not within the bounds
+    assert!(matches!(
+        ProofG1::new(
+            &mut rng,
+            proof_spec_other_bounds,
+            witnesses.clone(),
+            nonce.clone()
+        ),
+        Err(ProofSystemError::BoundCheckSmcWitnessNotInBounds(1))
+    ));
+
+    // Proof doesn't verify with params signed by a different verifier
+    let other_sig_params = SignatureParamsG1::<Bls12_381>::generate_using_rng(&mut rng, 1);
+    let other_keypair = KeypairG2::<Bls12_381>::generate_using_rng(&mut rng, &other_sig_params);
+    let other_params = SmcParams::new(&mut rng, 8, other_sig_params, &other_keypair).unwrap();
+    let statements = create_statements(
+        BoundCheckSmcStmt::new_statement_from_params(18, 65, other_params).unwrap(),
+    );
+    let proof_spec_other_params = ProofSpec::new(statements, meta_statements.clone(), vec![], None);
+    assert!(proof
+        .clone()
+        .verify(proof_spec_other_params, nonce.clone())
+        .is_err());
+
+    // The statement must be bound to a signed message by a witness equality, without it the proof can't be created
+    // or verified
+    let statements =
+        create_statements(BoundCheckSmcStmt::new_statement_from_params_ref(18, 65, 0).unwrap());
+    let proof_spec_no_equality = ProofSpec::new(
+        statements.clone(),
+        MetaStatements::new(),
+        all_setup_params.clone(),
+        None,
+    );
+    assert!(matches!(
+        proof_spec_no_equality.validate(),
+        Err(ProofSystemError::BoundCheckSmcWitnessNotInEquality(1))
+    ));
+    assert!(matches!(
+        ProofG1::new(
+            &mut rng,
+            proof_spec_no_equality.clone(),
+            witnesses.clone(),
+            nonce.clone()
+        ),
+        Err(ProofSystemError::BoundCheckSmcWitnessNotInEquality(1))
+    ));
+    assert!(matches!(
+        proof.verify(proof_spec_no_equality, nonce.clone()),
+        Err(ProofSystemError::BoundCheckSmcWitnessNotInEquality(1))
+    ));
+
+    // An equality only with another such statement doesn't bind the witness either
+    let mut statements = statements;
+    statements.add(BoundCheckSmcStmt::new_statement_from_params_ref(18, 65, 0).unwrap());
+    let mut meta_statements_smc_only = MetaStatements::new();
+    meta_statements_smc_only.add_witness_equality(EqualWitnesses(
+        vec![(1, 0), (2, 0)]
+            .into_iter()
+            .collect::<BTreeSet<WitnessRef>>(),
+    ));
+    assert!(matches!(
+        ProofSpec::new(statements, meta_statements_smc_only, all_setup_params, None).validate(),
+        Err(ProofSystemError::BoundCheckSmcWitnessNotInEquality(1))
+    ));
+
+    // Max must be greater than min and the base must be at least 2
+    assert!(matches!(
+        BoundCheckSmcStmt::<Bls12_381>::new_statement_from_params_ref::<G1Affine, G2Affine>(
+            10, 10, 0
+        ),
+        Err(ProofSystemError::BoundCheckMaxNotGreaterThanMin)
+    ));
+    let one_msg_sig_params = SignatureParamsG1::<Bls12_381>::generate_using_rng(&mut rng, 1);
+    assert!(matches!(
+        SmcParams::new(&mut rng, 1, one_msg_sig_params, &smc_keypair),
+        Err(ProofSystemError::SmcInvalidBase(1))
+    ));
+}
@@ -2,7 +2,7 @@ use ark_bls12_381::{Bls12_381, G1Affine};
 use ark_ff::PrimeField;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::collections::{BTreeMap, BTreeSet};
-use ark_std::{rand::prelude::StdRng, rand::SeedableRng};
+use ark_std::{rand::prelude::StdRng, rand::SeedableRng, UniformRand};
 use std::time::Instant;
 
 use proof_system::prelude::{
@@ -17,6 +17,12 @@ use proof_system::statement::{
 use proof_system::sub_protocols::bound_check_legogroth16::generate_snark_srs_bound_check;
 use proof_system::witness::PoKBBSSignatureG1 as PoKSignatureBBSG1Wit;
 
+use bbs_plus::encoding::{
+    date_to_bound, encode_date, encode_short_string, encode_signed_integer, signed_integer_to_bound,
+};
+use bbs_plus::prelude::{KeypairG2, SignatureG1, SignatureParamsG1};
+use bbs_plus::signature::{length_tagged_messages, MESSAGE_COUNT_INDEX};
+use blake2::Blake2b;
 use test_utils::bbs_plus::*;
 use test_utils::{test_serialization, Fr, ProofG1};
 
@@ -505,3 +511,141 @@ fn pok_of_bbs_plus_sig_and_many_bounded_messages() {
     check(false);
     check(true);
 }
+
+#[test]
+fn pok_of_bbs_plus_sig_over_variable_length_messages_and_bounded_message_count() {
+    // Prove knowledge of BBS+ signature over a variable number of messages and that the hidden message
+    // count satisfies some bounds i.e. min <= count <= max.
+    let mut rng = StdRng::seed_from_u64(0u64);
+
+    let label = b"test-variable-length";
+    let max_msg_count = 10;
+    let msg_count = 4;
+    // Params support more messages than any signature so the verifier does not learn the message count
+    let sig_params = SignatureParamsG1::<Bls12_381>::new::<Blake2b>(label, max_msg_count + 1);
+    let sig_keypair = KeypairG2::<Bls12_381>::generate_using_rng(&mut rng, &sig_params);
+    let msgs = (0..msg_count)
+        .map(|_| Fr::rand(&mut rng))
+        .collect::<Vec<_>>();
+    let sig = SignatureG1::<Bls12_381>::new_with_variable_length_messages::<_, Blake2b>(
+        &mut rng,
+        &msgs,
+        &sig_keypair.secret_key,
+        label,
+    )
+    .unwrap();
+
+    let snark_pk = generate_snark_srs_bound_check::<Bls12_381, _>(&mut rng).unwrap();
+    let (min, max) = (2, 5);
+
+    let mut prover_statements = Statements::new();
+    prover_statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+        sig_params.clone(),
+        sig_keypair.public_key.clone(),
+        BTreeMap::new(),
+    ));
+    prover_statements
+        .add(BoundCheckProverStmt::new_statement_from_params(min, max, snark_pk.clone()).unwrap());
+
+    let mut meta_statements = MetaStatements::new();
+    meta_statements.add_witness_equality(EqualWitnesses(
+        vec![(0, MESSAGE_COUNT_INDEX), (1, 0)]
+            .into_iter()
+            .collect::<BTreeSet<WitnessRef>>(),
+    ));
+
+    let proof_spec_prover =
+        ProofSpec::new(prover_statements, meta_statements.clone(), vec![], None);
+    proof_spec_prover.validate().unwrap();
+
+    // Witness for the signature is the length tagged messages padded to the size of params
+    let tagged_msgs = length_tagged_messages(&msgs, sig_params.supported_message_count()).unwrap();
+    let mut witnesses = Witnesses::new();
+    witnesses.add(PoKSignatureBBSG1Wit::new_as_witness(
+        sig,
+        tagged_msgs.into_iter().enumerate().collect(),
+    ));
+    witnesses.add(Witness::BoundCheckLegoGroth16(Fr::from(msg_count as u64)));
+
+    let proof = ProofG1::new(&mut rng, proof_spec_prover, witnesses, None).unwrap();
+
+    let mut verifier_statements = Statements::new();
+    verifier_statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+        sig_params,
+        sig_keypair.public_key.clone(),
+        BTreeMap::new(),
+    ));
+    verifier_statements.add(
+        BoundCheckVerifierStmt::new_statement_from_params(min, max, snark_pk.vk.clone()).unwrap(),
+    );
+    let verifier_proof_spec = ProofSpec::new(verifier_statements, meta_statements, vec![], None);
+    verifier_proof_spec.validate().unwrap();
+    proof.verify(verifier_proof_spec, None).unwrap();
+}
+
+#[test]
+fn pok_of_bbs_plus_sig_and_bounded_encoded_messages() {
+    // Prove knowledge of BBS+ signature over encoded messages and that a negative integer and a date satisfy
+    // bounds, with the bounds converted using the same encoding
+    let mut rng = StdRng::seed_from_u64(0u64);
+
+    let msgs = vec![
+        encode_signed_integer::<Fr>(-15),
+        encode_date::<Fr>(1990, 5, 17).unwrap(),
+        encode_short_string::<Fr>(b"John").unwrap(),
+    ];
+    let (sig_params, sig_keypair, sig) = sig_setup_given_messages(&mut rng, &msgs);
+    let snark_pk = generate_snark_srs_bound_check::<Bls12_381, _>(&mut rng).unwrap();
+
+    // -20 <= msgs[0] <= 10 and 1970-01-01 <= msgs[1] <= 1999-12-31
+    let bounds = [
+        (signed_integer_to_bound(-20), signed_integer_to_bound(10)),
+        (
+            date_to_bound(1970, 1, 1).unwrap(),
+            date_to_bound(1999, 12, 31).unwrap(),
+        ),
+    ];
+
+    let mut prover_statements = Statements::new();
+    let mut verifier_statements = Statements::new();
+    prover_statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+        sig_params.clone(),
+        sig_keypair.public_key.clone(),
+        BTreeMap::new(),
+    ));
+    verifier_statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+        sig_params.clone(),
+        sig_keypair.public_key.clone(),
+        BTreeMap::new(),
+    ));
+    let mut meta_statements = MetaStatements::new();
+    let mut witnesses = Witnesses::new();
+    witnesses.add(PoKSignatureBBSG1Wit::new_as_witness(
+        sig,
+        msgs.clone().into_iter().enumerate().collect(),
+    ));
+    for (i, (min, max)) in bounds.into_iter().enumerate() {
+        prover_statements.add(
+            BoundCheckProverStmt::new_statement_from_params(min, max, snark_pk.clone()).unwrap(),
+        );
+        verifier_statements.add(
+            BoundCheckVerifierStmt::new_statement_from_params(min, max, snark_pk.vk.clone())
+                .unwrap(),
+        );
+        meta_statements.add_witness_equality(EqualWitnesses(
+            vec![(0, i), (1 + i, 0)]
+                .into_iter()
+                .collect::<BTreeSet<WitnessRef>>(),
+        ));
+        witnesses.add(Witness::BoundCheckLegoGroth16(msgs[i]));
+    }
+
+    let proof_spec_prover =
+        ProofSpec::new(prover_statements, meta_statements.clone(), vec![], None);
+    proof_spec_prover.validate().unwrap();
+    let proof = ProofG1::new(&mut rng, proof_spec_prover, witnesses, None).unwrap();
+
+    let verifier_proof_spec = ProofSpec::new(verifier_statements, meta_statements, vec![], None);
+    verifier_proof_spec.validate().unwrap();
+    proof.verify(verifier_proof_spec, None).unwrap();
+}
@@ -0,0 +1,205 @@
+use ark_bls12_381::{Bls12_381, G1Affine, G2Affine, G2Projective};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::collections::{BTreeMap, BTreeSet};
+use ark_std::{rand::prelude::StdRng, rand::SeedableRng, UniformRand};
+use blake2::Blake2b;
+use proof_system::prelude::{
+    EqualWitnesses, MetaStatements, ProofSystemError, Witness, WitnessRef, Witnesses,
+};
+use proof_system::proof::Proof;
+use proof_system::proof_spec::ProofSpec;
+use proof_system::setup_params::SetupParams;
+use proof_system::statement::{
+    bbs_plus::PoKBBSSignatureG1 as PoKSignatureBBSG1Stmt,
+    discrete_log_equality_across_groups::DiscreteLogEqualityAcrossGroups as DiscreteLogEqualityAcrossGroupsStmt,
+    Statements,
+};
+use proof_system::witness::PoKBBSSignatureG1 as PoKSignatureBBSG1Wit;
+use schnorr_pok::discrete_log_equality_across_groups::CrossGroupCommitmentKey;
+use schnorr_pok::error::SchnorrError;
+use test_utils::bbs_plus::*;
+use test_utils::{test_serialization, Fr};
+
+const DST: &[u8] = b"TEST-CROSS-GROUP_XMD:BLAKE2B_SSWU_RO_";
+
+/// Prove knowledge of a BBS+ signature and that a specific signed message is the secret key of a public key in
+/// the group `G2`, like a key on a different curve
+fn check_pok_of_bbs_plus_sig_and_discrete_log_in_other_group<G2: AffineCurve>(
+    comm_key: CrossGroupCommitmentKey<G1Affine, G2>,
+    other_public_key: G2,
+) {
+    let mut rng = StdRng::seed_from_u64(0u64);
+
+    let msg_count = 5;
+    let mut msgs = (0..msg_count)
+        .map(|_| Fr::rand(&mut rng))
+        .collect::<Vec<_>>();
+    let key_msg_idx = 3;
+    let num_bits = 64;
+    let secret_key = u64::MAX - 12345;
+    msgs[key_msg_idx] = Fr::from(secret_key);
+    let (sig_params, keypair, sig) = sig_setup_given_messages(&mut rng, &msgs);
+
+    let public_key = comm_key
+        .g2
+        .mul(G2::ScalarField::from(secret_key).into_repr())
+        .into_affine();
+
+    let mut meta_statements = MetaStatements::new();
+    meta_statements.add_witness_equality(EqualWitnesses(
+        vec![(0, key_msg_idx), (1, 0)]
+            .into_iter()
+            .collect::<BTreeSet<WitnessRef>>(),
+    ));
+    test_serialization!(MetaStatements, meta_statements);
+
+    let mut witnesses = Witnesses::new();
+    witnesses.add(PoKSignatureBBSG1Wit::new_as_witness(
+        sig.clone(),
+        msgs.clone().into_iter().enumerate().collect(),
+    ));
+    witnesses.add(Witness::DiscreteLogEqualityAcrossGroups(msgs[key_msg_idx]));
+    test_serialization!(Witnesses<Bls12_381>, witnesses);
+
+    let create_statements = |cross_group_stmt| {
+        let mut statements = Statements::new();
+        statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+            sig_params.clone(),
+            keypair.public_key.clone(),
+            BTreeMap::new(),
+        ));
+        statements.add(cross_group_stmt);
+        statements
+    };
+
+    let statements = create_statements(
+        DiscreteLogEqualityAcrossGroupsStmt::new_statement_from_params(
+            public_key,
+            num_bits,
+            comm_key.clone(),
+        )
+        .unwrap(),
+    );
+    test_serialization!(Statements<Bls12_381, G1Affine, G2>, statements);
+
+    let proof_spec = ProofSpec::new(statements, meta_statements.clone(), vec![], None);
+    proof_spec.validate().unwrap();
+    test_serialization!(ProofSpec<Bls12_381, G1Affine, G2>, proof_spec);
+
+    let nonce = Some(b"test-nonce".to_vec());
+    let proof = Proof::<Bls12_381, G1Affine, Blake2b, G2>::new(
+        &mut rng,
+        proof_spec.clone(),
+        witnesses.clone(),
+        nonce.clone(),
+    )
+    .unwrap();
+    test_serialization!(Proof<Bls12_381, G1Affine, Blake2b, G2>, proof);
+    proof.verify(proof_spec, nonce.clone()).unwrap();
+
+    // Same but the commitment key is passed as setup params
+    let all_setup_params = vec![SetupParams::CrossGroupCommitmentKey(comm_key.clone())];
+    test_serialization!(Vec<SetupParams<Bls12_381, G1Affine, G2>>, all_setup_params);
+
+    let statements = create_statements(
+        DiscreteLogEqualityAcrossGroupsStmt::new_statement_from_params_ref(public_key, num_bits, 0)
+            .unwrap(),
+    );
+    let proof_spec = ProofSpec::new(
+        statements,
+        meta_statements.clone(),
+        all_setup_params.clone(),
+        None,
+    );
+    proof_spec.validate().unwrap();
+    test_serialization!(ProofSpec<Bls12_381, G1Affine, G2>, proof_spec);
+
+    let proof = Proof::<Bls12_381, G1Affine, Blake2b, G2>::new(
+        &mut rng,
+        proof_spec.clone(),
+        witnesses.clone(),
+        nonce.clone(),
+    )
+    .unwrap();
+    test_serialization!(Proof<Bls12_381, G1Affine, Blake2b, G2>, proof);
+    proof.clone().verify(proof_spec, nonce.clone()).unwrap();
+
+    // Proof doesn't verify for a different public key
+    let statements = create_statements(
+        DiscreteLogEqualityAcrossGroupsStmt::new_statement_from_params_ref(
+            other_public_key,
+            num_bits,
+            0,
+        )
+        .unwrap(),
+    );
+    let proof_spec_other_key = ProofSpec::new(
+        statements,
+        meta_statements.clone(),
+        all_setup_params.clone(),
+        None,
+    );
+    assert!(proof.verify(proof_spec_other_key, nonce.clone()).is_err());
+
+    // Proof cannot be created when the witness has more bits than allowed
+    let statements = create_statements(
+        DiscreteLogEqualityAcrossGroupsStmt::new_statement_from_params_ref(public_key, 32, 0)
+            .unwrap(),
+    );
+    let proof_spec_fewer_bits = ProofSpec::new(statements, meta_statements, all_setup_params, None);
+    assert!(matches!(
+        Proof::<Bls12_381, G1Affine, Blake2b, G2>::new(
+            &mut rng,
+            proof_spec_fewer_bits,
+            witnesses,
+            nonce
+        ),
+        Err(ProofSystemError::SchnorrError(
+            SchnorrError::WitnessTooLarge(32)
+        ))
+    ));
+
+    assert!(
+        DiscreteLogEqualityAcrossGroupsStmt::new_statement_from_params::<Bls12_381>(
+            public_key, 0, comm_key,
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn pok_of_bbs_plus_sig_and_discrete_log_in_other_group() {
+    let mut rng = StdRng::seed_from_u64(1u64);
+    // The other group is BLS12-381's G2 which is the default for `Proof`
+    let comm_key = CrossGroupCommitmentKey::<G1Affine, G2Affine>::new_with_hash_to_curve::<Blake2b>(
+        b"test", DST,
+    );
+    check_pok_of_bbs_plus_sig_and_discrete_log_in_other_group(
+        comm_key,
+        G2Projective::rand(&mut rng).into_affine(),
+    );
+}
+
+#[test]
+fn pok_of_bbs_plus_sig_and_discrete_log_in_group_of_different_order() {
+    // The other group is BN254's G1 whose order is different from BLS12-381's
+    let mut rng = StdRng::seed_from_u64(1u64);
+    let other_comm_key = CrossGroupCommitmentKey::<ark_bn254::G1Affine, ark_bn254::G1Affine>::new::<
+        Blake2b,
+    >(b"test");
+    let bls_comm_key = CrossGroupCommitmentKey::<G1Affine, G2Affine>::new_with_hash_to_curve::<
+        Blake2b,
+    >(b"test", DST);
+    let comm_key = CrossGroupCommitmentKey {
+        g1: bls_comm_key.g1,
+        h1: bls_comm_key.h1,
+        g2: other_comm_key.g2,
+        h2: other_comm_key.h2,
+    };
+    check_pok_of_bbs_plus_sig_and_discrete_log_in_other_group(
+        comm_key,
+        ark_bn254::G1Projective::rand(&mut rng).into_affine(),
+    );
+}
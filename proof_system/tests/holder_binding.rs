@@ -0,0 +1,282 @@
+use ark_bls12_381::{Bls12_381, G1Affine, G1Projective};
+use ark_ec::AffineCurve;
+use ark_ec::ProjectiveCurve;
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::collections::{BTreeMap, BTreeSet};
+use ark_std::{rand::prelude::StdRng, rand::SeedableRng, UniformRand};
+use blake2::Blake2b;
+use proof_system::prelude::{
+    EqualWitnesses, MetaStatements, ProofSystemError, Witness, WitnessRef, Witnesses,
+};
+use proof_system::proof::Proof;
+use proof_system::proof_spec::ProofSpec;
+use proof_system::setup_params::SetupParams;
+use proof_system::statement::{
+    bbs_plus::PoKBBSSignatureG1 as PoKSignatureBBSG1Stmt,
+    holder_binding::{self, HolderBinding as HolderBindingStmt},
+    Statements,
+};
+use proof_system::witness::PoKBBSSignatureG1 as PoKSignatureBBSG1Wit;
+use schnorr_pok::discrete_log_equality_across_groups::CrossGroupCommitmentKey;
+use test_utils::bbs_plus::*;
+use test_utils::{test_serialization, Fr, ProofG1};
+
+#[test]
+fn pok_of_bbs_plus_sig_bound_to_holder_public_key() {
+    // Prove knowledge of a BBS+ signature where one of the signed messages is the secret key of the holder's
+    // (device) public key. Neither the secret key nor the signed message is revealed. The public key is in the same
+    // group as the proof.
+    let mut rng = StdRng::seed_from_u64(0u64);
+
+    // Holder's device key
+    let base = G1Projective::rand(&mut rng).into_affine();
+    let secret_key = Fr::rand(&mut rng);
+    let public_key = holder_binding::compute_public_key(&base, &secret_key);
+
+    // The issuer signs the holder's secret key (using a blind signature in practice) as one of the messages
+    let msg_count = 5;
+    let sk_msg_idx = 1;
+    let mut msgs: Vec<Fr> = (0..msg_count).map(|_| Fr::rand(&mut rng)).collect();
+    msgs[sk_msg_idx] = secret_key;
+    let (sig_params, keypair, sig) = sig_setup_given_messages(&mut rng, &msgs);
+
+    let mut statements = Statements::new();
+    statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+        sig_params.clone(),
+        keypair.public_key.clone(),
+        BTreeMap::new(),
+    ));
+    statements.add(HolderBindingStmt::new_statement_from_params(
+        base, public_key,
+    ));
+
+    let mut meta_statements = MetaStatements::new();
+    meta_statements.add_witness_equality(EqualWitnesses(
+        vec![(0, sk_msg_idx), (1, 0)]
+            .into_iter()
+            .collect::<BTreeSet<WitnessRef>>(),
+    ));
+
+    test_serialization!(Statements<Bls12_381, G1Affine>, statements);
+    test_serialization!(MetaStatements, meta_statements);
+
+    let proof_spec = ProofSpec::new(statements, meta_statements.clone(), vec![], None);
+    proof_spec.validate().unwrap();
+
+    test_serialization!(ProofSpec<Bls12_381, G1Affine>, proof_spec);
+
+    let mut witnesses = Witnesses::new();
+    witnesses.add(PoKSignatureBBSG1Wit::new_as_witness(
+        sig.clone(),
+        msgs.clone().into_iter().enumerate().collect(),
+    ));
+    witnesses.add(Witness::HolderBinding(secret_key));
+
+    test_serialization!(Witnesses<Bls12_381>, witnesses);
+
+    let nonce = Some(b"test-nonce".to_vec());
+    let proof = ProofG1::new(&mut rng, proof_spec.clone(), witnesses, nonce.clone()).unwrap();
+
+    test_serialization!(ProofG1, proof);
+
+    proof.clone().verify(proof_spec, nonce.clone()).unwrap();
+
+    // Proof doesn't verify for a different public key
+    let mut statements_wrong_key = Statements::new();
+    statements_wrong_key.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+        sig_params.clone(),
+        keypair.public_key.clone(),
+        BTreeMap::new(),
+    ));
+    statements_wrong_key.add(HolderBindingStmt::new_statement_from_params(
+        base,
+        G1Projective::rand(&mut rng).into_affine(),
+    ));
+    let proof_spec_wrong_key =
+        ProofSpec::new(statements_wrong_key, meta_statements.clone(), vec![], None);
+    assert!(proof.verify(proof_spec_wrong_key, nonce.clone()).is_err());
+
+    // Proof doesn't verify when the signed message is not the secret key of the public key
+    let other_secret_key = Fr::rand(&mut rng);
+    let other_public_key = holder_binding::compute_public_key(&base, &other_secret_key);
+    let mut statements = Statements::new();
+    statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+        sig_params.clone(),
+        keypair.public_key.clone(),
+        BTreeMap::new(),
+    ));
+    statements.add(HolderBindingStmt::new_statement_from_params(
+        base,
+        other_public_key,
+    ));
+    let proof_spec_incorrect = ProofSpec::new(statements, meta_statements, vec![], None);
+    let mut witnesses = Witnesses::new();
+    witnesses.add(PoKSignatureBBSG1Wit::new_as_witness(
+        sig,
+        msgs.into_iter().enumerate().collect(),
+    ));
+    witnesses.add(Witness::HolderBinding(other_secret_key));
+    let proof = ProofG1::new(
+        &mut rng,
+        proof_spec_incorrect.clone(),
+        witnesses,
+        nonce.clone(),
+    )
+    .unwrap();
+    assert!(proof.verify(proof_spec_incorrect, nonce).is_err());
+}
+
+#[test]
+fn pok_of_bbs_plus_sig_bound_to_holder_public_key_in_other_group() {
+    // Same as above but the holder's public key is in a group of a different order, BN254's G1 here in place of
+    // secp256k1 or ed25519. So the secret key is an integer of at most `num_bits` bits.
+    let mut rng = StdRng::seed_from_u64(0u64);
+
+    let other_comm_key = CrossGroupCommitmentKey::<ark_bn254::G1Affine, ark_bn254::G1Affine>::new::<
+        Blake2b,
+    >(b"test");
+    let bls_comm_key = CrossGroupCommitmentKey::<G1Affine, G1Affine>::new::<Blake2b>(b"test");
+    let comm_key = CrossGroupCommitmentKey {
+        g1: bls_comm_key.g1,
+        h1: bls_comm_key.h1,
+        g2: other_comm_key.g2,
+        h2: other_comm_key.h2,
+    };
+
+    let num_bits = 64;
+    let secret_key = u64::MAX - 1;
+    let public_key =
+        holder_binding::compute_public_key(&comm_key.g2, &ark_bn254::Fr::from(secret_key));
+
+    let msg_count = 5;
+    let sk_msg_idx = 2;
+    let mut msgs: Vec<Fr> = (0..msg_count).map(|_| Fr::rand(&mut rng)).collect();
+    msgs[sk_msg_idx] = Fr::from(secret_key);
+    let (sig_params, keypair, sig) = sig_setup_given_messages(&mut rng, &msgs);
+
+    let all_setup_params = vec![SetupParams::CrossGroupCommitmentKey(comm_key.clone())];
+    let create_statements = |holder_binding_stmt| {
+        let mut statements = Statements::new();
+        statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+            sig_params.clone(),
+            keypair.public_key.clone(),
+            BTreeMap::new(),
+        ));
+        statements.add(holder_binding_stmt);
+        statements
+    };
+
+    let mut meta_statements = MetaStatements::new();
+    meta_statements.add_witness_equality(EqualWitnesses(
+        vec![(0, sk_msg_idx), (1, 0)]
+            .into_iter()
+            .collect::<BTreeSet<WitnessRef>>(),
+    ));
+
+    let mut witnesses = Witnesses::new();
+    witnesses.add(PoKSignatureBBSG1Wit::new_as_witness(
+        sig,
+        msgs.into_iter().enumerate().collect(),
+    ));
+    witnesses.add(Witness::HolderBinding(Fr::from(secret_key)));
+
+    let nonce = Some(b"test-nonce".to_vec());
+    for statements in [
+        create_statements(
+            HolderBindingStmt::new_statement_for_other_group_from_params(
+                public_key,
+                num_bits,
+                comm_key.clone(),
+            )
+            .unwrap(),
+        ),
+        create_statements(
+            HolderBindingStmt::new_statement_for_other_group_from_params_ref(
+                public_key, num_bits, 0,
+            )
+            .unwrap(),
+        ),
+    ] {
+        test_serialization!(Statements<Bls12_381, G1Affine, ark_bn254::G1Affine>, statements);
+
+        let proof_spec = ProofSpec::new(
+            statements,
+            meta_statements.clone(),
+            all_setup_params.clone(),
+            None,
+        );
+        proof_spec.validate().unwrap();
+        test_serialization!(ProofSpec<Bls12_381, G1Affine, ark_bn254::G1Affine>, proof_spec);
+
+        let proof = Proof::<Bls12_381, G1Affine, Blake2b, ark_bn254::G1Affine>::new(
+            &mut rng,
+            proof_spec.clone(),
+            witnesses.clone(),
+            nonce.clone(),
+        )
+        .unwrap();
+        test_serialization!(Proof<Bls12_381, G1Affine, Blake2b, ark_bn254::G1Affine>, proof);
+        proof.verify(proof_spec, nonce.clone()).unwrap();
+    }
+
+    // Proof doesn't verify for a different public key
+    let proof_spec = ProofSpec::new(
+        create_statements(
+            HolderBindingStmt::new_statement_for_other_group_from_params_ref(
+                public_key, num_bits, 0,
+            )
+            .unwrap(),
+        ),
+        meta_statements.clone(),
+        all_setup_params.clone(),
+        None,
+    );
+    let proof = Proof::<Bls12_381, G1Affine, Blake2b, ark_bn254::G1Affine>::new(
+        &mut rng,
+        proof_spec,
+        witnesses.clone(),
+        nonce.clone(),
+    )
+    .unwrap();
+    let other_public_key = comm_key
+        .g2
+        .mul(ark_bn254::Fr::from(secret_key - 1).into_repr())
+        .into_affine();
+    let proof_spec_wrong_key = ProofSpec::new(
+        create_statements(
+            HolderBindingStmt::new_statement_for_other_group_from_params_ref(
+                other_public_key,
+                num_bits,
+                0,
+            )
+            .unwrap(),
+        ),
+        meta_statements.clone(),
+        all_setup_params.clone(),
+        None,
+    );
+    assert!(proof.verify(proof_spec_wrong_key, nonce.clone()).is_err());
+
+    // A statement needs exactly one public key
+    let proof_spec_no_key = ProofSpec::new(
+        create_statements(proof_system::statement::Statement::HolderBinding(
+            HolderBindingStmt {
+                same_group: None,
+                other_group: None,
+            },
+        )),
+        meta_statements,
+        all_setup_params,
+        None,
+    );
+    assert!(matches!(
+        Proof::<Bls12_381, G1Affine, Blake2b, ark_bn254::G1Affine>::new(
+            &mut rng,
+            proof_spec_no_key,
+            witnesses,
+            nonce
+        ),
+        Err(ProofSystemError::InvalidHolderBindingStatement(1))
+    ));
+}
@@ -0,0 +1,274 @@
+use ark_bls12_381::{Bls12_381, G1Affine};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::collections::{BTreeMap, BTreeSet};
+use ark_std::{rand::prelude::StdRng, rand::SeedableRng, UniformRand};
+use bbs_plus::bbs::{Bls12381Sha256, SignatureG1 as IetfBBSSignatureG1};
+use bbs_plus::error::BBSPlusError;
+use vb_accumulator::prelude::{Accumulator, MembershipProvingKey};
+
+use proof_system::prelude::{
+    EqualWitnesses, MetaStatements, ProofSystemError, WitnessRef, Witnesses,
+};
+use proof_system::proof_spec::ProofSpec;
+use proof_system::setup_params::SetupParams;
+use proof_system::statement::{
+    accumulator::AccumulatorMembership as AccumulatorMembershipStmt,
+    bbs::PoKIetfBBSSignatureG1 as PoKSignatureIetfBBSG1Stmt,
+    bbs_plus::PoKBBSSignatureG1 as PoKSignatureBBSG1Stmt,
+};
+use proof_system::statement::{Statement, Statements};
+use proof_system::witness::{
+    Membership as MembershipWit, PoKBBSSignatureG1 as PoKSignatureBBSG1Wit,
+    PoKIetfBBSSignatureG1 as PoKSignatureIetfBBSG1Wit,
+};
+use test_utils::{accumulators::*, bbs::*, bbs_plus::*};
+use test_utils::{test_serialization, Fr, ProofG1};
+
+#[test]
+fn pok_of_ietf_bbs_and_bbs_plus_sig_and_message_equality() {
+    // Prove knowledge of a BBS signature created as per the IETF draft and a BBS+ signature and
+    // that 2 of the messages are same among them.
+    let mut rng = StdRng::seed_from_u64(0u64);
+
+    let header = b"test-header";
+    let msg_count_1 = 6;
+    let (msgs_1, params_1, keypair_1, sig_1) = ietf_bbs_sig_setup(&mut rng, msg_count_1, header);
+
+    let msg_count_2 = 8;
+    let mut msgs_2: Vec<Fr> = (0..msg_count_2).map(|_| Fr::rand(&mut rng)).collect();
+    msgs_2[6] = msgs_1[5];
+    msgs_2[7] = msgs_1[4];
+    let (params_2, keypair_2, sig_2) = sig_setup_given_messages(&mut rng, &msgs_2);
+
+    let mut revealed_msgs_1 = BTreeMap::new();
+    let mut unrevealed_msgs_1 = BTreeMap::new();
+    for i in 0..msg_count_1 {
+        if i == 0 || i == 2 {
+            revealed_msgs_1.insert(i, msgs_1[i]);
+        } else {
+            unrevealed_msgs_1.insert(i, msgs_1[i]);
+        }
+    }
+
+    let mut statements = Statements::new();
+    statements.add(PoKSignatureIetfBBSG1Stmt::new_statement_from_params::<
+        Bls12381Sha256,
+        _,
+        _,
+    >(
+        params_1.clone(),
+        keypair_1.public_key.clone(),
+        header.to_vec(),
+        revealed_msgs_1.clone(),
+    ));
+    statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+        params_2.clone(),
+        keypair_2.public_key.clone(),
+        BTreeMap::new(),
+    ));
+
+    let mut meta_statements = MetaStatements::new();
+    meta_statements.add_witness_equality(EqualWitnesses(
+        vec![(0, 5), (1, 6)]
+            .into_iter()
+            .collect::<BTreeSet<WitnessRef>>(),
+    ));
+    meta_statements.add_witness_equality(EqualWitnesses(
+        vec![(0, 4), (1, 7)]
+            .into_iter()
+            .collect::<BTreeSet<WitnessRef>>(),
+    ));
+
+    test_serialization!(Statements<Bls12_381, G1Affine>, statements);
+    test_serialization!(MetaStatements, meta_statements);
+
+    let context = Some(b"test".to_vec());
+    let proof_spec = ProofSpec::new(
+        statements.clone(),
+        meta_statements.clone(),
+        vec![],
+        context.clone(),
+    );
+    proof_spec.validate().unwrap();
+
+    test_serialization!(ProofSpec<Bls12_381, G1Affine>, proof_spec);
+
+    let mut witnesses = Witnesses::new();
+    witnesses.add(PoKSignatureIetfBBSG1Wit::new_as_witness(
+        sig_1.clone(),
+        unrevealed_msgs_1.clone(),
+    ));
+    witnesses.add(PoKSignatureBBSG1Wit::new_as_witness(
+        sig_2.clone(),
+        msgs_2.clone().into_iter().enumerate().collect(),
+    ));
+
+    test_serialization!(Witnesses<Bls12_381>, witnesses);
+
+    let nonce = Some(b"test-nonce".to_vec());
+    let proof = ProofG1::new(
+        &mut rng,
+        proof_spec.clone(),
+        witnesses.clone(),
+        nonce.clone(),
+    )
+    .unwrap();
+
+    test_serialization!(ProofG1, proof);
+
+    proof
+        .clone()
+        .verify(proof_spec.clone(), nonce.clone())
+        .unwrap();
+
+    // Proof doesn't verify when the verifier uses a different header
+    let mut statements_wrong_header = Statements::new();
+    statements_wrong_header.add(PoKSignatureIetfBBSG1Stmt::new_statement_from_params::<
+        Bls12381Sha256,
+        _,
+        _,
+    >(
+        params_1.clone(),
+        keypair_1.public_key.clone(),
+        b"another-header".to_vec(),
+        revealed_msgs_1.clone(),
+    ));
+    statements_wrong_header.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+        params_2.clone(),
+        keypair_2.public_key.clone(),
+        BTreeMap::new(),
+    ));
+    let proof_spec_wrong_header = ProofSpec::new(
+        statements_wrong_header,
+        meta_statements.clone(),
+        vec![],
+        context.clone(),
+    );
+    assert!(proof
+        .clone()
+        .verify(proof_spec_wrong_header, nonce.clone())
+        .is_err());
+
+    // Proof doesn't verify when the statement has an unknown ciphersuite as the domain can't be computed
+    let mut statements_unknown_ciphersuite = statements.clone();
+    match &mut statements_unknown_ciphersuite.0[0] {
+        Statement::PoKIetfBBSSignatureG1(s) => s.ciphersuite_id = b"unknown".to_vec(),
+        _ => unreachable!(),
+    }
+    let proof_spec_unknown_ciphersuite = ProofSpec::new(
+        statements_unknown_ciphersuite,
+        meta_statements.clone(),
+        vec![],
+        context.clone(),
+    );
+    assert!(matches!(
+        proof.verify(proof_spec_unknown_ciphersuite, nonce.clone()),
+        Err(ProofSystemError::BBSPlusError(
+            BBSPlusError::UnsupportedCiphersuite(_)
+        ))
+    ));
+
+    // Proof doesn't verify when messages claimed equal are not
+    let mut meta_statements_incorrect = MetaStatements::new();
+    meta_statements_incorrect.add_witness_equality(EqualWitnesses(
+        vec![(0, 3), (1, 6)]
+            .into_iter()
+            .collect::<BTreeSet<WitnessRef>>(),
+    ));
+    let proof_spec_incorrect =
+        ProofSpec::new(statements, meta_statements_incorrect, vec![], context);
+    let proof = ProofG1::new(
+        &mut rng,
+        proof_spec_incorrect.clone(),
+        witnesses,
+        nonce.clone(),
+    )
+    .unwrap();
+    assert!(proof.verify(proof_spec_incorrect, nonce).is_err());
+}
+
+#[test]
+fn pok_of_ietf_bbs_sig_and_accumulator_with_reusing_setup_params() {
+    // Prove knowledge of 2 BBS signatures created as per the IETF draft by the same signer and that one
+    // of the messages is a member of an accumulator. Signature params and public key are shared using `SetupParams`
+    let mut rng = StdRng::seed_from_u64(0u64);
+
+    let header = b"test-header";
+    let msg_count = 5;
+    let (msgs_1, params, keypair, sig_1) = ietf_bbs_sig_setup(&mut rng, msg_count, header);
+    let msgs_2: Vec<Fr> = (0..msg_count).map(|_| Fr::rand(&mut rng)).collect();
+    let sig_2 = IetfBBSSignatureG1::<Bls12_381>::new::<Bls12381Sha256>(
+        &msgs_2,
+        &keypair.secret_key,
+        &keypair.public_key,
+        &params,
+        header,
+    )
+    .unwrap();
+
+    let (accum_params, accum_keypair, mut accumulator, mut state) = setup_positive_accum(&mut rng);
+    let mem_prk = MembershipProvingKey::generate_using_rng(&mut rng);
+    let member_idx = 3;
+    let member = msgs_2[member_idx];
+    accumulator = accumulator
+        .add(member, &accum_keypair.secret_key, &mut state)
+        .unwrap();
+    let mem_wit = accumulator
+        .get_membership_witness(&member, &accum_keypair.secret_key, &state)
+        .unwrap();
+
+    let mut all_setup_params = vec![];
+    all_setup_params.push(SetupParams::BBSPlusSignatureParams(params.clone()));
+    all_setup_params.push(SetupParams::BBSPlusPublicKey(keypair.public_key.clone()));
+
+    let mut statements = Statements::new();
+    statements.add(PoKSignatureIetfBBSG1Stmt::new_statement_from_params_ref::<
+        Bls12381Sha256,
+        _,
+        _,
+    >(0, 1, header.to_vec(), BTreeMap::new()));
+    statements.add(PoKSignatureIetfBBSG1Stmt::new_statement_from_params_ref::<
+        Bls12381Sha256,
+        _,
+        _,
+    >(0, 1, header.to_vec(), BTreeMap::new()));
+    statements.add(AccumulatorMembershipStmt::new_statement_from_params(
+        accum_params.clone(),
+        accum_keypair.public_key.clone(),
+        mem_prk.clone(),
+        accumulator.value().clone(),
+    ));
+
+    let mut meta_statements = MetaStatements::new();
+    meta_statements.add_witness_equality(EqualWitnesses(
+        vec![(1, member_idx), (2, 0)]
+            .into_iter()
+            .collect::<BTreeSet<WitnessRef>>(),
+    ));
+
+    test_serialization!(Statements<Bls12_381, G1Affine>, statements);
+
+    let proof_spec = ProofSpec::new(statements, meta_statements, all_setup_params, None);
+    proof_spec.validate().unwrap();
+
+    test_serialization!(ProofSpec<Bls12_381, G1Affine>, proof_spec);
+
+    let mut witnesses = Witnesses::new();
+    witnesses.add(PoKSignatureIetfBBSG1Wit::new_as_witness(
+        sig_1,
+        msgs_1.into_iter().enumerate().collect(),
+    ));
+    witnesses.add(PoKSignatureIetfBBSG1Wit::new_as_witness(
+        sig_2,
+        msgs_2.into_iter().enumerate().collect(),
+    ));
+    witnesses.add(MembershipWit::new_as_witness(member, mem_wit));
+
+    test_serialization!(Witnesses<Bls12_381>, witnesses);
+
+    let proof = ProofG1::new(&mut rng, proof_spec.clone(), witnesses, None).unwrap();
+
+    test_serialization!(ProofG1, proof);
+
+    proof.verify(proof_spec, None).unwrap();
+}
@@ -0,0 +1,152 @@
+use ark_bls12_381::{Bls12_381, G1Affine, G1Projective};
+use ark_ec::ProjectiveCurve;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::collections::{BTreeMap, BTreeSet};
+use ark_std::{rand::prelude::StdRng, rand::SeedableRng, UniformRand};
+use proof_system::prelude::{
+    EqualWitnesses, MetaStatements, ProofSystemError, Witness, WitnessRef, Witnesses,
+};
+use proof_system::proof_spec::ProofSpec;
+use proof_system::setup_params::SetupParams;
+use proof_system::statement::{
+    bbs_plus::PoKBBSSignatureG1 as PoKSignatureBBSG1Stmt, inequality::Inequality as InequalityStmt,
+    Statements,
+};
+use proof_system::witness::PoKBBSSignatureG1 as PoKSignatureBBSG1Wit;
+use test_utils::bbs_plus::*;
+use test_utils::{test_serialization, Fr, ProofG1};
+
+#[test]
+fn pok_of_bbs_plus_sig_and_inequality_with_public_value() {
+    // Prove knowledge of a BBS+ signature and that a specific hidden message is not equal to a public value
+    let mut rng = StdRng::seed_from_u64(0u64);
+
+    let msg_count = 6;
+    let (msgs, sig_params, keypair, sig) = sig_setup(&mut rng, msg_count);
+
+    let inequal_msg_idx = 1;
+    let inequal_to = Fr::rand(&mut rng);
+    assert_ne!(msgs[inequal_msg_idx], inequal_to);
+
+    let comm_key = vec![
+        G1Projective::rand(&mut rng).into_affine(),
+        G1Projective::rand(&mut rng).into_affine(),
+    ];
+
+    let mut meta_statements = MetaStatements::new();
+    meta_statements.add_witness_equality(EqualWitnesses(
+        vec![(0, inequal_msg_idx), (1, 0)]
+            .into_iter()
+            .collect::<BTreeSet<WitnessRef>>(),
+    ));
+    test_serialization!(MetaStatements, meta_statements);
+
+    let mut witnesses = Witnesses::new();
+    witnesses.add(PoKSignatureBBSG1Wit::new_as_witness(
+        sig.clone(),
+        msgs.clone().into_iter().enumerate().collect(),
+    ));
+    witnesses.add(Witness::Inequality(msgs[inequal_msg_idx]));
+    test_serialization!(Witnesses<Bls12_381>, witnesses);
+
+    let create_statements = |inequal_stmt| {
+        let mut statements = Statements::new();
+        statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+            sig_params.clone(),
+            keypair.public_key.clone(),
+            BTreeMap::new(),
+        ));
+        statements.add(inequal_stmt);
+        statements
+    };
+
+    let statements = create_statements(InequalityStmt::new_statement_from_params(
+        comm_key.clone(),
+        inequal_to,
+    ));
+    test_serialization!(Statements<Bls12_381, G1Affine>, statements);
+
+    let proof_spec = ProofSpec::new(statements, meta_statements.clone(), vec![], None);
+    proof_spec.validate().unwrap();
+    test_serialization!(ProofSpec<Bls12_381, G1Affine>, proof_spec);
+
+    let nonce = Some(b"test-nonce".to_vec());
+    let proof = ProofG1::new(
+        &mut rng,
+        proof_spec.clone(),
+        witnesses.clone(),
+        nonce.clone(),
+    )
+    .unwrap();
+    test_serialization!(ProofG1, proof);
+    proof.verify(proof_spec, nonce.clone()).unwrap();
+
+    // Same but the commitment key is passed as setup params
+    let all_setup_params = vec![SetupParams::PedersenCommitmentKey(comm_key.clone())];
+    test_serialization!(Vec<SetupParams<Bls12_381, G1Affine>>, all_setup_params);
+
+    let statements =
+        create_statements(InequalityStmt::new_statement_from_params_ref(0, inequal_to));
+    test_serialization!(Statements<Bls12_381, G1Affine>, statements);
+
+    let proof_spec = ProofSpec::new(
+        statements,
+        meta_statements.clone(),
+        all_setup_params.clone(),
+        None,
+    );
+    proof_spec.validate().unwrap();
+    test_serialization!(ProofSpec<Bls12_381, G1Affine>, proof_spec);
+
+    let proof = ProofG1::new(
+        &mut rng,
+        proof_spec.clone(),
+        witnesses.clone(),
+        nonce.clone(),
+    )
+    .unwrap();
+    test_serialization!(ProofG1, proof);
+    proof.clone().verify(proof_spec, nonce.clone()).unwrap();
+
+    // Proof doesn't verify for a different public value
+    let statements = create_statements(InequalityStmt::new_statement_from_params_ref(
+        0,
+        Fr::rand(&mut rng),
+    ));
+    let proof_spec_other_value = ProofSpec::new(
+        statements,
+        meta_statements.clone(),
+        all_setup_params.clone(),
+        None,
+    );
+    assert!(proof.verify(proof_spec_other_value, nonce.clone()).is_err());
+
+    // Proof cannot be created when the message is equal to the public value
+    let statements = create_statements(InequalityStmt::new_statement_from_params_ref(
+        0,
+        msgs[inequal_msg_idx],
+    ));
+    let proof_spec_equal = ProofSpec::new(
+        statements,
+        meta_statements.clone(),
+        all_setup_params.clone(),
+        None,
+    );
+    assert!(matches!(
+        ProofG1::new(&mut rng, proof_spec_equal, witnesses.clone(), nonce.clone()),
+        Err(ProofSystemError::InequalityWitnessEqualToPublicValue(1))
+    ));
+
+    // Commitment key must have 2 bases
+    let statements = create_statements(InequalityStmt::new_statement_from_params(
+        vec![G1Projective::rand(&mut rng).into_affine()],
+        inequal_to,
+    ));
+    let proof_spec_invalid_key = ProofSpec::new(statements, meta_statements, vec![], None);
+    assert!(matches!(
+        ProofG1::new(&mut rng, proof_spec_invalid_key, witnesses, nonce),
+        Err(ProofSystemError::IncorrectCommitmentKeySizeForInequality(
+            1, 1
+        ))
+    ));
+}
@@ -0,0 +1,178 @@
+use ark_bls12_381::{Bls12_381, G1Affine, G1Projective};
+use ark_bn254::Bn254;
+use ark_ec::ProjectiveCurve;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::collections::{BTreeMap, BTreeSet};
+use ark_std::{rand::prelude::StdRng, rand::SeedableRng, UniformRand};
+use proof_system::prelude::{EqualWitnesses, MetaStatements, Witness, WitnessRef, Witnesses};
+use proof_system::proof_spec::ProofSpec;
+use proof_system::statement::{
+    bbs_plus::PoKBBSSignatureG1 as PoKSignatureBBSG1Stmt,
+    pseudonym::{self, Pseudonym},
+    Statements,
+};
+use proof_system::witness::PoKBBSSignatureG1 as PoKSignatureBBSG1Wit;
+use test_utils::bbs_plus::*;
+use test_utils::{test_serialization, ProofG1};
+
+const DST: &[u8] = b"TEST-PSEUDONYM_XMD:SHA-256_SSWU_RO_";
+
+#[test]
+fn pok_of_bbs_plus_sig_and_pseudonym() {
+    // Prove knowledge of a BBS+ signature and reveal a pseudonym for a verifier's scope. The pseudonym
+    // is created from a signed message which is kept hidden. The same signed message gives the same pseudonym
+    // for the same scope but different pseudonyms for different scopes.
+    let mut rng = StdRng::seed_from_u64(0u64);
+
+    let msg_count = 6;
+    let (msgs, sig_params, keypair, sig) = sig_setup(&mut rng, msg_count);
+
+    // The signed message acting as the prover's secret
+    let secret_msg_idx = 2;
+
+    let create_proof = |rng: &mut StdRng, scope: &[u8], nonce: Option<Vec<u8>>| {
+        let base = pseudonym::base_for_scope::<Bls12_381>(scope, DST).unwrap();
+        let pseudonym = pseudonym::compute_pseudonym(&base, &msgs[secret_msg_idx]);
+
+        let mut statements = Statements::new();
+        statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+            sig_params.clone(),
+            keypair.public_key.clone(),
+            BTreeMap::new(),
+        ));
+        statements.add(Pseudonym::new_statement(
+            scope.to_vec(),
+            DST.to_vec(),
+            pseudonym,
+        ));
+
+        let mut meta_statements = MetaStatements::new();
+        meta_statements.add_witness_equality(EqualWitnesses(
+            vec![(0, secret_msg_idx), (1, 0)]
+                .into_iter()
+                .collect::<BTreeSet<WitnessRef>>(),
+        ));
+
+        test_serialization!(Statements<Bls12_381, G1Affine>, statements);
+        test_serialization!(MetaStatements, meta_statements);
+
+        let proof_spec = ProofSpec::new(statements, meta_statements, vec![], None);
+        proof_spec.validate().unwrap();
+
+        test_serialization!(ProofSpec<Bls12_381, G1Affine>, proof_spec);
+
+        let mut witnesses = Witnesses::new();
+        witnesses.add(PoKSignatureBBSG1Wit::new_as_witness(
+            sig.clone(),
+            msgs.clone().into_iter().enumerate().collect(),
+        ));
+        witnesses.add(Witness::Pseudonym(msgs[secret_msg_idx]));
+
+        test_serialization!(Witnesses<Bls12_381>, witnesses);
+
+        let proof = ProofG1::new(rng, proof_spec.clone(), witnesses, nonce.clone()).unwrap();
+
+        test_serialization!(ProofG1, proof);
+
+        proof.clone().verify(proof_spec.clone(), nonce).unwrap();
+        (pseudonym, proof, proof_spec)
+    };
+
+    let scope_1 = b"verifier-1";
+    let scope_2 = b"verifier-2";
+    let nonce = Some(b"test-nonce".to_vec());
+
+    let (pseudonym_1, proof_1, proof_spec_1) = create_proof(&mut rng, scope_1, nonce.clone());
+    let (pseudonym_2, _, _) = create_proof(&mut rng, scope_1, None);
+    let (pseudonym_3, _, _) = create_proof(&mut rng, scope_2, nonce.clone());
+
+    // Same scope gives the same pseudonym across proofs
+    assert_eq!(pseudonym_1, pseudonym_2);
+    // Different scope gives a different pseudonym
+    assert_ne!(pseudonym_1, pseudonym_3);
+
+    // Proof doesn't verify for a different pseudonym
+    let mut statements_wrong_pseudonym = Statements::new();
+    statements_wrong_pseudonym.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+        sig_params.clone(),
+        keypair.public_key.clone(),
+        BTreeMap::new(),
+    ));
+    statements_wrong_pseudonym.add(Pseudonym::new_statement(
+        scope_1.to_vec(),
+        DST.to_vec(),
+        G1Projective::rand(&mut rng).into_affine(),
+    ));
+    let proof_spec_wrong_pseudonym = ProofSpec::new(
+        statements_wrong_pseudonym,
+        proof_spec_1.meta_statements.clone(),
+        vec![],
+        None,
+    );
+    assert!(proof_1
+        .clone()
+        .verify(proof_spec_wrong_pseudonym, nonce.clone())
+        .is_err());
+
+    // Proof doesn't verify for a different scope
+    let mut statements_wrong_scope = Statements::new();
+    statements_wrong_scope.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+        sig_params.clone(),
+        keypair.public_key.clone(),
+        BTreeMap::new(),
+    ));
+    statements_wrong_scope.add(Pseudonym::new_statement(
+        scope_2.to_vec(),
+        DST.to_vec(),
+        pseudonym_1,
+    ));
+    let proof_spec_wrong_scope = ProofSpec::new(
+        statements_wrong_scope,
+        proof_spec_1.meta_statements.clone(),
+        vec![],
+        None,
+    );
+    assert!(proof_1
+        .verify(proof_spec_wrong_scope, nonce.clone())
+        .is_err());
+
+    // Proof doesn't verify when the pseudonym is created from a message other than the one claimed equal
+    let base = pseudonym::base_for_scope::<Bls12_381>(scope_1, DST).unwrap();
+    let other_msg_idx = 4;
+    let pseudonym_other = pseudonym::compute_pseudonym(&base, &msgs[other_msg_idx]);
+
+    let mut statements = Statements::new();
+    statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+        sig_params.clone(),
+        keypair.public_key.clone(),
+        BTreeMap::new(),
+    ));
+    statements.add(Pseudonym::new_statement(
+        scope_1.to_vec(),
+        DST.to_vec(),
+        pseudonym_other,
+    ));
+    let proof_spec_incorrect = ProofSpec::new(
+        statements,
+        proof_spec_1.meta_statements.clone(),
+        vec![],
+        None,
+    );
+    let mut witnesses = Witnesses::new();
+    witnesses.add(PoKSignatureBBSG1Wit::new_as_witness(
+        sig.clone(),
+        msgs.clone().into_iter().enumerate().collect(),
+    ));
+    witnesses.add(Witness::Pseudonym(msgs[other_msg_idx]));
+    let proof = ProofG1::new(
+        &mut rng,
+        proof_spec_incorrect.clone(),
+        witnesses,
+        nonce.clone(),
+    )
+    .unwrap();
+    assert!(proof.verify(proof_spec_incorrect, nonce).is_err());
+
+    // Hash to curve is not implemented for the group of the pseudonym so there is no base for the scope
+    assert!(pseudonym::base_for_scope::<Bn254>(scope_1, DST).is_none());
+}
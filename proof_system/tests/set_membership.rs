@@ -0,0 +1,155 @@
+use ark_bls12_381::{Bls12_381, G1Affine};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::collections::{BTreeMap, BTreeSet};
+use ark_std::{rand::prelude::StdRng, rand::SeedableRng, UniformRand};
+use blake2::Blake2b;
+use compressed_sigma::set_membership::SetMembershipParams;
+use proof_system::prelude::{EqualWitnesses, MetaStatements, Witness, WitnessRef, Witnesses};
+use proof_system::proof_spec::ProofSpec;
+use proof_system::setup_params::SetupParams;
+use proof_system::statement::{
+    bbs_plus::PoKBBSSignatureG1 as PoKSignatureBBSG1Stmt,
+    set_membership::SetMembership as SetMembershipStmt, Statements,
+};
+use proof_system::witness::PoKBBSSignatureG1 as PoKSignatureBBSG1Wit;
+use test_utils::bbs_plus::*;
+use test_utils::{test_serialization, Fr, ProofG1};
+
+#[test]
+fn pok_of_bbs_plus_sig_and_set_membership() {
+    // Prove knowledge of a BBS+ signature and that a specific hidden message is a member of a public set
+    let mut rng = StdRng::seed_from_u64(0u64);
+
+    let msg_count = 6;
+    let member_msg_idx = 3;
+    let set_size = 20;
+
+    let set = (0..set_size)
+        .map(|_| Fr::rand(&mut rng))
+        .collect::<Vec<_>>();
+    let mut msgs = (0..msg_count)
+        .map(|_| Fr::rand(&mut rng))
+        .collect::<Vec<_>>();
+    msgs[member_msg_idx] = set[7];
+    let (sig_params, keypair, sig) = sig_setup_given_messages(&mut rng, &msgs);
+
+    let set_mem_params = SetMembershipParams::<G1Affine>::new::<Blake2b>(b"test", set_size);
+
+    let mut meta_statements = MetaStatements::new();
+    meta_statements.add_witness_equality(EqualWitnesses(
+        vec![(0, member_msg_idx), (1, 0)]
+            .into_iter()
+            .collect::<BTreeSet<WitnessRef>>(),
+    ));
+    test_serialization!(MetaStatements, meta_statements);
+
+    let mut statements = Statements::new();
+    statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+        sig_params.clone(),
+        keypair.public_key.clone(),
+        BTreeMap::new(),
+    ));
+    statements.add(SetMembershipStmt::new_statement_from_params(
+        set_mem_params.clone(),
+        set.clone(),
+    ));
+    test_serialization!(Statements<Bls12_381, G1Affine>, statements);
+
+    let proof_spec = ProofSpec::new(statements, meta_statements.clone(), vec![], None);
+    proof_spec.validate().unwrap();
+    test_serialization!(ProofSpec<Bls12_381, G1Affine>, proof_spec);
+
+    let mut witnesses = Witnesses::new();
+    witnesses.add(PoKSignatureBBSG1Wit::new_as_witness(
+        sig.clone(),
+        msgs.clone().into_iter().enumerate().collect(),
+    ));
+    witnesses.add(Witness::SetMembership(msgs[member_msg_idx]));
+    test_serialization!(Witnesses<Bls12_381>, witnesses);
+
+    let nonce = Some(b"test-nonce".to_vec());
+    let proof = ProofG1::new(
+        &mut rng,
+        proof_spec.clone(),
+        witnesses.clone(),
+        nonce.clone(),
+    )
+    .unwrap();
+    test_serialization!(ProofG1, proof);
+    proof.verify(proof_spec, nonce.clone()).unwrap();
+
+    // Same but the params are passed as setup params
+    let all_setup_params = vec![SetupParams::SetMembershipParams(set_mem_params.clone())];
+    test_serialization!(Vec<SetupParams<Bls12_381, G1Affine>>, all_setup_params);
+
+    let mut statements = Statements::new();
+    statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+        sig_params.clone(),
+        keypair.public_key.clone(),
+        BTreeMap::new(),
+    ));
+    statements.add(SetMembershipStmt::new_statement_from_params_ref(
+        0,
+        set.clone(),
+    ));
+    test_serialization!(Statements<Bls12_381, G1Affine>, statements);
+
+    let proof_spec = ProofSpec::new(
+        statements,
+        meta_statements.clone(),
+        all_setup_params.clone(),
+        None,
+    );
+    proof_spec.validate().unwrap();
+    test_serialization!(ProofSpec<Bls12_381, G1Affine>, proof_spec);
+
+    let proof = ProofG1::new(
+        &mut rng,
+        proof_spec.clone(),
+        witnesses.clone(),
+        nonce.clone(),
+    )
+    .unwrap();
+    test_serialization!(ProofG1, proof);
+    proof.clone().verify(proof_spec, nonce.clone()).unwrap();
+
+    // Proof doesn't verify for a different set
+    let mut other_set = set.clone();
+    other_set[7] = Fr::rand(&mut rng);
+    let mut statements = Statements::new();
+    statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
+        sig_params.clone(),
+        keypair.public_key.clone(),
+        BTreeMap::new(),
+    ));
+    statements.add(SetMembershipStmt::new_statement_from_params_ref(
+        0,
+        other_set.clone(),
+    ));
+    let proof_spec_other_set = ProofSpec::new(
+        statements,
+        meta_statements.clone(),
+        all_setup_params.clone(),
+        None,
+    );
+    assert!(proof
+        .verify(proof_spec_other_set.clone(), nonce.clone())
+        .is_err());
+
+    // Proof cannot be created when the message is not a member of the set
+    assert!(ProofG1::new(&mut rng, proof_spec_other_set, witnesses, nonce.clone()).is_err());
+
+    // Proof cannot be created when the set is larger than supported by the params
+    let large_set = (0..set_mem_params.max_set_size() + 1)
+        .map(|_| Fr::rand(&mut rng))
+        .collect::<Vec<_>>();
+    let mut statements = Statements::new();
+    statements.add(SetMembershipStmt::new_statement_from_params(
+        set_mem_params,
+        large_set.clone(),
+    ));
+    let proof_spec_large_set = ProofSpec::new(statements, MetaStatements::new(), vec![], None);
+    let mut witnesses = Witnesses::new();
+    witnesses.add(Witness::SetMembership(large_set[0]));
+    assert!(ProofG1::new(&mut rng, proof_spec_large_set, witnesses, nonce).is_err());
+}
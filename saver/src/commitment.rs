@@ -255,7 +255,7 @@ mod tests {
                 let bases = ek.commitment_key();
 
                 let mut wit2 = decomposed.clone();
-                wit2[n as usize] = r;
+                wit2[n as usize] = *r.expose();
 
                 let start = Instant::now();
                 let mut statements = Statements::new();
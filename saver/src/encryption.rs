@@ -28,6 +28,7 @@ use serde_with::serde_as;
 
 use crate::utils::CHUNK_TYPE;
 use dock_crypto_utils::ec::batch_normalize_projective_into_affine;
+use dock_crypto_utils::secret::Secret;
 use dock_crypto_utils::serde_utils::*;
 
 /// Ciphertext used with Groth16
@@ -186,8 +187,8 @@ pub struct Encryption<E: PairingEngine>(PhantomData<E>);
 
 impl<E: PairingEngine> Encryption<E> {
     /// Encrypt a message `m` in exponent-Elgamal after breaking it into chunks of `chunk_bit_size` bits.
-    /// Returns the ciphertext, commitment and randomness created for encryption. This is "Enc" from algorithm
-    /// 2 in the paper
+    /// Returns the ciphertext, commitment and randomness created for encryption. The randomness is a `Secret`
+    /// as it can be used to decrypt the ciphertext. This is "Enc" from algorithm 2 in the paper
     /// Ciphertext vector contains commitment `psi` as the last element
     pub fn encrypt<R: RngCore>(
         rng: &mut R,
@@ -195,7 +196,7 @@ impl<E: PairingEngine> Encryption<E> {
         ek: &EncryptionKey<E>,
         g_i: &[E::G1Affine],
         chunk_bit_size: u8,
-    ) -> crate::Result<(Ciphertext<E>, E::Fr)> {
+    ) -> crate::Result<(Ciphertext<E>, Secret<E::Fr>)> {
         let decomposed = utils::decompose(message, chunk_bit_size)?;
         let (mut ct, r) = Self::encrypt_decomposed_message(rng, decomposed, ek, g_i)?;
         Ok((
@@ -215,7 +216,7 @@ impl<E: PairingEngine> Encryption<E> {
         ek: &EncryptionKey<E>,
         snark_pk: &saver_groth16::ProvingKey<E>,
         chunk_bit_size: u8,
-    ) -> crate::Result<(Ciphertext<E>, E::Fr, ark_groth16::Proof<E>)> {
+    ) -> crate::Result<(Ciphertext<E>, Secret<E::Fr>, ark_groth16::Proof<E>)> {
         let g_i = saver_groth16::get_gs_for_encryption(&snark_pk.pk.vk);
         let (ct, r) = Encryption::encrypt(rng, message, &ek, g_i, chunk_bit_size)?;
         let decomposed_message = utils::decompose(message, chunk_bit_size)?
@@ -224,7 +225,7 @@ impl<E: PairingEngine> Encryption<E> {
             .collect::<Vec<_>>();
         let circuit =
             BitsizeCheckCircuit::new(chunk_bit_size, None, Some(decomposed_message.clone()), true);
-        let proof = saver_groth16::create_proof(circuit, &r, snark_pk, &ek, rng).unwrap();
+        let proof = saver_groth16::create_proof(circuit, r.expose(), snark_pk, &ek, rng).unwrap();
         Ok((ct, r, proof))
     }
 
@@ -235,7 +236,7 @@ impl<E: PairingEngine> Encryption<E> {
         ek: &EncryptionKey<E>,
         snark_vk: &ark_groth16::VerifyingKey<E>,
         chunk_bit_size: u8,
-    ) -> crate::Result<(Ciphertext<E>, E::Fr)> {
+    ) -> crate::Result<(Ciphertext<E>, Secret<E::Fr>)> {
         let g_i = saver_groth16::get_gs_for_encryption(&snark_vk);
         Self::encrypt(rng, message, ek, g_i, chunk_bit_size)
     }
@@ -248,13 +249,13 @@ impl<E: PairingEngine> Encryption<E> {
         ek: &EncryptionKey<E>,
         g_i: &[E::G1Affine],
         chunk_bit_size: u8,
-    ) -> crate::Result<(CiphertextAlt<E>, E::Fr)> {
+    ) -> crate::Result<(CiphertextAlt<E>, Secret<E::Fr>)> {
         let decomposed = utils::decompose(message, chunk_bit_size)?;
         let (mut ct, r) = Self::encrypt_decomposed_message(rng, decomposed, ek, g_i)?;
         let x_r_sum =
             ek.X.iter()
                 .fold(E::G1Affine::zero(), |a, &b| a.add(b))
-                .mul(r);
+                .mul(r.expose().into_repr());
         Ok((
             CiphertextAlt {
                 X_r: ct.remove(0),
@@ -273,7 +274,7 @@ impl<E: PairingEngine> Encryption<E> {
         ek: &EncryptionKey<E>,
         snark_vk: &legogroth16::VerifyingKey<E>,
         chunk_bit_size: u8,
-    ) -> crate::Result<(CiphertextAlt<E>, E::Fr)> {
+    ) -> crate::Result<(CiphertextAlt<E>, Secret<E::Fr>)> {
         let g_i = saver_legogroth16::get_gs_for_encryption(&snark_vk);
         Self::encrypt_alt(rng, message, ek, g_i, chunk_bit_size)
     }
@@ -655,7 +656,7 @@ impl<E: PairingEngine> Encryption<E> {
         message_chunks: Vec<CHUNK_TYPE>,
         ek: &EncryptionKey<E>,
         g_i: &[E::G1Affine],
-    ) -> crate::Result<(Vec<E::G1Affine>, E::Fr)> {
+    ) -> crate::Result<(Vec<E::G1Affine>, Secret<E::Fr>)> {
         if message_chunks.len() != ek.supported_chunks_count()? as usize {
             return Err(SaverError::IncompatibleEncryptionKey(
                 message_chunks.len(),
@@ -668,8 +669,8 @@ impl<E: PairingEngine> Encryption<E> {
                 g_i.len(),
             ));
         }
-        let r = E::Fr::rand(rng);
-        let r_repr = r.into_repr();
+        let r = Secret::new(E::Fr::rand(rng));
+        let r_repr = r.expose().into_repr();
         let mut ct = vec![];
         ct.push(ek.X_0.mul(r_repr));
         let mut m = message_chunks
@@ -681,7 +682,7 @@ impl<E: PairingEngine> Encryption<E> {
         }
 
         // Commit to the message chunks with randomness `r`
-        m.push(r.into_repr());
+        m.push(r_repr);
         let psi = VariableBaseMSM::multi_scalar_mul(&ek.commitment_key(), &m);
 
         ct.push(psi);
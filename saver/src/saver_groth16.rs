@@ -201,7 +201,7 @@ mod tests {
             );
 
             let start = Instant::now();
-            let proof = create_proof(circuit, &r, &snark_srs, &ek, &mut rng).unwrap();
+            let proof = create_proof(circuit, r.expose(), &snark_srs, &ek, &mut rng).unwrap();
             println!(
                 "Time taken to create Groth16 proof with chunk_bit_size {}: {:?}",
                 chunk_bit_size,
@@ -231,7 +231,7 @@ mod tests {
                     .fold(<Bls12_381 as PairingEngine>::G1Affine::zero(), |a, &b| {
                         a.add(b)
                     })
-                    .mul(r)
+                    .mul(r.expose().into_repr())
                     .into_affine();
 
             let (m_, _) = Encryption::decrypt_to_chunks(
@@ -257,9 +257,15 @@ mod tests {
             );
 
             let start = Instant::now();
-            let proof_2 =
-                protocol_2::create_proof(circuit.clone(), v.clone(), &r, &snark_srs, &ek, &mut rng)
-                    .unwrap();
+            let proof_2 = protocol_2::create_proof(
+                circuit.clone(),
+                v.clone(),
+                r.expose(),
+                &snark_srs,
+                &ek,
+                &mut rng,
+            )
+            .unwrap();
             println!(
                 "Time taken to create LegoGroth16 proof with chunk_bit_size {} as per protocol 2 {:?}",
                 chunk_bit_size,
@@ -293,7 +299,8 @@ mod tests {
 
             let start = Instant::now();
             let proof_1 =
-                protocol_1::create_proof(circuit, v, &r, &snark_srs, &ek, &mut rng).unwrap();
+                protocol_1::create_proof(circuit, v, r.expose(), &snark_srs, &ek, &mut rng)
+                    .unwrap();
             println!(
                 "Time taken to create LegoGroth16 proof with chunk_bit_size {} as per protocol 1 {:?}",
                 chunk_bit_size,
@@ -9,11 +9,13 @@ use ark_std::{
     rand::RngCore,
     UniformRand,
 };
-use digest::Digest;
+use digest::{BlockInput, Digest};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
-use dock_crypto_utils::hashing_utils::affine_group_elem_from_try_and_incr;
+use dock_crypto_utils::hashing_utils::{
+    affine_group_elem_from_hash_to_curve, affine_group_elem_from_try_and_incr, HashToCurve,
+};
 use dock_crypto_utils::serde_utils::*;
 
 /// Create "G" and "H" from the paper.
@@ -46,6 +48,24 @@ impl<E: PairingEngine> EncryptionGens<E> {
         Self { G, H }
     }
 
+    /// Same as `new` but uses the hash to curve of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html)
+    /// with domain separation tag `dst` rather than try-and-increment.
+    pub fn new_using_hash_to_curve<D: Digest + BlockInput>(label: &[u8], dst: &[u8]) -> Self
+    where
+        E::G1Affine: HashToCurve,
+        E::G2Affine: HashToCurve,
+    {
+        let G = affine_group_elem_from_hash_to_curve::<E::G1Affine, D>(
+            &to_bytes![label, " : G".as_bytes()].unwrap(),
+            dst,
+        );
+        let H = affine_group_elem_from_hash_to_curve::<E::G2Affine, D>(
+            &to_bytes![label, " : H".as_bytes()].unwrap(),
+            dst,
+        );
+        Self { G, H }
+    }
+
     pub fn new_using_rng<R: RngCore>(rng: &mut R) -> Self {
         let G = E::G1Projective::rand(rng).into_affine();
         let H = E::G2Projective::rand(rng).into_affine();
@@ -83,6 +103,23 @@ impl<G: AffineCurve> ChunkedCommitmentGens<G> {
         Self { G, H }
     }
 
+    /// Same as `new` but uses the hash to curve of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html)
+    /// with domain separation tag `dst` rather than try-and-increment.
+    pub fn new_using_hash_to_curve<D: Digest + BlockInput>(label: &[u8], dst: &[u8]) -> Self
+    where
+        G: HashToCurve,
+    {
+        let G = affine_group_elem_from_hash_to_curve::<G, D>(
+            &to_bytes![label, " : G".as_bytes()].unwrap(),
+            dst,
+        );
+        let H = affine_group_elem_from_hash_to_curve::<G, D>(
+            &to_bytes![label, " : H".as_bytes()].unwrap(),
+            dst,
+        );
+        Self { G, H }
+    }
+
     pub fn new_using_rng<R: RngCore>(rng: &mut R) -> Self {
         let G = G::Projective::rand(rng).into_affine();
         let H = G::Projective::rand(rng).into_affine();
@@ -127,6 +164,7 @@ pub(crate) mod tests {
     use ark_std::rand::prelude::StdRng;
     use ark_std::rand::SeedableRng;
     use blake2::Blake2b;
+    use sha2::Sha256;
 
     type Fr = <Bls12_381 as PairingEngine>::Fr;
 
@@ -164,6 +202,31 @@ pub(crate) mod tests {
         )
     }
 
+    #[test]
+    fn gens_using_hash_to_curve() {
+        let label = [1, 2, 3];
+        let dst = b"SAVER-TEST-GENS_XMD:SHA-256_SSWU_RO_";
+        let enc_gens_1 =
+            EncryptionGens::<Bls12_381>::new_using_hash_to_curve::<Sha256>(&label, dst);
+        let enc_gens_2 =
+            EncryptionGens::<Bls12_381>::new_using_hash_to_curve::<Sha256>(&label, dst);
+        let enc_gens_3 =
+            EncryptionGens::<Bls12_381>::new_using_hash_to_curve::<Sha256>(&[1, 2], dst);
+        assert_eq!(enc_gens_1, enc_gens_2);
+        assert_ne!(enc_gens_2, enc_gens_3);
+        assert_ne!(
+            enc_gens_1,
+            EncryptionGens::<Bls12_381>::new::<Sha256>(&label)
+        );
+
+        let comm_gens_1 = ChunkedCommitmentGens::<<Bls12_381 as PairingEngine>::G1Affine>::new_using_hash_to_curve::<Sha256>(&label, dst);
+        let comm_gens_2 = ChunkedCommitmentGens::<<Bls12_381 as PairingEngine>::G1Affine>::new_using_hash_to_curve::<Sha256>(&label, dst);
+        let comm_gens_3 = ChunkedCommitmentGens::<<Bls12_381 as PairingEngine>::G1Affine>::new_using_hash_to_curve::<Sha256>(&label, b"SAVER-TEST-GENS-OTHER_XMD:SHA-256_SSWU_RO_");
+        assert_eq!(comm_gens_1, comm_gens_2);
+        assert_ne!(comm_gens_2, comm_gens_3);
+        assert_ne!(comm_gens_1.G, comm_gens_1.H);
+    }
+
     #[test]
     fn setup_for_groth16_works() {
         fn check(chunk_bit_size: u8) {
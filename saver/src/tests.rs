@@ -133,7 +133,7 @@ fn bbs_plus_verifiably_encrypt_message() {
 
         let bases_comm_ct = ek.commitment_key();
         let mut wit_comm_ct = decomposed_message.clone();
-        wit_comm_ct.push(r.clone());
+        wit_comm_ct.push(*r.expose());
 
         let mut statements = Statements::new();
         statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
@@ -198,7 +198,7 @@ fn bbs_plus_verifiably_encrypt_message() {
         println!("Time taken to verify proof {:?}", start.elapsed());
 
         let start = Instant::now();
-        let proof = create_proof(circuit, &r, &snark_srs, &ek, &mut rng).unwrap();
+        let proof = create_proof(circuit, r.expose(), &snark_srs, &ek, &mut rng).unwrap();
         println!("Time taken to create Groth16 proof {:?}", start.elapsed());
 
         let start = Instant::now();
@@ -408,17 +408,17 @@ fn bbs_plus_verifiably_encrypt_many_messages() {
         let mut wit_comm_chunks_1 = decomposed_message_1.clone();
         wit_comm_chunks_1.push(blinding_1.clone());
         let mut wit_comm_ct_1 = decomposed_message_1.clone();
-        wit_comm_ct_1.push(r_1.clone());
+        wit_comm_ct_1.push(*r_1.expose());
 
         let mut wit_comm_chunks_2 = decomposed_message_2.clone();
         wit_comm_chunks_2.push(blinding_2.clone());
         let mut wit_comm_ct_2 = decomposed_message_2.clone();
-        wit_comm_ct_2.push(r_2.clone());
+        wit_comm_ct_2.push(*r_2.expose());
 
         let mut wit_comm_chunks_3 = decomposed_message_3.clone();
         wit_comm_chunks_3.push(blinding_3.clone());
         let mut wit_comm_ct_3 = decomposed_message_3.clone();
-        wit_comm_ct_3.push(r_3.clone());
+        wit_comm_ct_3.push(*r_3.expose());
 
         let mut statements = Statements::new();
         statements.add(PoKSignatureBBSG1Stmt::new_statement_from_params(
@@ -745,10 +745,10 @@ fn bbs_plus_verifiably_encrypt_message_from_2_sigs() {
         let bases_comm_ct = ek.commitment_key();
 
         let mut wit_comm_ct_1 = decomposed_message_1.clone();
-        wit_comm_ct_1.push(r_1.clone());
+        wit_comm_ct_1.push(*r_1.expose());
 
         let mut wit_comm_ct_2 = decomposed_message_2.clone();
-        wit_comm_ct_2.push(r_2.clone());
+        wit_comm_ct_2.push(*r_2.expose());
 
         let mut statements = Statements::new();
         // For 1st sig
@@ -863,8 +863,8 @@ fn bbs_plus_verifiably_encrypt_message_from_2_sigs() {
         println!("Time taken to verify proof {:?}", start.elapsed());
 
         let start = Instant::now();
-        let proof_1 = create_proof(circuit_1, &r_1, &snark_srs, &ek, &mut rng).unwrap();
-        let proof_2 = create_proof(circuit_2, &r_2, &snark_srs, &ek, &mut rng).unwrap();
+        let proof_1 = create_proof(circuit_1, r_1.expose(), &snark_srs, &ek, &mut rng).unwrap();
+        let proof_2 = create_proof(circuit_2, r_2.expose(), &snark_srs, &ek, &mut rng).unwrap();
         println!(
             "Time taken to create 2 Groth16 proofs {:?}",
             start.elapsed()
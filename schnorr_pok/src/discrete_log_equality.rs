@@ -0,0 +1,680 @@
+//! Proof of equality of discrete logs, i.e. `log_g(y) == log_h(z)` using the protocol by Chaum and Pedersen from
+//! [Wallet Databases with Observers](https://link.springer.com/chapter/10.1007/3-540-48071-4_7)
+//!
+//! Prover wants to prove knowledge of `x` such that `y = g * x` and `z = h * x`
+//! Step 1: Prover generates randomness `r`, and sends `t1 = g * r` and `t2 = h * r` to Verifier
+//! Step 2: Verifier generates random challenge `c` and send to Prover
+//! Step 3: Prover produces `s = r + x*c`, and sends s to Verifier
+//! Step 4: Verifier checks that `g * s = (y * c) + t1` and `h * s = (z * c) + t2`
+//!
+//! The batched variant proves the same for several tuples `(g_i, y_i, h_i, z_i)` that share the witness `x`, i.e.
+//! `y_i = g_i * x` and `z_i = h_i * x` for all `i`. The Prover sends `t1_i = g_i * r` and `t2_i = h_i * r` for each
+//! tuple and a single response `s`, and the Verifier checks `g_i * s = (y_i * c) + t1_i` and `h_i * s = (z_i * c) + t2_i`
+//! for each tuple. These checks are combined into a single multi-scalar multiplication using random weights.
+//! Combining the tuples themselves into one tuple before running the protocol above is not sound as tuples with the
+//! same bases but different discrete logs would combine into a valid tuple.
+
+use crate::error::SchnorrError;
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Write},
+    rand::RngCore,
+    vec::Vec,
+    UniformRand,
+};
+use digest::Digest;
+use dock_crypto_utils::msm::variable_base_msm;
+use dock_crypto_utils::secret::{Secret, SecretAs};
+use dock_crypto_utils::serde_utils::*;
+use dock_crypto_utils::transcript::Transcript;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use zeroize::Zeroize;
+
+/// Domain separator of the transcript from which the weights for batch verification are derived
+const BATCH_WEIGHTS_LABEL: &[u8] = b"PoKDiscreteLogEqualityBatchProof-weights";
+
+/// Protocol for proving `log_g(y) == log_h(z)`
+#[serde_as]
+#[derive(
+    Clone, PartialEq, Eq, Debug, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+pub struct PoKDiscreteLogEqualityProtocol<G: AffineCurve> {
+    /// `g * blinding`
+    #[serde_as(as = "AffineGroupBytes")]
+    pub t1: G,
+    /// `h * blinding`
+    #[serde_as(as = "AffineGroupBytes")]
+    pub t2: G,
+    #[serde_as(as = "SecretAs<FieldBytes>")]
+    blinding: Secret<G::ScalarField>,
+    #[serde_as(as = "SecretAs<FieldBytes>")]
+    witness: Secret<G::ScalarField>,
+}
+
+/// Proof that `log_g(y) == log_h(z)`
+#[serde_as]
+#[derive(
+    Clone, PartialEq, Eq, Debug, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+pub struct PoKDiscreteLogEqualityProof<G: AffineCurve> {
+    #[serde_as(as = "AffineGroupBytes")]
+    pub t1: G,
+    #[serde_as(as = "AffineGroupBytes")]
+    pub t2: G,
+    #[serde_as(as = "FieldBytes")]
+    pub response: G::ScalarField,
+}
+
+/// Protocol for proving `log_{g_i}(y_i) == log_{h_i}(z_i)` for several tuples `(g_i, y_i, h_i, z_i)` with the same
+/// discrete log
+#[serde_as]
+#[derive(
+    Clone, PartialEq, Eq, Debug, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+pub struct PoKDiscreteLogEqualityBatchProtocol<G: AffineCurve> {
+    /// `g_i * blinding` for each tuple
+    #[serde_as(as = "Vec<AffineGroupBytes>")]
+    pub t1: Vec<G>,
+    /// `h_i * blinding` for each tuple
+    #[serde_as(as = "Vec<AffineGroupBytes>")]
+    pub t2: Vec<G>,
+    #[serde_as(as = "SecretAs<FieldBytes>")]
+    blinding: Secret<G::ScalarField>,
+    #[serde_as(as = "SecretAs<FieldBytes>")]
+    witness: Secret<G::ScalarField>,
+}
+
+/// Proof that `log_{g_i}(y_i) == log_{h_i}(z_i)` for several tuples `(g_i, y_i, h_i, z_i)` with the same discrete log
+#[serde_as]
+#[derive(
+    Clone, PartialEq, Eq, Debug, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+pub struct PoKDiscreteLogEqualityBatchProof<G: AffineCurve> {
+    #[serde_as(as = "Vec<AffineGroupBytes>")]
+    pub t1: Vec<G>,
+    #[serde_as(as = "Vec<AffineGroupBytes>")]
+    pub t2: Vec<G>,
+    #[serde_as(as = "FieldBytes")]
+    pub response: G::ScalarField,
+}
+
+impl<G: AffineCurve> PoKDiscreteLogEqualityProtocol<G> {
+    pub fn init(witness: G::ScalarField, blinding: G::ScalarField, g: &G, h: &G) -> Self {
+        let b = blinding.into_repr();
+        Self {
+            t1: g.mul(b).into_affine(),
+            t2: h.mul(b).into_affine(),
+            blinding: Secret::new(blinding),
+            witness: Secret::new(witness),
+        }
+    }
+
+    pub fn challenge_contribution<W: Write>(
+        &self,
+        g: &G,
+        y: &G,
+        h: &G,
+        z: &G,
+        writer: W,
+    ) -> Result<(), SchnorrError> {
+        Self::compute_challenge_contribution(g, y, h, z, &self.t1, &self.t2, writer)
+    }
+
+    pub fn append_to_transcript(
+        &self,
+        g: &G,
+        y: &G,
+        h: &G,
+        z: &G,
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        Self::compute_transcript_contribution(g, y, h, z, &self.t1, &self.t2, transcript)
+    }
+
+    pub fn gen_proof(self, challenge: &G::ScalarField) -> PoKDiscreteLogEqualityProof<G> {
+        let response = *self.blinding.expose() + (*self.witness.expose() * *challenge);
+        PoKDiscreteLogEqualityProof {
+            t1: self.t1,
+            t2: self.t2,
+            response,
+        }
+    }
+
+    pub fn compute_challenge_contribution<W: Write>(
+        g: &G,
+        y: &G,
+        h: &G,
+        z: &G,
+        t1: &G,
+        t2: &G,
+        mut writer: W,
+    ) -> Result<(), SchnorrError> {
+        g.serialize_unchecked(&mut writer)?;
+        y.serialize_unchecked(&mut writer)?;
+        h.serialize_unchecked(&mut writer)?;
+        z.serialize_unchecked(&mut writer)?;
+        t1.serialize_unchecked(&mut writer)?;
+        t2.serialize_unchecked(writer).map_err(|e| e.into())
+    }
+
+    /// Append the values of [`PoKDiscreteLogEqualityProtocol::compute_challenge_contribution`] to the transcript
+    /// with a label each
+    pub fn compute_transcript_contribution(
+        g: &G,
+        y: &G,
+        h: &G,
+        z: &G,
+        t1: &G,
+        t2: &G,
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        transcript.append_point(b"g", g)?;
+        transcript.append_point(b"y", y)?;
+        transcript.append_point(b"h", h)?;
+        transcript.append_point(b"z", z)?;
+        transcript.append_point(b"t1", t1)?;
+        transcript.append_point(b"t2", t2)?;
+        Ok(())
+    }
+}
+
+impl<G: AffineCurve> Zeroize for PoKDiscreteLogEqualityProtocol<G> {
+    fn zeroize(&mut self) {
+        // Not zeroizing `self.t1` and `self.t2` as they are public
+        self.blinding.zeroize();
+        self.witness.zeroize();
+    }
+}
+
+impl<G: AffineCurve> Drop for PoKDiscreteLogEqualityProtocol<G> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<G: AffineCurve> PoKDiscreteLogEqualityProof<G> {
+    pub fn challenge_contribution<W: Write>(
+        &self,
+        g: &G,
+        y: &G,
+        h: &G,
+        z: &G,
+        writer: W,
+    ) -> Result<(), SchnorrError> {
+        PoKDiscreteLogEqualityProtocol::compute_challenge_contribution(
+            g, y, h, z, &self.t1, &self.t2, writer,
+        )
+    }
+
+    pub fn append_to_transcript(
+        &self,
+        g: &G,
+        y: &G,
+        h: &G,
+        z: &G,
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        PoKDiscreteLogEqualityProtocol::compute_transcript_contribution(
+            g, y, h, z, &self.t1, &self.t2, transcript,
+        )
+    }
+
+    /// g*response - y*challenge == t1 and h*response - z*challenge == t2
+    pub fn verify(&self, g: &G, y: &G, h: &G, z: &G, challenge: &G::ScalarField) -> bool {
+        let (t1, t2) = Self::compute_commitments(g, y, h, z, &self.response, challenge);
+        t1 == self.t1 && t2 == self.t2
+    }
+
+    /// Simulate the proof for the given challenge without knowing the witness, i.e. choose a random response
+    /// and compute `t1` and `t2` as `g*response - y*challenge` and `h*response - z*challenge`
+    pub fn simulate<R: RngCore>(
+        rng: &mut R,
+        g: &G,
+        y: &G,
+        h: &G,
+        z: &G,
+        challenge: &G::ScalarField,
+    ) -> Self {
+        let response = G::ScalarField::rand(rng);
+        let (t1, t2) = Self::compute_commitments(g, y, h, z, &response, challenge);
+        Self { t1, t2, response }
+    }
+
+    fn compute_commitments(
+        g: &G,
+        y: &G,
+        h: &G,
+        z: &G,
+        response: &G::ScalarField,
+        challenge: &G::ScalarField,
+    ) -> (G, G) {
+        let response = response.into_repr();
+        let challenge = challenge.into_repr();
+        let mut t1 = g.mul(response);
+        t1 -= y.mul(challenge);
+        let mut t2 = h.mul(response);
+        t2 -= z.mul(challenge);
+        (t1.into_affine(), t2.into_affine())
+    }
+}
+
+impl<G: AffineCurve> PoKDiscreteLogEqualityBatchProtocol<G> {
+    /// `tuples` are of the form `(g_i, y_i, h_i, z_i)` where `y_i = g_i * witness` and `z_i = h_i * witness`
+    pub fn init(
+        witness: G::ScalarField,
+        blinding: G::ScalarField,
+        tuples: &[(G, G, G, G)],
+    ) -> Result<Self, SchnorrError> {
+        if tuples.is_empty() {
+            return Err(SchnorrError::EmptyBatch);
+        }
+        let b = blinding.into_repr();
+        let t1 = G::Projective::batch_normalization_into_affine(
+            &tuples.iter().map(|t| t.0.mul(b)).collect::<Vec<_>>(),
+        );
+        let t2 = G::Projective::batch_normalization_into_affine(
+            &tuples.iter().map(|t| t.2.mul(b)).collect::<Vec<_>>(),
+        );
+        Ok(Self {
+            t1,
+            t2,
+            blinding: Secret::new(blinding),
+            witness: Secret::new(witness),
+        })
+    }
+
+    pub fn challenge_contribution<W: Write>(
+        &self,
+        tuples: &[(G, G, G, G)],
+        writer: W,
+    ) -> Result<(), SchnorrError> {
+        Self::compute_challenge_contribution(tuples, &self.t1, &self.t2, writer)
+    }
+
+    pub fn append_to_transcript(
+        &self,
+        tuples: &[(G, G, G, G)],
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        Self::compute_transcript_contribution(tuples, &self.t1, &self.t2, transcript)
+    }
+
+    pub fn gen_proof(self, challenge: &G::ScalarField) -> PoKDiscreteLogEqualityBatchProof<G> {
+        let response = *self.blinding.expose() + (*self.witness.expose() * *challenge);
+        PoKDiscreteLogEqualityBatchProof {
+            t1: self.t1.clone(),
+            t2: self.t2.clone(),
+            response,
+        }
+    }
+
+    pub fn compute_challenge_contribution<W: Write>(
+        tuples: &[(G, G, G, G)],
+        t1: &[G],
+        t2: &[G],
+        mut writer: W,
+    ) -> Result<(), SchnorrError> {
+        check_batch_size(tuples, t1, t2)?;
+        for (i, (g, y, h, z)) in tuples.iter().enumerate() {
+            PoKDiscreteLogEqualityProtocol::compute_challenge_contribution(
+                g,
+                y,
+                h,
+                z,
+                &t1[i],
+                &t2[i],
+                &mut writer,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Append the values of [`PoKDiscreteLogEqualityBatchProtocol::compute_challenge_contribution`] to the
+    /// transcript with a label each
+    pub fn compute_transcript_contribution(
+        tuples: &[(G, G, G, G)],
+        t1: &[G],
+        t2: &[G],
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        check_batch_size(tuples, t1, t2)?;
+        for (i, (g, y, h, z)) in tuples.iter().enumerate() {
+            PoKDiscreteLogEqualityProtocol::compute_transcript_contribution(
+                g, y, h, z, &t1[i], &t2[i], transcript,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<G: AffineCurve> Zeroize for PoKDiscreteLogEqualityBatchProtocol<G> {
+    fn zeroize(&mut self) {
+        // Not zeroizing `self.t1` and `self.t2` as they are public
+        self.blinding.zeroize();
+        self.witness.zeroize();
+    }
+}
+
+impl<G: AffineCurve> Drop for PoKDiscreteLogEqualityBatchProtocol<G> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<G: AffineCurve> PoKDiscreteLogEqualityBatchProof<G> {
+    pub fn challenge_contribution<W: Write>(
+        &self,
+        tuples: &[(G, G, G, G)],
+        writer: W,
+    ) -> Result<(), SchnorrError> {
+        PoKDiscreteLogEqualityBatchProtocol::compute_challenge_contribution(
+            tuples, &self.t1, &self.t2, writer,
+        )
+    }
+
+    pub fn append_to_transcript(
+        &self,
+        tuples: &[(G, G, G, G)],
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        PoKDiscreteLogEqualityBatchProtocol::compute_transcript_contribution(
+            tuples, &self.t1, &self.t2, transcript,
+        )
+    }
+
+    /// Checks `g_i*response - y_i*challenge == t1_i` and `h_i*response - z_i*challenge == t2_i` for all tuples. The
+    /// checks are combined into one as `sum(w_i * (g_i*response - y_i*challenge - t1_i) + w'_i * (h_i*response - z_i*challenge - t2_i)) == 0`
+    /// where the weights `w_i` and `w'_i` are derived from a transcript of the tuples, the proof and the challenge.
+    pub fn verify<D: Digest>(
+        &self,
+        tuples: &[(G, G, G, G)],
+        challenge: &G::ScalarField,
+    ) -> Result<(), SchnorrError> {
+        let mut transcript = Transcript::new(BATCH_WEIGHTS_LABEL);
+        self.append_to_transcript(tuples, &mut transcript)?;
+        transcript.append_scalar(b"response", &self.response)?;
+        transcript.append_scalar(b"challenge", challenge)?;
+        // Each weight is chained to the previous ones so they are all different
+        let mut weight = || transcript.challenge_scalar::<G::ScalarField, D>(b"weight");
+
+        let mut bases = Vec::with_capacity(6 * tuples.len());
+        let mut scalars = Vec::with_capacity(6 * tuples.len());
+        for (i, (g, y, h, z)) in tuples.iter().enumerate() {
+            for (base, elem, t, w) in [(g, y, &self.t1[i], weight()), (h, z, &self.t2[i], weight())]
+            {
+                bases.push(*base);
+                scalars.push(w * self.response);
+                bases.push(*elem);
+                scalars.push(-(w * challenge));
+                bases.push(*t);
+                scalars.push(-w);
+            }
+        }
+        if variable_base_msm(&bases, &scalars).is_zero() {
+            Ok(())
+        } else {
+            Err(SchnorrError::InvalidResponse)
+        }
+    }
+}
+
+fn check_batch_size<G: AffineCurve>(
+    tuples: &[(G, G, G, G)],
+    t1: &[G],
+    t2: &[G],
+) -> Result<(), SchnorrError> {
+    if tuples.is_empty() {
+        return Err(SchnorrError::EmptyBatch);
+    }
+    if t1.len() != tuples.len() {
+        return Err(SchnorrError::ExpectedSameSizeSequences(
+            t1.len(),
+            tuples.len(),
+        ));
+    }
+    if t2.len() != tuples.len() {
+        return Err(SchnorrError::ExpectedSameSizeSequences(
+            t2.len(),
+            tuples.len(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{compute_random_oracle_challenge, test_secret_serialization, test_serialization};
+    use ark_bls12_381::{Bls12_381, G1Affine, G1Projective};
+    use ark_ec::PairingEngine;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+    use blake2::Blake2b;
+
+    type Fr = <Bls12_381 as PairingEngine>::Fr;
+
+    #[test]
+    fn discrete_log_equality() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let g = G1Projective::rand(&mut rng).into_affine();
+        let h = G1Projective::rand(&mut rng).into_affine();
+        let witness = Fr::rand(&mut rng);
+        let y = g.mul(witness.into_repr()).into_affine();
+        let z = h.mul(witness.into_repr()).into_affine();
+
+        let protocol = PoKDiscreteLogEqualityProtocol::init(witness, Fr::rand(&mut rng), &g, &h);
+        test_secret_serialization!(PoKDiscreteLogEqualityProtocol<G1Affine>, protocol);
+
+        let mut chal_contrib_prover = vec![];
+        protocol
+            .challenge_contribution(&g, &y, &h, &z, &mut chal_contrib_prover)
+            .unwrap();
+        let challenge_prover = compute_random_oracle_challenge::<Fr, Blake2b>(&chal_contrib_prover);
+        let proof = protocol.gen_proof(&challenge_prover);
+
+        let mut chal_contrib_verifier = vec![];
+        proof
+            .challenge_contribution(&g, &y, &h, &z, &mut chal_contrib_verifier)
+            .unwrap();
+        assert_eq!(chal_contrib_prover, chal_contrib_verifier);
+        let challenge_verifier =
+            compute_random_oracle_challenge::<Fr, Blake2b>(&chal_contrib_verifier);
+        assert!(proof.verify(&g, &y, &h, &z, &challenge_verifier));
+        test_serialization!(PoKDiscreteLogEqualityProof<G1Affine>, proof);
+
+        // Challenge from a transcript in compatibility mode is same as the one from the contribution bytes
+        let mut transcript = Transcript::new_compatible();
+        proof
+            .append_to_transcript(&g, &y, &h, &z, &mut transcript)
+            .unwrap();
+        assert_eq!(
+            transcript.challenge_scalar::<Fr, Blake2b>(b"challenge"),
+            challenge_verifier
+        );
+
+        let protocol = PoKDiscreteLogEqualityProtocol::init(witness, Fr::rand(&mut rng), &g, &h);
+        let mut transcript = Transcript::new(b"test");
+        protocol
+            .append_to_transcript(&g, &y, &h, &z, &mut transcript)
+            .unwrap();
+        let challenge = transcript.challenge_scalar::<Fr, Blake2b>(b"challenge");
+        let proof_with_transcript = protocol.gen_proof(&challenge);
+        let mut transcript = Transcript::new(b"test");
+        proof_with_transcript
+            .append_to_transcript(&g, &y, &h, &z, &mut transcript)
+            .unwrap();
+        assert_eq!(
+            transcript.challenge_scalar::<Fr, Blake2b>(b"challenge"),
+            challenge
+        );
+        assert!(proof_with_transcript.verify(&g, &y, &h, &z, &challenge));
+
+        // Discrete logs are not equal
+        let other_z = h.mul(Fr::rand(&mut rng).into_repr()).into_affine();
+        assert!(!proof.verify(&g, &y, &h, &other_z, &challenge_verifier));
+        let protocol = PoKDiscreteLogEqualityProtocol::init(witness, Fr::rand(&mut rng), &g, &h);
+        let proof = protocol.gen_proof(&challenge_prover);
+        assert!(!proof.verify(&g, &y, &h, &other_z, &challenge_prover));
+
+        let simulated_proof =
+            PoKDiscreteLogEqualityProof::simulate(&mut rng, &g, &y, &h, &z, &challenge_prover);
+        assert!(simulated_proof.verify(&g, &y, &h, &z, &challenge_prover));
+        assert!(!simulated_proof.verify(&g, &y, &h, &z, &Fr::rand(&mut rng)));
+    }
+
+    #[test]
+    fn discrete_log_equality_batch() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let witness = Fr::rand(&mut rng);
+        let g = G1Projective::rand(&mut rng).into_affine();
+        let y = g.mul(witness.into_repr()).into_affine();
+        // Same `g` and `y` with different `h`, like VRF outputs with the same key
+        let mut tuples = (0..10)
+            .map(|_| {
+                let h = G1Projective::rand(&mut rng).into_affine();
+                (g, y, h, h.mul(witness.into_repr()).into_affine())
+            })
+            .collect::<Vec<_>>();
+
+        let protocol =
+            PoKDiscreteLogEqualityBatchProtocol::init(witness, Fr::rand(&mut rng), &tuples)
+                .unwrap();
+        test_secret_serialization!(PoKDiscreteLogEqualityBatchProtocol<G1Affine>, protocol);
+
+        let mut chal_contrib_prover = vec![];
+        protocol
+            .challenge_contribution(&tuples, &mut chal_contrib_prover)
+            .unwrap();
+        let challenge_prover = compute_random_oracle_challenge::<Fr, Blake2b>(&chal_contrib_prover);
+        let proof = protocol.gen_proof(&challenge_prover);
+
+        let mut chal_contrib_verifier = vec![];
+        proof
+            .challenge_contribution(&tuples, &mut chal_contrib_verifier)
+            .unwrap();
+        assert_eq!(chal_contrib_prover, chal_contrib_verifier);
+        let challenge_verifier =
+            compute_random_oracle_challenge::<Fr, Blake2b>(&chal_contrib_verifier);
+        proof
+            .verify::<Blake2b>(&tuples, &challenge_verifier)
+            .unwrap();
+        test_serialization!(PoKDiscreteLogEqualityBatchProof<G1Affine>, proof);
+
+        // Challenge from a transcript in compatibility mode is same as the one from the contribution bytes
+        let mut transcript = Transcript::new_compatible();
+        proof
+            .append_to_transcript(&tuples, &mut transcript)
+            .unwrap();
+        assert_eq!(
+            transcript.challenge_scalar::<Fr, Blake2b>(b"challenge"),
+            challenge_verifier
+        );
+
+        let protocol =
+            PoKDiscreteLogEqualityBatchProtocol::init(witness, Fr::rand(&mut rng), &tuples)
+                .unwrap();
+        let mut transcript = Transcript::new(b"test");
+        protocol
+            .append_to_transcript(&tuples, &mut transcript)
+            .unwrap();
+        let challenge = transcript.challenge_scalar::<Fr, Blake2b>(b"challenge");
+        let proof_with_transcript = protocol.gen_proof(&challenge);
+        let mut transcript = Transcript::new(b"test");
+        proof_with_transcript
+            .append_to_transcript(&tuples, &mut transcript)
+            .unwrap();
+        assert_eq!(
+            transcript.challenge_scalar::<Fr, Blake2b>(b"challenge"),
+            challenge
+        );
+        proof_with_transcript
+            .verify::<Blake2b>(&tuples, &challenge)
+            .unwrap();
+
+        // Fails if any tuple has a different discrete log
+        tuples[3].3 = tuples[3]
+            .2
+            .mul(Fr::rand(&mut rng).into_repr())
+            .into_affine();
+        assert!(proof
+            .verify::<Blake2b>(&tuples, &challenge_verifier)
+            .is_err());
+        let protocol =
+            PoKDiscreteLogEqualityBatchProtocol::init(witness, Fr::rand(&mut rng), &tuples)
+                .unwrap();
+        let proof = protocol.gen_proof(&challenge_prover);
+        assert!(proof.verify::<Blake2b>(&tuples, &challenge_prover).is_err());
+
+        assert!(PoKDiscreteLogEqualityBatchProtocol::<G1Affine>::init(
+            witness,
+            Fr::rand(&mut rng),
+            &[],
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn discrete_log_equality_batch_with_repeated_bases() {
+        // All tuples have the same bases `g` and `h` but different discrete logs. Combining these tuples with any
+        // weights gives a tuple with equal discrete logs, so the proof must check each tuple.
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let g = G1Projective::rand(&mut rng).into_affine();
+        let h = G1Projective::rand(&mut rng).into_affine();
+        let witnesses = (0..4).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let tuples = witnesses
+            .iter()
+            .map(|w| {
+                (
+                    g,
+                    g.mul(w.into_repr()).into_affine(),
+                    h,
+                    h.mul(w.into_repr()).into_affine(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Witness of a single tuple or a combination of the witnesses of all tuples
+        let sum = witnesses.iter().fold(Fr::zero(), |a, b| a + b);
+        for witness in [witnesses[0], witnesses[3], sum] {
+            let protocol =
+                PoKDiscreteLogEqualityBatchProtocol::init(witness, Fr::rand(&mut rng), &tuples)
+                    .unwrap();
+            let mut chal_contrib = vec![];
+            protocol
+                .challenge_contribution(&tuples, &mut chal_contrib)
+                .unwrap();
+            let challenge = compute_random_oracle_challenge::<Fr, Blake2b>(&chal_contrib);
+            let proof = protocol.gen_proof(&challenge);
+            assert!(proof.verify::<Blake2b>(&tuples, &challenge).is_err());
+        }
+
+        // Succeeds once all tuples have the same discrete log
+        let tuples = (0..4)
+            .map(|_| {
+                (
+                    g,
+                    g.mul(witnesses[0].into_repr()).into_affine(),
+                    h,
+                    h.mul(witnesses[0].into_repr()).into_affine(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let protocol =
+            PoKDiscreteLogEqualityBatchProtocol::init(witnesses[0], Fr::rand(&mut rng), &tuples)
+                .unwrap();
+        let mut chal_contrib = vec![];
+        protocol
+            .challenge_contribution(&tuples, &mut chal_contrib)
+            .unwrap();
+        let challenge = compute_random_oracle_challenge::<Fr, Blake2b>(&chal_contrib);
+        let proof = protocol.gen_proof(&challenge);
+        proof.verify::<Blake2b>(&tuples, &challenge).unwrap();
+
+        // Proof with a missing commitment is rejected
+        let mut bad_proof = proof.clone();
+        bad_proof.t1.pop();
+        assert!(bad_proof.verify::<Blake2b>(&tuples, &challenge).is_err());
+    }
+}
@@ -0,0 +1,900 @@
+//! Proof that the same bounded integer is committed in two groups which can have different orders, like a secret key
+//! used with 2 different curves. Uses bit-decomposition as in [MRL-0010](https://www.getmonero.org/resources/research-lab/pubs/MRL-0010.pdf)
+//! and [Chase et al.](https://eprint.iacr.org/2022/1593).
+//!
+//! Given commitment keys `(g1, h1)` in group `G1` and `(g2, h2)` in group `G2`, the prover proves knowledge of `x`, `r1`
+//! and `r2` such that `C1 = g1 * x + h1 * r1`, `C2 = g2 * x + h2 * r2` and `0 <= x < 2^n`. As the scalar fields of the
+//! groups differ, a single Schnorr response cannot be used for `x` in both groups. Instead:
+//! 1. The prover decomposes `x` into `n` bits `b_i` and commits to each bit in both groups as `C1_i = g1 * b_i + h1 * r1_i`
+//!    and `C2_i = g2 * b_i + h2 * r2_i`. The blindings are chosen such that `sum(2^i * r1_i) = r1` and
+//!    `sum(2^i * r2_i) = r2` so the verifier checks that `sum(2^i * C1_i) = C1` and `sum(2^i * C2_i) = C2`.
+//! 2. For each bit, the prover proves that either both `C1_i` and `C2_i` commit to 0 or both commit to 1 using an OR
+//!    proof from [CDS94](https://link.springer.com/chapter/10.1007/3-540-48658-5_19). Branch `j` of the OR proves
+//!    knowledge of the discrete logs of `C1_i - g1 * j` and `C2_i - g2 * j` wrt `h1` and `h2` respectively with the
+//!    same challenge `c_j`.
+//! 3. The challenges are bounded to 128 bits so that they are valid in both scalar fields without reduction and the
+//!    challenge `c` is split as `c = c_0 XOR c_1`. The responses are in the respective scalar fields.
+//!
+//! Since `2^n` is smaller than the order of both groups, the bits determine the same integer `x` in both groups. Both
+//! groups must have order greater than `2^128`. When the discrete log in `G2` is a public key `y = g2 * x`, `r2` is 0.
+
+use crate::error::SchnorrError;
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Write},
+    rand::RngCore,
+    vec::Vec,
+    UniformRand,
+};
+use digest::{BlockInput, Digest};
+use dock_crypto_utils::hashing_utils::{
+    affine_group_elem_from_hash_to_curve, affine_group_elem_from_try_and_incr, HashToCurve,
+};
+use dock_crypto_utils::secret::Secret;
+use dock_crypto_utils::serde_utils::*;
+use dock_crypto_utils::transcript::Transcript;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use zeroize::Zeroize;
+
+/// Commitment keys `(g1, h1)` in group `G1` and `(g2, h2)` in group `G2`. The discrete logs of `h1` wrt `g1` and of
+/// `h2` wrt `g2` must not be known to the prover.
+#[serde_as]
+#[derive(
+    Clone, PartialEq, Eq, Debug, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+pub struct CrossGroupCommitmentKey<G1: AffineCurve, G2: AffineCurve> {
+    #[serde_as(as = "AffineGroupBytes")]
+    pub g1: G1,
+    #[serde_as(as = "AffineGroupBytes")]
+    pub h1: G1,
+    #[serde_as(as = "AffineGroupBytes")]
+    pub g2: G2,
+    #[serde_as(as = "AffineGroupBytes")]
+    pub h2: G2,
+}
+
+/// A challenge of 128 bits which is valid in the scalar fields of both groups
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub struct ShortChallenge(pub u128);
+
+/// Protocol for proving that a bit is committed in both groups, i.e. `C1_i - g1 * b` and `C2_i - g2 * b` are
+/// multiples of `h1` and `h2` for the same bit `b`. The branch for `1 - b` is simulated.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BitCommitmentProtocol<G1: AffineCurve, G2: AffineCurve> {
+    /// `g1 * b + h1 * r1_i`
+    pub comm1: G1,
+    /// `g2 * b + h2 * r2_i`
+    pub comm2: G2,
+    /// Commitments to the randomness in `G1` for branches 0 and 1 of the OR
+    pub t1_0: G1,
+    pub t1_1: G1,
+    /// Commitments to the randomness in `G2` for branches 0 and 1 of the OR
+    pub t2_0: G2,
+    pub t2_1: G2,
+    /// Challenge and responses of the simulated branch
+    pub simulated_challenge: ShortChallenge,
+    pub simulated_resp1: G1::ScalarField,
+    pub simulated_resp2: G2::ScalarField,
+    bit: Secret<bool>,
+    /// Blindings of the bit in both commitments
+    blinding1: Secret<G1::ScalarField>,
+    blinding2: Secret<G2::ScalarField>,
+    /// Randomness for the Schnorr proofs of the real branch
+    randomness1: Secret<G1::ScalarField>,
+    randomness2: Secret<G2::ScalarField>,
+}
+
+/// Protocol for proving that the same integer of `n` bits is committed in both groups
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PoKDiscreteLogEqualityAcrossGroupsProtocol<G1: AffineCurve, G2: AffineCurve> {
+    /// One protocol for each bit, least significant first
+    pub bit_protocols: Vec<BitCommitmentProtocol<G1, G2>>,
+}
+
+/// Proof that a bit is committed in both groups
+#[serde_as]
+#[derive(
+    Clone, PartialEq, Eq, Debug, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+pub struct BitCommitmentProof<G1: AffineCurve, G2: AffineCurve> {
+    #[serde_as(as = "AffineGroupBytes")]
+    pub comm1: G1,
+    #[serde_as(as = "AffineGroupBytes")]
+    pub comm2: G2,
+    #[serde_as(as = "AffineGroupBytes")]
+    pub t1_0: G1,
+    #[serde_as(as = "AffineGroupBytes")]
+    pub t1_1: G1,
+    #[serde_as(as = "AffineGroupBytes")]
+    pub t2_0: G2,
+    #[serde_as(as = "AffineGroupBytes")]
+    pub t2_1: G2,
+    /// Challenge for branch 0, the one for branch 1 is `c XOR challenge_0` where `c` is the challenge of the proof
+    pub challenge_0: ShortChallenge,
+    #[serde_as(as = "FieldBytes")]
+    pub resp1_0: G1::ScalarField,
+    #[serde_as(as = "FieldBytes")]
+    pub resp1_1: G1::ScalarField,
+    #[serde_as(as = "FieldBytes")]
+    pub resp2_0: G2::ScalarField,
+    #[serde_as(as = "FieldBytes")]
+    pub resp2_1: G2::ScalarField,
+}
+
+/// Proof that the same integer of `n` bits is committed in both groups
+#[derive(
+    Clone, PartialEq, Eq, Debug, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
+)]
+#[serde(bound = "")]
+pub struct PoKDiscreteLogEqualityAcrossGroupsProof<G1: AffineCurve, G2: AffineCurve> {
+    /// One proof for each bit, least significant first
+    pub bit_proofs: Vec<BitCommitmentProof<G1, G2>>,
+}
+
+impl<G1: AffineCurve, G2: AffineCurve> CrossGroupCommitmentKey<G1, G2> {
+    /// Create the commitment key by hashing `label` using try-and-increment. Prefer
+    /// [`CrossGroupCommitmentKey::new_with_hash_to_curve`] when both groups support the hash to curve of RFC 9380.
+    pub fn new<D: Digest>(label: &[u8]) -> Self {
+        let hash = |suffix: &[u8]| [label, suffix].concat();
+        Self {
+            g1: affine_group_elem_from_try_and_incr::<G1, D>(&hash(b" : g1")),
+            h1: affine_group_elem_from_try_and_incr::<G1, D>(&hash(b" : h1")),
+            g2: affine_group_elem_from_try_and_incr::<G2, D>(&hash(b" : g2")),
+            h2: affine_group_elem_from_try_and_incr::<G2, D>(&hash(b" : h2")),
+        }
+    }
+
+    /// Commit to `witness` in both groups, i.e. return `g1 * witness + h1 * blinding1` and
+    /// `g2 * witness + h2 * blinding2`. `witness` is interpreted as an integer so it must be smaller than the orders
+    /// of both groups.
+    pub fn commit(
+        &self,
+        witness: &G1::ScalarField,
+        blinding1: &G1::ScalarField,
+        blinding2: &G2::ScalarField,
+    ) -> (G1, G2) {
+        let witness2 = scalar_from_bits::<G2::ScalarField>(&witness.into_repr().to_bits_le());
+        (
+            (self.g1.mul(witness.into_repr()) + self.h1.mul(blinding1.into_repr())).into_affine(),
+            (self.g2.mul(witness2.into_repr()) + self.h2.mul(blinding2.into_repr())).into_affine(),
+        )
+    }
+
+    pub fn serialize_for_challenge<W: Write>(&self, mut writer: W) -> Result<(), SchnorrError> {
+        self.g1.serialize_unchecked(&mut writer)?;
+        self.h1.serialize_unchecked(&mut writer)?;
+        self.g2.serialize_unchecked(&mut writer)?;
+        self.h2.serialize_unchecked(writer).map_err(|e| e.into())
+    }
+
+    pub fn append_to_transcript(&self, transcript: &mut Transcript) -> Result<(), SchnorrError> {
+        transcript.append_point(b"g1", &self.g1)?;
+        transcript.append_point(b"h1", &self.h1)?;
+        transcript.append_point(b"g2", &self.g2)?;
+        transcript.append_point(b"h2", &self.h2)?;
+        Ok(())
+    }
+}
+
+impl<G1: HashToCurve, G2: HashToCurve> CrossGroupCommitmentKey<G1, G2> {
+    /// Create the commitment key by hashing `label` using the hash to curve of RFC 9380 with domain separation tag
+    /// `dst` so that the discrete logs between the generators are not known to anyone
+    pub fn new_with_hash_to_curve<D: Digest + BlockInput>(label: &[u8], dst: &[u8]) -> Self {
+        let hash = |suffix: &[u8]| [label, suffix].concat();
+        Self {
+            g1: affine_group_elem_from_hash_to_curve::<G1, D>(&hash(b" : g1"), dst),
+            h1: affine_group_elem_from_hash_to_curve::<G1, D>(&hash(b" : h1"), dst),
+            g2: affine_group_elem_from_hash_to_curve::<G2, D>(&hash(b" : g2"), dst),
+            h2: affine_group_elem_from_hash_to_curve::<G2, D>(&hash(b" : h2"), dst),
+        }
+    }
+}
+
+impl ShortChallenge {
+    /// Takes the 128 least significant bits of the field element, like a challenge computed for other protocols
+    pub fn from_field<F: PrimeField>(f: &F) -> Self {
+        let repr = f.into_repr();
+        let limbs = repr.as_ref();
+        Self(limbs[0] as u128 | (limbs[1] as u128) << 64)
+    }
+
+    pub fn rand<R: RngCore>(rng: &mut R) -> Self {
+        Self(rng.next_u64() as u128 | (rng.next_u64() as u128) << 64)
+    }
+
+    pub fn to_field<F: PrimeField>(&self) -> F {
+        F::from(self.0)
+    }
+}
+
+impl CanonicalSerialize for ShortChallenge {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        writer.write_all(&self.0.to_le_bytes())?;
+        Ok(())
+    }
+
+    fn serialized_size(&self) -> usize {
+        16
+    }
+}
+
+impl CanonicalDeserialize for ShortChallenge {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let mut bytes = [0u8; 16];
+        reader.read_exact(&mut bytes)?;
+        Ok(Self(u128::from_le_bytes(bytes)))
+    }
+}
+
+impl<G1: AffineCurve, G2: AffineCurve> PoKDiscreteLogEqualityAcrossGroupsProtocol<G1, G2> {
+    /// Prove that `witness` has at most `num_bits` bits and is committed in `C1 = g1 * witness + h1 * blinding1` and
+    /// `C2 = g2 * witness + h2 * blinding2`
+    pub fn init<R: RngCore>(
+        rng: &mut R,
+        witness: &G1::ScalarField,
+        blinding1: &G1::ScalarField,
+        blinding2: &G2::ScalarField,
+        comm_key: &CrossGroupCommitmentKey<G1, G2>,
+        num_bits: u16,
+    ) -> Result<Self, SchnorrError> {
+        Self::validate_num_bits(num_bits)?;
+        let mut bits = witness.into_repr().to_bits_le();
+        if bits[num_bits as usize..].iter().any(|b| *b) {
+            return Err(SchnorrError::WitnessTooLarge(num_bits));
+        }
+        bits.truncate(num_bits as usize);
+
+        let blindings1 = Self::bit_blindings(rng, blinding1, num_bits);
+        let blindings2 = Self::bit_blindings(rng, blinding2, num_bits);
+        let bit_protocols = bits
+            .iter()
+            .zip(blindings1.into_iter().zip(blindings2))
+            .map(|(b, (b1, b2))| BitCommitmentProtocol::init(rng, *b, b1, b2, comm_key))
+            .collect();
+        bits.zeroize();
+        Ok(Self { bit_protocols })
+    }
+
+    pub fn challenge_contribution<W: Write>(
+        &self,
+        comm_key: &CrossGroupCommitmentKey<G1, G2>,
+        comm1: &G1,
+        comm2: &G2,
+        mut writer: W,
+    ) -> Result<(), SchnorrError> {
+        Self::compute_challenge_contribution_for_commitments(comm_key, comm1, comm2, &mut writer)?;
+        for p in &self.bit_protocols {
+            write_bit_commitments(
+                &p.comm1,
+                &p.comm2,
+                &p.t1_0,
+                &p.t1_1,
+                &p.t2_0,
+                &p.t2_1,
+                &mut writer,
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn append_to_transcript(
+        &self,
+        comm_key: &CrossGroupCommitmentKey<G1, G2>,
+        comm1: &G1,
+        comm2: &G2,
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        Self::compute_transcript_contribution_for_commitments(comm_key, comm1, comm2, transcript)?;
+        for p in &self.bit_protocols {
+            append_bit_commitments(
+                &p.comm1, &p.comm2, &p.t1_0, &p.t1_1, &p.t2_0, &p.t2_1, transcript,
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn gen_proof(
+        self,
+        challenge: &ShortChallenge,
+    ) -> PoKDiscreteLogEqualityAcrossGroupsProof<G1, G2> {
+        PoKDiscreteLogEqualityAcrossGroupsProof {
+            bit_proofs: self
+                .bit_protocols
+                .into_iter()
+                .map(|p| p.gen_proof(challenge))
+                .collect(),
+        }
+    }
+
+    /// The number of bits must be non-zero and `2^num_bits` must be smaller than the orders of both groups
+    pub fn validate_num_bits(num_bits: u16) -> Result<(), SchnorrError> {
+        let max_bits = ark_std::cmp::min(
+            G1::ScalarField::size_in_bits(),
+            G2::ScalarField::size_in_bits(),
+        ) - 1;
+        if num_bits == 0 || num_bits as usize > max_bits {
+            return Err(SchnorrError::InvalidNumberOfBits(num_bits));
+        }
+        Ok(())
+    }
+
+    fn compute_challenge_contribution_for_commitments<W: Write>(
+        comm_key: &CrossGroupCommitmentKey<G1, G2>,
+        comm1: &G1,
+        comm2: &G2,
+        mut writer: W,
+    ) -> Result<(), SchnorrError> {
+        comm_key.serialize_for_challenge(&mut writer)?;
+        comm1.serialize_unchecked(&mut writer)?;
+        comm2.serialize_unchecked(writer).map_err(|e| e.into())
+    }
+
+    fn compute_transcript_contribution_for_commitments(
+        comm_key: &CrossGroupCommitmentKey<G1, G2>,
+        comm1: &G1,
+        comm2: &G2,
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        comm_key.append_to_transcript(transcript)?;
+        transcript.append_point(b"comm1", comm1)?;
+        transcript.append_point(b"comm2", comm2)?;
+        Ok(())
+    }
+
+    /// Random blindings `r_i` for the bits such that `sum(2^i * r_i) = blinding`
+    fn bit_blindings<R: RngCore, F: PrimeField>(
+        rng: &mut R,
+        blinding: &F,
+        num_bits: u16,
+    ) -> Vec<F> {
+        let mut blindings = Vec::with_capacity(num_bits as usize);
+        blindings.push(F::zero());
+        let mut weighted_sum = F::zero();
+        let mut power_of_2 = F::one();
+        for _ in 1..num_bits {
+            power_of_2.double_in_place();
+            let r = F::rand(rng);
+            weighted_sum += power_of_2 * r;
+            blindings.push(r);
+        }
+        blindings[0] = *blinding - weighted_sum;
+        blindings
+    }
+}
+
+impl<G1: AffineCurve, G2: AffineCurve> Zeroize
+    for PoKDiscreteLogEqualityAcrossGroupsProtocol<G1, G2>
+{
+    fn zeroize(&mut self) {
+        self.bit_protocols.iter_mut().for_each(|p| p.zeroize());
+    }
+}
+
+impl<G1: AffineCurve, G2: AffineCurve> BitCommitmentProtocol<G1, G2> {
+    pub fn init<R: RngCore>(
+        rng: &mut R,
+        bit: bool,
+        blinding1: G1::ScalarField,
+        blinding2: G2::ScalarField,
+        comm_key: &CrossGroupCommitmentKey<G1, G2>,
+    ) -> Self {
+        let mut comm1 = comm_key.h1.mul(blinding1.into_repr());
+        let mut comm2 = comm_key.h2.mul(blinding2.into_repr());
+        if bit {
+            comm1.add_assign_mixed(&comm_key.g1);
+            comm2.add_assign_mixed(&comm_key.g2);
+        }
+        let comm1 = comm1.into_affine();
+        let comm2 = comm2.into_affine();
+
+        let randomness1 = G1::ScalarField::rand(rng);
+        let randomness2 = G2::ScalarField::rand(rng);
+        let t1 = comm_key.h1.mul(randomness1.into_repr()).into_affine();
+        let t2 = comm_key.h2.mul(randomness2.into_repr()).into_affine();
+
+        // Simulate the branch for the other bit
+        let simulated_challenge = ShortChallenge::rand(rng);
+        let simulated_resp1 = G1::ScalarField::rand(rng);
+        let simulated_resp2 = G2::ScalarField::rand(rng);
+        let (sim_t1, sim_t2) = compute_bit_commitments(
+            comm_key,
+            &comm1,
+            &comm2,
+            !bit,
+            &simulated_challenge,
+            &simulated_resp1,
+            &simulated_resp2,
+        );
+        let ((t1_0, t2_0), (t1_1, t2_1)) = if bit {
+            ((sim_t1, sim_t2), (t1, t2))
+        } else {
+            ((t1, t2), (sim_t1, sim_t2))
+        };
+        Self {
+            comm1,
+            comm2,
+            t1_0,
+            t1_1,
+            t2_0,
+            t2_1,
+            simulated_challenge,
+            simulated_resp1,
+            simulated_resp2,
+            bit: Secret::new(bit),
+            blinding1: Secret::new(blinding1),
+            blinding2: Secret::new(blinding2),
+            randomness1: Secret::new(randomness1),
+            randomness2: Secret::new(randomness2),
+        }
+    }
+
+    pub fn gen_proof(self, challenge: &ShortChallenge) -> BitCommitmentProof<G1, G2> {
+        let real_challenge = ShortChallenge(challenge.0 ^ self.simulated_challenge.0);
+        let resp1 = *self.randomness1.expose()
+            + real_challenge.to_field::<G1::ScalarField>() * self.blinding1.expose();
+        let resp2 = *self.randomness2.expose()
+            + real_challenge.to_field::<G2::ScalarField>() * self.blinding2.expose();
+        let (challenge_0, (resp1_0, resp2_0), (resp1_1, resp2_1)) = if *self.bit.expose() {
+            (
+                self.simulated_challenge,
+                (self.simulated_resp1, self.simulated_resp2),
+                (resp1, resp2),
+            )
+        } else {
+            (
+                real_challenge,
+                (resp1, resp2),
+                (self.simulated_resp1, self.simulated_resp2),
+            )
+        };
+        BitCommitmentProof {
+            comm1: self.comm1,
+            comm2: self.comm2,
+            t1_0: self.t1_0,
+            t1_1: self.t1_1,
+            t2_0: self.t2_0,
+            t2_1: self.t2_1,
+            challenge_0,
+            resp1_0,
+            resp1_1,
+            resp2_0,
+            resp2_1,
+        }
+    }
+}
+
+impl<G1: AffineCurve, G2: AffineCurve> Zeroize for BitCommitmentProtocol<G1, G2> {
+    fn zeroize(&mut self) {
+        // Not zeroizing the commitments, simulated challenge and responses as they are part of the proof
+        self.bit.zeroize();
+        self.blinding1.zeroize();
+        self.blinding2.zeroize();
+        self.randomness1.zeroize();
+        self.randomness2.zeroize();
+    }
+}
+
+impl<G1: AffineCurve, G2: AffineCurve> Drop for BitCommitmentProtocol<G1, G2> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<G1: AffineCurve, G2: AffineCurve> PoKDiscreteLogEqualityAcrossGroupsProof<G1, G2> {
+    pub fn challenge_contribution<W: Write>(
+        &self,
+        comm_key: &CrossGroupCommitmentKey<G1, G2>,
+        comm1: &G1,
+        comm2: &G2,
+        mut writer: W,
+    ) -> Result<(), SchnorrError> {
+        PoKDiscreteLogEqualityAcrossGroupsProtocol::compute_challenge_contribution_for_commitments(
+            comm_key,
+            comm1,
+            comm2,
+            &mut writer,
+        )?;
+        for p in &self.bit_proofs {
+            write_bit_commitments(
+                &p.comm1,
+                &p.comm2,
+                &p.t1_0,
+                &p.t1_1,
+                &p.t2_0,
+                &p.t2_1,
+                &mut writer,
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn append_to_transcript(
+        &self,
+        comm_key: &CrossGroupCommitmentKey<G1, G2>,
+        comm1: &G1,
+        comm2: &G2,
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        PoKDiscreteLogEqualityAcrossGroupsProtocol::compute_transcript_contribution_for_commitments(
+            comm_key, comm1, comm2, transcript,
+        )?;
+        for p in &self.bit_proofs {
+            append_bit_commitments(
+                &p.comm1, &p.comm2, &p.t1_0, &p.t1_1, &p.t2_0, &p.t2_1, transcript,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Verify that the bits committed in the proof sum up to the committed values in `comm1` and `comm2` and that
+    /// each bit is the same in both groups
+    pub fn verify(
+        &self,
+        comm_key: &CrossGroupCommitmentKey<G1, G2>,
+        comm1: &G1,
+        comm2: &G2,
+        num_bits: u16,
+        challenge: &ShortChallenge,
+    ) -> Result<(), SchnorrError> {
+        PoKDiscreteLogEqualityAcrossGroupsProtocol::<G1, G2>::validate_num_bits(num_bits)?;
+        if self.bit_proofs.len() != num_bits as usize {
+            return Err(SchnorrError::ExpectedSameSizeSequences(
+                num_bits as usize,
+                self.bit_proofs.len(),
+            ));
+        }
+        // sum(2^i * C1_i) and sum(2^i * C2_i) using Horner's method, most significant bit first
+        let mut sum1 = G1::Projective::zero();
+        let mut sum2 = G2::Projective::zero();
+        for p in self.bit_proofs.iter().rev() {
+            sum1.double_in_place();
+            sum1.add_assign_mixed(&p.comm1);
+            sum2.double_in_place();
+            sum2.add_assign_mixed(&p.comm2);
+        }
+        if sum1.into_affine() != *comm1 || sum2.into_affine() != *comm2 {
+            return Err(SchnorrError::InvalidBitDecomposition);
+        }
+        for p in &self.bit_proofs {
+            p.verify(comm_key, challenge)?;
+        }
+        Ok(())
+    }
+}
+
+impl<G1: AffineCurve, G2: AffineCurve> BitCommitmentProof<G1, G2> {
+    /// For both branches `j` of the OR, check `h1 * resp1_j = t1_j + (C1_i - g1 * j) * c_j` and
+    /// `h2 * resp2_j = t2_j + (C2_i - g2 * j) * c_j` where `c_0 XOR c_1 = challenge`
+    pub fn verify(
+        &self,
+        comm_key: &CrossGroupCommitmentKey<G1, G2>,
+        challenge: &ShortChallenge,
+    ) -> Result<(), SchnorrError> {
+        let challenge_1 = ShortChallenge(challenge.0 ^ self.challenge_0.0);
+        let (t1_0, t2_0) = compute_bit_commitments(
+            comm_key,
+            &self.comm1,
+            &self.comm2,
+            false,
+            &self.challenge_0,
+            &self.resp1_0,
+            &self.resp2_0,
+        );
+        let (t1_1, t2_1) = compute_bit_commitments(
+            comm_key,
+            &self.comm1,
+            &self.comm2,
+            true,
+            &challenge_1,
+            &self.resp1_1,
+            &self.resp2_1,
+        );
+        if t1_0 == self.t1_0 && t2_0 == self.t2_0 && t1_1 == self.t1_1 && t2_1 == self.t2_1 {
+            Ok(())
+        } else {
+            Err(SchnorrError::InvalidResponse)
+        }
+    }
+}
+
+/// Returns `h1 * resp1 - (C1 - g1 * bit) * challenge` and `h2 * resp2 - (C2 - g2 * bit) * challenge`
+fn compute_bit_commitments<G1: AffineCurve, G2: AffineCurve>(
+    comm_key: &CrossGroupCommitmentKey<G1, G2>,
+    comm1: &G1,
+    comm2: &G2,
+    bit: bool,
+    challenge: &ShortChallenge,
+    resp1: &G1::ScalarField,
+    resp2: &G2::ScalarField,
+) -> (G1, G2) {
+    let mut y1 = comm1.into_projective();
+    let mut y2 = comm2.into_projective();
+    if bit {
+        y1 -= comm_key.g1.into_projective();
+        y2 -= comm_key.g2.into_projective();
+    }
+    let mut t1 = comm_key.h1.mul(resp1.into_repr());
+    t1 -= y1.mul(challenge.to_field::<G1::ScalarField>().into_repr());
+    let mut t2 = comm_key.h2.mul(resp2.into_repr());
+    t2 -= y2.mul(challenge.to_field::<G2::ScalarField>().into_repr());
+    (t1.into_affine(), t2.into_affine())
+}
+
+fn write_bit_commitments<G1: AffineCurve, G2: AffineCurve, W: Write>(
+    comm1: &G1,
+    comm2: &G2,
+    t1_0: &G1,
+    t1_1: &G1,
+    t2_0: &G2,
+    t2_1: &G2,
+    mut writer: W,
+) -> Result<(), SchnorrError> {
+    comm1.serialize_unchecked(&mut writer)?;
+    comm2.serialize_unchecked(&mut writer)?;
+    t1_0.serialize_unchecked(&mut writer)?;
+    t1_1.serialize_unchecked(&mut writer)?;
+    t2_0.serialize_unchecked(&mut writer)?;
+    t2_1.serialize_unchecked(writer).map_err(|e| e.into())
+}
+
+fn append_bit_commitments<G1: AffineCurve, G2: AffineCurve>(
+    comm1: &G1,
+    comm2: &G2,
+    t1_0: &G1,
+    t1_1: &G1,
+    t2_0: &G2,
+    t2_1: &G2,
+    transcript: &mut Transcript,
+) -> Result<(), SchnorrError> {
+    transcript.append_point(b"bit_comm1", comm1)?;
+    transcript.append_point(b"bit_comm2", comm2)?;
+    transcript.append_point(b"t1_0", t1_0)?;
+    transcript.append_point(b"t1_1", t1_1)?;
+    transcript.append_point(b"t2_0", t2_0)?;
+    transcript.append_point(b"t2_1", t2_1)?;
+    Ok(())
+}
+
+/// Field element for the integer with the given bits, least significant first
+fn scalar_from_bits<F: PrimeField>(bits: &[bool]) -> F {
+    let mut f = F::zero();
+    for b in bits.iter().rev() {
+        f.double_in_place();
+        if *b {
+            f += F::one();
+        }
+    }
+    f
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute_random_oracle_challenge;
+    use crate::test_serialization;
+    use ark_bls12_381::{Bls12_381, G1Affine, G2Affine};
+    use ark_ec::PairingEngine;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+    use blake2::Blake2b;
+
+    type Fr = <Bls12_381 as PairingEngine>::Fr;
+
+    const DST: &[u8] = b"TEST-CROSS-GROUP_XMD:BLAKE2B_SSWU_RO_";
+
+    #[test]
+    fn discrete_log_equality_across_groups() {
+        // BLS12-381's G1 and G2 have the same order but the protocol treats their scalar fields as unrelated
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let comm_key = CrossGroupCommitmentKey::<G1Affine, G2Affine>::new_with_hash_to_curve::<
+            Blake2b,
+        >(b"test", DST);
+        test_serialization!(CrossGroupCommitmentKey<G1Affine, G2Affine>, comm_key);
+
+        let num_bits = 64;
+        let witness = Fr::from(u64::MAX - 1000);
+        let blinding1 = Fr::rand(&mut rng);
+        // Discrete log in G2 is a public key, i.e. `y = g2 * witness`
+        let blinding2 = Fr::zero();
+        let (comm1, comm2) = comm_key.commit(&witness, &blinding1, &blinding2);
+        assert_eq!(comm2, comm_key.g2.mul(witness.into_repr()).into_affine());
+
+        let protocol = PoKDiscreteLogEqualityAcrossGroupsProtocol::init(
+            &mut rng, &witness, &blinding1, &blinding2, &comm_key, num_bits,
+        )
+        .unwrap();
+        let mut chal_contrib_prover = vec![];
+        protocol
+            .challenge_contribution(&comm_key, &comm1, &comm2, &mut chal_contrib_prover)
+            .unwrap();
+        let challenge_prover = ShortChallenge::from_field(&compute_random_oracle_challenge::<
+            Fr,
+            Blake2b,
+        >(&chal_contrib_prover));
+        let proof = protocol.gen_proof(&challenge_prover);
+
+        let mut chal_contrib_verifier = vec![];
+        proof
+            .challenge_contribution(&comm_key, &comm1, &comm2, &mut chal_contrib_verifier)
+            .unwrap();
+        assert_eq!(chal_contrib_prover, chal_contrib_verifier);
+        let challenge_verifier = ShortChallenge::from_field(&compute_random_oracle_challenge::<
+            Fr,
+            Blake2b,
+        >(&chal_contrib_verifier));
+        proof
+            .verify(&comm_key, &comm1, &comm2, num_bits, &challenge_verifier)
+            .unwrap();
+        test_serialization!(PoKDiscreteLogEqualityAcrossGroupsProof<G1Affine, G2Affine>, proof);
+
+        // Challenge from a transcript in compatibility mode is same as the one from the contribution bytes
+        let mut transcript = Transcript::new_compatible();
+        proof
+            .append_to_transcript(&comm_key, &comm1, &comm2, &mut transcript)
+            .unwrap();
+        assert_eq!(
+            ShortChallenge::from_field(&transcript.challenge_scalar::<Fr, Blake2b>(b"challenge")),
+            challenge_verifier
+        );
+
+        let protocol_with_transcript = PoKDiscreteLogEqualityAcrossGroupsProtocol::init(
+            &mut rng, &witness, &blinding1, &blinding2, &comm_key, num_bits,
+        )
+        .unwrap();
+        let mut transcript = Transcript::new(b"test");
+        protocol_with_transcript
+            .append_to_transcript(&comm_key, &comm1, &comm2, &mut transcript)
+            .unwrap();
+        let challenge =
+            ShortChallenge::from_field(&transcript.challenge_scalar::<Fr, Blake2b>(b"challenge"));
+        let proof_with_transcript = protocol_with_transcript.gen_proof(&challenge);
+        let mut transcript = Transcript::new(b"test");
+        proof_with_transcript
+            .append_to_transcript(&comm_key, &comm1, &comm2, &mut transcript)
+            .unwrap();
+        assert_eq!(
+            ShortChallenge::from_field(&transcript.challenge_scalar::<Fr, Blake2b>(b"challenge")),
+            challenge
+        );
+        proof_with_transcript
+            .verify(&comm_key, &comm1, &comm2, num_bits, &challenge)
+            .unwrap();
+
+        // Fails for a different challenge, number of bits or commitment
+        assert!(proof
+            .verify(
+                &comm_key,
+                &comm1,
+                &comm2,
+                num_bits,
+                &ShortChallenge::rand(&mut rng)
+            )
+            .is_err());
+        assert!(proof
+            .verify(&comm_key, &comm1, &comm2, num_bits + 1, &challenge_verifier)
+            .is_err());
+        let (_, other_comm2) = comm_key.commit(&(witness + Fr::from(1u64)), &blinding1, &blinding2);
+        assert!(matches!(
+            proof.verify(
+                &comm_key,
+                &comm1,
+                &other_comm2,
+                num_bits,
+                &challenge_verifier
+            ),
+            Err(SchnorrError::InvalidBitDecomposition)
+        ));
+
+        // A bit committed as 0 in one group and 1 in the other fails
+        let mut bit_proof = proof.bit_proofs[5].clone();
+        bit_proof.comm2 =
+            (bit_proof.comm2.into_projective() + comm_key.g2.into_projective()).into_affine();
+        assert!(bit_proof.verify(&comm_key, &challenge_verifier).is_err());
+
+        // Witness larger than the number of bits
+        assert!(matches!(
+            PoKDiscreteLogEqualityAcrossGroupsProtocol::init(
+                &mut rng, &witness, &blinding1, &blinding2, &comm_key, 63,
+            ),
+            Err(SchnorrError::WitnessTooLarge(63))
+        ));
+
+        // Witness of 248 bits
+        let num_bits = 248;
+        let mut bytes = [0u8; 31];
+        rng.fill_bytes(&mut bytes);
+        let witness = Fr::from_le_bytes_mod_order(&bytes);
+        let blinding2 = Fr::rand(&mut rng);
+        let (comm1, comm2) = comm_key.commit(&witness, &blinding1, &blinding2);
+        let protocol = PoKDiscreteLogEqualityAcrossGroupsProtocol::init(
+            &mut rng, &witness, &blinding1, &blinding2, &comm_key, num_bits,
+        )
+        .unwrap();
+        let proof = protocol.gen_proof(&challenge_prover);
+        proof
+            .verify(&comm_key, &comm1, &comm2, num_bits, &challenge_prover)
+            .unwrap();
+
+        assert!(matches!(
+            PoKDiscreteLogEqualityAcrossGroupsProtocol::<G1Affine, G2Affine>::validate_num_bits(0),
+            Err(SchnorrError::InvalidNumberOfBits(0))
+        ));
+        assert!(
+            PoKDiscreteLogEqualityAcrossGroupsProtocol::<G1Affine, G2Affine>::validate_num_bits(
+                255
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn discrete_log_equality_across_groups_of_different_order() {
+        // BLS12-381's G1 and BN254's G1 have different orders
+        type G2 = ark_bn254::G1Affine;
+        type Fr2 = ark_bn254::Fr;
+
+        let mut rng = StdRng::seed_from_u64(0u64);
+        // There is no hash to curve for BN254 so its generators are created with try-and-increment
+        let other_comm_key = CrossGroupCommitmentKey::<G2, G2>::new::<Blake2b>(b"test");
+        let comm_key = CrossGroupCommitmentKey::<G1Affine, G2> {
+            g1: affine_group_elem_from_hash_to_curve::<G1Affine, Blake2b>(b"test : g1", DST),
+            h1: affine_group_elem_from_hash_to_curve::<G1Affine, Blake2b>(b"test : h1", DST),
+            g2: other_comm_key.g2,
+            h2: other_comm_key.h2,
+        };
+        test_serialization!(CrossGroupCommitmentKey<G1Affine, G2>, comm_key);
+
+        let num_bits = 252;
+        let mut bytes = [0u8; 31];
+        rng.fill_bytes(&mut bytes);
+        bytes[30] |= 0x08;
+        let witness = Fr::from_le_bytes_mod_order(&bytes);
+        let blinding1 = Fr::rand(&mut rng);
+        let blinding2 = Fr2::zero();
+        let (comm1, comm2) = comm_key.commit(&witness, &blinding1, &blinding2);
+        assert_eq!(
+            comm2,
+            comm_key
+                .g2
+                .mul(Fr2::from_le_bytes_mod_order(&bytes).into_repr())
+                .into_affine()
+        );
+
+        let protocol = PoKDiscreteLogEqualityAcrossGroupsProtocol::init(
+            &mut rng, &witness, &blinding1, &blinding2, &comm_key, num_bits,
+        )
+        .unwrap();
+        let mut chal_contrib_prover = vec![];
+        protocol
+            .challenge_contribution(&comm_key, &comm1, &comm2, &mut chal_contrib_prover)
+            .unwrap();
+        let challenge = ShortChallenge::from_field(
+            &compute_random_oracle_challenge::<Fr, Blake2b>(&chal_contrib_prover),
+        );
+        let proof = protocol.gen_proof(&challenge);
+
+        let mut chal_contrib_verifier = vec![];
+        proof
+            .challenge_contribution(&comm_key, &comm1, &comm2, &mut chal_contrib_verifier)
+            .unwrap();
+        assert_eq!(chal_contrib_prover, chal_contrib_verifier);
+        proof
+            .verify(&comm_key, &comm1, &comm2, num_bits, &challenge)
+            .unwrap();
+        test_serialization!(PoKDiscreteLogEqualityAcrossGroupsProof<G1Affine, G2>, proof);
+
+        // Public key in BN254 for a different discrete log
+        let other_comm2 = comm2 + comm_key.g2;
+        assert!(proof
+            .verify(&comm_key, &comm1, &other_comm2, num_bits, &challenge)
+            .is_err());
+
+        // BN254's scalar field has 254 bits so a larger witness can't be used
+        assert!(
+            PoKDiscreteLogEqualityAcrossGroupsProtocol::<G1Affine, G2>::validate_num_bits(254)
+                .is_err()
+        );
+    }
+}
@@ -10,6 +10,16 @@ pub enum SchnorrError {
     ExpectedSameSizeSequences(usize, usize),
     IndexOutOfBounds(usize, usize),
     InvalidResponse,
+    /// The witness given to the prover of an OR relation does not belong to the relation it committed for
+    WitnessIncompatibleWithProverState,
+    /// A batch proof needs at least 1 item
+    EmptyBatch,
+    /// Number of bits of a bounded witness must be non-zero and less than the size of the scalar fields
+    InvalidNumberOfBits(u16),
+    /// Witness does not fit in the given number of bits
+    WitnessTooLarge(u16),
+    /// The committed bits don't sum up to the committed value
+    InvalidBitDecomposition,
     #[serde(with = "ArkSerializationError")]
     Serialization(SerializationError),
 }
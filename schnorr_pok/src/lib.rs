@@ -18,6 +18,18 @@
 //!
 //! Above can be generalized to more than 2 `x`s
 //!
+//! The `sigma` module allows composing such protocols using AND and OR, and proving equality of discrete logs
+//! across different bases.
+//!
+//! The `discrete_log_equality` module has the Chaum-Pedersen protocol to prove `log_g(y) == log_h(z)` and its batched
+//! variant for several such tuples with the same discrete log.
+//!
+//! The `discrete_log_equality_across_groups` module proves that the same bounded integer is committed in two groups
+//! which can have different orders using bit-decomposition.
+//!
+//! Challenges can be derived using a [`Transcript`] to which the challenge contributions are appended with labels.
+//! See the module `transcript` of `dock_crypto_utils` for details.
+//!
 //! There is another variant of Schnorr which gives shorter proof but is not implemented yet:
 //! 1. Prover creates `r` and then `T = r * G`.
 //! 2. Prover computes challenge as `c = Hash(G||Y||T)`.
@@ -34,14 +46,20 @@ use ark_std::{
     fmt::Debug,
     io::{Read, Write},
     ops::Add,
+    rand::RngCore,
     vec::Vec,
+    UniformRand,
 };
 use digest::Digest;
 use zeroize::Zeroize;
 
 use dock_crypto_utils::hashing_utils::field_elem_from_try_and_incr;
 
+use dock_crypto_utils::secret::{Secret, SecretAs};
 use dock_crypto_utils::serde_utils::*;
+use dock_crypto_utils::transcript::Transcript;
+
+pub use dock_crypto_utils;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
@@ -49,12 +67,29 @@ use dock_crypto_utils::msm::variable_base_msm;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+pub mod discrete_log_equality;
+pub mod discrete_log_equality_across_groups;
 pub mod error;
+pub mod sigma;
 
 /// Trait implemented by Schnorr-based protocols for returning their contribution to the overall challenge.
 /// i.e. overall challenge is of form Hash({m_i}), and this function returns the bytecode for m_j for some j.
 pub trait SchnorrChallengeContributor {
     fn challenge_contribution<W: Write>(&self, writer: W) -> Result<(), SchnorrError>;
+
+    /// Append the challenge contribution to the transcript with the given label. By default, the contribution is
+    /// appended as a single message but implementations should append the individual group elements and field elements.
+    /// In compatibility mode, the transcript must get the same bytes as [`SchnorrChallengeContributor::challenge_contribution`].
+    fn append_to_transcript(
+        &self,
+        label: &'static [u8],
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        let mut bytes = Vec::new();
+        self.challenge_contribution(&mut bytes)?;
+        transcript.append_message(label, &bytes);
+        Ok(())
+    }
 }
 
 /// Commitment to randomness during step 1 of the Schnorr protocol to prove knowledge of 1 or more discrete logs
@@ -64,8 +99,8 @@ pub trait SchnorrChallengeContributor {
 )]
 pub struct SchnorrCommitment<G: AffineCurve> {
     /// Randomness. 1 per discrete log
-    #[serde_as(as = "Vec<FieldBytes>")]
-    pub blindings: Vec<G::ScalarField>,
+    #[serde_as(as = "SecretAs<Vec<FieldBytes>>")]
+    pub blindings: Secret<Vec<G::ScalarField>>,
     /// The commitment to all the randomnesses, i.e. `bases[0] * blindings[0] + ... + bases[i] * blindings[i]`
     #[serde_as(as = "AffineGroupBytes")]
     pub t: G,
@@ -79,7 +114,10 @@ where
     /// for step-1 of the protocol. Extra `bases` or `blindings` are ignored.
     pub fn new(bases: &[G], blindings: Vec<G::ScalarField>) -> Self {
         let t = variable_base_msm(bases, &blindings).into_affine();
-        Self { blindings, t }
+        Self {
+            blindings: Secret::new(blindings),
+            t,
+        }
     }
 
     /// Create responses for each witness (discrete log) as `response[i] = self.blindings[i] + (witnesses[i] * challenge)`
@@ -88,13 +126,14 @@ where
         witnesses: &[G::ScalarField],
         challenge: &G::ScalarField,
     ) -> Result<SchnorrResponse<G>, SchnorrError> {
-        if self.blindings.len() != witnesses.len() {
+        let blindings = self.blindings.expose();
+        if blindings.len() != witnesses.len() {
             return Err(SchnorrError::ExpectedSameSizeSequences(
-                self.blindings.len(),
+                blindings.len(),
                 witnesses.len(),
             ));
         }
-        let responses = cfg_iter!(self.blindings)
+        let responses = cfg_iter!(blindings)
             .zip(cfg_iter!(witnesses))
             .map(|(b, w)| *b + (*w * *challenge))
             .collect::<Vec<_>>();
@@ -126,6 +165,15 @@ where
     fn challenge_contribution<W: Write>(&self, writer: W) -> Result<(), SchnorrError> {
         self.t.serialize_unchecked(writer).map_err(|e| e.into())
     }
+
+    fn append_to_transcript(
+        &self,
+        label: &'static [u8],
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        transcript.append_point(label, &self.t)?;
+        Ok(())
+    }
 }
 
 /// Response during step 3 of the Schnorr protocol to prove knowledge of 1 or more discrete logs
@@ -163,6 +211,24 @@ where
         }
     }
 
+    /// Simulate the protocol for the given challenge without knowing the witnesses, i.e. choose random responses
+    /// and compute the commitment `t` as `bases[0]*responses[0] + ... + bases[i]*responses[i] - y*challenge`.
+    /// Returns the commitment and the responses which together are an accepting transcript.
+    pub fn simulate<R: RngCore>(
+        rng: &mut R,
+        bases: &[G],
+        y: &G,
+        challenge: &G::ScalarField,
+    ) -> (G, Self) {
+        let responses = (0..bases.len())
+            .map(|_| G::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+        let t = variable_base_msm(bases, &responses)
+            .add(y.mul(-*challenge))
+            .into_affine();
+        (t, Self(responses))
+    }
+
     /// Get response for the specified discrete log
     pub fn get_response(&self, idx: usize) -> Result<&G::ScalarField, SchnorrError> {
         if idx >= self.0.len() {
@@ -178,7 +244,9 @@ where
     // TODO: Add function for challenge contribution (bytes that are hashed)
 }
 
-// Proof of knowledge of a single discrete log
+// Proof of knowledge of a single discrete log. The blinding and witness are wrapped in `Secret` which is
+// referred to through this crate so callers only need `dock_crypto_utils::serde_utils::*` in scope for the
+// serde attributes.
 
 #[macro_export]
 macro_rules! impl_proof_of_knowledge_of_discrete_log {
@@ -198,10 +266,10 @@ macro_rules! impl_proof_of_knowledge_of_discrete_log {
         pub struct $protocol_name<G: AffineCurve> {
             #[serde_as(as = "AffineGroupBytes")]
             pub t: G,
-            #[serde_as(as = "FieldBytes")]
-            blinding: G::ScalarField,
-            #[serde_as(as = "FieldBytes")]
-            witness: G::ScalarField,
+            #[serde_as(as = "SecretAs<FieldBytes>")]
+            blinding: $crate::dock_crypto_utils::secret::Secret<G::ScalarField>,
+            #[serde_as(as = "SecretAs<FieldBytes>")]
+            witness: $crate::dock_crypto_utils::secret::Secret<G::ScalarField>,
         }
 
         #[serde_as]
@@ -230,8 +298,8 @@ macro_rules! impl_proof_of_knowledge_of_discrete_log {
                 let t = base.mul(blinding.into_repr()).into_affine();
                 Self {
                     t,
-                    blinding,
-                    witness,
+                    blinding: $crate::dock_crypto_utils::secret::Secret::new(blinding),
+                    witness: $crate::dock_crypto_utils::secret::Secret::new(witness),
                 }
             }
 
@@ -245,7 +313,7 @@ macro_rules! impl_proof_of_knowledge_of_discrete_log {
             }
 
             pub fn gen_proof(self, challenge: &G::ScalarField) -> $proof_name<G> {
-                let response = self.blinding + (self.witness * *challenge);
+                let response = *self.blinding.expose() + (*self.witness.expose() * *challenge);
                 $proof_name {
                     t: self.t,
                     response,
@@ -291,6 +359,23 @@ macro_rules! impl_proof_of_knowledge_of_discrete_log {
                 $protocol_name::compute_challenge_contribution(base, y, &self.t, writer)
             }
 
+            /// Simulate the proof for the given challenge without knowing the witness, i.e. choose a random
+            /// response and compute `t` as `base*response - y*challenge`
+            pub fn simulate<R: ark_std::rand::RngCore>(
+                rng: &mut R,
+                base: &G,
+                y: &G,
+                challenge: &G::ScalarField,
+            ) -> Self {
+                let response = <G::ScalarField as ark_std::UniformRand>::rand(rng);
+                let mut t = base.mul(response.into_repr());
+                t -= y.mul(challenge.into_repr());
+                Self {
+                    t: t.into_affine(),
+                    response,
+                }
+            }
+
             /// base*response - y*challenge == t
             pub fn verify(&self, y: &G, base: &G, challenge: &G::ScalarField) -> bool {
                 let mut expected = base.mul(self.response.into_repr());
@@ -301,7 +386,7 @@ macro_rules! impl_proof_of_knowledge_of_discrete_log {
     };
 }
 
-/// Uses try-and-increment. Vulnerable to side channel attacks.
+/// Uses try-and-increment. Vulnerable to side channel attacks. Prefer deriving the challenge from a [`Transcript`].
 pub fn compute_random_oracle_challenge<F: PrimeField, D: Digest>(challenge_bytes: &[u8]) -> F {
     field_elem_from_try_and_incr::<F, D>(challenge_bytes)
 }
@@ -352,6 +437,23 @@ mod tests {
         };
     }
 
+    /// Serialization of objects containing secrets should fail unless the feature `serialize-secrets` is enabled
+    #[macro_export]
+    macro_rules! test_secret_serialization {
+        ($obj_type:ty, $obj: ident) => {
+            if dock_crypto_utils::secret::SECRETS_SERIALIZABLE {
+                test_serialization!($obj_type, $obj);
+            } else {
+                let mut serz = vec![];
+                assert!(ark_serialize::CanonicalSerialize::serialize(&$obj, &mut serz).is_err());
+                assert!($obj.serialize_unchecked(&mut serz).is_err());
+                assert!($obj.serialize_uncompressed(&mut serz).is_err());
+                assert!(serde_json::to_string(&$obj).is_err());
+                assert!(rmp_serde::to_vec_named(&$obj).is_err());
+            }
+        };
+    }
+
     macro_rules! test_schnorr_in_group {
         ( $group_element_proj:ident, $group_element_affine:ident ) => {
             let mut rng = StdRng::seed_from_u64(0u64);
@@ -379,7 +481,7 @@ mod tests {
                 .collect::<Vec<_>>();
 
             let comm = SchnorrCommitment::new(&bases, blindings);
-            test_serialization!(
+            test_secret_serialization!(
                 SchnorrCommitment<<Bls12_381 as PairingEngine>::$group_element_affine>,
                 comm
             );
@@ -390,6 +492,29 @@ mod tests {
 
             resp.is_valid(&bases, &y, &comm.t, &challenge).unwrap();
 
+            // Simulated transcript is valid as well
+            let (sim_t, sim_resp) = SchnorrResponse::simulate(&mut rng, &bases, &y, &challenge);
+            sim_resp.is_valid(&bases, &y, &sim_t, &challenge).unwrap();
+            assert!(sim_resp
+                .is_valid(&bases, &y, &sim_t, &Fr::rand(&mut rng))
+                .is_err());
+
+            // Challenge from a transcript in compatibility mode is same as the one from the contribution bytes
+            let mut contribution = vec![];
+            comm.challenge_contribution(&mut contribution).unwrap();
+            let mut transcript = Transcript::new_compatible();
+            comm.append_to_transcript(b"t", &mut transcript).unwrap();
+            assert_eq!(
+                transcript.challenge_scalar::<Fr, Blake2b>(b"challenge"),
+                compute_random_oracle_challenge::<Fr, Blake2b>(&contribution)
+            );
+
+            let mut transcript = Transcript::new(b"test");
+            comm.append_to_transcript(b"t", &mut transcript).unwrap();
+            let challenge = transcript.challenge_scalar::<Fr, Blake2b>(b"challenge");
+            let resp_1 = comm.response(&witnesses, &challenge).unwrap();
+            resp_1.is_valid(&bases, &y, &comm.t, &challenge).unwrap();
+
             drop(comm);
 
             test_serialization!(
@@ -425,7 +550,7 @@ mod tests {
                     .challenge_contribution(&base, &y, &mut chal_contrib_prover)
                     .unwrap();
 
-                test_serialization!(
+                test_secret_serialization!(
                     $protocol_name<<Bls12_381 as PairingEngine>::$group_affine>,
                     protocol
                 );
@@ -445,6 +570,11 @@ mod tests {
                 assert_eq!(chal_contrib_prover, chal_contrib_verifier);
                 assert_eq!(challenge_prover, challenge_verifier);
 
+                let simulated_proof =
+                    $proof_name::simulate(&mut rng, &base, &y, &challenge_verifier);
+                assert!(simulated_proof.verify(&y, &base, &challenge_verifier));
+                assert!(!simulated_proof.verify(&y, &base, &Fr::rand(&mut rng)));
+
                 test_serialization!(
                     $proof_name<<Bls12_381 as PairingEngine>::$group_affine>,
                     proof
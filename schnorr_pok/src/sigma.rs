@@ -0,0 +1,750 @@
+//! Composable Sigma protocols. A relation implements [`SigmaProtocol`] by describing its 3 moves (commit, respond and
+//! verify) and a simulator which creates an accepting transcript for a given challenge without the witness.
+//! Relations can then be combined using:
+//!
+//! - [`And`]: prove knowledge of witnesses of both relations. Both use the same challenge.
+//! - [`Or`]: prove knowledge of a witness of either relation without revealing which, as described in
+//!   [CDS94](https://link.springer.com/chapter/10.1007/3-540-48658-5_19). The prover simulates the transcript of the
+//!   relation it does not know the witness for and the challenges of both relations must add up to the overall challenge.
+//!
+//! The basic relations are [`DiscreteLogs`] for knowledge of 1 or more discrete logs, i.e. `y = g_1*x_1 + g_2*x_2 + ...`,
+//! and [`EqualDiscreteLogs`] for equality of discrete logs across different bases, i.e. `y = g*x` and `z = h*x`.
+//!
+//! The challenge contribution of a relation (via [`SchnorrChallengeContributor`]) consists of its public values and
+//! [`SigmaProof`] uses it with the commitment to make the protocol non-interactive using Fiat-Shamir.
+
+use crate::error::SchnorrError;
+use crate::{
+    compute_random_oracle_challenge, SchnorrChallengeContributor, SchnorrCommitment,
+    SchnorrResponse,
+};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    fmt::Debug,
+    io::{Read, Write},
+    rand::RngCore,
+    vec::Vec,
+    UniformRand,
+};
+use digest::Digest;
+use dock_crypto_utils::secret::Secret;
+use dock_crypto_utils::transcript::Transcript;
+
+/// Labels used when the challenge of a [`SigmaProof`] is derived from a [`Transcript`]
+const RELATION_LABEL: &[u8] = b"relation";
+const COMMITMENT_LABEL: &[u8] = b"commitment";
+const CHALLENGE_LABEL: &[u8] = b"challenge";
+
+/// A Sigma protocol for a relation. The relation's public values are the challenge contribution.
+pub trait SigmaProtocol: SchnorrChallengeContributor {
+    type ScalarField: PrimeField;
+    type Witness;
+    /// Secret state of the prover between the commit and respond steps
+    type ProverState;
+    /// The first message of the prover
+    type Commitment: Clone + Debug + PartialEq + CanonicalSerialize + CanonicalDeserialize;
+    /// The last message of the prover
+    type Response: Clone + Debug + PartialEq + CanonicalSerialize + CanonicalDeserialize;
+
+    /// Step 1 of the protocol. Create the commitment to randomness.
+    fn commit<R: RngCore>(
+        &self,
+        rng: &mut R,
+        witness: &Self::Witness,
+    ) -> Result<(Self::ProverState, Self::Commitment), SchnorrError>;
+
+    /// Step 3 of the protocol. Create the response for the challenge.
+    fn respond(
+        &self,
+        state: Self::ProverState,
+        witness: &Self::Witness,
+        challenge: &Self::ScalarField,
+    ) -> Result<Self::Response, SchnorrError>;
+
+    fn verify(
+        &self,
+        commitment: &Self::Commitment,
+        challenge: &Self::ScalarField,
+        response: &Self::Response,
+    ) -> Result<(), SchnorrError>;
+
+    /// Create an accepting transcript for the given challenge without knowing the witness
+    fn simulate<R: RngCore>(
+        &self,
+        rng: &mut R,
+        challenge: &Self::ScalarField,
+    ) -> (Self::Commitment, Self::Response);
+}
+
+/// Knowledge of discrete logs `x_i` in `y = bases[0]*x_0 + bases[1]*x_1 + ... + bases[i]*x_i`
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DiscreteLogs<G: AffineCurve> {
+    pub bases: Vec<G>,
+    pub y: G,
+}
+
+/// Knowledge of `x` such that `y = g*x` and `z = h*x`. The bases can be in different groups with the same scalar
+/// field.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct EqualDiscreteLogs<G1: AffineCurve, G2: AffineCurve<ScalarField = G1::ScalarField>> {
+    pub g: G1,
+    pub y: G1,
+    pub h: G2,
+    pub z: G2,
+}
+
+/// Knowledge of witnesses of both relations
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct And<A, B>(pub A, pub B);
+
+/// Knowledge of a witness of either relation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Or<A, B>(pub A, pub B);
+
+/// Witness for an [`Or`] relation, i.e. the witness of one of the relations
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OrWitness<WA, WB> {
+    Left(WA),
+    Right(WB),
+}
+
+/// State of the prover of an [`Or`] relation. Contains the state of the relation it knows the witness for and the
+/// simulated challenge and response of the other relation.
+pub enum OrProverState<A: SigmaProtocol, B: SigmaProtocol<ScalarField = A::ScalarField>> {
+    Left(A::ProverState, A::ScalarField, B::Response),
+    Right(A::ScalarField, A::Response, B::ProverState),
+}
+
+/// Response for an [`Or`] relation. The challenge for the right relation is the overall challenge minus
+/// `left_challenge`.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct OrResponse<
+    F: PrimeField,
+    RA: CanonicalSerialize + CanonicalDeserialize,
+    RB: CanonicalSerialize + CanonicalDeserialize,
+> {
+    pub left_challenge: F,
+    pub left: RA,
+    pub right: RB,
+}
+
+/// Non-interactive proof for a relation using Fiat-Shamir. The challenge is the hash of the context, the relation's
+/// challenge contribution and the commitment.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct SigmaProof<P: SigmaProtocol> {
+    pub commitment: P::Commitment,
+    pub response: P::Response,
+}
+
+impl<G: AffineCurve> SchnorrChallengeContributor for DiscreteLogs<G> {
+    fn challenge_contribution<W: Write>(&self, mut writer: W) -> Result<(), SchnorrError> {
+        for b in &self.bases {
+            b.serialize_unchecked(&mut writer)?;
+        }
+        self.y.serialize_unchecked(writer).map_err(|e| e.into())
+    }
+
+    fn append_to_transcript(
+        &self,
+        label: &'static [u8],
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        for b in &self.bases {
+            transcript.append_point(label, b)?;
+        }
+        transcript.append_point(label, &self.y)?;
+        Ok(())
+    }
+}
+
+impl<G: AffineCurve> SigmaProtocol for DiscreteLogs<G> {
+    type ScalarField = G::ScalarField;
+    type Witness = Vec<G::ScalarField>;
+    type ProverState = SchnorrCommitment<G>;
+    type Commitment = G;
+    type Response = SchnorrResponse<G>;
+
+    fn commit<R: RngCore>(
+        &self,
+        rng: &mut R,
+        witness: &Self::Witness,
+    ) -> Result<(Self::ProverState, Self::Commitment), SchnorrError> {
+        if witness.len() != self.bases.len() {
+            return Err(SchnorrError::ExpectedSameSizeSequences(
+                witness.len(),
+                self.bases.len(),
+            ));
+        }
+        let blindings = (0..self.bases.len())
+            .map(|_| G::ScalarField::rand(rng))
+            .collect();
+        let comm = SchnorrCommitment::new(&self.bases, blindings);
+        let t = comm.t;
+        Ok((comm, t))
+    }
+
+    fn respond(
+        &self,
+        state: Self::ProverState,
+        witness: &Self::Witness,
+        challenge: &Self::ScalarField,
+    ) -> Result<Self::Response, SchnorrError> {
+        state.response(witness, challenge)
+    }
+
+    fn verify(
+        &self,
+        commitment: &Self::Commitment,
+        challenge: &Self::ScalarField,
+        response: &Self::Response,
+    ) -> Result<(), SchnorrError> {
+        response.is_valid(&self.bases, &self.y, commitment, challenge)
+    }
+
+    fn simulate<R: RngCore>(
+        &self,
+        rng: &mut R,
+        challenge: &Self::ScalarField,
+    ) -> (Self::Commitment, Self::Response) {
+        SchnorrResponse::simulate(rng, &self.bases, &self.y, challenge)
+    }
+}
+
+impl<G1: AffineCurve, G2: AffineCurve<ScalarField = G1::ScalarField>> SchnorrChallengeContributor
+    for EqualDiscreteLogs<G1, G2>
+{
+    fn challenge_contribution<W: Write>(&self, mut writer: W) -> Result<(), SchnorrError> {
+        self.g.serialize_unchecked(&mut writer)?;
+        self.y.serialize_unchecked(&mut writer)?;
+        self.h.serialize_unchecked(&mut writer)?;
+        self.z.serialize_unchecked(writer).map_err(|e| e.into())
+    }
+
+    fn append_to_transcript(
+        &self,
+        label: &'static [u8],
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        transcript.append_point(label, &self.g)?;
+        transcript.append_point(label, &self.y)?;
+        transcript.append_point(label, &self.h)?;
+        transcript.append_point(label, &self.z)?;
+        Ok(())
+    }
+}
+
+impl<G1: AffineCurve, G2: AffineCurve<ScalarField = G1::ScalarField>> SigmaProtocol
+    for EqualDiscreteLogs<G1, G2>
+{
+    type ScalarField = G1::ScalarField;
+    type Witness = G1::ScalarField;
+    type ProverState = Secret<G1::ScalarField>;
+    type Commitment = (G1, G2);
+    type Response = G1::ScalarField;
+
+    fn commit<R: RngCore>(
+        &self,
+        rng: &mut R,
+        _witness: &Self::Witness,
+    ) -> Result<(Self::ProverState, Self::Commitment), SchnorrError> {
+        let blinding = G1::ScalarField::rand(rng);
+        let repr = blinding.into_repr();
+        let t = (
+            self.g.mul(repr).into_affine(),
+            self.h.mul(repr).into_affine(),
+        );
+        Ok((Secret::new(blinding), t))
+    }
+
+    fn respond(
+        &self,
+        state: Self::ProverState,
+        witness: &Self::Witness,
+        challenge: &Self::ScalarField,
+    ) -> Result<Self::Response, SchnorrError> {
+        Ok(*state.expose() + *witness * *challenge)
+    }
+
+    fn verify(
+        &self,
+        commitment: &Self::Commitment,
+        challenge: &Self::ScalarField,
+        response: &Self::Response,
+    ) -> Result<(), SchnorrError> {
+        if self.simulated_commitment(challenge, response) == *commitment {
+            Ok(())
+        } else {
+            Err(SchnorrError::InvalidResponse)
+        }
+    }
+
+    fn simulate<R: RngCore>(
+        &self,
+        rng: &mut R,
+        challenge: &Self::ScalarField,
+    ) -> (Self::Commitment, Self::Response) {
+        let response = G1::ScalarField::rand(rng);
+        (self.simulated_commitment(challenge, &response), response)
+    }
+}
+
+impl<G1: AffineCurve, G2: AffineCurve<ScalarField = G1::ScalarField>> EqualDiscreteLogs<G1, G2> {
+    /// Returns `(g*response - y*challenge, h*response - z*challenge)`
+    fn simulated_commitment(
+        &self,
+        challenge: &G1::ScalarField,
+        response: &G1::ScalarField,
+    ) -> (G1, G2) {
+        let challenge = challenge.into_repr();
+        let response = response.into_repr();
+        let mut t1 = self.g.mul(response);
+        t1 -= self.y.mul(challenge);
+        let mut t2 = self.h.mul(response);
+        t2 -= self.z.mul(challenge);
+        (t1.into_affine(), t2.into_affine())
+    }
+}
+
+impl<A: SchnorrChallengeContributor, B: SchnorrChallengeContributor> SchnorrChallengeContributor
+    for And<A, B>
+{
+    fn challenge_contribution<W: Write>(&self, mut writer: W) -> Result<(), SchnorrError> {
+        self.0.challenge_contribution(&mut writer)?;
+        self.1.challenge_contribution(writer)
+    }
+
+    fn append_to_transcript(
+        &self,
+        label: &'static [u8],
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        self.0.append_to_transcript(label, transcript)?;
+        self.1.append_to_transcript(label, transcript)
+    }
+}
+
+impl<A: SigmaProtocol, B: SigmaProtocol<ScalarField = A::ScalarField>> SigmaProtocol for And<A, B> {
+    type ScalarField = A::ScalarField;
+    type Witness = (A::Witness, B::Witness);
+    type ProverState = (A::ProverState, B::ProverState);
+    type Commitment = (A::Commitment, B::Commitment);
+    type Response = (A::Response, B::Response);
+
+    fn commit<R: RngCore>(
+        &self,
+        rng: &mut R,
+        witness: &Self::Witness,
+    ) -> Result<(Self::ProverState, Self::Commitment), SchnorrError> {
+        let (state_a, comm_a) = self.0.commit(rng, &witness.0)?;
+        let (state_b, comm_b) = self.1.commit(rng, &witness.1)?;
+        Ok(((state_a, state_b), (comm_a, comm_b)))
+    }
+
+    fn respond(
+        &self,
+        state: Self::ProverState,
+        witness: &Self::Witness,
+        challenge: &Self::ScalarField,
+    ) -> Result<Self::Response, SchnorrError> {
+        Ok((
+            self.0.respond(state.0, &witness.0, challenge)?,
+            self.1.respond(state.1, &witness.1, challenge)?,
+        ))
+    }
+
+    fn verify(
+        &self,
+        commitment: &Self::Commitment,
+        challenge: &Self::ScalarField,
+        response: &Self::Response,
+    ) -> Result<(), SchnorrError> {
+        self.0.verify(&commitment.0, challenge, &response.0)?;
+        self.1.verify(&commitment.1, challenge, &response.1)
+    }
+
+    fn simulate<R: RngCore>(
+        &self,
+        rng: &mut R,
+        challenge: &Self::ScalarField,
+    ) -> (Self::Commitment, Self::Response) {
+        let (comm_a, resp_a) = self.0.simulate(rng, challenge);
+        let (comm_b, resp_b) = self.1.simulate(rng, challenge);
+        ((comm_a, comm_b), (resp_a, resp_b))
+    }
+}
+
+impl<A: SchnorrChallengeContributor, B: SchnorrChallengeContributor> SchnorrChallengeContributor
+    for Or<A, B>
+{
+    fn challenge_contribution<W: Write>(&self, mut writer: W) -> Result<(), SchnorrError> {
+        self.0.challenge_contribution(&mut writer)?;
+        self.1.challenge_contribution(writer)
+    }
+
+    fn append_to_transcript(
+        &self,
+        label: &'static [u8],
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        self.0.append_to_transcript(label, transcript)?;
+        self.1.append_to_transcript(label, transcript)
+    }
+}
+
+impl<A: SigmaProtocol, B: SigmaProtocol<ScalarField = A::ScalarField>> SigmaProtocol for Or<A, B> {
+    type ScalarField = A::ScalarField;
+    type Witness = OrWitness<A::Witness, B::Witness>;
+    type ProverState = OrProverState<A, B>;
+    type Commitment = (A::Commitment, B::Commitment);
+    type Response = OrResponse<A::ScalarField, A::Response, B::Response>;
+
+    /// Commits for the relation whose witness is known and simulates the transcript of the other with a random challenge
+    fn commit<R: RngCore>(
+        &self,
+        rng: &mut R,
+        witness: &Self::Witness,
+    ) -> Result<(Self::ProverState, Self::Commitment), SchnorrError> {
+        let simulated_challenge = A::ScalarField::rand(rng);
+        Ok(match witness {
+            OrWitness::Left(w) => {
+                let (state, comm_a) = self.0.commit(rng, w)?;
+                let (comm_b, resp_b) = self.1.simulate(rng, &simulated_challenge);
+                (
+                    OrProverState::Left(state, simulated_challenge, resp_b),
+                    (comm_a, comm_b),
+                )
+            }
+            OrWitness::Right(w) => {
+                let (comm_a, resp_a) = self.0.simulate(rng, &simulated_challenge);
+                let (state, comm_b) = self.1.commit(rng, w)?;
+                (
+                    OrProverState::Right(simulated_challenge, resp_a, state),
+                    (comm_a, comm_b),
+                )
+            }
+        })
+    }
+
+    fn respond(
+        &self,
+        state: Self::ProverState,
+        witness: &Self::Witness,
+        challenge: &Self::ScalarField,
+    ) -> Result<Self::Response, SchnorrError> {
+        match (state, witness) {
+            (OrProverState::Left(state, right_challenge, right), OrWitness::Left(w)) => {
+                let left_challenge = *challenge - right_challenge;
+                Ok(OrResponse {
+                    left_challenge,
+                    left: self.0.respond(state, w, &left_challenge)?,
+                    right,
+                })
+            }
+            (OrProverState::Right(left_challenge, left, state), OrWitness::Right(w)) => {
+                Ok(OrResponse {
+                    left_challenge,
+                    left,
+                    right: self.1.respond(state, w, &(*challenge - left_challenge))?,
+                })
+            }
+            _ => Err(SchnorrError::WitnessIncompatibleWithProverState),
+        }
+    }
+
+    fn verify(
+        &self,
+        commitment: &Self::Commitment,
+        challenge: &Self::ScalarField,
+        response: &Self::Response,
+    ) -> Result<(), SchnorrError> {
+        self.0
+            .verify(&commitment.0, &response.left_challenge, &response.left)?;
+        self.1.verify(
+            &commitment.1,
+            &(*challenge - response.left_challenge),
+            &response.right,
+        )
+    }
+
+    fn simulate<R: RngCore>(
+        &self,
+        rng: &mut R,
+        challenge: &Self::ScalarField,
+    ) -> (Self::Commitment, Self::Response) {
+        let left_challenge = A::ScalarField::rand(rng);
+        let (comm_a, left) = self.0.simulate(rng, &left_challenge);
+        let (comm_b, right) = self.1.simulate(rng, &(*challenge - left_challenge));
+        (
+            (comm_a, comm_b),
+            OrResponse {
+                left_challenge,
+                left,
+                right,
+            },
+        )
+    }
+}
+
+impl<P: SigmaProtocol> SigmaProof<P> {
+    /// Create a non-interactive proof. `context` is hashed for the challenge and can be used for a nonce or to bind
+    /// the proof to other data.
+    pub fn new<R: RngCore, D: Digest>(
+        rng: &mut R,
+        relation: &P,
+        witness: &P::Witness,
+        context: &[u8],
+    ) -> Result<Self, SchnorrError> {
+        let (state, commitment) = relation.commit(rng, witness)?;
+        let challenge = Self::compute_challenge::<D>(relation, &commitment, context)?;
+        let response = relation.respond(state, witness, &challenge)?;
+        Ok(Self {
+            commitment,
+            response,
+        })
+    }
+
+    pub fn verify<D: Digest>(&self, relation: &P, context: &[u8]) -> Result<(), SchnorrError> {
+        let challenge = Self::compute_challenge::<D>(relation, &self.commitment, context)?;
+        relation.verify(&self.commitment, &challenge, &self.response)
+    }
+
+    /// Uses try-and-increment over the concatenation of the context, the relation's challenge contribution and the
+    /// commitment. Prefer [`SigmaProof::compute_challenge_with_transcript`].
+    pub fn compute_challenge<D: Digest>(
+        relation: &P,
+        commitment: &P::Commitment,
+        context: &[u8],
+    ) -> Result<P::ScalarField, SchnorrError> {
+        let mut bytes = context.to_vec();
+        relation.challenge_contribution(&mut bytes)?;
+        commitment.serialize_unchecked(&mut bytes)?;
+        Ok(compute_random_oracle_challenge::<P::ScalarField, D>(&bytes))
+    }
+
+    /// Create a non-interactive proof with the challenge derived from the transcript. Any context, like a nonce,
+    /// should be appended to the transcript before calling this.
+    pub fn new_with_transcript<R: RngCore, D: Digest>(
+        rng: &mut R,
+        relation: &P,
+        witness: &P::Witness,
+        mut transcript: Transcript,
+    ) -> Result<Self, SchnorrError> {
+        let (state, commitment) = relation.commit(rng, witness)?;
+        let challenge =
+            Self::compute_challenge_with_transcript::<D>(relation, &commitment, &mut transcript)?;
+        let response = relation.respond(state, witness, &challenge)?;
+        Ok(Self {
+            commitment,
+            response,
+        })
+    }
+
+    /// Verify the proof created by [`SigmaProof::new_with_transcript`] given a transcript identical to the one
+    /// used by the prover
+    pub fn verify_with_transcript<D: Digest>(
+        &self,
+        relation: &P,
+        mut transcript: Transcript,
+    ) -> Result<(), SchnorrError> {
+        let challenge = Self::compute_challenge_with_transcript::<D>(
+            relation,
+            &self.commitment,
+            &mut transcript,
+        )?;
+        relation.verify(&self.commitment, &challenge, &self.response)
+    }
+
+    /// Append the relation's public values and the commitment to the transcript and derive the challenge. In
+    /// compatibility mode, this gives the same challenge as [`SigmaProof::compute_challenge`] when the context is
+    /// appended first.
+    pub fn compute_challenge_with_transcript<D: Digest>(
+        relation: &P,
+        commitment: &P::Commitment,
+        transcript: &mut Transcript,
+    ) -> Result<P::ScalarField, SchnorrError> {
+        relation.append_to_transcript(RELATION_LABEL, transcript)?;
+        let mut bytes = Vec::new();
+        commitment.serialize_unchecked(&mut bytes)?;
+        transcript.append_message(COMMITMENT_LABEL, &bytes);
+        Ok(transcript.challenge_scalar::<P::ScalarField, D>(CHALLENGE_LABEL))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_381::{Bls12_381, G1Affine, G1Projective, G2Affine, G2Projective};
+    use ark_ec::PairingEngine;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+    use blake2::Blake2b;
+    use dock_crypto_utils::msm::variable_base_msm;
+
+    type Fr = <Bls12_381 as PairingEngine>::Fr;
+
+    fn discrete_logs<R: RngCore>(rng: &mut R, count: usize) -> (DiscreteLogs<G1Affine>, Vec<Fr>) {
+        let bases = (0..count)
+            .map(|_| G1Projective::rand(rng).into_affine())
+            .collect::<Vec<_>>();
+        let witnesses = (0..count).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let y = variable_base_msm(&bases, &witnesses).into_affine();
+        (DiscreteLogs { bases, y }, witnesses)
+    }
+
+    fn equal_discrete_logs<R: RngCore>(rng: &mut R) -> (EqualDiscreteLogs<G1Affine, G2Affine>, Fr) {
+        let witness = Fr::rand(rng);
+        let g = G1Projective::rand(rng).into_affine();
+        let h = G2Projective::rand(rng).into_affine();
+        let relation = EqualDiscreteLogs {
+            g,
+            y: g.mul(witness.into_repr()).into_affine(),
+            h,
+            z: h.mul(witness.into_repr()).into_affine(),
+        };
+        (relation, witness)
+    }
+
+    #[test]
+    fn basic_relations() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let context = b"test-context";
+
+        let (dl, dl_wit) = discrete_logs(&mut rng, 3);
+        let proof = SigmaProof::new::<_, Blake2b>(&mut rng, &dl, &dl_wit, context).unwrap();
+        proof.verify::<Blake2b>(&dl, context).unwrap();
+        assert!(proof.verify::<Blake2b>(&dl, b"other-context").is_err());
+
+        let mut bytes = vec![];
+        CanonicalSerialize::serialize(&proof, &mut bytes).unwrap();
+        let deserz = SigmaProof::<DiscreteLogs<G1Affine>>::deserialize(&bytes[..]).unwrap();
+        assert_eq!(deserz, proof);
+
+        let (eq, eq_wit) = equal_discrete_logs(&mut rng);
+        let proof = SigmaProof::new::<_, Blake2b>(&mut rng, &eq, &eq_wit, context).unwrap();
+        proof.verify::<Blake2b>(&eq, context).unwrap();
+
+        // Logs are not equal
+        let mut bad_eq = eq.clone();
+        bad_eq.z = G2Projective::rand(&mut rng).into_affine();
+        let proof = SigmaProof::new::<_, Blake2b>(&mut rng, &bad_eq, &eq_wit, context).unwrap();
+        assert!(proof.verify::<Blake2b>(&bad_eq, context).is_err());
+
+        // Wrong number of witnesses
+        assert!(
+            SigmaProof::new::<_, Blake2b>(&mut rng, &dl, &dl_wit[1..].to_vec(), context).is_err()
+        );
+
+        // Simulated transcripts are accepting
+        let challenge = Fr::rand(&mut rng);
+        let (comm, resp) = dl.simulate(&mut rng, &challenge);
+        dl.verify(&comm, &challenge, &resp).unwrap();
+        let (comm, resp) = eq.simulate(&mut rng, &challenge);
+        eq.verify(&comm, &challenge, &resp).unwrap();
+    }
+
+    #[test]
+    fn composed_relations() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let context = b"test-context";
+
+        let (dl_1, dl_wit_1) = discrete_logs(&mut rng, 2);
+        let (dl_2, dl_wit_2) = discrete_logs(&mut rng, 1);
+        let (eq, eq_wit) = equal_discrete_logs(&mut rng);
+
+        let and = And(dl_1.clone(), eq.clone());
+        let wit = (dl_wit_1.clone(), eq_wit);
+        let proof = SigmaProof::new::<_, Blake2b>(&mut rng, &and, &wit, context).unwrap();
+        proof.verify::<Blake2b>(&and, context).unwrap();
+
+        // Prover knows only one of the witnesses, either left or right
+        let or = Or(dl_1.clone(), dl_2.clone());
+        for wit in [
+            OrWitness::Left(dl_wit_1.clone()),
+            OrWitness::Right(dl_wit_2.clone()),
+        ] {
+            let proof = SigmaProof::new::<_, Blake2b>(&mut rng, &or, &wit, context).unwrap();
+            proof.verify::<Blake2b>(&or, context).unwrap();
+
+            let mut bytes = vec![];
+            CanonicalSerialize::serialize(&proof, &mut bytes).unwrap();
+            let deserz =
+                SigmaProof::<Or<DiscreteLogs<G1Affine>, DiscreteLogs<G1Affine>>>::deserialize(
+                    &bytes[..],
+                )
+                .unwrap();
+            assert_eq!(deserz, proof);
+        }
+
+        // Wrong witness for the relation
+        let proof = SigmaProof::new::<_, Blake2b>(
+            &mut rng,
+            &or,
+            &OrWitness::Left(vec![dl_wit_1[1], dl_wit_1[0]]),
+            context,
+        )
+        .unwrap();
+        assert!(proof.verify::<Blake2b>(&or, context).is_err());
+
+        // Nested composition: (dl_1 OR dl_2) AND (eq OR dl_1)
+        let nested = And(or.clone(), Or(eq.clone(), dl_1.clone()));
+        let wit = (OrWitness::Right(dl_wit_2.clone()), OrWitness::Left(eq_wit));
+        let proof = SigmaProof::new::<_, Blake2b>(&mut rng, &nested, &wit, context).unwrap();
+        proof.verify::<Blake2b>(&nested, context).unwrap();
+
+        // Tampering with the split of the challenge fails
+        let mut bad_proof = proof.clone();
+        bad_proof.response.0.left_challenge += Fr::from(1u64);
+        assert!(bad_proof.verify::<Blake2b>(&nested, context).is_err());
+
+        let challenge = Fr::rand(&mut rng);
+        let (comm, resp) = nested.simulate(&mut rng, &challenge);
+        nested.verify(&comm, &challenge, &resp).unwrap();
+    }
+
+    #[test]
+    fn relations_with_transcript() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let context = b"test-context";
+
+        let (dl_1, dl_wit_1) = discrete_logs(&mut rng, 2);
+        let (dl_2, _) = discrete_logs(&mut rng, 1);
+        let (eq, eq_wit) = equal_discrete_logs(&mut rng);
+        let nested = And(Or(dl_1.clone(), dl_2), eq);
+        let wit = (OrWitness::Left(dl_wit_1), eq_wit);
+
+        // In compatibility mode, the challenge is same as the one computed from the context
+        let proof = SigmaProof::new::<_, Blake2b>(&mut rng, &nested, &wit, context).unwrap();
+        let mut transcript = Transcript::new_compatible();
+        transcript.append_message(b"context", context);
+        assert_eq!(
+            SigmaProof::compute_challenge_with_transcript::<Blake2b>(
+                &nested,
+                &proof.commitment,
+                &mut transcript
+            )
+            .unwrap(),
+            SigmaProof::compute_challenge::<Blake2b>(&nested, &proof.commitment, context).unwrap()
+        );
+
+        let create_transcript = |context: &[u8]| {
+            let mut transcript = Transcript::new(b"test");
+            transcript.append_message(b"context", context);
+            transcript
+        };
+        let proof = SigmaProof::new_with_transcript::<_, Blake2b>(
+            &mut rng,
+            &nested,
+            &wit,
+            create_transcript(context),
+        )
+        .unwrap();
+        proof
+            .verify_with_transcript::<Blake2b>(&nested, create_transcript(context))
+            .unwrap();
+        assert!(proof
+            .verify_with_transcript::<Blake2b>(&nested, create_transcript(b"other-context"))
+            .is_err());
+        assert!(proof.verify::<Blake2b>(&nested, context).is_err());
+    }
+}
@@ -0,0 +1,35 @@
+use ark_bls12_381::Bls12_381;
+use ark_std::rand::RngCore;
+use ark_std::UniformRand;
+use bbs_plus::bbs::{Bls12381Sha256, Ciphersuite, SignatureG1};
+use bbs_plus::prelude::{KeypairG2, SignatureParamsG1};
+
+use crate::Fr;
+
+/// Create a BBS signature as per the IETF draft over `message_count` random messages with the `header`.
+/// Returns the messages, params, keypair and signature.
+pub fn ietf_bbs_sig_setup<R: RngCore>(
+    rng: &mut R,
+    message_count: usize,
+    header: &[u8],
+) -> (
+    Vec<Fr>,
+    SignatureParamsG1<Bls12_381>,
+    KeypairG2<Bls12_381>,
+    SignatureG1<Bls12_381>,
+) {
+    let messages: Vec<Fr> = (0..message_count).map(|_| Fr::rand(rng)).collect();
+    let params = Bls12381Sha256::signature_params(message_count);
+    let keypair = KeypairG2::<Bls12_381>::generate_using_rng(rng, &params);
+    let sig = SignatureG1::<Bls12_381>::new::<Bls12381Sha256>(
+        &messages,
+        &keypair.secret_key,
+        &keypair.public_key,
+        &params,
+        header,
+    )
+    .unwrap();
+    sig.verify::<Bls12381Sha256>(&messages, &keypair.public_key, &params, header)
+        .unwrap();
+    (messages, params, keypair, sig)
+}
@@ -8,6 +8,7 @@ pub type G1 = <Bls12_381 as PairingEngine>::G1Affine;
 pub type ProofG1 = Proof<Bls12_381, G1Affine, Blake2b>;
 
 pub mod accumulators;
+pub mod bbs;
 pub mod bbs_plus;
 #[macro_use]
 pub mod serialization;
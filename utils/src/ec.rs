@@ -1,5 +1,7 @@
 use ark_ec::{PairingEngine, ProjectiveCurve};
-use ark_std::{cfg_into_iter, cfg_iter, vec::Vec};
+use ark_ff::{to_bytes, BigInteger, PrimeField};
+use ark_std::{cfg_into_iter, cfg_iter, rand::Rng, vec::Vec, UniformRand};
+use subtle::{Choice, ConditionallySelectable};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -27,3 +29,143 @@ pub fn pairing_product_with_g2_prepared<E: PairingEngine>(
         .collect();
     E::product_of_pairings(pairs.iter())
 }
+
+/// Multiply `base` by `scalar` using the Montgomery ladder. Unlike `ProjectiveCurve::mul`, all bits of the
+/// scalar's representation are processed, including the leading zeros, and each bit does one addition and one
+/// doubling. Which of the 2 ladder points is added to and which is doubled is decided by swapping them with
+/// [`conditional_swap`] rather than by branching on or indexing with the bit. As the group operations take shortcuts
+/// for the identity, the ladder starts from a random point `Q` rather than the identity and `Q * 2^n` is subtracted
+/// from the result, where `n` is the number of bits processed. Note that the field arithmetic underneath is not
+/// guaranteed to be constant time by arkworks.
+pub fn mul_ladder<G: ProjectiveCurve, R: Rng>(rng: &mut R, base: &G, scalar: &G::ScalarField) -> G {
+    let mut q = G::rand(rng);
+    // Invariant: `r_1 - r_0 = base`, if `swapped` is 1, `r_0` and `r_1` are in each other's place
+    let mut r_0 = q;
+    let mut r_1 = q + base;
+    let mut swapped = Choice::from(0);
+    for bit in ladder_bits(scalar) {
+        let bit = Choice::from(bit as u8);
+        // Put the point to be doubled in `r_0`
+        conditional_swap(&mut r_0, &mut r_1, swapped ^ bit);
+        swapped = bit;
+        r_1 += &r_0;
+        r_0.double_in_place();
+        q.double_in_place();
+    }
+    conditional_swap(&mut r_0, &mut r_1, swapped);
+    r_0 - q
+}
+
+/// Bits of the scalar processed by [`mul_ladder`], most significant first. These are all the bits of the scalar's
+/// representation so their count is the same for every scalar.
+fn ladder_bits<F: PrimeField>(scalar: &F) -> Vec<bool> {
+    scalar.into_repr().to_bits_be()
+}
+
+/// Swap `a` and `b` if `choice` is 1 and leave them unchanged if 0. The swap is done over the byte
+/// encodings of the points using `subtle` so that the same operations and memory accesses happen for
+/// both values of `choice`.
+pub fn conditional_swap<G: ProjectiveCurve>(a: &mut G, b: &mut G, choice: Choice) {
+    let mut a_bytes = to_bytes![*a].unwrap();
+    let mut b_bytes = to_bytes![*b].unwrap();
+    for (x, y) in a_bytes.iter_mut().zip(b_bytes.iter_mut()) {
+        u8::conditional_swap(x, y, choice);
+    }
+    *a = G::read(a_bytes.as_slice()).unwrap();
+    *b = G::read(b_bytes.as_slice()).unwrap();
+}
+
+/// Multiply `base` by `scalar` by splitting the scalar in 2 random shares, `scalar = k_1 + k_2`, and computing
+/// `base * k_1 + base * k_2` using [`mul_ladder`]. As each share is uniformly random, the number of bits of the
+/// shares and thus any remaining timing variation does not depend on `scalar`.
+pub fn blinded_mul<G: ProjectiveCurve, R: Rng>(
+    rng: &mut R,
+    base: &G,
+    scalar: &G::ScalarField,
+) -> G {
+    let k_1 = G::ScalarField::rand(rng);
+    let k_2 = *scalar - k_1;
+    mul_ladder(rng, base, &k_1) + mul_ladder(rng, base, &k_2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ff::blinded_inverse;
+    use ark_bls12_381::{Fr, G1Projective};
+    use ark_ff::{Field, One, Zero};
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+    use std::time::Instant;
+
+    #[test]
+    fn ladder_and_blinded_operations() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let base = G1Projective::rand(&mut rng);
+        for scalar in [Fr::zero(), Fr::one(), -Fr::one(), Fr::rand(&mut rng)] {
+            let expected = base.mul(scalar.into_repr());
+            assert_eq!(mul_ladder(&mut rng, &base, &scalar), expected);
+            assert_eq!(blinded_mul(&mut rng, &base, &scalar), expected);
+            assert_eq!(blinded_inverse(&mut rng, &scalar), scalar.inverse());
+        }
+
+        let (a, b) = (G1Projective::rand(&mut rng), G1Projective::rand(&mut rng));
+        let (mut x, mut y) = (a, b);
+        conditional_swap(&mut x, &mut y, Choice::from(0));
+        assert_eq!((x, y), (a, b));
+        conditional_swap(&mut x, &mut y, Choice::from(1));
+        assert_eq!((x, y), (b, a));
+    }
+
+    #[test]
+    fn ladder_processes_all_bits() {
+        // `ProjectiveCurve::mul` skips the leading zeros of the scalar but the ladder does an addition and a doubling
+        // for every bit of the representation, so the number of steps is the same for small and large scalars
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let bits_count = Fr::one().into_repr().as_ref().len() * 64;
+        for scalar in [
+            Fr::zero(),
+            Fr::one(),
+            Fr::from(3u64),
+            -Fr::one(),
+            Fr::rand(&mut rng),
+        ] {
+            let bits = ladder_bits(&scalar);
+            assert_eq!(bits.len(), bits_count);
+            assert_eq!(
+                Fr::from_repr(<Fr as PrimeField>::BigInt::from_bits_be(&bits)).unwrap(),
+                scalar
+            );
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn ladder_timing_independent_of_scalar() {
+        // Compares wall-clock time so run it explicitly on an idle machine with a release build
+        // `ProjectiveCurve::mul` skips the leading zeros of the scalar so multiplying by a small scalar is
+        // much faster (by more than 10 times here). The ladder should take about the same time for a small and a large
+        // scalar, the margin is only for noise.
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let base = G1Projective::rand(&mut rng);
+        let small = Fr::from(3u64);
+        let large = -Fr::one();
+        let iterations = 50;
+
+        let mut time = |scalar: &Fr| {
+            let start = Instant::now();
+            for _ in 0..iterations {
+                let _ = mul_ladder(&mut rng, &base, scalar);
+            }
+            start.elapsed().as_secs_f64()
+        };
+        // Warm up
+        time(&large);
+        let (t_small, t_large) = (time(&small), time(&large));
+        let ratio = t_small / t_large;
+        println!(
+            "Ladder time for small scalar {:?}, for large scalar {:?}",
+            t_small, t_large
+        );
+        assert!(ratio > 0.5 && ratio < 2.0, "ratio {}", ratio);
+    }
+}
@@ -29,6 +29,14 @@ pub fn non_zero_random<F: PrimeField, R: Rng>(rng: &mut R) -> F {
     r
 }
 
+/// Inverse of `x` computed as `r * (x * r)^-1` for a random non-zero `r`. As the inverted value `x * r` is
+/// uniformly random, the time taken by the (variable time) inversion does not depend on `x`. Returns `None`
+/// if `x` is 0.
+pub fn blinded_inverse<F: PrimeField, R: Rng>(rng: &mut R, x: &F) -> Option<F> {
+    let r = non_zero_random::<F, R>(rng);
+    (*x * r).inverse().map(|i| i * r)
+}
+
 pub fn powers<F: PrimeField>(s: &F, num: usize) -> Vec<F> {
     let mut powers = vec![F::one()];
     for i in 1..num {
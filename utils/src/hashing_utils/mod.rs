@@ -0,0 +1,306 @@
+#![allow(non_snake_case)]
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, Field, PrimeField};
+use ark_std::{
+    any::{Any, TypeId},
+    vec,
+    vec::Vec,
+};
+use digest::{generic_array::typenum::Unsigned, BlockInput, Digest, FixedOutput, Reset, Update};
+use hkdf::Hkdf;
+
+pub mod bls12_381;
+pub mod sswu;
+
+const ZERO_AS_OCTET: [u8; 1] = [0u8];
+
+/// Deterministically generate a field element from given seed similar to the procedure defined
+/// here <https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-04#section-2.3>
+/// This process can be used to create secret keys from limited entropy source (the seed) without
+/// using any other source of randomness.
+/// `ikm` is the seed, `salt` is for domain separation. The above spec mentions `key_info` but is
+/// omitted here as only one element is created.
+/// Note that it can be variable time but it's less likely
+pub fn field_elem_from_seed<F, D>(ikm: &[u8], salt: &[u8]) -> F
+where
+    F: PrimeField,
+    D: Digest + Update + BlockInput + FixedOutput + Reset + Default + Clone,
+{
+    // IKM || I2OSP(0, 1), append 1 byte as 0 to `ikm`
+    let mut ikm_appended = ikm.to_vec();
+    ikm_appended.extend_from_slice(&ZERO_AS_OCTET);
+
+    // log_2(r), byte size of the field order
+    let field_size_in_bytes = (F::size_in_bits() + 7) / 8;
+
+    // I2OSP(L, 2), convert `L` to a 2 byte array
+    // L = ceil(3 * log_2(r) / 16)
+    let L: u16 = (3 * field_size_in_bytes as u16 + 15) / 16;
+    let L_as_bytes = L.to_be_bytes();
+
+    loop {
+        let salt_hash = D::digest(salt);
+        let (_, hkdf) = Hkdf::<D>::extract(Some(&salt_hash), &ikm_appended);
+        let mut okm = vec![0u8; field_size_in_bytes];
+
+        // This cannot fail
+        hkdf.expand(&L_as_bytes, &mut okm).unwrap();
+        let f = F::from_be_bytes_mod_order(&okm);
+        if !f.is_zero() {
+            return f;
+        }
+    }
+}
+
+/// Hash bytes to a point on the curve. Returns as Projective coordinates. This is vulnerable to timing attack and is only used when input
+/// is public anyway like when generating setup parameters.
+pub fn projective_group_elem_from_try_and_incr<G: AffineCurve, D: Digest>(
+    bytes: &[u8],
+) -> G::Projective {
+    let mut hash = D::digest(bytes);
+    let mut g = G::from_random_bytes(&hash);
+    let mut j = 1u64;
+    while g.is_none() {
+        hash = D::digest(&to_bytes![bytes, "-attempt-".as_bytes(), j].unwrap());
+        g = G::from_random_bytes(&hash);
+        j += 1;
+    }
+    g.unwrap().mul_by_cofactor_to_projective()
+}
+
+/// Hash bytes to a point on the curve. Returns as Affine coordinates. This is vulnerable to timing attack and is only used when input
+/// is public anyway like when generating setup parameters.
+pub fn affine_group_elem_from_try_and_incr<G: AffineCurve, D: Digest>(bytes: &[u8]) -> G {
+    projective_group_elem_from_try_and_incr::<G, D>(bytes).into_affine()
+}
+
+/// Groups for which a hash to curve as defined in [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html)
+/// is implemented. Unlike try-and-increment, the output is standardized so other implementations can
+/// reproduce the same group elements given the same message and domain separation tag.
+pub trait HashToCurve: AffineCurve {
+    /// Hash `msg` to a point in the prime order subgroup using domain separation tag `dst`.
+    fn hash_to_curve<D: Digest + BlockInput>(msg: &[u8], dst: &[u8]) -> Self::Projective;
+}
+
+/// Hash bytes to a point on the curve using the hash to curve of RFC 9380 and domain separation tag `dst`.
+/// Returns as Projective coordinates.
+pub fn projective_group_elem_from_hash_to_curve<G: HashToCurve, D: Digest + BlockInput>(
+    bytes: &[u8],
+    dst: &[u8],
+) -> G::Projective {
+    G::hash_to_curve::<D>(bytes, dst)
+}
+
+/// Hash bytes to a point on the curve using the hash to curve of RFC 9380 and domain separation tag `dst`.
+/// Returns as Affine coordinates.
+pub fn affine_group_elem_from_hash_to_curve<G: HashToCurve, D: Digest + BlockInput>(
+    bytes: &[u8],
+    dst: &[u8],
+) -> G {
+    projective_group_elem_from_hash_to_curve::<G, D>(bytes, dst).into_affine()
+}
+
+/// Like [`affine_group_elem_from_hash_to_curve`] but for a group which is only known to be an `AffineCurve`, like a
+/// group of a generic pairing. Returns `None` if the group does not implement [`HashToCurve`].
+pub fn affine_group_elem_from_hash_to_curve_if_supported<G: AffineCurve, D: Digest + BlockInput>(
+    bytes: &[u8],
+    dst: &[u8],
+) -> Option<G> {
+    fn hash<H: HashToCurve, G: AffineCurve, D: Digest + BlockInput>(
+        bytes: &[u8],
+        dst: &[u8],
+    ) -> Option<G> {
+        if TypeId::of::<H>() != TypeId::of::<G>() {
+            return None;
+        }
+        let elem = affine_group_elem_from_hash_to_curve::<H, D>(bytes, dst);
+        (&elem as &dyn Any).downcast_ref::<G>().copied()
+    }
+    hash::<ark_bls12_381::G1Affine, G, D>(bytes, dst)
+        .or_else(|| hash::<ark_bls12_381::G2Affine, G, D>(bytes, dst))
+}
+
+/// Hash bytes to a field element. This is vulnerable to timing attack and is only used when input
+/// is public anyway like when generating setup parameters or challenge
+pub fn field_elem_from_try_and_incr<F: PrimeField, D: Digest>(bytes: &[u8]) -> F {
+    let mut hash = D::digest(bytes);
+    let mut f = F::from_random_bytes(&hash);
+    let mut j = 1u64;
+    while f.is_none() {
+        hash = D::digest(&to_bytes![bytes, "-attempt-".as_bytes(), j].unwrap());
+        f = F::from_random_bytes(&hash);
+        j += 1;
+    }
+    f.unwrap()
+}
+
+/// Expand `msg` into `len_in_bytes` uniformly random bytes using `expand_message_xmd` as defined in
+/// section 5.3.1 of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3.1). `dst` is the
+/// domain separation tag and if its longer than 255 bytes, it's hashed as described in section 5.3.3.
+/// Panics if `len_in_bytes` is more than 255 times the digest size or more than 65535.
+pub fn expand_message_xmd<D>(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8>
+where
+    D: Digest + BlockInput,
+{
+    let b_in_bytes = <D as Digest>::OutputSize::to_usize();
+    let r_in_bytes = <D as BlockInput>::BlockSize::to_usize();
+    let ell = len_in_bytes.div_ceil(b_in_bytes);
+    assert!(ell <= 255 && len_in_bytes <= 65535);
+
+    let long_dst;
+    let dst = if dst.len() > 255 {
+        long_dst = D::new().chain(b"H2C-OVERSIZE-DST-").chain(dst).finalize();
+        &long_dst[..]
+    } else {
+        dst
+    };
+    // DST_prime = DST || I2OSP(len(DST), 1)
+    let dst_len = [dst.len() as u8];
+
+    // b_0 = H(Z_pad || msg || I2OSP(len_in_bytes, 2) || I2OSP(0, 1) || DST_prime)
+    let b_0 = D::new()
+        .chain(vec![0u8; r_in_bytes])
+        .chain(msg)
+        .chain((len_in_bytes as u16).to_be_bytes())
+        .chain([0u8])
+        .chain(dst)
+        .chain(dst_len)
+        .finalize();
+
+    // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)
+    let mut b_i = D::new()
+        .chain(&b_0)
+        .chain([1u8])
+        .chain(dst)
+        .chain(dst_len)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * b_in_bytes);
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+        let xored = b_0
+            .iter()
+            .zip(b_i.iter())
+            .map(|(a, b)| a ^ b)
+            .collect::<Vec<_>>();
+        b_i = D::new()
+            .chain(xored)
+            .chain([i as u8])
+            .chain(dst)
+            .chain(dst_len)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+/// Hash `msg` to `count` elements of the field `F` as defined in section 5.2 of
+/// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-5.2) using `expand_message_xmd`
+/// with `dst` as the domain separation tag. Assumes a security level of 128 bits. Works for extension
+/// fields as well, where each element is created from `F::extension_degree()` elements of the base prime field.
+pub fn hash_to_field<F, D>(msg: &[u8], dst: &[u8], count: usize) -> Vec<F>
+where
+    F: Field,
+    D: Digest + BlockInput,
+{
+    // L = ceil((ceil(log2(p)) + k) / 8)
+    let L = (<F::BasePrimeField as PrimeField>::size_in_bits() + 128).div_ceil(8);
+    let m = F::extension_degree() as usize;
+    let uniform_bytes = expand_message_xmd::<D>(msg, dst, count * m * L);
+    uniform_bytes
+        .chunks(m * L)
+        .map(|elem_bytes| {
+            let base_elems = elem_bytes
+                .chunks(L)
+                .map(F::BasePrimeField::from_be_bytes_mod_order)
+                .collect::<Vec<_>>();
+            F::from_base_prime_field_elems(&base_elems).unwrap()
+        })
+        .collect()
+}
+
+/// Hash `msg` to a single element of the prime field `F`. Uses [`hash_to_field`]
+pub fn hash_to_field_elem<F, D>(msg: &[u8], dst: &[u8]) -> F
+where
+    F: PrimeField,
+    D: Digest + BlockInput,
+{
+    hash_to_field::<F, D>(msg, dst, 1).pop().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::Sha256;
+
+    #[test]
+    fn expand_message_xmd_test_vectors() {
+        // Test vectors from appendix K.1 of RFC 9380
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let vectors: [(&[u8], usize, &str); 5] = [
+            (
+                b"",
+                0x20,
+                "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+            ),
+            (
+                b"abc",
+                0x20,
+                "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+            ),
+            (
+                b"abcdef0123456789",
+                0x20,
+                "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+            ),
+            (
+                b"",
+                0x80,
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+            ),
+            (
+                b"abc",
+                0x80,
+                "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40",
+            ),
+        ];
+        for (msg, len, expected) in vectors {
+            assert_eq!(
+                hex::encode(expand_message_xmd::<Sha256>(msg, dst, len)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn hash_to_curve_if_supported() {
+        let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+        assert_eq!(
+            affine_group_elem_from_hash_to_curve_if_supported::<ark_bls12_381::G1Affine, Sha256>(
+                b"abc", dst
+            ),
+            Some(affine_group_elem_from_hash_to_curve::<
+                ark_bls12_381::G1Affine,
+                Sha256,
+            >(b"abc", dst))
+        );
+        assert_eq!(
+            affine_group_elem_from_hash_to_curve_if_supported::<ark_bls12_381::G2Affine, Sha256>(
+                b"abc", dst
+            ),
+            Some(affine_group_elem_from_hash_to_curve::<
+                ark_bls12_381::G2Affine,
+                Sha256,
+            >(b"abc", dst))
+        );
+        assert!(
+            affine_group_elem_from_hash_to_curve_if_supported::<ark_bn254::G1Affine, Sha256>(
+                b"abc", dst
+            )
+            .is_none()
+        );
+    }
+}
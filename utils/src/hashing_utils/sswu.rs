@@ -0,0 +1,140 @@
+//! Hashing to an elliptic curve using the simplified Shallue-van de Woestijne-Ulas (SSWU) map to a
+//! curve isogenous to the target curve followed by the isogeny map, as described in section 6.6.3
+//! of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-6.6.3). This is needed for curves
+//! like BLS12-381 where `A * B = 0` for the target curve.
+//! Hashing is deterministic and, unlike try-and-increment, never retries with a different input.
+
+use ark_ec::{
+    short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    AffineCurve, ProjectiveCurve, SWModelParameters,
+};
+use ark_ff::{Field, LegendreSymbol, One, SquareRootField, Zero};
+use digest::{BlockInput, Digest};
+
+use crate::hashing_utils::hash_to_field;
+
+/// Parameters for the SSWU map to the isogenous curve `E': y^2 = x^3 + A' * x + B'` and the isogeny map
+/// from `E'` to the target curve.
+pub trait SWUIsogenyParameters {
+    /// The target curve
+    type Curve: SWModelParameters;
+
+    /// Coefficient `A'` of the isogenous curve
+    const ISO_A: BaseField<Self>;
+    /// Coefficient `B'` of the isogenous curve
+    const ISO_B: BaseField<Self>;
+    /// The non-square `Z` of the SSWU map
+    const Z: BaseField<Self>;
+
+    /// Coefficients of the isogeny map polynomials, constant term first
+    const X_NUM: &'static [BaseField<Self>];
+    const X_DEN: &'static [BaseField<Self>];
+    const Y_NUM: &'static [BaseField<Self>];
+    const Y_DEN: &'static [BaseField<Self>];
+
+    /// The `sgn0` function as defined in section 4.1 of RFC 9380
+    fn sgn0(elem: &BaseField<Self>) -> bool;
+
+    /// Clear the cofactor of a point on the target curve to map it to the prime order subgroup
+    /// as defined in section 7 of RFC 9380.
+    fn clear_cofactor(point: GroupProjective<Self::Curve>) -> GroupProjective<Self::Curve>;
+}
+
+pub type BaseField<P> = <<P as SWUIsogenyParameters>::Curve as ark_ec::ModelParameters>::BaseField;
+
+/// `hash_to_curve` as defined in section 3 of RFC 9380, returns a point in the prime order subgroup.
+/// `dst` is the domain separation tag.
+pub fn hash_to_curve<P, D>(msg: &[u8], dst: &[u8]) -> GroupProjective<P::Curve>
+where
+    P: SWUIsogenyParameters,
+    BaseField<P>: SquareRootField,
+    D: Digest + BlockInput,
+{
+    let u = hash_to_field::<BaseField<P>, D>(msg, dst, 2);
+    let mut q = map_to_curve::<P>(&u[0]).into_projective();
+    q.add_assign_mixed(&map_to_curve::<P>(&u[1]));
+    P::clear_cofactor(q)
+}
+
+/// `encode_to_curve` as defined in section 3 of RFC 9380. Its output is not uniformly distributed.
+/// `dst` is the domain separation tag.
+pub fn encode_to_curve<P, D>(msg: &[u8], dst: &[u8]) -> GroupProjective<P::Curve>
+where
+    P: SWUIsogenyParameters,
+    BaseField<P>: SquareRootField,
+    D: Digest + BlockInput,
+{
+    let u = hash_to_field::<BaseField<P>, D>(msg, dst, 1);
+    P::clear_cofactor(map_to_curve::<P>(&u[0]).into_projective())
+}
+
+/// Map a field element to a point on the target curve. The point is not necessarily in the prime order subgroup.
+pub fn map_to_curve<P>(u: &BaseField<P>) -> GroupAffine<P::Curve>
+where
+    P: SWUIsogenyParameters,
+    BaseField<P>: SquareRootField,
+{
+    let (x, y) = map_to_isogenous_curve::<P>(u);
+    iso_map::<P>(&x, &y)
+}
+
+/// Simplified SWU map to the isogenous curve as defined in section 6.6.2 of RFC 9380. Returns the
+/// affine coordinates of the point.
+fn map_to_isogenous_curve<P>(u: &BaseField<P>) -> (BaseField<P>, BaseField<P>)
+where
+    P: SWUIsogenyParameters,
+    BaseField<P>: SquareRootField,
+{
+    let a = P::ISO_A;
+    let b = P::ISO_B;
+    let z = P::Z;
+
+    // tv1 = Z^2 * u^4 + Z * u^2
+    let z_u2 = z * u.square();
+    let tv1 = z_u2.square() + z_u2;
+    // x1 = (-B / A) * (1 + inv0(tv1)) and if tv1 == 0, x1 = B / (Z * A)
+    let x1 = if tv1.is_zero() {
+        b * (z * a).inverse().unwrap()
+    } else {
+        -b * a.inverse().unwrap() * (BaseField::<P>::one() + tv1.inverse().unwrap())
+    };
+    // gx1 = x1^3 + A * x1 + B
+    let gx1 = (x1.square() + a) * x1 + b;
+    // x2 = Z * u^2 * x1, gx2 = x2^3 + A * x2 + B
+    let x2 = z_u2 * x1;
+    let gx2 = (x2.square() + a) * x2 + b;
+
+    let (x, mut y) = match gx1.legendre() {
+        LegendreSymbol::Zero | LegendreSymbol::QuadraticResidue => (x1, gx1.sqrt().unwrap()),
+        LegendreSymbol::QuadraticNonResidue => (x2, gx2.sqrt().unwrap()),
+    };
+    if P::sgn0(u) != P::sgn0(&y) {
+        y = -y;
+    }
+    (x, y)
+}
+
+/// Apply the isogeny map to the point `(x, y)` of the isogenous curve. Returns the point at infinity
+/// in the exceptional case of any denominator being 0.
+fn iso_map<P>(x: &BaseField<P>, y: &BaseField<P>) -> GroupAffine<P::Curve>
+where
+    P: SWUIsogenyParameters,
+{
+    let x_den = evaluate_poly(P::X_DEN, x);
+    let y_den = evaluate_poly(P::Y_DEN, x);
+    if x_den.is_zero() || y_den.is_zero() {
+        return GroupAffine::zero();
+    }
+    let x_num = evaluate_poly(P::X_NUM, x);
+    let y_num = evaluate_poly(P::Y_NUM, x);
+    GroupAffine::new(
+        x_num * x_den.inverse().unwrap(),
+        *y * y_num * y_den.inverse().unwrap(),
+        false,
+    )
+}
+
+/// Evaluate polynomial with coefficients `coeffs` (constant term first) at `x` using Horner's method
+fn evaluate_poly<F: Field>(coeffs: &[F], x: &F) -> F {
+    coeffs.iter().rev().fold(F::zero(), |acc, c| acc * x + c)
+}
@@ -9,3 +9,5 @@ pub mod hashing_utils;
 pub mod msm;
 pub mod poly;
 pub mod randomized_pairing_check;
+pub mod secret;
+pub mod transcript;
@@ -0,0 +1,210 @@
+//! Wrapper for secret values like witnesses and blindings of proof protocols. The wrapped value is zeroized
+//! when dropped, is not printed by `Debug` and cannot be serialized (with serde or `CanonicalSerialize`) unless
+//! the feature `serialize-secrets` is enabled. Deserialization is always allowed.
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    fmt,
+    io::{self, Read, Write},
+    marker::PhantomData,
+};
+use serde::{de::Deserializer, ser::Error, Deserialize, Serialize, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+use zeroize::Zeroize;
+
+/// Whether secrets can be serialized, i.e. whether the feature `serialize-secrets` is enabled
+pub const SECRETS_SERIALIZABLE: bool = cfg!(feature = "serialize-secrets");
+
+const SERIALIZATION_REFUSED: &str =
+    "serializing a secret requires the feature `serialize-secrets` of dock_crypto_utils";
+
+/// A secret value which is zeroized on drop and redacted in `Debug`
+#[derive(Clone, PartialEq, Eq, Default)]
+pub struct Secret<T: Zeroize>(T);
+
+impl<T: Zeroize> Secret<T> {
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Reference to the secret value. Care must be taken to not copy it to places which are not zeroized.
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+
+    pub fn expose_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+
+    /// Reference to the secret value for serializing it. Errors if serialization of secrets is not enabled.
+    /// Useful when manually implementing `CanonicalSerialize` for a type containing a secret.
+    // `io::Error::other` is not available without std
+    #[allow(clippy::io_other_error)]
+    pub fn expose_for_serialization(&self) -> Result<&T, SerializationError> {
+        if SECRETS_SERIALIZABLE {
+            Ok(&self.0)
+        } else {
+            Err(SerializationError::IoError(io::Error::new(
+                io::ErrorKind::Other,
+                SERIALIZATION_REFUSED,
+            )))
+        }
+    }
+}
+
+impl<T: Zeroize> From<T> for Secret<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Zeroize> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Secret([REDACTED])")
+    }
+}
+
+impl<T: Zeroize> Zeroize for Secret<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Zeroize> Drop for Secret<T> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<T: Zeroize + CanonicalSerialize> CanonicalSerialize for Secret<T> {
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.expose_for_serialization()?.serialize(writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.0.serialized_size()
+    }
+
+    fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.expose_for_serialization()?
+            .serialize_uncompressed(writer)
+    }
+
+    fn serialize_unchecked<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.expose_for_serialization()?.serialize_unchecked(writer)
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        self.0.uncompressed_size()
+    }
+}
+
+impl<T: Zeroize + CanonicalDeserialize> CanonicalDeserialize for Secret<T> {
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        T::deserialize(reader).map(Self)
+    }
+
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        T::deserialize_uncompressed(reader).map(Self)
+    }
+
+    fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        T::deserialize_unchecked(reader).map(Self)
+    }
+}
+
+impl<T: Zeroize + Serialize> Serialize for Secret<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !SECRETS_SERIALIZABLE {
+            return Err(S::Error::custom(SERIALIZATION_REFUSED));
+        }
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: Zeroize + Deserialize<'de>> Deserialize<'de> for Secret<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self)
+    }
+}
+
+/// Serde adapter for [`Secret`] to be used with `serde_as` where the secret value is serialized using
+/// the adapter `U`, like `#[serde_as(as = "SecretAs<FieldBytes>")]` for a secret field element
+pub struct SecretAs<U>(PhantomData<U>);
+
+impl<T: Zeroize, U: SerializeAs<T>> SerializeAs<Secret<T>> for SecretAs<U> {
+    fn serialize_as<S: Serializer>(source: &Secret<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        if !SECRETS_SERIALIZABLE {
+            return Err(S::Error::custom(SERIALIZATION_REFUSED));
+        }
+        U::serialize_as(&source.0, serializer)
+    }
+}
+
+impl<'de, T: Zeroize, U: DeserializeAs<'de, T>> DeserializeAs<'de, Secret<T>> for SecretAs<U> {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Secret<T>, D::Error> {
+        U::deserialize_as(deserializer).map(Secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serde_utils::FieldBytes;
+    use ark_bls12_381::Fr;
+    use ark_std::{
+        format,
+        rand::{rngs::StdRng, SeedableRng},
+        vec,
+        vec::Vec,
+        UniformRand,
+    };
+    use serde_with::serde_as;
+
+    #[serde_as]
+    #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Protocol {
+        #[serde_as(as = "FieldBytes")]
+        public: Fr,
+        #[serde_as(as = "SecretAs<Vec<FieldBytes>>")]
+        witnesses: Secret<Vec<Fr>>,
+    }
+
+    #[test]
+    fn secret() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let value = (0..5).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let mut secret = Secret::new(value.clone());
+        assert_eq!(secret.expose(), &value);
+
+        let protocol = Protocol {
+            public: Fr::rand(&mut rng),
+            witnesses: secret.clone(),
+        };
+        let debug = format!("{:?} {:?}", secret, protocol);
+        assert!(debug.contains("[REDACTED]"));
+        assert!(!debug.contains(&format!("{:?}", value[0])));
+
+        let mut bytes = vec![];
+        let json = serde_json::to_string(&protocol);
+        if SECRETS_SERIALIZABLE {
+            secret.serialize(&mut bytes).unwrap();
+            assert_eq!(Secret::<Vec<Fr>>::deserialize(&bytes[..]).unwrap(), secret);
+            let deser = serde_json::from_str::<Protocol>(&json.unwrap()).unwrap();
+            assert_eq!(deser, protocol);
+        } else {
+            assert!(secret.serialize(&mut bytes).is_err());
+            assert!(secret.serialize_unchecked(&mut bytes).is_err());
+            assert!(secret.serialize_uncompressed(&mut bytes).is_err());
+            assert!(serde_json::to_string(&Secret::new(5u64)).is_err());
+            assert!(json.is_err());
+
+            // Deserialization is allowed
+            value.serialize(&mut bytes).unwrap();
+            assert_eq!(Secret::<Vec<Fr>>::deserialize(&bytes[..]).unwrap(), secret);
+        }
+
+        secret.zeroize();
+        assert!(secret.expose().iter().all(|v| *v == Fr::from(0u64)));
+    }
+}
@@ -5,6 +5,8 @@ use ark_std::{io, string::ToString, vec::Vec};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_with::{DeserializeAs, SerializeAs};
 
+pub use crate::secret::SecretAs;
+
 pub type FieldBytes = AsCanonical;
 pub type AffineGroupBytes = AsCanonical;
 
@@ -0,0 +1,230 @@
+//! A transcript of the public values exchanged in a proof protocol from which the challenges are derived, in the
+//! spirit of [Merlin](https://merlin.cool). Each appended value is labelled and length prefixed, and the transcript
+//! starts with a domain separator so that values cannot be shifted between messages, and proofs made for one
+//! protocol (domain) cannot be replayed for another. Challenges are derived by hashing the transcript, expanding
+//! the hash to 16 bytes more than the size of the field and reducing the result modulo the field order so that
+//! they are (statistically close to) uniform and computed in constant time, unlike try-and-increment.
+//!
+//! A transcript can also be created in compatibility mode where labels and the domain separator are ignored, the
+//! appended values are simply concatenated and a challenge is derived from the concatenation using
+//! try-and-increment as done by [`field_elem_from_try_and_incr`]. This produces the same challenges as the
+//! ad-hoc concatenation of challenge contributions used before this type was introduced.
+
+use crate::hashing_utils::field_elem_from_try_and_incr;
+use ark_ec::AffineCurve;
+use ark_ff::PrimeField;
+use ark_serialize::SerializationError;
+use ark_std::vec::Vec;
+use digest::Digest;
+
+/// Label of the domain separator appended when the transcript is created
+const DOMAIN_SEPARATOR_LABEL: &[u8] = b"dom-sep";
+
+/// Number of bytes more than the field size that are reduced to get a challenge
+const CHALLENGE_EXTRA_BYTES: usize = 16;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transcript {
+    bytes: Vec<u8>,
+    compatible: bool,
+}
+
+impl Transcript {
+    /// Create a new transcript for the protocol identified by `label`
+    pub fn new(label: &'static [u8]) -> Self {
+        let mut transcript = Self {
+            bytes: Vec::new(),
+            compatible: false,
+        };
+        transcript.append_message(DOMAIN_SEPARATOR_LABEL, label);
+        transcript
+    }
+
+    /// Create a new transcript in compatibility mode, i.e. labels are ignored, the appended values are concatenated
+    /// and challenges are derived using try-and-increment.
+    pub fn new_compatible() -> Self {
+        Self {
+            bytes: Vec::new(),
+            compatible: true,
+        }
+    }
+
+    /// Whether the transcript was created in compatibility mode
+    pub fn is_compatible(&self) -> bool {
+        self.compatible
+    }
+
+    /// Append the given bytes with the label. In compatibility mode, only the bytes are appended.
+    pub fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        if !self.compatible {
+            self.bytes
+                .extend_from_slice(&(label.len() as u32).to_le_bytes());
+            self.bytes.extend_from_slice(label);
+            self.bytes
+                .extend_from_slice(&(message.len() as u64).to_le_bytes());
+        }
+        self.bytes.extend_from_slice(message);
+    }
+
+    /// Append the uncompressed serialization of the group element with the label
+    pub fn append_point<G: AffineCurve>(
+        &mut self,
+        label: &'static [u8],
+        point: &G,
+    ) -> Result<(), SerializationError> {
+        let mut bytes = Vec::new();
+        point.serialize_unchecked(&mut bytes)?;
+        self.append_message(label, &bytes);
+        Ok(())
+    }
+
+    /// Append the number of group elements followed by each group element with the label. In compatibility mode, this
+    /// is same as appending the serialization of the slice.
+    pub fn append_points<G: AffineCurve>(
+        &mut self,
+        label: &'static [u8],
+        points: &[G],
+    ) -> Result<(), SerializationError> {
+        self.append_message(label, &(points.len() as u64).to_le_bytes());
+        for point in points {
+            self.append_point(label, point)?;
+        }
+        Ok(())
+    }
+
+    /// Append the serialization of the field element with the label
+    pub fn append_scalar<F: PrimeField>(
+        &mut self,
+        label: &'static [u8],
+        scalar: &F,
+    ) -> Result<(), SerializationError> {
+        let mut bytes = Vec::new();
+        scalar.serialize_unchecked(&mut bytes)?;
+        self.append_message(label, &bytes);
+        Ok(())
+    }
+
+    /// Derive a challenge from everything appended so far. The label and the bytes used for the challenge are
+    /// appended to the transcript so that subsequent challenges are different and depend on this one. In
+    /// compatibility mode, the challenge is computed using try-and-increment over the appended bytes and the
+    /// transcript is left unchanged.
+    pub fn challenge_scalar<F: PrimeField, D: Digest>(&mut self, label: &'static [u8]) -> F {
+        if self.compatible {
+            return field_elem_from_try_and_incr::<F, D>(&self.bytes);
+        }
+        self.bytes
+            .extend_from_slice(&(label.len() as u32).to_le_bytes());
+        self.bytes.extend_from_slice(label);
+        let seed = D::digest(&self.bytes);
+        let len = F::size_in_bits().div_ceil(8) + CHALLENGE_EXTRA_BYTES;
+        let mut wide = Vec::with_capacity(len + seed.len());
+        let mut counter = 0u32;
+        while wide.len() < len {
+            let mut block = seed.to_vec();
+            block.extend_from_slice(&counter.to_le_bytes());
+            wide.extend_from_slice(&D::digest(&block));
+            counter += 1;
+        }
+        wide.truncate(len);
+        let challenge = F::from_le_bytes_mod_order(&wide);
+        self.append_message(label, &wide);
+        challenge
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_381::{Fr, G1Affine, G1Projective};
+    use ark_ec::ProjectiveCurve;
+    use ark_serialize::CanonicalSerialize;
+    use ark_std::{
+        rand::{rngs::StdRng, SeedableRng},
+        UniformRand,
+    };
+    use blake2::Blake2b;
+
+    #[test]
+    fn labelled_challenges() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let point = G1Projective::rand(&mut rng).into_affine();
+        let scalar = Fr::rand(&mut rng);
+
+        let create = |domain: &'static [u8], label: &'static [u8]| {
+            let mut t = Transcript::new(domain);
+            t.append_point(label, &point).unwrap();
+            t.append_scalar(b"scalar", &scalar).unwrap();
+            t.append_message(b"msg", b"hello");
+            t
+        };
+
+        let mut t1 = create(b"test", b"point");
+        let mut t2 = create(b"test", b"point");
+        assert!(!t1.is_compatible());
+        assert_eq!(t1, t2);
+        let c1 = t1.challenge_scalar::<Fr, Blake2b>(b"challenge");
+        assert_eq!(c1, t2.challenge_scalar::<Fr, Blake2b>(b"challenge"));
+
+        // Subsequent challenges are chained to the previous ones
+        let c2 = t1.challenge_scalar::<Fr, Blake2b>(b"challenge");
+        assert_ne!(c1, c2);
+        assert_eq!(c2, t2.challenge_scalar::<Fr, Blake2b>(b"challenge"));
+
+        // Different domain, value label or challenge label give different challenges
+        assert_ne!(
+            create(b"other", b"point").challenge_scalar::<Fr, Blake2b>(b"challenge"),
+            c1
+        );
+        assert_ne!(
+            create(b"test", b"other").challenge_scalar::<Fr, Blake2b>(b"challenge"),
+            c1
+        );
+        assert_ne!(
+            create(b"test", b"point").challenge_scalar::<Fr, Blake2b>(b"other"),
+            c1
+        );
+
+        // Moving bytes between messages changes the challenge
+        let mut t3 = Transcript::new(b"test");
+        t3.append_message(b"msg", b"ab");
+        t3.append_message(b"msg", b"c");
+        let mut t4 = Transcript::new(b"test");
+        t4.append_message(b"msg", b"a");
+        t4.append_message(b"msg", b"bc");
+        assert_ne!(
+            t3.challenge_scalar::<Fr, Blake2b>(b"challenge"),
+            t4.challenge_scalar::<Fr, Blake2b>(b"challenge")
+        );
+    }
+
+    #[test]
+    fn compatible_challenges() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let point = G1Projective::rand(&mut rng).into_affine();
+        let scalar = Fr::rand(&mut rng);
+
+        let mut t = Transcript::new_compatible();
+        assert!(t.is_compatible());
+        t.append_message(b"nonce", b"nonce");
+        t.append_point(b"point", &point).unwrap();
+        t.append_scalar(b"scalar", &scalar).unwrap();
+        t.append_points(b"points", &[point, point]).unwrap();
+
+        let mut bytes = b"nonce".to_vec();
+        point.serialize_unchecked(&mut bytes).unwrap();
+        scalar.serialize_unchecked(&mut bytes).unwrap();
+        vec![point, point].serialize_unchecked(&mut bytes).unwrap();
+
+        let expected = field_elem_from_try_and_incr::<Fr, Blake2b>(&bytes);
+        assert_eq!(t.challenge_scalar::<Fr, Blake2b>(b"challenge"), expected);
+        // Challenge is not chained in compatibility mode
+        assert_eq!(t.challenge_scalar::<Fr, Blake2b>(b"challenge"), expected);
+
+        let mut labelled = Transcript::new(b"test");
+        labelled.append_point::<G1Affine>(b"point", &point).unwrap();
+        assert_ne!(
+            labelled.challenge_scalar::<Fr, Blake2b>(b"challenge"),
+            expected
+        );
+    }
+}
@@ -82,4 +82,21 @@ pub mod tests {
             assert_eq!($obj, deser);
         };
     }
+
+    /// Serialization of objects containing secrets should fail unless the feature `serialize-secrets` is enabled
+    #[macro_export]
+    macro_rules! test_secret_serialization {
+        ($obj_type:ty, $obj: expr) => {
+            if dock_crypto_utils::secret::SECRETS_SERIALIZABLE {
+                test_serialization!($obj_type, $obj);
+            } else {
+                let mut serz = vec![];
+                assert!(CanonicalSerialize::serialize(&$obj, &mut serz).is_err());
+                assert!($obj.serialize_unchecked(&mut serz).is_err());
+                assert!($obj.serialize_uncompressed(&mut serz).is_err());
+                assert!(serde_json::to_string(&$obj).is_err());
+                assert!(rmp_serde::to_vec_named(&$obj).is_err());
+            }
+        };
+    }
 }
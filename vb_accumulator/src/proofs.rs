@@ -13,6 +13,7 @@
 //! use vb_accumulator::positive::{PositiveAccumulator, Accumulator};
 //! use vb_accumulator::witness::MembershipWitness;
 //! use vb_accumulator::proofs::{MembershipProofProtocol, MembershipProvingKey};
+//! use sha2::Sha256;
 //! use vb_accumulator::persistence::State;
 //!
 //! let params = SetupParams::<Bls12_381>::generate_using_rng(&mut rng);
@@ -30,6 +31,8 @@
 //!
 //! // The prover and verifier should agree on the proving key
 //! let prk = MembershipProvingKey::generate_using_rng(&mut rng);
+//! // or create it from a public label using the hash to curve of RFC 9380 with a domain separation tag
+//! let prk = MembershipProvingKey::new_using_hash_to_curve::<Sha256>(b"test", b"MY-APP-ACCUM-PROVING-KEY_XMD:SHA-256_SSWU_RO_");
 //!
 //! // Prover initializes the protocol
 //! let protocol = MembershipProofProtocol::init(
@@ -105,9 +108,13 @@ use ark_std::{
     vec::Vec,
     UniformRand,
 };
-use digest::Digest;
-use dock_crypto_utils::hashing_utils::projective_group_elem_from_try_and_incr;
+use digest::{BlockInput, Digest};
+use dock_crypto_utils::hashing_utils::{
+    projective_group_elem_from_hash_to_curve, projective_group_elem_from_try_and_incr, HashToCurve,
+};
+use dock_crypto_utils::secret::{Secret, SecretAs};
 use dock_crypto_utils::serde_utils::*;
+use dock_crypto_utils::transcript::Transcript;
 use schnorr_pok::error::SchnorrError;
 use schnorr_pok::SchnorrChallengeContributor;
 use zeroize::Zeroize;
@@ -193,6 +200,35 @@ where
 
         ProvingKey { X, Y, Z }
     }
+
+    /// Generate by hashing known strings like `generate_proving_key_using_hash` but using the hash to
+    /// curve of RFC 9380 with domain separation tag `dst`
+    fn generate_proving_key_using_hash_to_curve<D: Digest + BlockInput>(
+        label: &[u8],
+        dst: &[u8],
+    ) -> ProvingKey<G>
+    where
+        G: HashToCurve,
+    {
+        let mut elems: [G::Projective; 3] = [
+            projective_group_elem_from_hash_to_curve::<G, D>(
+                &to_bytes![label, " : X".as_bytes()].unwrap(),
+                dst,
+            ),
+            projective_group_elem_from_hash_to_curve::<G, D>(
+                &to_bytes![label, " : Y".as_bytes()].unwrap(),
+                dst,
+            ),
+            projective_group_elem_from_hash_to_curve::<G, D>(
+                &to_bytes![label, " : Z".as_bytes()].unwrap(),
+                dst,
+            ),
+        ];
+        G::Projective::batch_normalization(&mut elems);
+        let [X, Y, Z] = [elems[0].into(), elems[1].into(), elems[2].into()];
+
+        ProvingKey { X, Y, Z }
+    }
 }
 
 impl<G> MembershipProvingKey<G>
@@ -208,6 +244,17 @@ where
     pub fn new<D: Digest>(label: &[u8]) -> Self {
         Self(ProvingKey::generate_proving_key_using_hash::<D>(label))
     }
+
+    /// Generate by hashing known strings like [`Self::new`] but using the hash to curve of
+    /// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html) with domain separation tag `dst`.
+    pub fn new_using_hash_to_curve<D: Digest + BlockInput>(label: &[u8], dst: &[u8]) -> Self
+    where
+        G: HashToCurve,
+    {
+        Self(ProvingKey::generate_proving_key_using_hash_to_curve::<D>(
+            label, dst,
+        ))
+    }
 }
 
 impl<G> NonMembershipProvingKey<G>
@@ -235,6 +282,25 @@ where
         }
     }
 
+    /// Generate by hashing known strings like [`Self::new`] but using the hash to curve of
+    /// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html) with domain separation tag `dst`.
+    /// The membership proving key derived from it is the one created by `MembershipProvingKey::new_using_hash_to_curve`
+    /// with the same arguments.
+    pub fn new_using_hash_to_curve<D: Digest + BlockInput>(label: &[u8], dst: &[u8]) -> Self
+    where
+        G: HashToCurve,
+    {
+        let XYZ = ProvingKey::generate_proving_key_using_hash_to_curve::<D>(label, dst);
+        Self {
+            XYZ,
+            K: projective_group_elem_from_hash_to_curve::<G, D>(
+                &to_bytes![label, " : K".as_bytes()].unwrap(),
+                dst,
+            )
+            .into(),
+        }
+    }
+
     /// Derive the membership proving key when doing a membership proof with a universal accumulator.
     pub fn derive_membership_proving_key(&self) -> MembershipProvingKey<G> {
         MembershipProvingKey(self.XYZ.clone())
@@ -401,8 +467,8 @@ pub struct MembershipProof<E: PairingEngine> {
     Clone, PartialEq, Eq, Debug, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
 )]
 pub struct MembershipProofProtocol<E: PairingEngine> {
-    #[serde_as(as = "FieldBytes")]
-    pub element: E::Fr,
+    #[serde_as(as = "SecretAs<FieldBytes>")]
+    pub element: Secret<E::Fr>,
     #[serde(
         bound = "MembershipRandomizedWitness<E::G1Affine>: Serialize, for<'a> MembershipRandomizedWitness<E::G1Affine>: Deserialize<'a>"
     )]
@@ -414,7 +480,7 @@ pub struct MembershipProofProtocol<E: PairingEngine> {
     #[serde(
         bound = "MembershipSchnorrResponse<E::Fr>: Serialize, for<'a> MembershipSchnorrResponse<E::Fr>: Deserialize<'a>"
     )]
-    pub schnorr_blindings: MembershipBlindings<E::Fr>,
+    pub schnorr_blindings: Secret<MembershipBlindings<E::Fr>>,
 }
 
 /// Randomized non-membership witness
@@ -517,10 +583,10 @@ pub struct NonMembershipProof<E: PairingEngine> {
     Clone, PartialEq, Eq, Debug, CanonicalSerialize, CanonicalDeserialize, Serialize, Deserialize,
 )]
 pub struct NonMembershipProofProtocol<E: PairingEngine> {
-    #[serde_as(as = "FieldBytes")]
-    pub element: E::Fr,
-    #[serde_as(as = "FieldBytes")]
-    pub d: E::Fr,
+    #[serde_as(as = "SecretAs<FieldBytes>")]
+    pub element: Secret<E::Fr>,
+    #[serde_as(as = "SecretAs<FieldBytes>")]
+    pub d: Secret<E::Fr>,
     #[serde(
         bound = "NonMembershipRandomizedWitness<E::G1Affine>: Serialize, for<'a> NonMembershipRandomizedWitness<E::G1Affine>: Deserialize<'a>"
     )]
@@ -532,7 +598,7 @@ pub struct NonMembershipProofProtocol<E: PairingEngine> {
     #[serde(
         bound = "NonMembershipBlindings<E::Fr>: Serialize, for<'a> NonMembershipBlindings<E::Fr>: Deserialize<'a>"
     )]
-    pub schnorr_blindings: NonMembershipBlindings<E::Fr>,
+    pub schnorr_blindings: Secret<NonMembershipBlindings<E::Fr>>,
 }
 
 impl<G> SchnorrChallengeContributor for RandomizedWitness<G>
@@ -546,6 +612,17 @@ where
             .serialize_unchecked(&mut writer)
             .map_err(|e| e.into())
     }
+
+    fn append_to_transcript(
+        &self,
+        label: &'static [u8],
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        transcript.append_point(label, &self.E_C)?;
+        transcript.append_point(label, &self.T_sigma)?;
+        transcript.append_point(label, &self.T_rho)?;
+        Ok(())
+    }
 }
 
 impl<E> SchnorrChallengeContributor for SchnorrCommit<E>
@@ -561,6 +638,21 @@ where
             .serialize_unchecked(&mut writer)
             .map_err(|e| e.into())
     }
+
+    fn append_to_transcript(
+        &self,
+        label: &'static [u8],
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        let mut R_E = Vec::new();
+        self.R_E.serialize_unchecked(&mut R_E)?;
+        transcript.append_message(label, &R_E);
+        transcript.append_point(label, &self.R_sigma)?;
+        transcript.append_point(label, &self.R_rho)?;
+        transcript.append_point(label, &self.R_delta_sigma)?;
+        transcript.append_point(label, &self.R_delta_rho)?;
+        Ok(())
+    }
 }
 
 impl<G> SchnorrChallengeContributor for MembershipRandomizedWitness<G>
@@ -570,6 +662,14 @@ where
     fn challenge_contribution<W: Write>(&self, writer: W) -> Result<(), SchnorrError> {
         self.0.challenge_contribution(writer)
     }
+
+    fn append_to_transcript(
+        &self,
+        label: &'static [u8],
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        self.0.append_to_transcript(label, transcript)
+    }
 }
 
 impl<E> SchnorrChallengeContributor for MembershipSchnorrCommit<E>
@@ -579,6 +679,14 @@ where
     fn challenge_contribution<W: Write>(&self, writer: W) -> Result<(), SchnorrError> {
         self.0.challenge_contribution(writer)
     }
+
+    fn append_to_transcript(
+        &self,
+        label: &'static [u8],
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        self.0.append_to_transcript(label, transcript)
+    }
 }
 
 impl<G> SchnorrChallengeContributor for NonMembershipRandomizedWitness<G>
@@ -592,6 +700,17 @@ where
             .serialize_unchecked(&mut writer)
             .map_err(|e| e.into())
     }
+
+    fn append_to_transcript(
+        &self,
+        label: &'static [u8],
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        self.C.append_to_transcript(label, transcript)?;
+        transcript.append_point(label, &self.E_d)?;
+        transcript.append_point(label, &self.E_d_inv)?;
+        Ok(())
+    }
 }
 
 impl<E> SchnorrChallengeContributor for NonMembershipSchnorrCommit<E>
@@ -605,6 +724,17 @@ where
             .serialize_unchecked(&mut writer)
             .map_err(|e| e.into())
     }
+
+    fn append_to_transcript(
+        &self,
+        label: &'static [u8],
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        self.C.append_to_transcript(label, transcript)?;
+        transcript.append_point(label, &self.R_A)?;
+        transcript.append_point(label, &self.R_B)?;
+        Ok(())
+    }
 }
 
 impl<G: AffineCurve> SchnorrChallengeContributor for ProvingKey<G> {
@@ -615,12 +745,31 @@ impl<G: AffineCurve> SchnorrChallengeContributor for ProvingKey<G> {
             .serialize_unchecked(&mut writer)
             .map_err(|e| e.into())
     }
+
+    fn append_to_transcript(
+        &self,
+        label: &'static [u8],
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        transcript.append_point(label, &self.X)?;
+        transcript.append_point(label, &self.Y)?;
+        transcript.append_point(label, &self.Z)?;
+        Ok(())
+    }
 }
 
 impl<G: AffineCurve> SchnorrChallengeContributor for MembershipProvingKey<G> {
     fn challenge_contribution<W: Write>(&self, writer: W) -> Result<(), SchnorrError> {
         self.0.challenge_contribution(writer)
     }
+
+    fn append_to_transcript(
+        &self,
+        label: &'static [u8],
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        self.0.append_to_transcript(label, transcript)
+    }
 }
 
 impl<G: AffineCurve> SchnorrChallengeContributor for NonMembershipProvingKey<G> {
@@ -630,6 +779,16 @@ impl<G: AffineCurve> SchnorrChallengeContributor for NonMembershipProvingKey<G>
             .serialize_unchecked(&mut writer)
             .map_err(|e| e.into())
     }
+
+    fn append_to_transcript(
+        &self,
+        label: &'static [u8],
+        transcript: &mut Transcript,
+    ) -> Result<(), SchnorrError> {
+        self.XYZ.append_to_transcript(label, transcript)?;
+        transcript.append_point(label, &self.K)?;
+        Ok(())
+    }
 }
 
 impl<F: PrimeField + SquareRootField> SchnorrResponse<F> {
@@ -822,6 +981,33 @@ pub(crate) trait ProofProtocol<E: PairingEngine> {
             .map_err(|e| e.into())
     }
 
+    /// Append the values of [`compute_challenge_contribution`] to the transcript with a label each
+    ///
+    /// [`compute_challenge_contribution`]: ProofProtocol::compute_challenge_contribution
+    fn compute_transcript_contribution(
+        randomized_witness: &impl SchnorrChallengeContributor,
+        schnorr_commit: &impl SchnorrChallengeContributor,
+        accumulator_value: &E::G1Affine,
+        pk: &PublicKey<E::G2Affine>,
+        params: &SetupParams<E>,
+        prk: &impl SchnorrChallengeContributor,
+        transcript: &mut Transcript,
+    ) -> Result<(), VBAccumulatorError> {
+        randomized_witness.append_to_transcript(b"randomized_witness", transcript)?;
+        schnorr_commit.append_to_transcript(b"schnorr_commit", transcript)?;
+        transcript.append_point(b"accumulator_value", accumulator_value)?;
+        transcript.append_point(b"public_key", &pk.0)?;
+        transcript.append_point(b"P", &params.P)?;
+        transcript.append_point(b"P_tilde", &params.P_tilde)?;
+        if transcript.is_compatible() {
+            // The challenge contribution has the setup params twice
+            transcript.append_point(b"P", &params.P)?;
+            transcript.append_point(b"P_tilde", &params.P_tilde)?;
+        }
+        prk.append_to_transcript(b"proving_key", transcript)?;
+        Ok(())
+    }
+
     /// Compute responses for the Schnorr protocols
     fn compute_responses(
         element: &E::Fr,
@@ -984,23 +1170,51 @@ pub(crate) trait ProofProtocol<E: PairingEngine> {
         T_sigma_table: &[E::G1Projective],
         T_rho_table: &[E::G1Projective],
     ) -> Result<(), VBAccumulatorError> {
+        let (R_sigma, R_rho, R_delta_sigma, R_delta_rho) = Self::compute_schnorr_commitments(
+            schnorr_response,
+            challenge,
+            context,
+            X_table,
+            Y_table,
+            T_sigma_table,
+            T_rho_table,
+        );
+        if R_sigma != schnorr_commit.R_sigma {
+            return Err(VBAccumulatorError::SigmaResponseInvalid);
+        }
+        if R_rho != schnorr_commit.R_rho {
+            return Err(VBAccumulatorError::RhoResponseInvalid);
+        }
+        if R_delta_sigma != schnorr_commit.R_delta_sigma {
+            return Err(VBAccumulatorError::DeltaSigmaResponseInvalid);
+        }
+        if R_delta_rho != schnorr_commit.R_delta_rho {
+            return Err(VBAccumulatorError::DeltaRhoResponseInvalid);
+        }
+        Ok(())
+    }
+
+    /// Compute `R_sigma`, `R_rho`, `R_delta_sigma` and `R_delta_rho` from the responses and the challenge
+    fn compute_schnorr_commitments(
+        schnorr_response: &SchnorrResponse<E::Fr>,
+        challenge: &E::Fr,
+        context: &WnafContext,
+        X_table: &[E::G1Projective],
+        Y_table: &[E::G1Projective],
+        T_sigma_table: &[E::G1Projective],
+        T_rho_table: &[E::G1Projective],
+    ) -> (E::G1Affine, E::G1Affine, E::G1Affine, E::G1Affine) {
         // R_sigma = schnorr_response.s_sigma * prk.X - challenge * randomized_witness.T_sigma
         let mut R_sigma = context
             .mul_with_table(&X_table, &schnorr_response.s_sigma)
             .unwrap();
         R_sigma -= context.mul_with_table(&T_sigma_table, challenge).unwrap();
-        if R_sigma.into_affine() != schnorr_commit.R_sigma {
-            return Err(VBAccumulatorError::SigmaResponseInvalid);
-        }
 
         // R_rho = schnorr_response.s_rho * prk.Y - challenge * randomized_witness.T_rho;
         let mut R_rho = context
             .mul_with_table(&Y_table, &schnorr_response.s_rho)
             .unwrap();
         R_rho -= context.mul_with_table(&T_rho_table, challenge).unwrap();
-        if R_rho.into_affine() != schnorr_commit.R_rho {
-            return Err(VBAccumulatorError::RhoResponseInvalid);
-        }
 
         // R_delta_sigma = schnorr_response.s_y * randomized_witness.T_sigma - schnorr_response.s_delta_sigma * prk.X;
         let mut R_delta_sigma = context
@@ -1009,9 +1223,6 @@ pub(crate) trait ProofProtocol<E: PairingEngine> {
         R_delta_sigma -= context
             .mul_with_table(&X_table, &schnorr_response.s_delta_sigma)
             .unwrap();
-        if R_delta_sigma.into_affine() != schnorr_commit.R_delta_sigma {
-            return Err(VBAccumulatorError::DeltaSigmaResponseInvalid);
-        }
 
         // R_delta_rho = schnorr_response.s_y * randomized_witness.T_rho - schnorr_response.s_delta_rho * prk.Y;
         let mut R_delta_rho = context
@@ -1020,10 +1231,76 @@ pub(crate) trait ProofProtocol<E: PairingEngine> {
         R_delta_rho -= context
             .mul_with_table(&Y_table, &schnorr_response.s_delta_rho)
             .unwrap();
-        if R_delta_rho.into_affine() != schnorr_commit.R_delta_rho {
-            return Err(VBAccumulatorError::DeltaRhoResponseInvalid);
-        }
-        Ok(())
+        (
+            R_sigma.into_affine(),
+            R_rho.into_affine(),
+            R_delta_sigma.into_affine(),
+            R_delta_rho.into_affine(),
+        )
+    }
+
+    /// Simulate the randomized witness, Schnorr commitments and responses for the given challenge without
+    /// knowing the (non)member or the witness. The randomized witness consists of commitments which are uniformly
+    /// random so random group elements are chosen for them. Then the responses are chosen randomly and the
+    /// commitments to randomness are computed from them like the verifier does.
+    /// `pairing_extra` is the same as in [`verify_proof`](ProofProtocol::verify_proof)
+    fn simulate_randomized_witness_and_schnorr_proof<R: RngCore>(
+        rng: &mut R,
+        pairing_extra: Option<E::G1Projective>,
+        accumulator_value: &E::G1Affine,
+        challenge: &E::Fr,
+        pk: &PublicKey<E::G2Affine>,
+        params: &SetupParams<E>,
+        prk: &ProvingKey<E::G1Affine>,
+    ) -> (
+        RandomizedWitness<E::G1Affine>,
+        SchnorrCommit<E>,
+        SchnorrResponse<E::Fr>,
+    ) {
+        let randomized_witness = RandomizedWitness {
+            E_C: E::G1Projective::rand(rng).into_affine(),
+            T_sigma: E::G1Projective::rand(rng).into_affine(),
+            T_rho: E::G1Projective::rand(rng).into_affine(),
+        };
+        let schnorr_response = SchnorrResponse {
+            s_y: E::Fr::rand(rng),
+            s_sigma: E::Fr::rand(rng),
+            s_rho: E::Fr::rand(rng),
+            s_delta_sigma: E::Fr::rand(rng),
+            s_delta_rho: E::Fr::rand(rng),
+        };
+        let (context, X_table, Y_table, Z_table, T_sigma_table, T_rho_table, E_C_table) =
+            Self::get_tables(prk, &randomized_witness);
+        let (R_sigma, R_rho, R_delta_sigma, R_delta_rho) = Self::compute_schnorr_commitments(
+            &schnorr_response,
+            challenge,
+            &context,
+            &X_table,
+            &Y_table,
+            &T_sigma_table,
+            &T_rho_table,
+        );
+        let (p, q) = Self::get_g1_for_pairing_checks(
+            &schnorr_response,
+            pairing_extra,
+            accumulator_value,
+            challenge,
+            &context,
+            &E_C_table,
+            &Z_table,
+        );
+        let R_E = pairing_product::<E>(&[p, q], &[params.P_tilde, pk.0]);
+        (
+            randomized_witness,
+            SchnorrCommit {
+                R_E,
+                R_sigma,
+                R_rho,
+                R_delta_sigma,
+                R_delta_rho,
+            },
+            schnorr_response,
+        )
     }
 
     fn get_g1_for_pairing_checks(
@@ -1102,10 +1379,10 @@ where
             &prk.0,
         );
         Self {
-            element: *element,
+            element: Secret::new(*element),
             randomized_witness: MembershipRandomizedWitness(rw),
             schnorr_commit: MembershipSchnorrCommit(sc),
-            schnorr_blindings: MembershipBlindings(bl),
+            schnorr_blindings: Secret::new(MembershipBlindings(bl)),
         }
     }
 
@@ -1132,11 +1409,35 @@ where
         )
     }
 
+    /// Contribution of this protocol to the overall challenge appended to the transcript
+    pub fn append_to_transcript(
+        &self,
+        accumulator_value: &E::G1Affine,
+        pk: &PublicKey<E::G2Affine>,
+        params: &SetupParams<E>,
+        prk: &MembershipProvingKey<E::G1Affine>,
+        transcript: &mut Transcript,
+    ) -> Result<(), VBAccumulatorError> {
+        Self::compute_transcript_contribution(
+            &self.randomized_witness,
+            &self.schnorr_commit,
+            accumulator_value,
+            pk,
+            params,
+            &prk.0,
+            transcript,
+        )
+    }
+
     /// Create membership proof once the overall challenge is ready. Delegates to [`compute_responses`]
     ///
     /// [`compute_responses`]: ProofProtocol::compute_responses
     pub fn gen_proof(self, challenge: &E::Fr) -> MembershipProof<E> {
-        let resp = Self::compute_responses(&self.element, &self.schnorr_blindings.0, challenge);
+        let resp = Self::compute_responses(
+            self.element.expose(),
+            &self.schnorr_blindings.expose().0,
+            challenge,
+        );
         MembershipProof {
             randomized_witness: self.randomized_witness.clone(),
             schnorr_commit: self.schnorr_commit.clone(),
@@ -1228,8 +1529,8 @@ where
         );
 
         Self {
-            element: *element,
-            d: witness.d,
+            element: Secret::new(*element),
+            d: Secret::new(witness.d),
             randomized_witness: NonMembershipRandomizedWitness {
                 C: rw,
                 E_d: E_d.into_affine(),
@@ -1240,14 +1541,14 @@ where
                 R_A: R_A.into_affine(),
                 R_B: R_B.into_affine(),
             },
-            schnorr_blindings: NonMembershipBlindings {
+            schnorr_blindings: Secret::new(NonMembershipBlindings {
                 C: bl,
                 tau,
                 pi,
                 r_u,
                 r_v,
                 r_w,
-            },
+            }),
         }
     }
 
@@ -1274,18 +1575,39 @@ where
         )
     }
 
+    /// Contribution of this protocol to the overall challenge appended to the transcript
+    pub fn append_to_transcript(
+        &self,
+        accumulator_value: &E::G1Affine,
+        pk: &PublicKey<E::G2Affine>,
+        params: &SetupParams<E>,
+        prk: &NonMembershipProvingKey<E::G1Affine>,
+        transcript: &mut Transcript,
+    ) -> Result<(), VBAccumulatorError> {
+        Self::compute_transcript_contribution(
+            &self.randomized_witness,
+            &self.schnorr_commit,
+            accumulator_value,
+            pk,
+            params,
+            &prk.XYZ,
+            transcript,
+        )
+    }
+
     /// Create membership proof once the overall challenge is ready. Computes the response for `witness.d`
     /// and then delegates to [`compute_responses`]
     ///
     /// [`compute_responses`]: ProofProtocol::compute_responses
     pub fn gen_proof(self, challenge: &E::Fr) -> NonMembershipProof<E> {
         // For d != 0
-        let challenge_times_d = *challenge * self.d;
-        let s_u = self.schnorr_blindings.r_u + challenge_times_d;
-        let s_v = self.schnorr_blindings.r_v + (*challenge * self.schnorr_blindings.tau);
-        let s_w = self.schnorr_blindings.r_w - (challenge_times_d * self.schnorr_blindings.pi);
+        let blindings = self.schnorr_blindings.expose();
+        let challenge_times_d = *challenge * self.d.expose();
+        let s_u = blindings.r_u + challenge_times_d;
+        let s_v = blindings.r_v + (*challenge * blindings.tau);
+        let s_w = blindings.r_w - (challenge_times_d * blindings.pi);
 
-        let resp = Self::compute_responses(&self.element, &self.schnorr_blindings.C, challenge);
+        let resp = Self::compute_responses(self.element.expose(), &blindings.C, challenge);
 
         NonMembershipProof {
             randomized_witness: self.randomized_witness.clone(),
@@ -1339,6 +1661,26 @@ where
         )
     }
 
+    /// Challenge contribution for this proof appended to the transcript
+    pub fn append_to_transcript(
+        &self,
+        accumulator_value: &E::G1Affine,
+        pk: &PublicKey<E::G2Affine>,
+        params: &SetupParams<E>,
+        prk: &MembershipProvingKey<E::G1Affine>,
+        transcript: &mut Transcript,
+    ) -> Result<(), VBAccumulatorError> {
+        MembershipProofProtocol::compute_transcript_contribution(
+            &self.randomized_witness,
+            &self.schnorr_commit,
+            accumulator_value,
+            pk,
+            params,
+            &prk.0,
+            transcript,
+        )
+    }
+
     /// Verify this proof. Delegates to [`verify_proof`]
     ///
     /// [`verify_proof`]: ProofProtocol::verify_proof
@@ -1391,6 +1733,34 @@ where
     pub fn get_schnorr_response_for_element(&self) -> &E::Fr {
         self.schnorr_response.0.get_response_for_element()
     }
+
+    /// Simulate the proof for the given challenge without knowing a member or its witness. Delegates to
+    /// [`simulate_randomized_witness_and_schnorr_proof`]
+    ///
+    /// [`simulate_randomized_witness_and_schnorr_proof`]: ProofProtocol::simulate_randomized_witness_and_schnorr_proof
+    pub fn simulate<R: RngCore>(
+        rng: &mut R,
+        accumulator_value: &E::G1Affine,
+        challenge: &E::Fr,
+        pk: &PublicKey<E::G2Affine>,
+        params: &SetupParams<E>,
+        prk: &MembershipProvingKey<E::G1Affine>,
+    ) -> Self {
+        let (rw, sc, resp) = <MembershipProofProtocol<E> as ProofProtocol<E>>::simulate_randomized_witness_and_schnorr_proof(
+            rng,
+            None,
+            accumulator_value,
+            challenge,
+            pk,
+            params,
+            &prk.0,
+        );
+        Self {
+            randomized_witness: MembershipRandomizedWitness(rw),
+            schnorr_commit: MembershipSchnorrCommit(sc),
+            schnorr_response: MembershipSchnorrResponse(resp),
+        }
+    }
 }
 
 impl<E> NonMembershipProof<E>
@@ -1417,6 +1787,26 @@ where
         )
     }
 
+    /// Challenge contribution for this proof appended to the transcript
+    pub fn append_to_transcript(
+        &self,
+        accumulator_value: &E::G1Affine,
+        pk: &PublicKey<E::G2Affine>,
+        params: &SetupParams<E>,
+        prk: &NonMembershipProvingKey<E::G1Affine>,
+        transcript: &mut Transcript,
+    ) -> Result<(), VBAccumulatorError> {
+        NonMembershipProofProtocol::compute_transcript_contribution(
+            &self.randomized_witness,
+            &self.schnorr_commit,
+            accumulator_value,
+            pk,
+            params,
+            &prk.XYZ,
+            transcript,
+        )
+    }
+
     /// Verify this proof. Verify the responses for the relation `witness.d != 0` and then delegates
     /// to [`verify_proof`]
     ///
@@ -1506,46 +1896,133 @@ where
         P_table: &[E::G1Projective],
         E_d_table: &[E::G1Projective],
     ) -> Result<(), VBAccumulatorError> {
+        let (R_A, R_B) = Self::compute_schnorr_commitments_for_d(
+            &self.randomized_witness.E_d_inv,
+            &self.schnorr_response,
+            challenge,
+            context,
+            K_table,
+            P_table,
+            E_d_table,
+        );
+        if R_A != self.schnorr_commit.R_A {
+            return Err(VBAccumulatorError::E_d_ResponseInvalid);
+        }
+        if R_B != self.schnorr_commit.R_B {
+            return Err(VBAccumulatorError::E_d_inv_ResponseInvalid);
+        }
+        Ok(())
+    }
+
+    pub fn get_pairing_contribution(
+        &self,
+        challenge: &E::Fr,
+        context: &WnafContext,
+        K_table: &[E::G1Projective],
+        E_d_table: &[E::G1Projective],
+    ) -> E::G1Projective {
+        Self::compute_pairing_contribution(
+            &self.schnorr_response.s_v,
+            challenge,
+            context,
+            K_table,
+            E_d_table,
+        )
+    }
+
+    /// Simulate the proof for the given challenge without knowing a non-member or its witness. The commitments
+    /// `E_d` and `E_d_inv` are chosen randomly and `R_A` and `R_B` are computed from random responses. Then
+    /// delegates to [`simulate_randomized_witness_and_schnorr_proof`]
+    ///
+    /// [`simulate_randomized_witness_and_schnorr_proof`]: ProofProtocol::simulate_randomized_witness_and_schnorr_proof
+    pub fn simulate<R: RngCore>(
+        rng: &mut R,
+        accumulator_value: &E::G1Affine,
+        challenge: &E::Fr,
+        pk: &PublicKey<E::G2Affine>,
+        params: &SetupParams<E>,
+        prk: &NonMembershipProvingKey<E::G1Affine>,
+    ) -> Self {
+        let E_d = E::G1Projective::rand(rng).into_affine();
+        let E_d_inv = E::G1Projective::rand(rng).into_affine();
+        let s_u = E::Fr::rand(rng);
+        let s_v = E::Fr::rand(rng);
+        let s_w = E::Fr::rand(rng);
+        let (context, K_table, P_table, E_d_table) = Self::get_tables(prk, params, &E_d);
+        let pairing_extra =
+            Self::compute_pairing_contribution(&s_v, challenge, &context, &K_table, &E_d_table);
+        let (rw, sc, resp) = <NonMembershipProofProtocol<E> as ProofProtocol<E>>::simulate_randomized_witness_and_schnorr_proof(
+            rng,
+            Some(pairing_extra),
+            accumulator_value,
+            challenge,
+            pk,
+            params,
+            &prk.XYZ,
+        );
+        let schnorr_response = NonMembershipSchnorrResponse {
+            C: resp,
+            s_u,
+            s_v,
+            s_w,
+        };
+        let (R_A, R_B) = Self::compute_schnorr_commitments_for_d(
+            &E_d_inv,
+            &schnorr_response,
+            challenge,
+            &context,
+            &K_table,
+            &P_table,
+            &E_d_table,
+        );
+        Self {
+            randomized_witness: NonMembershipRandomizedWitness {
+                C: rw,
+                E_d,
+                E_d_inv,
+            },
+            schnorr_commit: NonMembershipSchnorrCommit { C: sc, R_A, R_B },
+            schnorr_response,
+        }
+    }
+
+    /// Compute `R_A` and `R_B` for the relations proving `d != 0` from the responses and the challenge
+    fn compute_schnorr_commitments_for_d(
+        E_d_inv: &E::G1Affine,
+        schnorr_response: &NonMembershipSchnorrResponse<E::Fr>,
+        challenge: &E::Fr,
+        context: &WnafContext,
+        K_table: &[E::G1Projective],
+        P_table: &[E::G1Projective],
+        E_d_table: &[E::G1Projective],
+    ) -> (E::G1Affine, E::G1Affine) {
         // R_A = schnorr_response.s_u * params.P + schnorr_response.s_v * prk.K - challenge * randomized_witness.E_d;
         let mut R_A = context
-            .mul_with_table(&P_table, &self.schnorr_response.s_u)
+            .mul_with_table(&P_table, &schnorr_response.s_u)
             .unwrap();
         R_A += context
-            .mul_with_table(&K_table, &self.schnorr_response.s_v)
+            .mul_with_table(&K_table, &schnorr_response.s_v)
             .unwrap();
         R_A -= context.mul_with_table(&E_d_table, challenge).unwrap();
 
-        if R_A.into_affine() != self.schnorr_commit.R_A {
-            return Err(VBAccumulatorError::E_d_ResponseInvalid);
-        }
-
         // R_B = schnorr_response.s_w * prk.K + schnorr_response.s_u * randomized_witness.E_d_inv - challenge * params.P;
         let mut R_B = context
-            .mul_with_table(&K_table, &self.schnorr_response.s_w)
+            .mul_with_table(&K_table, &schnorr_response.s_w)
             .unwrap();
-        R_B += self
-            .randomized_witness
-            .E_d_inv
-            .mul(self.schnorr_response.s_u.into_repr());
+        R_B += E_d_inv.mul(schnorr_response.s_u.into_repr());
         R_B -= context.mul_with_table(&P_table, challenge).unwrap();
-
-        if R_B.into_affine() != self.schnorr_commit.R_B {
-            return Err(VBAccumulatorError::E_d_inv_ResponseInvalid);
-        }
-        Ok(())
+        (R_A.into_affine(), R_B.into_affine())
     }
 
-    pub fn get_pairing_contribution(
-        &self,
+    fn compute_pairing_contribution(
+        s_v: &E::Fr,
         challenge: &E::Fr,
         context: &WnafContext,
         K_table: &[E::G1Projective],
         E_d_table: &[E::G1Projective],
     ) -> E::G1Projective {
         // -schnorr_response.s_v * prk.K + challenge * randomized_witness.E_d
-        context
-            .mul_with_table(&K_table, &-self.schnorr_response.s_v)
-            .unwrap()
+        context.mul_with_table(&K_table, &-*s_v).unwrap()
             + context.mul_with_table(&E_d_table, challenge).unwrap()
     }
 
@@ -1569,13 +2046,14 @@ where
 mod tests {
     use super::*;
     use crate::positive::{tests::setup_positive_accum, Accumulator};
-    use crate::test_serialization;
     use crate::universal::tests::setup_universal_accum;
+    use crate::{test_secret_serialization, test_serialization};
 
-    use ark_bls12_381::Bls12_381;
-    use ark_std::{rand::rngs::StdRng, rand::SeedableRng, UniformRand};
+    use ark_bls12_381::{Bls12_381, G1Affine};
+    use ark_std::{rand::rngs::StdRng, rand::SeedableRng, UniformRand, Zero};
     use blake2::Blake2b;
     use schnorr_pok::compute_random_oracle_challenge;
+    use sha2::Sha256;
     use std::time::{Duration, Instant};
 
     type Fr = <Bls12_381 as PairingEngine>::Fr;
@@ -1632,7 +2110,7 @@ mod tests {
             );
             proof_create_duration += start.elapsed();
 
-            test_serialization!(MembershipProofProtocol<Bls12_381>, protocol);
+            test_secret_serialization!(MembershipProofProtocol<Bls12_381>, protocol);
 
             let mut chal_bytes_prover = vec![];
             protocol
@@ -1772,7 +2250,7 @@ mod tests {
             );
             proof_create_duration += start.elapsed();
 
-            test_serialization!(NonMembershipProofProtocol<Bls12_381>, protocol);
+            test_secret_serialization!(NonMembershipProofProtocol<Bls12_381>, protocol);
 
             let mut chal_bytes_prover = vec![];
             protocol
@@ -1851,4 +2329,349 @@ mod tests {
             count, proof_verif_with_rand_pair_check_duration
         );
     }
+
+    #[test]
+    fn proving_keys_using_hash_to_curve() {
+        let mut rng = StdRng::seed_from_u64(0u64);
+        let dst = b"VB-ACCUM-TEST-PROVING-KEY_XMD:SHA-256_SSWU_RO_";
+
+        let mem_prk =
+            MembershipProvingKey::<G1Affine>::new_using_hash_to_curve::<Sha256>(b"test", dst);
+        let non_mem_prk =
+            NonMembershipProvingKey::<G1Affine>::new_using_hash_to_curve::<Sha256>(b"test", dst);
+        for g in [&mem_prk.0.X, &mem_prk.0.Y, &mem_prk.0.Z, &non_mem_prk.K] {
+            assert!(!g.is_zero());
+            assert!(g.is_in_correct_subgroup_assuming_on_curve());
+        }
+        assert_eq!(non_mem_prk.derive_membership_proving_key(), mem_prk);
+        assert_eq!(
+            mem_prk,
+            MembershipProvingKey::<G1Affine>::new_using_hash_to_curve::<Sha256>(b"test", dst)
+        );
+        assert_ne!(
+            mem_prk,
+            MembershipProvingKey::<G1Affine>::new_using_hash_to_curve::<Sha256>(b"test1", dst)
+        );
+        assert_ne!(
+            mem_prk,
+            MembershipProvingKey::<G1Affine>::new_using_hash_to_curve::<Sha256>(
+                b"test",
+                b"VB-ACCUM-TEST-PROVING-KEY-OTHER_XMD:SHA-256_SSWU_RO_"
+            )
+        );
+        assert_ne!(
+            mem_prk,
+            MembershipProvingKey::<G1Affine>::new::<Sha256>(b"test")
+        );
+        assert_ne!(
+            non_mem_prk,
+            NonMembershipProvingKey::<G1Affine>::new::<Sha256>(b"test")
+        );
+
+        test_serialization!(MembershipProvingKey<G1Affine>, mem_prk);
+        test_serialization!(NonMembershipProvingKey<G1Affine>, non_mem_prk);
+
+        // Proofs work with the proving keys
+        let (params, keypair, mut accumulator, mut state) = setup_positive_accum(&mut rng);
+        let member = Fr::rand(&mut rng);
+        accumulator = accumulator
+            .add(member, &keypair.secret_key, &mut state)
+            .unwrap();
+        let mem_wit = accumulator
+            .get_membership_witness(&member, &keypair.secret_key, &state)
+            .unwrap();
+        let protocol = MembershipProofProtocol::init(
+            &mut rng,
+            &member,
+            None,
+            &mem_wit,
+            &keypair.public_key,
+            &params,
+            &mem_prk,
+        );
+        let mut chal_bytes = vec![];
+        protocol
+            .challenge_contribution(
+                accumulator.value(),
+                &keypair.public_key,
+                &params,
+                &mem_prk,
+                &mut chal_bytes,
+            )
+            .unwrap();
+        let challenge = compute_random_oracle_challenge::<Fr, Blake2b>(&chal_bytes);
+        protocol
+            .gen_proof(&challenge)
+            .verify(
+                accumulator.value(),
+                &challenge,
+                &keypair.public_key,
+                &params,
+                &mem_prk,
+            )
+            .unwrap();
+
+        let (params, keypair, accumulator, _, mut state) = setup_universal_accum(&mut rng, 100);
+        let non_member = Fr::rand(&mut rng);
+        let non_mem_wit = accumulator
+            .get_non_membership_witness(&non_member, &keypair.secret_key, &mut state, &params)
+            .unwrap();
+        let protocol = NonMembershipProofProtocol::init(
+            &mut rng,
+            &non_member,
+            None,
+            &non_mem_wit,
+            &keypair.public_key,
+            &params,
+            &non_mem_prk,
+        );
+        let mut chal_bytes = vec![];
+        protocol
+            .challenge_contribution(
+                accumulator.value(),
+                &keypair.public_key,
+                &params,
+                &non_mem_prk,
+                &mut chal_bytes,
+            )
+            .unwrap();
+        let challenge = compute_random_oracle_challenge::<Fr, Blake2b>(&chal_bytes);
+        protocol
+            .gen_proof(&challenge)
+            .verify(
+                accumulator.value(),
+                &challenge,
+                &keypair.public_key,
+                &params,
+                &non_mem_prk,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn simulated_membership_and_non_membership_proofs() {
+        // Simulated proofs are accepted for the challenge they are simulated for
+        let mut rng = StdRng::seed_from_u64(0u64);
+
+        let (params, keypair, accumulator, _) = setup_positive_accum(&mut rng);
+        let prk = MembershipProvingKey::generate_using_rng(&mut rng);
+        let challenge = Fr::rand(&mut rng);
+        let proof = MembershipProof::simulate(
+            &mut rng,
+            accumulator.value(),
+            &challenge,
+            &keypair.public_key,
+            &params,
+            &prk,
+        );
+        proof
+            .verify(
+                accumulator.value(),
+                &challenge,
+                &keypair.public_key,
+                &params,
+                &prk,
+            )
+            .unwrap();
+        assert!(proof
+            .verify(
+                accumulator.value(),
+                &Fr::rand(&mut rng),
+                &keypair.public_key,
+                &params,
+                &prk,
+            )
+            .is_err());
+
+        let (params, keypair, accumulator, _, _) = setup_universal_accum(&mut rng, 100);
+        let prk = NonMembershipProvingKey::generate_using_rng(&mut rng);
+        let proof = NonMembershipProof::simulate(
+            &mut rng,
+            accumulator.value(),
+            &challenge,
+            &keypair.public_key,
+            &params,
+            &prk,
+        );
+        proof
+            .verify(
+                accumulator.value(),
+                &challenge,
+                &keypair.public_key,
+                &params,
+                &prk,
+            )
+            .unwrap();
+        assert!(proof
+            .verify(
+                accumulator.value(),
+                &Fr::rand(&mut rng),
+                &keypair.public_key,
+                &params,
+                &prk,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn membership_and_non_membership_proofs_with_transcript() {
+        // Challenge is derived from a transcript, with and without compatibility mode
+        let mut rng = StdRng::seed_from_u64(0u64);
+
+        let (params, keypair, accumulator, mut state) = setup_positive_accum(&mut rng);
+        let prk = MembershipProvingKey::generate_using_rng(&mut rng);
+        let elem = Fr::rand(&mut rng);
+        let accumulator = accumulator
+            .add(elem, &keypair.secret_key, &mut state)
+            .unwrap();
+        let witness = accumulator
+            .get_membership_witness(&elem, &keypair.secret_key, &state)
+            .unwrap();
+        let protocol = MembershipProofProtocol::init(
+            &mut rng,
+            &elem,
+            None,
+            &witness,
+            &keypair.public_key,
+            &params,
+            &prk,
+        );
+
+        // In compatibility mode, the challenge is same as the one from the contribution bytes
+        let mut chal_bytes = vec![];
+        protocol
+            .challenge_contribution(
+                accumulator.value(),
+                &keypair.public_key,
+                &params,
+                &prk,
+                &mut chal_bytes,
+            )
+            .unwrap();
+        let mut transcript = Transcript::new_compatible();
+        protocol
+            .append_to_transcript(
+                accumulator.value(),
+                &keypair.public_key,
+                &params,
+                &prk,
+                &mut transcript,
+            )
+            .unwrap();
+        assert_eq!(
+            transcript.challenge_scalar::<Fr, Blake2b>(b"challenge"),
+            compute_random_oracle_challenge::<Fr, Blake2b>(&chal_bytes)
+        );
+
+        let mut transcript = Transcript::new(b"test");
+        protocol
+            .append_to_transcript(
+                accumulator.value(),
+                &keypair.public_key,
+                &params,
+                &prk,
+                &mut transcript,
+            )
+            .unwrap();
+        let challenge_prover = transcript.challenge_scalar::<Fr, Blake2b>(b"challenge");
+        let proof = protocol.gen_proof(&challenge_prover);
+
+        let mut transcript = Transcript::new(b"test");
+        proof
+            .append_to_transcript(
+                accumulator.value(),
+                &keypair.public_key,
+                &params,
+                &prk,
+                &mut transcript,
+            )
+            .unwrap();
+        let challenge_verifier = transcript.challenge_scalar::<Fr, Blake2b>(b"challenge");
+        assert_eq!(challenge_prover, challenge_verifier);
+        proof
+            .verify(
+                accumulator.value(),
+                &challenge_verifier,
+                &keypair.public_key,
+                &params,
+                &prk,
+            )
+            .unwrap();
+
+        let (params, keypair, accumulator, _, mut state) = setup_universal_accum(&mut rng, 100);
+        let prk = NonMembershipProvingKey::generate_using_rng(&mut rng);
+        let elem = Fr::rand(&mut rng);
+        let witness = accumulator
+            .get_non_membership_witness(&elem, &keypair.secret_key, &mut state, &params)
+            .unwrap();
+        let protocol = NonMembershipProofProtocol::init(
+            &mut rng,
+            &elem,
+            None,
+            &witness,
+            &keypair.public_key,
+            &params,
+            &prk,
+        );
+
+        let mut chal_bytes = vec![];
+        protocol
+            .challenge_contribution(
+                accumulator.value(),
+                &keypair.public_key,
+                &params,
+                &prk,
+                &mut chal_bytes,
+            )
+            .unwrap();
+        let mut transcript = Transcript::new_compatible();
+        protocol
+            .append_to_transcript(
+                accumulator.value(),
+                &keypair.public_key,
+                &params,
+                &prk,
+                &mut transcript,
+            )
+            .unwrap();
+        assert_eq!(
+            transcript.challenge_scalar::<Fr, Blake2b>(b"challenge"),
+            compute_random_oracle_challenge::<Fr, Blake2b>(&chal_bytes)
+        );
+
+        let mut transcript = Transcript::new(b"test");
+        protocol
+            .append_to_transcript(
+                accumulator.value(),
+                &keypair.public_key,
+                &params,
+                &prk,
+                &mut transcript,
+            )
+            .unwrap();
+        let challenge_prover = transcript.challenge_scalar::<Fr, Blake2b>(b"challenge");
+        let proof = protocol.gen_proof(&challenge_prover);
+
+        let mut transcript = Transcript::new(b"test");
+        proof
+            .append_to_transcript(
+                accumulator.value(),
+                &keypair.public_key,
+                &params,
+                &prk,
+                &mut transcript,
+            )
+            .unwrap();
+        let challenge_verifier = transcript.challenge_scalar::<Fr, Blake2b>(b"challenge");
+        assert_eq!(challenge_prover, challenge_verifier);
+        proof
+            .verify(
+                accumulator.value(),
+                &challenge_verifier,
+                &keypair.public_key,
+                &params,
+                &prk,
+            )
+            .unwrap();
+    }
 }
@@ -8,10 +8,13 @@
 //! ```
 //! use ark_bls12_381::Bls12_381;
 //! use blake2::Blake2b;
+//! use sha2::Sha256;
 //! use vb_accumulator::setup::{Keypair, SetupParams};
 //!
 //! let params = SetupParams::<Bls12_381>::generate_using_rng(&mut rng);
 //! let params_1 = SetupParams::<Bls12_381>::new::<Blake2b>(&[1, 2, 3, 4]);
+//! // Using the hash to curve of RFC 9380 with a domain separation tag
+//! let params_2 = SetupParams::<Bls12_381>::new_using_hash_to_curve::<Sha256>(&[1, 2, 3, 4], b"MY-APP-ACCUM-PARAMS_XMD:SHA-256_SSWU_RO_");
 //!
 //! // Generate keypair using random number generator
 //! let keypair = Keypair::<Bls12_381>::generate_using_rng(&mut rng, &params);
@@ -43,7 +46,8 @@ use digest::{BlockInput, Digest, FixedOutput, Reset, Update};
 use schnorr_pok::{error::SchnorrError, impl_proof_of_knowledge_of_discrete_log};
 
 use dock_crypto_utils::hashing_utils::{
-    field_elem_from_seed, projective_group_elem_from_try_and_incr,
+    field_elem_from_seed, projective_group_elem_from_hash_to_curve,
+    projective_group_elem_from_try_and_incr, HashToCurve,
 };
 use dock_crypto_utils::serde_utils::*;
 
@@ -138,6 +142,27 @@ where
         Self { P, P_tilde }
     }
 
+    /// Generate params by hashing a known string like [`Self::new`] but using the hash to curve
+    /// of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html) with domain separation tag `dst`.
+    /// The hashed messages are the same as in `new`.
+    pub fn new_using_hash_to_curve<D: Digest + BlockInput>(label: &[u8], dst: &[u8]) -> Self
+    where
+        E::G1Affine: HashToCurve,
+        E::G2Affine: HashToCurve,
+    {
+        let P = projective_group_elem_from_hash_to_curve::<E::G1Affine, D>(
+            &to_bytes![label, " : P".as_bytes()].unwrap(),
+            dst,
+        )
+        .into();
+        let P_tilde = projective_group_elem_from_hash_to_curve::<E::G2Affine, D>(
+            &to_bytes![label, " : P_tilde".as_bytes()].unwrap(),
+            dst,
+        )
+        .into();
+        Self { P, P_tilde }
+    }
+
     /// Params shouldn't be 0
     pub fn is_valid(&self) -> bool {
         !self.P.is_zero() && !self.P_tilde.is_zero()
@@ -212,11 +237,12 @@ impl_proof_of_knowledge_of_discrete_log!(PoKSecretKeyInPublicKey, PoKSecretKeyIn
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_serialization;
+    use crate::{test_secret_serialization, test_serialization};
     use ark_bls12_381::Bls12_381;
     use ark_std::rand::{rngs::StdRng, SeedableRng};
     use blake2::Blake2b;
     use schnorr_pok::compute_random_oracle_challenge;
+    use sha2::Sha256;
 
     type Fr = <Bls12_381 as PairingEngine>::Fr;
 
@@ -259,6 +285,31 @@ mod tests {
         drop(keypair);
     }
 
+    #[test]
+    fn params_using_hash_to_curve() {
+        let dst = b"VB-ACCUM-TEST-PARAMS_XMD:SHA-256_SSWU_RO_";
+        let params = SetupParams::<Bls12_381>::new_using_hash_to_curve::<Sha256>(b"test", dst);
+        assert!(params.is_valid());
+        assert!(params.P.is_in_correct_subgroup_assuming_on_curve());
+        assert!(params.P_tilde.is_in_correct_subgroup_assuming_on_curve());
+        assert_eq!(
+            params,
+            SetupParams::<Bls12_381>::new_using_hash_to_curve::<Sha256>(b"test", dst)
+        );
+        assert_ne!(
+            params,
+            SetupParams::<Bls12_381>::new_using_hash_to_curve::<Sha256>(b"test1", dst)
+        );
+        assert_ne!(
+            params,
+            SetupParams::<Bls12_381>::new_using_hash_to_curve::<Sha256>(
+                b"test",
+                b"VB-ACCUM-TEST-PARAMS-OTHER_XMD:SHA-256_SSWU_RO_"
+            )
+        );
+        assert_ne!(params, SetupParams::<Bls12_381>::new::<Sha256>(b"test"));
+    }
+
     #[test]
     fn setup_serialization() {
         let mut rng = StdRng::seed_from_u64(0u64);
@@ -303,7 +354,7 @@ mod tests {
             .challenge_contribution(base, &pk.0, &mut chal_contrib_prover)
             .unwrap();
 
-        test_serialization!(
+        test_secret_serialization!(
             PoKSecretKeyInPublicKey::<<Bls12_381 as PairingEngine>::G2Affine>,
             protocol
         );